@@ -0,0 +1,156 @@
+#!/usr/bin/env python3
+"""Generate `src/mime/db.rs` from a `mime.types` file.
+
+The `mime.types` file shipped by the Debian `media-types` package tracks the
+IANA media types registry, and adds the extensions commonly used for each
+type. Run this script when updating the table:
+
+    python3 scripts/generate_mime_db.py /etc/mime.types > src/mime/db.rs
+"""
+
+import re
+import sys
+
+# Extensions missing from `mime.types`, or which map to a different type on
+# the web than they do in the registry.
+OVERRIDES = {
+    "js": "text/javascript",
+    "mjs": "text/javascript",
+    "json": "application/json",
+    "map": "application/json",
+    "webmanifest": "application/manifest+json",
+    "md": "text/markdown",
+    "markdown": "text/markdown",
+    "wasm": "application/wasm",
+    "woff": "font/woff",
+    "woff2": "font/woff2",
+    "otf": "font/otf",
+    "ttf": "font/ttf",
+    "avif": "image/avif",
+    "heic": "image/heic",
+    "heif": "image/heif",
+    "jxl": "image/jxl",
+    "ico": "image/vnd.microsoft.icon",
+    "mp4": "video/mp4",
+    "m4a": "audio/mp4",
+    "mp3": "audio/mpeg",
+    "wav": "audio/wav",
+    "ogg": "audio/ogg",
+    "opus": "audio/ogg",
+    "xml": "application/xml",
+    "ts": "video/mp2t",
+    "7z": "application/x-7z-compressed",
+    "rar": "application/vnd.rar",
+    "zst": "application/zstd",
+    "br": "application/x-brotli",
+}
+
+# Types which compress well even though they aren't text, or which don't have
+# a `+json` or `+xml` structured syntax suffix.
+COMPRESSIBLE = {
+    "application/ecmascript",
+    "application/javascript",
+    "application/json",
+    "application/manifest+json",
+    "application/postscript",
+    "application/rtf",
+    "application/wasm",
+    "application/x-csh",
+    "application/x-javascript",
+    "application/x-latex",
+    "application/x-sh",
+    "application/x-tar",
+    "application/x-tex",
+    "application/xml",
+    "application/xml-dtd",
+    "application/vnd.ms-fontobject",
+    "font/otf",
+    "font/ttf",
+    "image/bmp",
+    "image/vnd.microsoft.icon",
+    "image/x-icon",
+}
+
+# Text formats which are stored compressed.
+INCOMPRESSIBLE = {
+    "text/event-stream",
+}
+
+# Structured syntax suffixes of text-based formats. Keep in sync with
+# `Mime::is_compressible`.
+TEXT_SUFFIXES = {"json", "xml", "text", "yaml"}
+
+TOKEN = re.compile(r"^[a-z0-9!#$&^_.+-]+/[a-z0-9!#$&^_.+-]+$")
+EXTENSION = re.compile(r"^[a-z0-9_+.-]+$")
+
+
+def suffix(subtype):
+    name, plus, suffix = subtype.rpartition("+")
+    return suffix if plus and name and suffix else None
+
+
+def compressible(essence):
+    if essence in INCOMPRESSIBLE:
+        return False
+    basetype, subtype = essence.split("/")
+    return (
+        basetype == "text"
+        or suffix(subtype) in TEXT_SUFFIXES
+        or essence in COMPRESSIBLE
+    )
+
+
+def main(path):
+    types = {}
+    with open(path) as f:
+        for line in f:
+            fields = line.split("#", 1)[0].split()
+            if not fields:
+                continue
+            essence = fields[0].lower()
+            assert TOKEN.match(essence), essence
+            exts = types.setdefault(essence, [])
+            for ext in fields[1:]:
+                ext = ext.lower()
+                # Skip backup file suffixes such as `~`.
+                if not EXTENSION.match(ext):
+                    continue
+                if ext not in exts:
+                    exts.append(ext)
+
+    for ext, essence in OVERRIDES.items():
+        exts = types.setdefault(essence, [])
+        if ext not in exts:
+            exts.append(ext)
+
+    # The first type listing an extension as its primary extension wins,
+    # followed by the first type listing it at all.
+    by_ext = dict(OVERRIDES)
+    for primary in (True, False):
+        for essence, exts in sorted(types.items()):
+            for n, ext in enumerate(exts):
+                if (n == 0) == primary:
+                    by_ext.setdefault(ext, essence)
+
+    out = sys.stdout
+    out.write("// This file is generated by scripts/generate_mime_db.py\n")
+    out.write("// Do not edit manually\n\n")
+    out.write("/// Extensions and the media type they map to, sorted by extension.\n")
+    out.write("#[rustfmt::skip]\n")
+    out.write("pub(crate) static EXTENSIONS: &[(&str, &str)] = &[\n")
+    for ext, essence in sorted(by_ext.items()):
+        out.write(f'    ("{ext}", "{essence}"),\n')
+    out.write("];\n\n")
+    out.write("/// Media types, their extensions, and whether they are compressible,\n")
+    out.write("/// sorted by media type.\n")
+    out.write("#[rustfmt::skip]\n")
+    out.write("pub(crate) static TYPES: &[(&str, &[&str], bool)] = &[\n")
+    for essence, exts in sorted(types.items()):
+        exts = ", ".join(f'"{ext}"' for ext in exts)
+        flag = "true" if compressible(essence) else "false"
+        out.write(f'    ("{essence}", &[{exts}], {flag}),\n')
+    out.write("];\n")
+
+
+if __name__ == "__main__":
+    main(sys.argv[1] if len(sys.argv) > 1 else "/etc/mime.types")
@@ -1,10 +1,11 @@
 use crate::headers::{HeaderName, HeaderValue, Headers, AUTHORIZATION};
-use crate::Status;
 use crate::{
-    auth::{AuthenticationScheme, Authorization},
+    auth::{AuthenticationScheme, Authorization, WwwAuthenticate},
     headers::Header,
 };
-use crate::{bail_status as bail, ensure_status as ensure};
+use crate::{Error, StatusCode};
+
+use std::fmt::{self, Display};
 
 /// HTTP Basic authorization.
 ///
@@ -53,40 +54,97 @@ impl BasicAuth {
     }
 
     /// Create a new instance from headers.
+    ///
+    /// Decoding failures carry a [`BasicAuthError`] which can be retrieved
+    /// with [`Error::downcast_ref`](crate::Error::downcast_ref).
     pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
         let auth = match Authorization::from_headers(headers)? {
             Some(auth) => auth,
             None => return Ok(None),
         };
 
-        let scheme = auth.scheme();
-        ensure!(
-            matches!(scheme, AuthenticationScheme::Basic),
-            400,
-            "Expected basic auth scheme found `{}`",
-            scheme
-        );
+        if auth.scheme() != AuthenticationScheme::Basic {
+            return Err(BasicAuthError::WrongScheme.into_error());
+        }
         Self::from_credentials(auth.credentials()).map(Some)
     }
 
+    /// Create a new instance from headers, treating a missing `Authorization`
+    /// header as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Request;
+    /// use http_types::auth::{BasicAuth, BasicAuthError};
+    ///
+    /// let req = Request::get("https://example.com");
+    /// let err = BasicAuth::require(&req).unwrap_err();
+    /// assert_eq!(err.status(), 401);
+    /// assert_eq!(err.downcast_ref(), Some(&BasicAuthError::MissingHeader));
+    /// ```
+    pub fn require(headers: impl AsRef<Headers>) -> crate::Result<Self> {
+        match Self::from_headers(headers)? {
+            Some(auth) => Ok(auth),
+            None => Err(BasicAuthError::MissingHeader.into_error()),
+        }
+    }
+
     /// Create a new instance from the base64 encoded credentials.
+    ///
+    /// The decoded credentials are expected to be UTF-8, as advertised by
+    /// [`BasicAuth::challenge`].
     pub fn from_credentials(credentials: impl AsRef<[u8]>) -> crate::Result<Self> {
-        let bytes = base64::decode(credentials).status(400)?;
-        let credentials = String::from_utf8(bytes).status(400)?;
-
-        let mut iter = credentials.splitn(2, ':');
-        let username = iter.next();
-        let password = iter.next();
+        let bytes =
+            base64::decode(credentials).map_err(|_| BasicAuthError::InvalidBase64.into_error())?;
+        let credentials =
+            String::from_utf8(bytes).map_err(|_| BasicAuthError::InvalidUtf8.into_error())?;
 
-        let (username, password) = match (username, password) {
-            (Some(username), Some(password)) => (username.to_string(), password.to_string()),
-            (Some(_), None) => bail!(400, "Expected basic auth to contain a password"),
-            (None, _) => bail!(400, "Expected basic auth to contain a username"),
+        let (username, password) = match credentials.split_once(':') {
+            Some((username, password)) => (username.to_string(), password.to_string()),
+            None => return Err(BasicAuthError::MissingColon.into_error()),
         };
 
         Ok(Self { username, password })
     }
 
+    /// Create a `WWW-Authenticate` challenge for the `Basic` scheme which asks
+    /// clients to encode credentials as UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::auth::BasicAuth;
+    /// use http_types::headers::Header;
+    ///
+    /// let challenge = BasicAuth::challenge("staging");
+    /// assert_eq!(challenge.header_value(), r#"Basic realm="staging", charset="UTF-8""#);
+    /// ```
+    pub fn challenge(realm: impl Into<String>) -> WwwAuthenticate {
+        WwwAuthenticate::new(AuthenticationScheme::Basic, realm.into())
+    }
+
+    /// Check whether these credentials match the given username and password.
+    ///
+    /// The comparison takes the same amount of time regardless of where the
+    /// inputs differ, so it does not leak how much of a credential was guessed
+    /// correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::auth::BasicAuth;
+    ///
+    /// let authz = BasicAuth::new("nori", "secret_fish!!");
+    /// assert!(authz.verify("nori", "secret_fish!!"));
+    /// assert!(!authz.verify("nori", "secret_fish"));
+    /// ```
+    pub fn verify(&self, username: impl AsRef<str>, password: impl AsRef<str>) -> bool {
+        let username = constant_time_eq(self.username.as_bytes(), username.as_ref().as_bytes());
+        let password = constant_time_eq(self.password.as_bytes(), password.as_ref().as_bytes());
+        username & password
+    }
+
     /// Get the username.
     pub fn username(&self) -> &str {
         self.username.as_str()
@@ -111,6 +169,59 @@ impl Header for BasicAuth {
     }
 }
 
+/// Compare two byte slices without short-circuiting on the first difference.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let len = a.len().max(b.len());
+    let mut diff = (a.len() != b.len()) as u8;
+    for i in 0..len {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        // Keep the optimizer from exiting the loop early once `diff` is set.
+        diff = std::hint::black_box(diff | (x ^ y));
+    }
+    std::hint::black_box(diff) == 0
+}
+
+/// The reason `Basic` credentials could not be decoded.
+///
+/// Errors produced by [`BasicAuth`] carry a `401 Unauthorized` status, so a
+/// server can reply with a [`BasicAuth::challenge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BasicAuthError {
+    /// The request did not contain an `Authorization` header.
+    MissingHeader,
+    /// The `Authorization` header used a scheme other than `Basic`.
+    WrongScheme,
+    /// The credentials were not valid base64.
+    InvalidBase64,
+    /// The decoded credentials were not valid UTF-8.
+    InvalidUtf8,
+    /// The decoded credentials did not contain a `:` separating the username
+    /// from the password.
+    MissingColon,
+}
+
+impl Display for BasicAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "Expected an Authorization header"),
+            Self::WrongScheme => write!(f, "Expected basic auth scheme"),
+            Self::InvalidBase64 => write!(f, "Expected basic auth credentials to be base64"),
+            Self::InvalidUtf8 => write!(f, "Expected basic auth credentials to be UTF-8"),
+            Self::MissingColon => write!(f, "Expected basic auth to contain a password"),
+        }
+    }
+}
+
+impl std::error::Error for BasicAuthError {}
+
+impl BasicAuthError {
+    fn into_error(self) -> Error {
+        Error::new(StatusCode::Unauthorized, self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -141,4 +252,35 @@ mod test {
         let err = BasicAuth::from_headers(headers).unwrap_err();
         assert_eq!(err.status(), 400);
     }
+
+    #[test]
+    fn error_kinds() -> crate::Result<()> {
+        let kind = |value: &str| {
+            let mut headers = Headers::new();
+            headers.insert(AUTHORIZATION, value).unwrap();
+            let err = BasicAuth::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 401);
+            *err.downcast_ref::<BasicAuthError>().unwrap()
+        };
+
+        assert_eq!(kind("Bearer 0xdeadbeef"), BasicAuthError::WrongScheme);
+        assert_eq!(kind("Basic !!!"), BasicAuthError::InvalidBase64);
+        assert_eq!(kind("Basic bm9yaQ=="), BasicAuthError::MissingColon);
+        assert_eq!(kind("Basic /w=="), BasicAuthError::InvalidUtf8);
+
+        let err = BasicAuth::require(Headers::new()).unwrap_err();
+        assert_eq!(err.downcast_ref(), Some(&BasicAuthError::MissingHeader));
+        Ok(())
+    }
+
+    #[test]
+    fn verify() {
+        let authz = BasicAuth::new("nori", "secret_fish!!");
+        assert!(authz.verify("nori", "secret_fish!!"));
+        assert!(!authz.verify("chashu", "secret_fish!!"));
+        assert!(!authz.verify("nori", "secret_fish!"));
+        assert!(!authz.verify("nori", ""));
+        assert!(constant_time_eq(b"", b""));
+        assert!(!constant_time_eq(&[0; 256], &[]));
+    }
 }
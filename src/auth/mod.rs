@@ -30,5 +30,5 @@ mod www_authenticate;
 
 pub use authentication_scheme::AuthenticationScheme;
 pub use authorization::Authorization;
-pub use basic_auth::BasicAuth;
+pub use basic_auth::{BasicAuth, BasicAuthError};
 pub use www_authenticate::WwwAuthenticate;
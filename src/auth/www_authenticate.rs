@@ -1,5 +1,6 @@
 use crate::bail_status as bail;
 use crate::headers::{HeaderName, HeaderValue, Headers, WWW_AUTHENTICATE};
+use crate::parse_utils::{parse_quoted_string, parse_token};
 use crate::{auth::AuthenticationScheme, headers::Header};
 
 /// Define the authentication method that should be used to gain access to a
@@ -8,11 +9,14 @@ use crate::{auth::AuthenticationScheme, headers::Header};
 /// # Specifications
 ///
 /// - [RFC 7235, section 4.1: WWW-Authenticate](https://tools.ietf.org/html/rfc7235#section-4.1)
+/// - [RFC 7617, section 2.1: The 'charset' auth-param](https://tools.ietf.org/html/rfc7617#section-2.1)
 ///
 /// # Implementation Notes
 ///
 /// This implementation only encodes and parses a single authentication method,
-/// further authorization methods are ignored. It also always passes the utf-8 encoding flag.
+/// further authorization methods are ignored. Challenges created through
+/// `WwwAuthenticate::new` pass the utf-8 encoding flag by default; use
+/// `set_charset` to change this.
 ///
 /// # Examples
 ///
@@ -40,12 +44,17 @@ use crate::{auth::AuthenticationScheme, headers::Header};
 pub struct WwwAuthenticate {
     scheme: AuthenticationScheme,
     realm: String,
+    charset: Option<String>,
 }
 
 impl WwwAuthenticate {
     /// Create a new instance of `WwwAuthenticate`.
     pub fn new(scheme: AuthenticationScheme, realm: String) -> Self {
-        Self { scheme, realm }
+        Self {
+            scheme,
+            realm,
+            charset: Some(String::from("UTF-8")),
+        }
     }
 
     /// Create a new instance from headers.
@@ -62,35 +71,64 @@ impl WwwAuthenticate {
         let mut iter = value.as_str().splitn(2, ' ');
         let scheme = iter.next();
         let credential = iter.next();
-        let (scheme, realm) = match (scheme, credential) {
+        let (scheme, params) = match (scheme, credential) {
             (None, _) => bail!(400, "Could not find scheme"),
             (Some(_), None) => bail!(400, "Could not find realm"),
-            (Some(scheme), Some(realm)) => (scheme.parse()?, realm.to_owned()),
+            (Some(scheme), Some(params)) => (scheme.parse()?, params),
         };
 
-        let realm = realm.trim_start();
-        let realm = match realm.strip_prefix(r#"realm=""#) {
+        let mut realm = None;
+        let mut charset = None;
+        let mut input = params.trim_start();
+        let mut first = true;
+        while !input.is_empty() {
+            let (name, rest) = match parse_token(input) {
+                (Some(name), rest) => (name, rest),
+                (None, _) => bail!(400, "Expected an auth-param name"),
+            };
+            let rest = match rest.strip_prefix('=') {
+                Some(rest) => rest,
+                // A token without `=` after a comma starts the next challenge.
+                None if !first => break,
+                None => bail!(400, "Expected `=` after auth-param `{}`", name),
+            };
+            first = false;
+            let (value, rest) = if rest.starts_with('"') {
+                match parse_quoted_string(rest) {
+                    (Some(value), rest) => (value.into_owned(), rest),
+                    (None, _) => bail!(400, r"Expected a closing quote"),
+                }
+            } else {
+                match parse_token(rest) {
+                    (Some(value), rest) => (value.to_owned(), rest),
+                    (None, _) => bail!(400, "Expected a value for auth-param `{}`", name),
+                }
+            };
+
+            if name.eq_ignore_ascii_case("realm") {
+                realm = Some(value);
+            } else if name.eq_ignore_ascii_case("charset") {
+                charset = Some(value);
+            }
+
+            input = rest.trim_start();
+            if let Some(rest) = input.strip_prefix(',') {
+                input = rest.trim_start();
+            } else if !input.is_empty() {
+                bail!(400, "Expected a `,` between auth-params");
+            }
+        }
+
+        let realm = match realm {
             Some(realm) => realm,
             None => bail!(400, "realm not found"),
         };
 
-        let mut chars = realm.chars();
-        let mut closing_quote = false;
-        let realm = (&mut chars)
-            .take_while(|c| {
-                if c == &'"' {
-                    closing_quote = true;
-                    false
-                } else {
-                    true
-                }
-            })
-            .collect();
-        if !closing_quote {
-            bail!(400, r"Expected a closing quote");
-        }
-
-        Ok(Some(Self { scheme, realm }))
+        Ok(Some(Self {
+            scheme,
+            realm,
+            charset,
+        }))
     }
 
     /// Get the authorization scheme.
@@ -109,9 +147,36 @@ impl WwwAuthenticate {
     }
 
     /// Set the authorization realm.
+    ///
+    /// Characters that can't be sent in a header, such as line breaks and
+    /// non-ASCII characters, are sent as `?`.
     pub fn set_realm(&mut self, realm: String) {
         self.realm = realm;
     }
+
+    /// Get the charset the server expects credentials to be encoded with.
+    pub fn charset(&self) -> Option<&str> {
+        self.charset.as_deref()
+    }
+
+    /// Set the charset the server expects credentials to be encoded with.
+    ///
+    /// [RFC 7617](https://tools.ietf.org/html/rfc7617#section-2.1) only allows
+    /// `"UTF-8"` for the `Basic` scheme. Pass `None` to omit the parameter.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the charset isn't a
+    /// token.
+    pub fn set_charset(&mut self, charset: Option<String>) -> crate::Result<()> {
+        if let Some(charset) = &charset {
+            if !matches!(parse_token(charset), (Some(_), "")) {
+                bail!(400, "Invalid charset `{}`", charset);
+            }
+        }
+        self.charset = charset;
+        Ok(())
+    }
 }
 
 impl Header for WwwAuthenticate {
@@ -120,13 +185,33 @@ impl Header for WwwAuthenticate {
     }
 
     fn header_value(&self) -> HeaderValue {
-        let output = format!(r#"{} realm="{}", charset="UTF-8""#, self.scheme, self.realm);
+        let mut output = format!(r#"{} realm="{}""#, self.scheme, quote(&self.realm));
+        if let Some(charset) = &self.charset {
+            output.push_str(&format!(r#", charset="{}""#, quote(charset)));
+        }
 
-        // SAFETY: the internal string is validated to be ASCII.
+        // SAFETY: `quote` only outputs ASCII, and the scheme is a token.
         unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
     }
 }
 
+/// Escape a value for use in a quoted-string, replacing the characters that
+/// can't be part of one with `?`.
+fn quote(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                output.push('\\');
+                output.push(c);
+            }
+            '\t' | ' '..='~' => output.push(c),
+            _ => output.push('?'),
+        }
+    }
+    output
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -153,6 +238,68 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn charset() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(WWW_AUTHENTICATE, r#"Basic charset=UTF-8, realm="foo""#)?;
+        let authz = WwwAuthenticate::from_headers(&headers)?.unwrap();
+        assert_eq!(authz.realm(), "foo");
+        assert_eq!(authz.charset(), Some("UTF-8"));
+
+        let mut headers = Headers::new();
+        headers.insert(WWW_AUTHENTICATE, r#"Bearer realm="foo""#)?;
+        let mut authz = WwwAuthenticate::from_headers(&headers)?.unwrap();
+        assert_eq!(authz.charset(), None);
+
+        authz.set_charset(None)?;
+        assert_eq!(authz.header_value(), r#"Bearer realm="foo""#);
+
+        for invalid in &[
+            "UTF-8\r\nSet-Cookie: a=b",
+            "UTF 8",
+            "\"UTF-8\"",
+            "ütf-8",
+            "",
+        ] {
+            let err = authz.set_charset(Some(invalid.to_string())).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", invalid);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn realm_round_trip() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(WWW_AUTHENTICATE, r#"Basic realm="a\"b\\c""#)?;
+        let authz = WwwAuthenticate::from_headers(&headers)?.unwrap();
+        assert_eq!(authz.realm(), r#"a"b\c"#);
+
+        authz.apply_header(&mut headers);
+        let mut authz = WwwAuthenticate::from_headers(&headers)?.unwrap();
+        assert_eq!(authz.realm(), r#"a"b\c"#);
+
+        authz.set_realm("caf\u{e9}\r\nSet-Cookie: a=b".into());
+        assert_eq!(
+            authz.header_value(),
+            r#"Basic realm="caf???Set-Cookie: a=b""#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn further_challenges_are_ignored() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(
+            WWW_AUTHENTICATE,
+            r#"Basic realm="a", charset="UTF-8", Bearer realm="b""#,
+        )?;
+        let authz = WwwAuthenticate::from_headers(&headers)?.unwrap();
+        assert_eq!(authz.scheme(), AuthenticationScheme::Basic);
+        assert_eq!(authz.realm(), "a");
+        assert_eq!(authz.charset(), Some("UTF-8"));
+        Ok(())
+    }
+
     #[test]
     fn bad_request_on_parse_error() {
         let mut headers = Headers::new();
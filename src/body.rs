@@ -6,7 +6,11 @@ use std::convert::TryFrom;
 use std::fmt::{self, Debug};
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::conditional::ETag;
+#[cfg(feature = "serde")]
+use crate::content::UnsupportedMediaType;
 use crate::mime::{self, Mime};
 use crate::{Status, StatusCode};
 
@@ -59,6 +63,7 @@ pin_project_lite::pin_project! {
         mime: Option<Mime>,
         length: Option<u64>,
         bytes_read: u64,
+        modified: Option<SystemTime>,
     }
 }
 
@@ -82,6 +87,7 @@ impl Body {
             mime: Some(mime::BYTE_STREAM),
             length: Some(0),
             bytes_read: 0,
+            modified: None,
         }
     }
 
@@ -113,6 +119,7 @@ impl Body {
             mime: Some(mime::BYTE_STREAM),
             length,
             bytes_read: 0,
+            modified: None,
         }
     }
 
@@ -157,6 +164,7 @@ impl Body {
             length: Some(bytes.len() as u64),
             reader: Box::new(io::Cursor::new(bytes)),
             bytes_read: 0,
+            modified: None,
         }
     }
 
@@ -207,11 +215,81 @@ impl Body {
             length: Some(s.len() as u64),
             reader: Box::new(io::Cursor::new(s.into_bytes())),
             bytes_read: 0,
+            modified: None,
         }
     }
 
+    /// Create a `Body` from a string, encoded using the given charset.
+    ///
+    /// The charset is looked up using the [WHATWG encoding
+    /// labels](https://encoding.spec.whatwg.org/#names-and-labels), which
+    /// makes this suitable for encoding a response in the charset returned by
+    /// [`AcceptCharset::negotiate`](crate::content::AcceptCharset::negotiate).
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `text/plain`, with the `charset` parameter set
+    /// to the requested charset.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with a status of `406` if the charset is unknown,
+    /// or if the string contains characters which cannot be represented in
+    /// the charset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::content::AcceptCharset;
+    /// use http_types::{Body, Request, Response};
+    ///
+    /// let mut req = Request::get("https://example.com");
+    /// req.insert_header("Accept-Charset", "iso-8859-1");
+    /// let accept = AcceptCharset::from_headers(&req)?.unwrap();
+    /// let charset = accept.negotiate(&["utf-8", "iso-8859-1"])?;
+    ///
+    /// let mut res = Response::new(200);
+    /// res.set_body(Body::from_string_with_charset("Grüße", charset)?);
+    /// assert_eq!(res["Content-Type"], "text/plain;charset=iso-8859-1");
+    /// assert_eq!(res.body_bytes().await?, b"Gr\xfc\xdfe");
+    /// # Ok(()) }) }
+    /// ```
+    #[cfg(feature = "charset")]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "charset")))]
+    pub fn from_string_with_charset(s: impl AsRef<str>, charset: &str) -> crate::Result<Self> {
+        let charset = charset.trim().to_ascii_lowercase();
+        let encoding = match encoding_rs::Encoding::for_label(charset.as_bytes()) {
+            Some(encoding) => encoding.output_encoding(),
+            None => crate::bail_status!(406, "Unknown charset `{}`", charset),
+        };
+        if encoding == encoding_rs::UTF_8 {
+            return Ok(Self::from_string(s.as_ref().to_owned()));
+        }
+
+        let (bytes, _, unmappable) = encoding.encode(s.as_ref());
+        if unmappable || !in_repertoire(&charset, encoding, &bytes) {
+            crate::bail_status!(406, "String cannot be encoded as `{}`", charset);
+        }
+        let mime = format!("text/plain;charset={}", charset);
+        let mut body = Self::from_bytes(bytes.into_owned());
+        body.set_mime(Some(mime.parse()?));
+        Ok(body)
+    }
+
     /// Read the body as a string
     ///
+    /// With the `charset` feature enabled, the body is decoded using the
+    /// `charset` parameter of its mime type, which is looked up using the
+    /// [WHATWG encoding labels](https://encoding.spec.whatwg.org/#names-and-labels).
+    /// Otherwise, and if no charset is declared, the body is read as UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with a status of `422` if the body is not valid in
+    /// its charset. With the `charset` feature enabled, an unknown charset
+    /// errors with a status of `415`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -225,6 +303,15 @@ impl Body {
     /// # Ok(()) }) }
     /// ```
     pub async fn into_string(mut self) -> crate::Result<String> {
+        #[cfg(feature = "charset")]
+        if let Some(encoding) = charset_encoding(self.mime.as_ref())? {
+            let bytes = self.into_bytes().await?;
+            return match encoding.decode_without_bom_handling_and_without_replacement(&bytes) {
+                Some(s) => Ok(s.into_owned()),
+                None => crate::bail_status!(422, "Body is not valid `{}`", encoding.name()),
+            };
+        }
+
         let len = usize::try_from(self.len().unwrap_or(0)).status(StatusCode::PayloadTooLarge)?;
         let mut result = String::with_capacity(len);
         self.read_to_string(&mut result)
@@ -255,6 +342,7 @@ impl Body {
             reader: Box::new(io::Cursor::new(bytes)),
             mime: Some(mime::JSON),
             bytes_read: 0,
+            modified: None,
         };
         Ok(body)
     }
@@ -286,6 +374,44 @@ impl Body {
         serde_json::from_slice(&buf).status(StatusCode::UnprocessableEntity)
     }
 
+    /// Parse the body as JSON, after checking its mime type is JSON.
+    ///
+    /// Both `application/json` and types with the `+json` suffix, such as
+    /// `application/problem+json`, are accepted.
+    ///
+    /// # Errors
+    ///
+    /// If the mime type is missing or not JSON, an error with the status of
+    /// `415` is returned. It wraps an
+    /// [`UnsupportedMediaType`](crate::content::UnsupportedMediaType).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::convert::{json, Deserialize};
+    /// use http_types::Body;
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// # #[serde(crate = "serde_crate")]
+    /// struct Problem { title: String }
+    ///
+    /// let mut body = Body::from_json(&json!({ "title": "Not Found" }))?;
+    /// body.set_mime(Some("application/problem+json".parse()?));
+    /// let problem: Problem = body.into_json_strict().await?;
+    /// assert_eq!(&problem.title, "Not Found");
+    ///
+    /// let body = Body::from_string(r#"{ "title": "Not Found" }"#.into());
+    /// let err = body.into_json_strict::<Problem>().await.unwrap_err();
+    /// assert_eq!(err.status(), 415);
+    /// # Ok(()) }) }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub async fn into_json_strict<T: DeserializeOwned>(self) -> crate::Result<T> {
+        UnsupportedMediaType::check(self.mime.clone(), &[mime::JSON], Mime::is_json)?;
+        self.into_json().await
+    }
+
     /// Creates a `Body` from a type, serializing it using form encoding.
     ///
     /// # Mime
@@ -324,6 +450,7 @@ impl Body {
             reader: Box::new(io::Cursor::new(bytes)),
             mime: Some(mime::FORM),
             bytes_read: 0,
+            modified: None,
         };
         Ok(body)
     }
@@ -434,7 +561,8 @@ impl Body {
         mut file: async_std::fs::File,
         path: &std::path::Path,
     ) -> io::Result<Self> {
-        let len = file.metadata().await?.len();
+        let metadata = file.metadata().await?;
+        let len = metadata.len();
 
         // Look at magic bytes first, look at extension second, fall back to
         // octet stream.
@@ -448,6 +576,7 @@ impl Body {
             length: Some(len),
             reader: Box::new(io::BufReader::new(file)),
             bytes_read: 0,
+            modified: metadata.modified().ok(),
         })
     }
 
@@ -496,6 +625,60 @@ impl Body {
         self.mime = mime;
     }
 
+    /// Returns the modification time of the file this body was created from.
+    ///
+    /// This is only set for bodies created with [`Body::from_path`],
+    /// [`Body::from_file`] or [`Body::from_file_with_path`], and is used to
+    /// derive weak ETags and `Last-Modified` headers.
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
+
+    /// Read the body into a `Vec<u8>`, computing a strong ETag from its
+    /// contents as it is read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let body = Body::from_string("Hello, Nori!".to_string());
+    /// let (bytes, etag) = body.into_bytes_with_etag().await?;
+    /// assert_eq!(bytes, b"Hello, Nori!");
+    /// assert!(etag.is_strong());
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn into_bytes_with_etag(mut self) -> crate::Result<(Vec<u8>, ETag)> {
+        let mut bytes = Vec::with_capacity(1024);
+        let mut hasher = sha1_smol::Sha1::new();
+        let mut buf = [0; 8 * 1024];
+        loop {
+            let n = self
+                .read(&mut buf)
+                .await
+                .status(StatusCode::UnprocessableEntity)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buf[..n]);
+            bytes.extend_from_slice(&buf[..n]);
+        }
+        let digest = hasher.digest().bytes();
+        let etag = ETag::new(base64::encode_config(digest, base64::URL_SAFE_NO_PAD));
+        Ok((bytes, etag))
+    }
+
+    /// Create a weak ETag from the size and modification time of the file
+    /// this body was created from.
+    ///
+    /// Returns `None` if the body wasn't created from a file.
+    pub fn weak_etag(&self) -> Option<ETag> {
+        let modified = self.modified?.duration_since(UNIX_EPOCH).ok()?;
+        let etag = format!("{:x}-{:x}", self.length?, modified.as_secs());
+        Some(ETag::new_weak(etag))
+    }
+
     /// Create a Body by chaining another Body after this one, consuming both.
     ///
     /// If both Body instances have a length, and their sum does not overflow,
@@ -532,6 +715,7 @@ impl Body {
             length,
             reader: Box::new(futures_lite::io::AsyncReadExt::chain(self, other)),
             bytes_read: 0,
+            modified: None,
         }
     }
 }
@@ -626,12 +810,60 @@ async fn peek_mime(file: &mut async_std::fs::File) -> io::Result<Option<Mime>> {
     Ok(mime)
 }
 
+/// Look up the encoding declared by the `charset` parameter of a mime type.
+///
+/// Returns `None` if no charset is declared, or if it's UTF-8.
+#[cfg(feature = "charset")]
+fn charset_encoding(mime: Option<&Mime>) -> crate::Result<Option<&'static encoding_rs::Encoding>> {
+    let charset = match mime.and_then(|mime| mime.param("charset")) {
+        Some(charset) => charset,
+        None => return Ok(None),
+    };
+    match encoding_rs::Encoding::for_label(charset.as_str().as_bytes()) {
+        Some(encoding) if encoding == encoding_rs::UTF_8 => Ok(None),
+        Some(encoding) => Ok(Some(encoding)),
+        None => crate::bail_status!(415, "Unsupported charset `{}`", charset),
+    }
+}
+
+/// Check that bytes encoded with a WHATWG encoding mean the same in the
+/// charset that was asked for.
+///
+/// The WHATWG maps labels such as `us-ascii`, `iso-8859-1` and `iso-8859-9`
+/// to Windows code pages, which use `0x80..=0x9F` for printable characters
+/// where the ISO charsets have control codes.
+#[cfg(feature = "charset")]
+fn in_repertoire(charset: &str, encoding: &'static encoding_rs::Encoding, bytes: &[u8]) -> bool {
+    const ASCII: &[&str] = &[
+        "ansi_x3.4-1968",
+        "ascii",
+        "cp367",
+        "csascii",
+        "ibm367",
+        "iso-ir-6",
+        "iso646-us",
+        "iso_646.irv:1991",
+        "us",
+        "us-ascii",
+    ];
+    const WINDOWS: &[&str] = &["windows-", "cp125", "x-cp125", "dos-874"];
+
+    if ASCII.contains(&charset) {
+        bytes.is_ascii()
+    } else if encoding.name().starts_with("windows-")
+        && !WINDOWS.iter().any(|prefix| charset.starts_with(prefix))
+    {
+        !bytes.iter().any(|b| (0x80..=0x9F).contains(b))
+    } else {
+        true
+    }
+}
+
 /// Look at the extension of a file to determine the mime type.
 /// This is useful for plain-text formats such as HTML and CSS.
 #[cfg(all(feature = "fs", not(target_os = "unknown")))]
 fn guess_ext(path: &std::path::Path) -> Option<Mime> {
-    let ext = path.extension().and_then(|p| p.to_str());
-    ext.and_then(Mime::from_extension)
+    Mime::from_path(path)
 }
 
 #[cfg(test)]
@@ -653,6 +885,26 @@ mod test {
         assert_eq!(res.unwrap_err().status(), 422);
     }
 
+    #[async_std::test]
+    async fn json_strict() -> crate::Result<()> {
+        for json in &[
+            "application/json",
+            "application/vnd.api+json; charset=utf-8",
+        ] {
+            let mut body = Body::from_string("[1]".into());
+            body.set_mime(Some(json.parse()?));
+            assert_eq!(body.into_json_strict::<Vec<u8>>().await?, [1]);
+        }
+
+        for other in &["text/plain", "application/jsonx", "application/json-seq"] {
+            let mut body = Body::from_string("[1]".into());
+            body.set_mime(Some(other.parse()?));
+            let err = body.into_json_strict::<Vec<u8>>().await.unwrap_err();
+            assert_eq!(err.status(), 415, "{}", other);
+        }
+        Ok(())
+    }
+
     #[async_std::test]
     async fn form_status() {
         #[derive(Debug, Deserialize)]
@@ -879,4 +1131,92 @@ mod test {
 
         Ok(())
     }
+
+    #[async_std::test]
+    async fn strong_etag() -> crate::Result<()> {
+        let body = Body::from_reader(Cursor::new("abc"), None);
+        let (bytes, etag) = body.into_bytes_with_etag().await?;
+        assert_eq!(bytes, b"abc");
+        assert_eq!(etag, ETag::new("qZk-NkcGgWq6PiVxeFDCbJzQ2J0".into()));
+        assert!(Body::from("abc").weak_etag().is_none());
+        Ok(())
+    }
+
+    #[cfg(all(feature = "fs", not(target_os = "unknown")))]
+    #[async_std::test]
+    async fn file_etag() -> crate::Result<()> {
+        let path = "tests/fixtures/index.html";
+        let metadata = std::fs::metadata(path)?;
+        let modified = metadata.modified()?.duration_since(UNIX_EPOCH)?;
+
+        let body = Body::from_path(path).await?;
+        assert_eq!(body.modified(), Some(metadata.modified()?));
+        let expected = format!("{:x}-{:x}", metadata.len(), modified.as_secs());
+        assert_eq!(body.weak_etag(), Some(ETag::new_weak(expected)));
+
+        let mut res = crate::Response::new(200);
+        res.set_body(body);
+        res.with_etag().await?;
+        assert!(ETag::from_headers(&res)?.unwrap().is_weak());
+        let last_modified = crate::conditional::LastModified::from_headers(&res)?.unwrap();
+        let elapsed = metadata
+            .modified()?
+            .duration_since(last_modified.modified())?;
+        assert_eq!(elapsed.as_secs(), 0);
+        Ok(())
+    }
+
+    #[cfg(feature = "charset")]
+    #[async_std::test]
+    async fn decode_charset() -> crate::Result<()> {
+        let mut body = Body::from_bytes(b"caf\xe9".to_vec());
+        body.set_mime(Some("text/plain;charset=ISO-8859-1".parse()?));
+        assert_eq!(body.into_string().await?, "café");
+
+        let mut body = Body::from_bytes(vec![0x82, 0xa0, 0x82, 0xa2]);
+        body.set_mime(Some("text/plain;charset=shift_jis".parse()?));
+        assert_eq!(body.into_string().await?, "あい");
+
+        let mut body = Body::from_bytes(vec![0x82]);
+        body.set_mime(Some("text/plain;charset=shift_jis".parse()?));
+        assert_eq!(body.into_string().await.unwrap_err().status(), 422);
+
+        let mut body = Body::from_bytes(b"hello".to_vec());
+        body.set_mime(Some("text/plain;charset=x-unknown".parse()?));
+        assert_eq!(body.into_string().await.unwrap_err().status(), 415);
+        Ok(())
+    }
+
+    #[cfg(feature = "charset")]
+    #[async_std::test]
+    async fn encode_charset() -> crate::Result<()> {
+        let body = Body::from_string_with_charset("あい", "Shift_JIS")?;
+        assert_eq!(body.mime().unwrap().param("charset").unwrap(), "shift_jis");
+        assert_eq!(body.into_string().await?, "あい");
+
+        let body = Body::from_string_with_charset("café", "utf-8")?;
+        assert_eq!(body.mime(), Some(&mime::PLAIN));
+
+        let body = Body::from_string_with_charset("€", "windows-1252")?;
+        assert_eq!(
+            body.mime().unwrap().param("charset").unwrap(),
+            "windows-1252"
+        );
+        assert_eq!(body.into_bytes().await?, b"\x80");
+
+        let body = Body::from_string_with_charset("café", "Latin1")?;
+        assert_eq!(body.mime().unwrap().param("charset").unwrap(), "latin1");
+        assert_eq!(body.into_bytes().await?, b"caf\xe9");
+
+        for (s, charset) in &[
+            ("あい", "latin1"),
+            ("€", "iso-8859-1"),
+            ("café", "us-ascii"),
+            ("hello", "x-unknown"),
+        ] {
+            let err = Body::from_string_with_charset(s, charset).unwrap_err();
+            assert_eq!(err.status(), 406, "{}", charset);
+        }
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 use headers::Header;
 
 use crate::headers::{HeaderName, HeaderValue, Headers, CACHE_CONTROL};
+use crate::parse_utils::{parse_quoted_string, parse_token};
 use crate::{cache::CacheDirective, headers};
 
 use std::fmt::{self, Debug, Write};
@@ -31,35 +32,85 @@ use std::slice;
 /// #
 /// # Ok(()) }
 /// ```
+///
+/// # Targeted Cache-Control Fields
+///
+/// Header fields which use the same syntax but only apply to some caches,
+/// such as `CDN-Cache-Control` and `Surrogate-Control`, can be parsed and
+/// emitted by passing their name to [`CacheControl::new_targeted`] and
+/// [`CacheControl::from_targeted_headers`].
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Response;
+/// use http_types::cache::{CacheControl, CacheDirective, CacheExtension};
+/// use http_types::headers::CDN_CACHE_CONTROL;
+///
+/// let mut res = Response::new(200);
+/// res.insert_header("CDN-Cache-Control", r#"max-age=60, no-cache="set-cookie", x-shield"#);
+///
+/// let entries = CacheControl::from_targeted_headers(&res, CDN_CACHE_CONTROL)?.unwrap();
+/// let mut entries = entries.iter();
+/// assert_eq!(entries.next().unwrap(), &CacheDirective::MaxAge(std::time::Duration::from_secs(60)));
+/// assert_eq!(entries.next().unwrap(), &CacheDirective::NoCacheFields(vec!["set-cookie".into()]));
+/// assert_eq!(entries.next().unwrap(), &CacheDirective::Extension(CacheExtension::new("x-shield", None)?));
+/// #
+/// # Ok(()) }
+/// ```
 pub struct CacheControl {
+    name: HeaderName,
     entries: Vec<CacheDirective>,
 }
 
 impl CacheControl {
     /// Create a new instance of `CacheControl`.
     pub fn new() -> Self {
-        Self { entries: vec![] }
+        Self::new_targeted(CACHE_CONTROL)
+    }
+
+    /// Create a new instance for a targeted cache control field, such as
+    /// `CDN-Cache-Control`.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 9213: Targeted HTTP Cache Control](https://www.rfc-editor.org/rfc/rfc9213)
+    pub fn new_targeted(name: impl Into<HeaderName>) -> Self {
+        Self {
+            name: name.into(),
+            entries: vec![],
+        }
     }
 
     /// Create a new instance from headers.
     pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        Self::from_targeted_headers(headers, CACHE_CONTROL)
+    }
+
+    /// Create a new instance from a targeted cache control field, such as
+    /// `CDN-Cache-Control`.
+    pub fn from_targeted_headers(
+        headers: impl AsRef<Headers>,
+        name: impl Into<HeaderName>,
+    ) -> crate::Result<Option<Self>> {
+        let name = name.into();
         let mut entries = vec![];
-        let headers = match headers.as_ref().get(CACHE_CONTROL) {
+        let headers = match headers.as_ref().get(&name) {
             Some(headers) => headers,
             None => return Ok(None),
         };
 
         for value in headers {
-            for part in value.as_str().trim().split(',') {
-                // Try and parse a directive from a str. If the directive is
-                // unkown we skip it.
-                if let Some(entry) = CacheDirective::from_str(part)? {
-                    entries.push(entry);
-                }
-            }
+            parse_directives(value.as_str(), &mut entries);
         }
 
-        Ok(Some(Self { entries }))
+        Ok(Some(Self { name, entries }))
+    }
+
+    /// Get the name of the header field this instance is read from and
+    /// written to.
+    pub fn name(&self) -> &HeaderName {
+        &self.name
     }
     /// Push a directive into the list of entries.
     pub fn push(&mut self, directive: CacheDirective) {
@@ -81,9 +132,78 @@ impl CacheControl {
     }
 }
 
+/// Parse a comma-separated list of directives, respecting quoted values.
+///
+/// Malformed list elements are skipped so that one bad directive doesn't
+/// cause the rest of the header to be ignored.
+fn parse_directives(input: &str, entries: &mut Vec<CacheDirective>) {
+    let mut input = input.trim_start();
+    while !input.is_empty() {
+        // Empty list elements are allowed, and ignored.
+        if let Some(rest) = input.strip_prefix(',') {
+            input = rest.trim_start();
+            continue;
+        }
+
+        input = match parse_directive(input) {
+            Some((directive, rest)) => {
+                entries.push(directive);
+                rest
+            }
+            None => skip_element(input),
+        };
+    }
+}
+
+/// Parse a single directive, returning it together with the input following
+/// its trailing `,`. Returns `None` if the list element is malformed.
+fn parse_directive(input: &str) -> Option<(CacheDirective, &str)> {
+    let (name, rest) = match parse_token(input) {
+        (Some(name), rest) => (name, rest),
+        (None, _) => return None,
+    };
+    let (value, rest) = match rest.strip_prefix('=') {
+        Some(rest) if rest.starts_with('"') => match parse_quoted_string(rest) {
+            (Some(value), rest) => (Some(value), rest),
+            (None, _) => return None,
+        },
+        Some(rest) => match parse_token(rest) {
+            (Some(value), rest) => (Some(value.into()), rest),
+            (None, _) => return None,
+        },
+        None => (None, rest),
+    };
+
+    let rest = rest.trim_start();
+    let rest = match rest.strip_prefix(',') {
+        Some(rest) => rest.trim_start(),
+        None if rest.is_empty() => rest,
+        None => return None,
+    };
+    let directive = CacheDirective::from_parts(name, value.as_deref()).ok()?;
+    Some((directive, rest))
+}
+
+/// Skip to the start of the next list element, ignoring commas inside quoted
+/// strings.
+fn skip_element(input: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in input.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ',' if !quoted => return input[i + 1..].trim_start(),
+            _ => {}
+        }
+    }
+    ""
+}
+
 impl Header for CacheControl {
     fn header_name(&self) -> HeaderName {
-        CACHE_CONTROL
+        self.name.clone()
     }
     fn header_value(&self) -> HeaderValue {
         let mut output = String::new();
@@ -1,6 +1,9 @@
-use crate::headers::HeaderValue;
+use crate::headers::{HeaderName, HeaderValue};
+use crate::parse_utils::parse_token;
 use crate::Status;
 
+use std::fmt;
+use std::str::FromStr;
 use std::time::Duration;
 
 /// An HTTP `Cache-Control` directive.
@@ -17,8 +20,14 @@ pub enum CacheDirective {
     MinFresh(Duration),
     /// Once a response is stale, a fresh response must be retrieved.
     MustRevalidate,
+    /// The response may only be stored by caches that understand the caching
+    /// requirements of its status code.
+    MustUnderstand,
     /// The response may be cached, but must always be revalidated before being used.
     NoCache,
+    /// The listed header fields may not be sent in a response to a subsequent
+    /// request without successful revalidation (`no-cache="field"`).
+    NoCacheFields(Vec<HeaderName>),
     /// The response may not be cached.
     NoStore,
     /// An intermediate cache or proxy should not edit the response body,
@@ -29,6 +38,9 @@ pub enum CacheDirective {
     /// The response may be stored only by a browser's cache, even if the
     /// response is normally non-cacheable.
     Private,
+    /// Shared caches may store the response, but not the listed header fields
+    /// (`private="field"`).
+    PrivateFields(Vec<HeaderName>),
     /// Like must-revalidate, but only for shared caches (e.g., proxies).
     ProxyRevalidate,
     /// The response may be stored by any cache, even if the response is normally
@@ -41,6 +53,87 @@ pub enum CacheDirective {
     /// Indicates the client will accept a stale response, while asynchronously
     /// checking in the background for a fresh one.
     StaleWhileRevalidate(Duration),
+    /// A directive that isn't otherwise recognized.
+    Extension(CacheExtension),
+}
+
+/// A `Cache-Control` directive that isn't otherwise recognized, with its
+/// lowercased name and optional value.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cache::{CacheControl, CacheDirective, CacheExtension};
+/// use http_types::headers::Header;
+///
+/// let mut entries = CacheControl::new();
+/// let extension = CacheExtension::new("Community", Some("UCI Irvine"))?;
+/// assert_eq!(extension.name(), "community");
+/// entries.push(CacheDirective::Extension(extension));
+/// assert_eq!(entries.header_value(), r#"community="UCI Irvine""#);
+///
+/// assert!(CacheExtension::new("bad name", None).is_err());
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheExtension {
+    name: String,
+    value: Option<String>,
+}
+
+impl CacheExtension {
+    /// Create a new instance of `CacheExtension`.
+    ///
+    /// Values that aren't tokens are sent as quoted strings.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if the name isn't a
+    /// token, or if the value contains non-printable or non-ASCII characters.
+    pub fn new(name: &str, value: Option<&str>) -> crate::Result<Self> {
+        if !matches!(parse_token(name), (Some(_), "")) {
+            crate::bail_status!(400, "Invalid Cache-Control directive `{}`", name);
+        }
+        if let Some(value) = value {
+            if !value
+                .bytes()
+                .all(|b| b == b'\t' || b == b' ' || b.is_ascii_graphic())
+            {
+                crate::bail_status!(400, "Invalid value for Cache-Control directive `{}`", name);
+            }
+        }
+        Ok(Self {
+            name: name.to_ascii_lowercase(),
+            value: value.map(String::from),
+        })
+    }
+
+    /// The lowercased name of the directive.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value of the directive, if any.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+impl fmt::Display for CacheExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        match &self.value {
+            Some(value) if matches!(parse_token(value), (Some(_), "")) => write!(f, "={}", value),
+            Some(value) => {
+                let escaped = value.replace('\\', r"\\").replace('"', r#"\""#);
+                write!(f, "=\"{}\"", escaped)
+            }
+            None => Ok(()),
+        }
+    }
 }
 
 impl CacheDirective {
@@ -49,7 +142,15 @@ impl CacheDirective {
         use CacheDirective::*;
         matches!(
             self,
-            MaxAge(_) | MaxStale(_) | MinFresh(_) | NoCache | NoStore | NoTransform | OnlyIfCached
+            MaxAge(_)
+                | MaxStale(_)
+                | MinFresh(_)
+                | NoCache
+                | NoStore
+                | NoTransform
+                | OnlyIfCached
+                | StaleIfError(_)
+                | Extension(..)
         )
     }
 
@@ -59,74 +160,90 @@ impl CacheDirective {
         matches!(
             self,
             MustRevalidate
+                | MustUnderstand
                 | NoCache
+                | NoCacheFields(_)
                 | NoStore
                 | NoTransform
                 | Public
                 | Private
+                | PrivateFields(_)
                 | ProxyRevalidate
                 | MaxAge(_)
                 | SMaxAge(_)
                 | StaleIfError(_)
                 | StaleWhileRevalidate(_)
+                | Extension(..)
         )
     }
 
-    /// Create an instance from a string slice.
-    //
-    // This is a private method rather than a trait because we assume the
-    // input string is a single-value only. This is upheld by the calling
-    // function, but we cannot guarantee this to be true in the general
-    // sense.
-    pub(crate) fn from_str(s: &str) -> crate::Result<Option<Self>> {
+    /// Create an instance from a directive name and its optional value.
+    ///
+    /// Quoted values are expected to have been unescaped by the caller.
+    /// Directives that aren't recognized are returned as
+    /// `CacheDirective::Extension`.
+    pub(crate) fn from_parts(name: &str, value: Option<&str>) -> crate::Result<Self> {
         use CacheDirective::*;
 
-        let s = s.trim();
-
-        // We're dealing with an empty string.
-        if s.is_empty() {
-            return Ok(None);
-        }
-
-        let s = s.to_lowercase();
-        let mut parts = s.split('=');
-        let next = parts.next().unwrap();
+        let name = name.to_ascii_lowercase();
 
-        let mut get_dur = || -> crate::Result<Duration> {
-            let dur = parts.next().status(400)?;
+        let get_dur = || -> crate::Result<Duration> {
+            let dur = value.status(400)?;
             let dur: u64 = dur.parse::<u64>().status(400)?;
             Ok(Duration::new(dur, 0))
         };
 
-        // This won't panic because each input string has at least one part.
-        let res = match next {
-            "immutable" => Some(Immutable),
-            "no-cache" => Some(NoCache),
-            "no-store" => Some(NoStore),
-            "no-transform" => Some(NoTransform),
-            "only-if-cached" => Some(OnlyIfCached),
-            "must-revalidate" => Some(MustRevalidate),
-            "public" => Some(Public),
-            "private" => Some(Private),
-            "proxy-revalidate" => Some(ProxyRevalidate),
-            "max-age" => Some(MaxAge(get_dur()?)),
-            "max-stale" => match parts.next() {
+        let res = match name.as_str() {
+            "immutable" => Immutable,
+            "no-cache" => match value {
+                Some(fields) => NoCacheFields(parse_field_names(fields)?),
+                None => NoCache,
+            },
+            "no-store" => NoStore,
+            "no-transform" => NoTransform,
+            "only-if-cached" => OnlyIfCached,
+            "must-revalidate" => MustRevalidate,
+            "must-understand" => MustUnderstand,
+            "public" => Public,
+            "private" => match value {
+                Some(fields) => PrivateFields(parse_field_names(fields)?),
+                None => Private,
+            },
+            "proxy-revalidate" => ProxyRevalidate,
+            "max-age" => MaxAge(get_dur()?),
+            "max-stale" => match value {
                 Some(secs) => {
                     let dur: u64 = secs.parse::<u64>().status(400)?;
-                    Some(MaxStale(Some(Duration::new(dur, 0))))
+                    MaxStale(Some(Duration::new(dur, 0)))
                 }
-                None => Some(MaxStale(None)),
+                None => MaxStale(None),
             },
-            "min-fresh" => Some(MinFresh(get_dur()?)),
-            "s-maxage" => Some(SMaxAge(get_dur()?)),
-            "stale-if-error" => Some(StaleIfError(get_dur()?)),
-            "stale-while-revalidate" => Some(StaleWhileRevalidate(get_dur()?)),
-            _ => None,
+            "min-fresh" => MinFresh(get_dur()?),
+            "s-maxage" => SMaxAge(get_dur()?),
+            "stale-if-error" => StaleIfError(get_dur()?),
+            "stale-while-revalidate" => StaleWhileRevalidate(get_dur()?),
+            _ => Extension(CacheExtension::new(&name, value)?),
         };
         Ok(res)
     }
 }
 
+/// Parse the comma-separated list of field names of a qualified `no-cache` or
+/// `private` directive.
+fn parse_field_names(s: &str) -> crate::Result<Vec<HeaderName>> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| HeaderName::from_str(name).status(400))
+        .collect()
+}
+
+/// Format a list of field names as a quoted string.
+fn fmt_field_names(names: &[HeaderName]) -> String {
+    let names: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+    format!(r#""{}""#, names.join(", "))
+}
+
 impl From<CacheDirective> for HeaderValue {
     fn from(directive: CacheDirective) -> Self {
         use CacheDirective::*;
@@ -141,16 +258,20 @@ impl From<CacheDirective> for HeaderValue {
             },
             MinFresh(dur) => h(format!("min-fresh={}", dur.as_secs())),
             MustRevalidate => h("must-revalidate".to_string()),
+            MustUnderstand => h("must-understand".to_string()),
             NoCache => h("no-cache".to_string()),
+            NoCacheFields(names) => h(format!("no-cache={}", fmt_field_names(&names))),
             NoStore => h("no-store".to_string()),
             NoTransform => h("no-transform".to_string()),
             OnlyIfCached => h("only-if-cached".to_string()),
             Private => h("private".to_string()),
+            PrivateFields(names) => h(format!("private={}", fmt_field_names(&names))),
             ProxyRevalidate => h("proxy-revalidate".to_string()),
             Public => h("public".to_string()),
-            SMaxAge(dur) => h(format!("s-max-age={}", dur.as_secs())),
+            SMaxAge(dur) => h(format!("s-maxage={}", dur.as_secs())),
             StaleIfError(dur) => h(format!("stale-if-error={}", dur.as_secs())),
             StaleWhileRevalidate(dur) => h(format!("stale-while-revalidate={}", dur.as_secs())),
+            Extension(extension) => h(extension.to_string()),
         }
     }
 }
@@ -5,18 +5,21 @@
 //! - [RFC 8246: HTTP Immutable Responses](https://tools.ietf.org/html/rfc8246)
 //! - [RFC 7234: Hypertext Transfer Protocol (HTTP/1.1): Caching](https://tools.ietf.org/html/rfc7234)
 //! - [RFC 5861: HTTP Cache-Control Extensions for Stale Content](https://tools.ietf.org/html/rfc5861)
+//! - [RFC 9111, section 5.2: Cache-Control](https://www.rfc-editor.org/rfc/rfc9111#section-5.2)
+//! - [RFC 9213: Targeted HTTP Cache Control](https://www.rfc-editor.org/rfc/rfc9213)
 
 #[allow(clippy::module_inception)]
 mod cache_control;
 mod cache_directive;
 
 pub use cache_control::CacheControl;
-pub use cache_directive::CacheDirective;
+pub use cache_directive::{CacheDirective, CacheExtension};
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::headers::{Header, Headers, CACHE_CONTROL};
+    use std::time::Duration;
 
     #[test]
     fn smoke() -> crate::Result<()> {
@@ -35,20 +38,106 @@ mod test {
     }
 
     #[test]
-    fn ignore_unkonwn_directives() -> crate::Result<()> {
+    fn keep_unknown_directives() -> crate::Result<()> {
         let mut headers = Headers::new();
         headers.insert(CACHE_CONTROL, "barrel_roll").unwrap();
         let entries = CacheControl::from_headers(headers)?.unwrap();
         let mut entries = entries.iter();
+        assert_eq!(
+            entries.next().unwrap(),
+            &CacheDirective::Extension(CacheExtension::new("barrel_roll", None)?)
+        );
         assert!(entries.next().is_none());
         Ok(())
     }
 
     #[test]
-    fn bad_request_on_parse_error() {
+    fn round_trip() -> crate::Result<()> {
+        let input = r#"no-cache="set-cookie, x-token", private="authorization", must-understand, no-store, s-maxage=10, community="UCI", x-foo=bar, x-quote="a \"b\"""#;
         let mut headers = Headers::new();
-        headers.insert(CACHE_CONTROL, "min-fresh=0.9").unwrap(); // floats are not supported
-        let err = CacheControl::from_headers(headers).unwrap_err();
-        assert_eq!(err.status(), 400);
+        headers.insert(CACHE_CONTROL, input).unwrap();
+        let entries = CacheControl::from_headers(&headers)?.unwrap();
+        assert_eq!(
+            entries.iter().collect::<Vec<_>>(),
+            vec![
+                &CacheDirective::NoCacheFields(vec!["set-cookie".into(), "x-token".into()]),
+                &CacheDirective::PrivateFields(vec!["authorization".into()]),
+                &CacheDirective::MustUnderstand,
+                &CacheDirective::NoStore,
+                &CacheDirective::SMaxAge(std::time::Duration::from_secs(10)),
+                &CacheDirective::Extension(CacheExtension::new("community", Some("UCI"))?),
+                &CacheDirective::Extension(CacheExtension::new("x-foo", Some("bar"))?),
+                &CacheDirective::Extension(CacheExtension::new("x-quote", Some(r#"a "b""#))?),
+            ]
+        );
+
+        // Quoting is normalized, but the directives survive a round-trip.
+        let mut output = Headers::new();
+        entries.apply_header(&mut output);
+        assert_eq!(
+            output[CACHE_CONTROL],
+            input.replace(r#"community="UCI""#, "community=UCI")
+        );
+        let reparsed = CacheControl::from_headers(&output)?.unwrap();
+        assert!(entries.iter().eq(reparsed.iter()));
+        Ok(())
+    }
+
+    #[test]
+    fn targeted() -> crate::Result<()> {
+        use crate::headers::SURROGATE_CONTROL;
+
+        let mut entries = CacheControl::new_targeted(SURROGATE_CONTROL);
+        entries.push(CacheDirective::MaxAge(std::time::Duration::from_secs(60)));
+        entries.push(CacheDirective::Extension(CacheExtension::new(
+            "content",
+            Some("ESI/1.0"),
+        )?));
+
+        let mut headers = Headers::new();
+        entries.apply_header(&mut headers);
+        assert_eq!(
+            headers[SURROGATE_CONTROL],
+            r#"max-age=60, content="ESI/1.0""#
+        );
+        assert!(CacheControl::from_headers(&headers)?.is_none());
+
+        let entries = CacheControl::from_targeted_headers(&headers, SURROGATE_CONTROL)?.unwrap();
+        assert_eq!(entries.name(), &SURROGATE_CONTROL);
+        assert_eq!(entries.iter().count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_directives_are_skipped() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(
+            CACHE_CONTROL,
+            "min-fresh=0.9, =junk, max-age=60 x, no-store, no-cache=\"a, private",
+        )?; // floats are not supported
+        headers.append(CACHE_CONTROL, "max-age=30, public")?;
+        let entries = CacheControl::from_headers(headers)?.unwrap();
+        let mut entries = entries.iter();
+        assert_eq!(entries.next().unwrap(), &CacheDirective::NoStore);
+        assert_eq!(
+            entries.next().unwrap(),
+            &CacheDirective::MaxAge(Duration::from_secs(30))
+        );
+        assert_eq!(entries.next().unwrap(), &CacheDirective::Public);
+        assert!(entries.next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_extensions() {
+        for (name, value) in &[
+            ("x foo", None),
+            ("", None),
+            ("x-foo", Some("caf\u{e9}")),
+            ("x-foo", Some("a\r\nSet-Cookie: a=b")),
+        ] {
+            let err = CacheExtension::new(name, *value).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", name);
+        }
     }
 }
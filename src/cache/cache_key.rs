@@ -0,0 +1,168 @@
+use crate::conditional::Vary;
+use crate::headers::HeaderName;
+use crate::{Method, Request, Url};
+
+/// A key to look up stored responses by.
+///
+/// The primary key is made up of the request method and its URL, without the
+/// fragment. When the stored response carries a `Vary` header, the values of
+/// the request headers it names form a secondary key, which must also match
+/// for the stored response to be reused.
+///
+/// # Specifications
+///
+/// - [RFC 9111, section 2: Overview of Cache Operation](https://www.rfc-editor.org/rfc/rfc9111#section-2)
+/// - [RFC 9111, section 4.1: Calculating Cache Keys with the Vary Header Field](https://www.rfc-editor.org/rfc/rfc9111#section-4.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cache::CacheKey;
+/// use http_types::conditional::Vary;
+/// use http_types::Request;
+///
+/// let mut vary = Vary::new();
+/// vary.push("Accept-Language")?;
+///
+/// let mut a = Request::get("https://example.com/menu#drinks");
+/// a.insert_header("Accept-Language", "en");
+/// let mut b = Request::get("https://example.com/menu");
+/// b.insert_header("Accept-Language", "nl");
+///
+/// assert_eq!(CacheKey::new(&a), CacheKey::new(&b));
+/// assert_ne!(CacheKey::with_vary(&a, &vary), CacheKey::with_vary(&b, &vary));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    method: Method,
+    url: Url,
+    vary: Vec<(HeaderName, Option<String>)>,
+}
+
+impl CacheKey {
+    /// Create the primary cache key for a request.
+    pub fn new(req: &Request) -> Self {
+        let mut url = req.url().clone();
+        url.set_fragment(None);
+        Self {
+            method: req.method(),
+            url,
+            vary: vec![],
+        }
+    }
+
+    /// Create a cache key for a request, including the values of the request
+    /// headers named by the stored response's `Vary` header.
+    pub fn with_vary(req: &Request, vary: &Vary) -> Self {
+        let mut key = Self::new(req);
+        for name in vary {
+            let value = req.header(name).map(|values| {
+                values
+                    .iter()
+                    .map(|value| normalize(value.as_str()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            });
+            key.vary.push((name.clone(), value));
+        }
+        key.vary.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+        key.vary.dedup_by(|a, b| a.0 == b.0);
+        key
+    }
+
+    /// Get the request method.
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Get the normalized request URL.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Get the primary key, without any `Vary` header values.
+    pub fn primary(&self) -> Self {
+        Self {
+            method: self.method,
+            url: self.url.clone(),
+            vary: vec![],
+        }
+    }
+
+    /// Returns `true` if this key includes the values of headers named by a
+    /// `Vary` header.
+    pub fn is_secondary(&self) -> bool {
+        !self.vary.is_empty()
+    }
+
+    /// An iterator visiting the header names and normalized values that make
+    /// up the secondary key. A value of `None` means the header was absent.
+    pub fn vary_values(&self) -> impl Iterator<Item = (&HeaderName, Option<&str>)> {
+        self.vary
+            .iter()
+            .map(|(name, value)| (name, value.as_deref()))
+    }
+}
+
+/// Trim a header value and collapse runs of whitespace, so semantically
+/// equivalent values produce the same key.
+fn normalize(value: &str) -> String {
+    value
+        .split(',')
+        .map(|part| part.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn primary_key() {
+        let a = Request::get("HTTPS://Example.com:443/a?b=c#d");
+        let b = Request::get("https://example.com/a?b=c");
+        assert_eq!(CacheKey::new(&a), CacheKey::new(&b));
+        assert_eq!(
+            CacheKey::new(&a).url().as_str(),
+            "https://example.com/a?b=c"
+        );
+
+        let c = Request::head("https://example.com/a?b=c");
+        assert_ne!(CacheKey::new(&a), CacheKey::new(&c));
+        let d = Request::get("https://example.com/a?c=b");
+        assert_ne!(CacheKey::new(&a), CacheKey::new(&d));
+    }
+
+    #[test]
+    fn secondary_key() -> crate::Result<()> {
+        let mut vary = Vary::new();
+        vary.push("Accept-Encoding")?;
+        vary.push("Accept-Language")?;
+
+        let mut a = Request::get("https://example.com");
+        a.insert_header("Accept-Encoding", "gzip,   br")?;
+        let mut b = Request::get("https://example.com");
+        b.insert_header("Accept-Encoding", " gzip, br")?;
+
+        let key = CacheKey::with_vary(&a, &vary);
+        assert!(key.is_secondary());
+        assert_eq!(key, CacheKey::with_vary(&b, &vary));
+        assert_eq!(key.primary(), CacheKey::new(&a));
+        assert_eq!(
+            key.vary_values().collect::<Vec<_>>(),
+            vec![
+                (&HeaderName::from("accept-encoding"), Some("gzip, br")),
+                (&HeaderName::from("accept-language"), None),
+            ]
+        );
+
+        b.insert_header("Accept-Language", "")?;
+        assert_ne!(key, CacheKey::with_vary(&b, &vary));
+        Ok(())
+    }
+}
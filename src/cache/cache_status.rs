@@ -0,0 +1,464 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, CACHE_STATUS};
+use crate::utils::structured_field::{self, BareItem, Item};
+
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::slice;
+
+/// Describe how caches have handled a response.
+///
+/// # Specifications
+///
+/// - [RFC 9211: The Cache-Status HTTP Response Header Field](https://www.rfc-editor.org/rfc/rfc9211)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Response;
+/// use http_types::cache::{CacheStatus, CacheStatusEntry, ForwardReason};
+///
+/// let mut origin_side = CacheStatusEntry::new("ReverseProxy");
+/// origin_side.set_forward(Some(ForwardReason::UriMiss));
+/// origin_side.set_stored(true);
+///
+/// let mut user_side = CacheStatusEntry::new("ForwardProxy");
+/// user_side.set_hit(true);
+/// user_side.set_ttl(Some(-412));
+///
+/// let mut cache_status = CacheStatus::new();
+/// cache_status.push(origin_side);
+/// cache_status.push(user_side);
+///
+/// let mut res = Response::new(200);
+/// res.insert_header(&cache_status, &cache_status);
+/// assert_eq!(
+///     res["Cache-Status"],
+///     "ReverseProxy;fwd=uri-miss;stored, ForwardProxy;hit;ttl=-412"
+/// );
+///
+/// let cache_status = CacheStatus::from_headers(res)?.unwrap();
+/// let entry = cache_status.iter().last().unwrap();
+/// assert_eq!(entry.cache(), "ForwardProxy");
+/// assert!(entry.hit());
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CacheStatus {
+    entries: Vec<CacheStatusEntry>,
+}
+
+impl CacheStatus {
+    /// Create a new instance of `CacheStatus`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Entries from multiple `Cache-Status` headers are combined in order.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let items = match structured_field::parse_header(headers.as_ref(), CACHE_STATUS)? {
+            Some(items) => items,
+            None => return Ok(None),
+        };
+        let entries = items
+            .into_iter()
+            .map(CacheStatusEntry::from_item)
+            .collect::<crate::Result<_>>()?;
+        Ok(Some(Self { entries }))
+    }
+
+    /// Append an entry for the cache that handled the response.
+    pub fn push(&mut self, entry: CacheStatusEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Get the entry of the cache closest to the user.
+    pub fn last(&self) -> Option<&CacheStatusEntry> {
+        self.entries.last()
+    }
+
+    /// An iterator visiting all entries, starting with the cache closest to
+    /// the origin server.
+    pub fn iter(&self) -> slice::Iter<'_, CacheStatusEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Header for CacheStatus {
+    fn header_name(&self) -> HeaderName {
+        CACHE_STATUS
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        structured_field::header_value(self.entries.iter().map(|entry| entry.to_item()))
+    }
+}
+
+impl IntoIterator for CacheStatus {
+    type Item = CacheStatusEntry;
+    type IntoIter = std::vec::IntoIter<CacheStatusEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CacheStatus {
+    type Item = &'a CacheStatusEntry;
+    type IntoIter = slice::Iter<'a, CacheStatusEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// How a single cache handled a response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheStatusEntry {
+    cache: String,
+    hit: bool,
+    forward: Option<ForwardReason>,
+    forward_status: Option<u16>,
+    ttl: Option<i64>,
+    stored: bool,
+    collapsed: bool,
+    key: Option<String>,
+    detail: Option<String>,
+    extensions: Vec<(String, BareItem)>,
+}
+
+impl CacheStatusEntry {
+    /// Create a new entry for the cache with the given identifier.
+    pub fn new(cache: impl Into<String>) -> Self {
+        Self {
+            cache: cache.into(),
+            hit: false,
+            forward: None,
+            forward_status: None,
+            ttl: None,
+            stored: false,
+            collapsed: false,
+            key: None,
+            detail: None,
+            extensions: vec![],
+        }
+    }
+
+    /// Get the identifier of the cache.
+    pub fn cache(&self) -> &str {
+        &self.cache
+    }
+
+    /// Returns `true` if the response was served from the cache without
+    /// contacting the next hop.
+    pub fn hit(&self) -> bool {
+        self.hit
+    }
+
+    /// Set whether the response was a hit.
+    pub fn set_hit(&mut self, hit: bool) {
+        self.hit = hit;
+    }
+
+    /// Get the reason the request was forwarded towards the origin.
+    pub fn forward(&self) -> Option<&ForwardReason> {
+        self.forward.as_ref()
+    }
+
+    /// Set the reason the request was forwarded towards the origin.
+    pub fn set_forward(&mut self, reason: Option<ForwardReason>) {
+        self.forward = reason;
+    }
+
+    /// Get the status code the next hop responded with.
+    pub fn forward_status(&self) -> Option<u16> {
+        self.forward_status
+    }
+
+    /// Set the status code the next hop responded with.
+    pub fn set_forward_status(&mut self, status: Option<u16>) {
+        self.forward_status = status;
+    }
+
+    /// Get the remaining freshness lifetime in seconds, which is negative
+    /// for stale responses.
+    pub fn ttl(&self) -> Option<i64> {
+        self.ttl
+    }
+
+    /// Set the remaining freshness lifetime in seconds.
+    pub fn set_ttl(&mut self, ttl: Option<i64>) {
+        self.ttl = ttl;
+    }
+
+    /// Returns `true` if the forwarded response was stored.
+    pub fn stored(&self) -> bool {
+        self.stored
+    }
+
+    /// Set whether the forwarded response was stored.
+    pub fn set_stored(&mut self, stored: bool) {
+        self.stored = stored;
+    }
+
+    /// Returns `true` if the request was collapsed with other requests.
+    pub fn collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// Set whether the request was collapsed with other requests.
+    pub fn set_collapsed(&mut self, collapsed: bool) {
+        self.collapsed = collapsed;
+    }
+
+    /// Get the implementation-specific cache key.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// Set the implementation-specific cache key.
+    pub fn set_key(&mut self, key: Option<String>) {
+        self.key = key;
+    }
+
+    /// Get additional implementation-specific details.
+    pub fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+
+    /// Set additional implementation-specific details.
+    pub fn set_detail(&mut self, detail: Option<String>) {
+        self.detail = detail;
+    }
+
+    /// Get the serialized value of an extension parameter, such as `?1` or
+    /// `"text"`.
+    pub fn extension(&self, name: &str) -> Option<String> {
+        structured_field::get_param(&self.extensions, name)
+    }
+
+    /// Set an extension parameter from its serialized value.
+    ///
+    /// # Errors
+    ///
+    /// This method errors if the name is not a valid parameter key, or the
+    /// value is not a valid structured field item.
+    pub fn set_extension(&mut self, name: &str, value: &str) -> crate::Result<()> {
+        structured_field::set_param(&mut self.extensions, name, value)
+    }
+
+    fn from_item(item: Item) -> crate::Result<Self> {
+        let cache = match item.bare_item.as_str() {
+            Some(cache) => cache,
+            None => crate::bail_status!(400, "Cache-Status entries must start with a cache"),
+        };
+        let mut entry = Self::new(cache);
+        for (key, value) in item.params {
+            match key.as_str() {
+                "hit" => entry.hit = param(&key, value.as_bool())?,
+                "fwd" => {
+                    let reason = param(&key, value.as_str())?;
+                    entry.forward = Some(ForwardReason::from_token(reason));
+                }
+                "fwd-status" => {
+                    let status = param(&key, value.as_integer())?;
+                    entry.forward_status = Some(param(&key, u16::try_from(status).ok())?);
+                }
+                "ttl" => entry.ttl = Some(param(&key, value.as_integer())?),
+                "stored" => entry.stored = param(&key, value.as_bool())?,
+                "collapsed" => entry.collapsed = param(&key, value.as_bool())?,
+                "key" => entry.key = Some(param(&key, value.as_str())?.to_owned()),
+                "detail" => entry.detail = Some(param(&key, value.as_str())?.to_owned()),
+                _ => entry.extensions.push((key, value)),
+            }
+        }
+        Ok(entry)
+    }
+
+    fn to_item(&self) -> Item {
+        let mut item = Item::new(BareItem::token_or_string(&self.cache));
+        if self.hit {
+            item.push_param("hit", BareItem::Boolean(true));
+        }
+        if let Some(reason) = &self.forward {
+            item.push_param("fwd", BareItem::token_or_string(reason.as_str()));
+        }
+        if let Some(status) = self.forward_status {
+            item.push_param("fwd-status", BareItem::Integer(status.into()));
+        }
+        if let Some(ttl) = self.ttl {
+            item.push_param("ttl", BareItem::Integer(ttl));
+        }
+        if self.stored {
+            item.push_param("stored", BareItem::Boolean(true));
+        }
+        if self.collapsed {
+            item.push_param("collapsed", BareItem::Boolean(true));
+        }
+        if let Some(key) = &self.key {
+            item.push_param("key", BareItem::String(key.clone()));
+        }
+        if let Some(detail) = &self.detail {
+            item.push_param("detail", BareItem::token_or_string(detail));
+        }
+        for (key, value) in &self.extensions {
+            item.push_param(key, value.clone());
+        }
+        item
+    }
+}
+
+/// Check the type of a parameter value.
+fn param<T>(key: &str, value: Option<T>) -> crate::Result<T> {
+    structured_field::expect_param("Cache-Status", key, value)
+}
+
+/// Why a cache forwarded a request towards the origin server.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForwardReason {
+    /// The cache was configured to not handle this request.
+    Bypass,
+    /// The request method's semantics require the request to be forwarded.
+    Method,
+    /// The cache did not contain any responses that matched the request URI.
+    UriMiss,
+    /// The cache contained a response matching the URI, but not the
+    /// selecting header fields of `Vary`.
+    VaryMiss,
+    /// The cache did not contain any responses that could be used.
+    Miss,
+    /// The cache was able to select a fresh response, but request semantics
+    /// such as `Cache-Control: no-cache` required forwarding.
+    Request,
+    /// The cache was able to select a response, but it was stale.
+    Stale,
+    /// The cache was able to select a partial response, but it did not
+    /// contain all of the requested ranges.
+    Partial,
+    /// An unregistered forward reason.
+    Extension(String),
+}
+
+impl ForwardReason {
+    /// Get the serialized token.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Bypass => "bypass",
+            Self::Method => "method",
+            Self::UriMiss => "uri-miss",
+            Self::VaryMiss => "vary-miss",
+            Self::Miss => "miss",
+            Self::Request => "request",
+            Self::Stale => "stale",
+            Self::Partial => "partial",
+            Self::Extension(s) => s,
+        }
+    }
+
+    fn from_token(s: &str) -> Self {
+        match s {
+            "bypass" => Self::Bypass,
+            "method" => Self::Method,
+            "uri-miss" => Self::UriMiss,
+            "vary-miss" => Self::VaryMiss,
+            "miss" => Self::Miss,
+            "request" => Self::Request,
+            "stale" => Self::Stale,
+            "partial" => Self::Partial,
+            s => Self::Extension(s.to_owned()),
+        }
+    }
+}
+
+impl Display for ForwardReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Response;
+
+    #[test]
+    fn multi_hop() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.insert_header(
+            "Cache-Status",
+            r#"OriginCache; hit; ttl=1100; key="/a?b", "CDN Company Here";fwd=vary-miss;fwd-status=304;stored;collapsed"#,
+        )?;
+        res.append_header(
+            "Cache-Status",
+            "BrowserCache; fwd=prefetch; detail=memory; x-shard=12",
+        )?;
+
+        let cache_status = CacheStatus::from_headers(&res)?.unwrap();
+        assert_eq!(cache_status.len(), 3);
+        let entries: Vec<_> = cache_status.iter().collect();
+
+        assert_eq!(entries[0].cache(), "OriginCache");
+        assert!(entries[0].hit());
+        assert_eq!(entries[0].ttl(), Some(1100));
+        assert_eq!(entries[0].key(), Some("/a?b"));
+
+        assert_eq!(entries[1].cache(), "CDN Company Here");
+        assert_eq!(entries[1].forward(), Some(&ForwardReason::VaryMiss));
+        assert_eq!(entries[1].forward_status(), Some(304));
+        assert!(entries[1].stored() && entries[1].collapsed());
+
+        let last = cache_status.last().unwrap();
+        assert_eq!(
+            last.forward(),
+            Some(&ForwardReason::Extension("prefetch".into()))
+        );
+        assert_eq!(last.detail(), Some("memory"));
+        assert_eq!(last.extension("x-shard"), Some("12".into()));
+
+        let mut res = Response::new(200);
+        res.insert_header(&cache_status, &cache_status)?;
+        assert_eq!(
+            res["Cache-Status"],
+            r#"OriginCache;hit;ttl=1100;key="/a?b", "CDN Company Here";fwd=vary-miss;fwd-status=304;stored;collapsed, BrowserCache;fwd=prefetch;detail=memory;x-shard=12"#
+        );
+        assert_eq!(CacheStatus::from_headers(res)?.unwrap(), cache_status);
+        Ok(())
+    }
+
+    #[test]
+    fn extensions() -> crate::Result<()> {
+        let mut entry = CacheStatusEntry::new("Cache");
+        entry.set_extension("region", r#""eu-west""#)?;
+        entry.set_extension("region", "us")?;
+        assert_eq!(entry.extension("region"), Some("us".into()));
+        assert!(entry.set_extension("Region", "us").is_err());
+        assert!(entry.set_extension("region", "two words").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() -> crate::Result<()> {
+        for value in &["?1", "Cache;ttl=soon", "Cache;fwd-status=-1", "Cache;hit=1"] {
+            let mut res = Response::new(200);
+            res.insert_header("Cache-Status", *value)?;
+            let err = CacheStatus::from_headers(res).unwrap_err();
+            assert_eq!(err.status(), 400);
+        }
+        Ok(())
+    }
+}
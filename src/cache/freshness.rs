@@ -0,0 +1,492 @@
+use crate::cache::{Age, CacheControl, CacheDirective, Expires};
+use crate::conditional::LastModified;
+use crate::headers::Headers;
+use crate::other::Date;
+use crate::{Response, StatusCode};
+
+use std::time::{Duration, SystemTime};
+
+/// The kind of cache a response is stored in.
+///
+/// Shared caches (proxies, CDNs) honour `s-maxage` and `proxy-revalidate`,
+/// and may not store responses marked `private`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheKind {
+    /// A cache dedicated to a single user, such as a browser cache.
+    Private,
+    /// A cache that stores responses for reuse by more than one user.
+    Shared,
+}
+
+/// Whether a stored response may be used to satisfy a request.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Usability {
+    /// The stored response is fresh and may be served.
+    Fresh,
+    /// The stored response is stale, but the request's `max-stale` directive
+    /// allows it to be served.
+    StaleAllowed,
+    /// The stored response is stale, but may be served while it is
+    /// revalidated in the background (`stale-while-revalidate`).
+    StaleWhileRevalidate,
+    /// The stored response may not be served without successful validation
+    /// with the origin server.
+    MustRevalidate,
+}
+
+/// The age and freshness of a stored response.
+///
+/// # Specifications
+///
+/// - [RFC 9111, section 4.2: Freshness](https://www.rfc-editor.org/rfc/rfc9111#section-4.2)
+/// - [RFC 5861: HTTP Cache-Control Extensions for Stale Content](https://tools.ietf.org/html/rfc5861)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cache::{CacheControl, CacheDirective, CacheKind, Freshness};
+/// use http_types::other::Date;
+/// use http_types::Response;
+/// use std::time::{Duration, SystemTime};
+///
+/// let request_time = SystemTime::now();
+/// let response_time = request_time + Duration::from_secs(1);
+///
+/// let mut res = Response::new(200);
+/// let date = Date::new(response_time);
+/// res.insert_header(&date, &date);
+/// let mut cache_control = CacheControl::new();
+/// cache_control.push(CacheDirective::MaxAge(Duration::from_secs(60)));
+/// res.insert_header(&cache_control, &cache_control);
+///
+/// let freshness = Freshness::new(&res, request_time, response_time, CacheKind::Private)?;
+/// assert_eq!(freshness.freshness_lifetime(), Duration::from_secs(60));
+///
+/// let later = response_time + Duration::from_secs(30);
+/// assert!(freshness.is_fresh(later));
+///
+/// let much_later = response_time + Duration::from_secs(90);
+/// assert!(!freshness.is_fresh(much_later));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Freshness {
+    response_time: SystemTime,
+    corrected_initial_age: Duration,
+    lifetime: Duration,
+    heuristic: bool,
+    no_cache: bool,
+    must_revalidate: bool,
+    stale_while_revalidate: Option<Duration>,
+    stale_if_error: Option<Duration>,
+}
+
+impl Freshness {
+    /// Compute the freshness of a stored response.
+    ///
+    /// `request_time` is the time the request which produced the response was
+    /// sent, and `response_time` the time the response was received.
+    pub fn new(
+        res: &Response,
+        request_time: SystemTime,
+        response_time: SystemTime,
+        kind: CacheKind,
+    ) -> crate::Result<Self> {
+        Self::from_parts(res, res.status(), request_time, response_time, kind)
+    }
+
+    /// Compute the freshness of a stored response from its headers and status.
+    pub(crate) fn from_parts(
+        res: &impl AsRef<Headers>,
+        status: StatusCode,
+        request_time: SystemTime,
+        response_time: SystemTime,
+        kind: CacheKind,
+    ) -> crate::Result<Self> {
+        let res = res.as_ref();
+        let date = match Date::from_headers(res)? {
+            Some(date) => date.into(),
+            None => response_time,
+        };
+        let age_value = Age::from_headers(res)?
+            .map(|age| age.duration())
+            .unwrap_or_default();
+
+        // RFC 9111, section 4.2.3: Calculating Age.
+        let apparent_age = duration_between(date, response_time);
+        let response_delay = duration_between(request_time, response_time);
+        let corrected_age_value = age_value + response_delay;
+        let corrected_initial_age = apparent_age.max(corrected_age_value);
+
+        let mut max_age = None;
+        let mut s_maxage = None;
+        let mut no_cache = false;
+        let mut must_revalidate = false;
+        let mut public = false;
+        let mut stale_while_revalidate = None;
+        let mut stale_if_error = None;
+        if let Some(cache_control) = CacheControl::from_headers(res)? {
+            for directive in cache_control {
+                match directive {
+                    CacheDirective::MaxAge(dur) => max_age = Some(dur),
+                    CacheDirective::SMaxAge(dur) => s_maxage = Some(dur),
+                    CacheDirective::NoCache => no_cache = true,
+                    CacheDirective::MustRevalidate => must_revalidate = true,
+                    CacheDirective::ProxyRevalidate if kind == CacheKind::Shared => {
+                        must_revalidate = true
+                    }
+                    CacheDirective::Public => public = true,
+                    CacheDirective::StaleWhileRevalidate(dur) => stale_while_revalidate = Some(dur),
+                    CacheDirective::StaleIfError(dur) => stale_if_error = Some(dur),
+                    _ => {}
+                }
+            }
+        }
+
+        // RFC 9111, section 5.2.2.10: s-maxage implies proxy-revalidate.
+        if kind == CacheKind::Shared && s_maxage.is_some() {
+            must_revalidate = true;
+        }
+
+        // RFC 9111, section 4.2.1: Calculating Freshness Lifetime.
+        let mut heuristic = false;
+        let lifetime = match (kind, s_maxage, max_age) {
+            (CacheKind::Shared, Some(s_maxage), _) => s_maxage,
+            (_, _, Some(max_age)) => max_age,
+            _ => match Expires::from_headers(res) {
+                Ok(Some(expires)) => duration_between(date, expires.expiration()),
+                // An invalid `Expires` value represents a time in the past.
+                Err(_) => Duration::from_secs(0),
+                Ok(None) => match LastModified::from_headers(res)? {
+                    Some(last_modified) if public || is_heuristically_cacheable(status) => {
+                        // RFC 9111, section 4.2.2: a typical heuristic is 10%
+                        // of the time since the resource was last modified.
+                        heuristic = true;
+                        duration_between(last_modified.modified(), date) / 10
+                    }
+                    _ => Duration::from_secs(0),
+                },
+            },
+        };
+
+        Ok(Self {
+            response_time,
+            corrected_initial_age,
+            lifetime,
+            heuristic,
+            no_cache,
+            must_revalidate,
+            stale_while_revalidate,
+            stale_if_error,
+        })
+    }
+
+    /// Get the age of the response at `now`.
+    pub fn current_age(&self, now: SystemTime) -> Duration {
+        self.corrected_initial_age + duration_between(self.response_time, now)
+    }
+
+    /// Get the amount of time the response stays fresh after it was generated.
+    pub fn freshness_lifetime(&self) -> Duration {
+        self.lifetime
+    }
+
+    /// Returns `true` if the freshness lifetime was computed heuristically
+    /// from the `Last-Modified` header.
+    ///
+    /// Caches should add a warning when serving heuristically fresh responses
+    /// that are more than 24 hours old.
+    pub fn is_heuristic(&self) -> bool {
+        self.heuristic
+    }
+
+    /// Returns `true` if the response is fresh at `now`.
+    pub fn is_fresh(&self, now: SystemTime) -> bool {
+        self.lifetime > self.current_age(now)
+    }
+
+    /// Get the amount of time the response remains fresh after `now`.
+    pub fn time_to_live(&self, now: SystemTime) -> Duration {
+        self.lifetime.saturating_sub(self.current_age(now))
+    }
+
+    /// Get the amount of time the response has been stale at `now`.
+    pub fn staleness(&self, now: SystemTime) -> Duration {
+        self.current_age(now).saturating_sub(self.lifetime)
+    }
+
+    /// Determine whether the response may be used to satisfy a request at
+    /// `now`, honouring the request's `no-cache`, `max-age`, `max-stale` and
+    /// `min-fresh` directives.
+    pub fn usability(&self, req: impl AsRef<Headers>, now: SystemTime) -> crate::Result<Usability> {
+        if self.no_cache {
+            return Ok(Usability::MustRevalidate);
+        }
+
+        let age = self.current_age(now);
+        let mut max_stale = None;
+        let mut min_fresh = Duration::from_secs(0);
+        if let Some(cache_control) = CacheControl::from_headers(req)? {
+            for directive in cache_control {
+                match directive {
+                    CacheDirective::NoCache => return Ok(Usability::MustRevalidate),
+                    CacheDirective::MaxAge(max_age) if age > max_age => {
+                        return Ok(Usability::MustRevalidate)
+                    }
+                    CacheDirective::MaxStale(dur) => max_stale = Some(dur),
+                    CacheDirective::MinFresh(dur) => min_fresh = dur,
+                    _ => {}
+                }
+            }
+        }
+
+        if self.lifetime > age + min_fresh {
+            return Ok(Usability::Fresh);
+        }
+
+        // The response is still fresh, but not fresh enough for `min-fresh`.
+        // Staleness allowances don't apply to it.
+        if self.lifetime > age {
+            return Ok(Usability::MustRevalidate);
+        }
+
+        // RFC 9111, section 4.2.4: a cache must not serve stale responses
+        // marked `must-revalidate`, even if the client asks for it.
+        if self.must_revalidate {
+            return Ok(Usability::MustRevalidate);
+        }
+
+        let staleness = self.staleness(now);
+        match max_stale {
+            Some(None) => return Ok(Usability::StaleAllowed),
+            Some(Some(max_stale)) if staleness <= max_stale => return Ok(Usability::StaleAllowed),
+            _ => {}
+        }
+
+        match self.stale_while_revalidate {
+            Some(window) if staleness <= window => Ok(Usability::StaleWhileRevalidate),
+            _ => Ok(Usability::MustRevalidate),
+        }
+    }
+
+    /// Returns `true` if the response may be served at `now` because the
+    /// origin server could not be reached or returned a `5xx` error.
+    ///
+    /// Both the response's and the request's `stale-if-error` directives are
+    /// honoured.
+    pub fn allows_stale_if_error(
+        &self,
+        req: impl AsRef<Headers>,
+        now: SystemTime,
+    ) -> crate::Result<bool> {
+        if self.is_fresh(now) {
+            return Ok(true);
+        }
+        if self.must_revalidate || self.no_cache {
+            return Ok(false);
+        }
+
+        let mut window = self.stale_if_error;
+        if let Some(cache_control) = CacheControl::from_headers(req)? {
+            for directive in cache_control {
+                if let CacheDirective::StaleIfError(dur) = directive {
+                    window = Some(dur);
+                }
+            }
+        }
+
+        Ok(matches!(window, Some(window) if self.staleness(now) <= window))
+    }
+}
+
+/// The status codes that are heuristically cacheable.
+///
+/// See [RFC 9110, section 15.1](https://www.rfc-editor.org/rfc/rfc9110#section-15.1).
+pub(crate) fn is_heuristically_cacheable(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::Ok
+            | StatusCode::NonAuthoritativeInformation
+            | StatusCode::NoContent
+            | StatusCode::PartialContent
+            | StatusCode::MultipleChoice
+            | StatusCode::MovedPermanently
+            | StatusCode::PermanentRedirect
+            | StatusCode::NotFound
+            | StatusCode::MethodNotAllowed
+            | StatusCode::Gone
+            | StatusCode::UriTooLong
+            | StatusCode::NotImplemented
+    )
+}
+
+/// The duration from `earlier` to `later`, or zero if `later` comes first.
+fn duration_between(earlier: SystemTime, later: SystemTime) -> Duration {
+    later.duration_since(earlier).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::{AGE, CACHE_CONTROL, DATE, EXPIRES, LAST_MODIFIED};
+    use crate::utils::fmt_http_date;
+
+    fn at(secs: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_600_000_000 + secs)
+    }
+
+    fn response(headers: &[(&str, &str)]) -> Response {
+        let mut res = Response::new(200);
+        for (name, value) in headers {
+            res.insert_header(*name, *value).unwrap();
+        }
+        res
+    }
+
+    #[test]
+    fn age_calculation() -> crate::Result<()> {
+        let date = fmt_http_date(at(0));
+        let res = response(&[(DATE.as_str(), &date), (AGE.as_str(), "10")]);
+        let freshness = Freshness::new(&res, at(0), at(2), CacheKind::Private)?;
+
+        // corrected_age_value = 10 + 2 > apparent_age = 2
+        assert_eq!(freshness.current_age(at(2)), Duration::from_secs(12));
+        assert_eq!(freshness.current_age(at(7)), Duration::from_secs(17));
+        Ok(())
+    }
+
+    #[test]
+    fn lifetime_precedence() -> crate::Result<()> {
+        let date = fmt_http_date(at(0));
+        let expires = fmt_http_date(at(100));
+        let res = response(&[
+            (DATE.as_str(), &date),
+            (EXPIRES.as_str(), &expires),
+            (CACHE_CONTROL.as_str(), "max-age=50, s-maxage=20"),
+        ]);
+
+        let private = Freshness::new(&res, at(0), at(0), CacheKind::Private)?;
+        assert_eq!(private.freshness_lifetime(), Duration::from_secs(50));
+        let shared = Freshness::new(&res, at(0), at(0), CacheKind::Shared)?;
+        assert_eq!(shared.freshness_lifetime(), Duration::from_secs(20));
+
+        let res = response(&[(DATE.as_str(), &date), (EXPIRES.as_str(), &expires)]);
+        let freshness = Freshness::new(&res, at(0), at(0), CacheKind::Private)?;
+        assert_eq!(freshness.freshness_lifetime(), Duration::from_secs(100));
+
+        let res = response(&[(DATE.as_str(), &date), (EXPIRES.as_str(), "0")]);
+        let freshness = Freshness::new(&res, at(0), at(0), CacheKind::Private)?;
+        assert!(!freshness.is_fresh(at(0)));
+        Ok(())
+    }
+
+    #[test]
+    fn heuristic_lifetime() -> crate::Result<()> {
+        let date = fmt_http_date(at(1000));
+        let last_modified = fmt_http_date(at(0));
+        let res = response(&[
+            (DATE.as_str(), &date),
+            (LAST_MODIFIED.as_str(), &last_modified),
+        ]);
+        let freshness = Freshness::new(&res, at(1000), at(1000), CacheKind::Private)?;
+        assert!(freshness.is_heuristic());
+        assert_eq!(freshness.freshness_lifetime(), Duration::from_secs(100));
+
+        let mut res = res;
+        res.set_status(StatusCode::Created);
+        let freshness = Freshness::new(&res, at(1000), at(1000), CacheKind::Private)?;
+        assert!(!freshness.is_heuristic());
+        assert_eq!(freshness.freshness_lifetime(), Duration::from_secs(0));
+        Ok(())
+    }
+
+    #[test]
+    fn usability() -> crate::Result<()> {
+        let date = fmt_http_date(at(0));
+        let res = response(&[
+            (DATE.as_str(), &date),
+            (
+                CACHE_CONTROL.as_str(),
+                "max-age=60, stale-while-revalidate=30",
+            ),
+        ]);
+        let freshness = Freshness::new(&res, at(0), at(0), CacheKind::Private)?;
+        let req = |value: &str| {
+            let mut headers = Headers::new();
+            if !value.is_empty() {
+                headers.insert(CACHE_CONTROL, value).unwrap();
+            }
+            headers
+        };
+
+        assert_eq!(freshness.usability(req(""), at(10))?, Usability::Fresh);
+        assert_eq!(
+            freshness.usability(req("no-cache"), at(10))?,
+            Usability::MustRevalidate
+        );
+        assert_eq!(
+            freshness.usability(req("max-age=5"), at(10))?,
+            Usability::MustRevalidate
+        );
+        assert_eq!(
+            freshness.usability(req("min-fresh=55"), at(10))?,
+            Usability::MustRevalidate
+        );
+        assert_eq!(
+            freshness.usability(req("min-fresh=55, max-stale"), at(10))?,
+            Usability::MustRevalidate
+        );
+        assert_eq!(
+            freshness.usability(req("min-fresh=45"), at(10))?,
+            Usability::Fresh
+        );
+        assert_eq!(
+            freshness.usability(req(""), at(80))?,
+            Usability::StaleWhileRevalidate
+        );
+        assert_eq!(
+            freshness.usability(req(""), at(100))?,
+            Usability::MustRevalidate
+        );
+        assert_eq!(
+            freshness.usability(req("max-stale=60"), at(100))?,
+            Usability::StaleAllowed
+        );
+        assert_eq!(
+            freshness.usability(req("max-stale"), at(1000))?,
+            Usability::StaleAllowed
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn must_revalidate_prevents_stale() -> crate::Result<()> {
+        let res = response(&[(CACHE_CONTROL.as_str(), "max-age=60, must-revalidate")]);
+        let freshness = Freshness::new(&res, at(0), at(0), CacheKind::Private)?;
+        let mut req = Headers::new();
+        req.insert(CACHE_CONTROL, "max-stale")?;
+        assert_eq!(
+            freshness.usability(&req, at(100))?,
+            Usability::MustRevalidate
+        );
+        assert!(!freshness.allows_stale_if_error(&req, at(100))?);
+        Ok(())
+    }
+
+    #[test]
+    fn stale_if_error() -> crate::Result<()> {
+        let res = response(&[(CACHE_CONTROL.as_str(), "max-age=60, stale-if-error=60")]);
+        let freshness = Freshness::new(&res, at(0), at(0), CacheKind::Private)?;
+        assert!(freshness.allows_stale_if_error(Headers::new(), at(100))?);
+        assert!(!freshness.allows_stale_if_error(Headers::new(), at(200))?);
+
+        let mut req = Headers::new();
+        req.insert(CACHE_CONTROL, "stale-if-error=300")?;
+        assert!(freshness.allows_stale_if_error(&req, at(200))?);
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Web page performance can be significantly improved by caching resources.
 //! This submodule includes headers and types to communicate how and when to
-//! cache resources.
+//! cache resources, and to compute whether a stored response may be reused.
 //!
 //! # Further Reading
 //!
@@ -10,11 +10,21 @@
 
 mod age;
 mod cache_control;
+mod cache_key;
+mod cache_status;
 mod clear_site_data;
 mod expires;
+mod freshness;
+mod storable;
+
+pub mod store;
 
 pub use age::Age;
 pub use cache_control::CacheControl;
-pub use cache_control::CacheDirective;
+pub use cache_control::{CacheDirective, CacheExtension};
+pub use cache_key::CacheKey;
+pub use cache_status::{CacheStatus, CacheStatusEntry, ForwardReason};
 pub use clear_site_data::{ClearDirective, ClearSiteData};
 pub use expires::Expires;
+pub use freshness::{CacheKind, Freshness, Usability};
+pub use storable::is_storable;
@@ -0,0 +1,199 @@
+use crate::cache::freshness::is_heuristically_cacheable;
+use crate::cache::{CacheControl, CacheDirective, CacheKind};
+use crate::conditional::Vary;
+use crate::headers::{AUTHORIZATION, EXPIRES};
+use crate::{Method, Request, Response, StatusCode};
+
+/// Determine whether a response may be stored by a cache.
+///
+/// This checks the request method, the status code, the `no-store` and
+/// `private` directives, the rules for requests carrying an `Authorization`
+/// header, and `Vary: *`. The response must also carry explicit or heuristic
+/// freshness information, so it can be reused once stored.
+///
+/// # Specifications
+///
+/// - [RFC 9111, section 3: Storing Responses in Caches](https://www.rfc-editor.org/rfc/rfc9111#section-3)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cache::{is_storable, CacheControl, CacheDirective, CacheKind};
+/// use http_types::{Request, Response};
+/// use std::time::Duration;
+///
+/// let req = Request::get("https://example.com");
+/// let mut res = Response::new(200);
+///
+/// let mut cache_control = CacheControl::new();
+/// cache_control.push(CacheDirective::Private);
+/// cache_control.push(CacheDirective::MaxAge(Duration::from_secs(60)));
+/// res.insert_header(&cache_control, &cache_control);
+///
+/// assert!(is_storable(&req, &res, CacheKind::Private)?);
+/// assert!(!is_storable(&req, &res, CacheKind::Shared)?);
+/// #
+/// # Ok(()) }
+/// ```
+pub fn is_storable(req: &Request, res: &Response, kind: CacheKind) -> crate::Result<bool> {
+    if !matches!(req.method(), Method::Get | Method::Head) {
+        return Ok(false);
+    }
+
+    // Partial content can't be stored without range support, and `304 Not
+    // Modified` only updates an existing entry.
+    let status = res.status();
+    if status.is_informational()
+        || status == StatusCode::PartialContent
+        || status == StatusCode::NotModified
+    {
+        return Ok(false);
+    }
+
+    if let Some(cache_control) = CacheControl::from_headers(req)? {
+        if cache_control.iter().any(|d| d == &CacheDirective::NoStore) {
+            return Ok(false);
+        }
+    }
+
+    let mut public = false;
+    let mut private = false;
+    let mut max_age = false;
+    let mut s_maxage = false;
+    let mut must_revalidate = false;
+    let mut no_store = false;
+    let mut must_understand = false;
+    if let Some(cache_control) = CacheControl::from_headers(res)? {
+        for directive in cache_control {
+            match directive {
+                CacheDirective::NoStore => no_store = true,
+                CacheDirective::MustUnderstand => must_understand = true,
+                CacheDirective::Public => public = true,
+                CacheDirective::Private => private = true,
+                CacheDirective::MaxAge(_) => max_age = true,
+                CacheDirective::SMaxAge(_) => s_maxage = true,
+                CacheDirective::MustRevalidate => must_revalidate = true,
+                _ => {}
+            }
+        }
+    }
+
+    // RFC 9111, section 5.2.2.3: `must-understand` overrides `no-store` for
+    // caches that understand the status code, and forbids storing otherwise.
+    if must_understand {
+        if !is_heuristically_cacheable(status) {
+            return Ok(false);
+        }
+    } else if no_store {
+        return Ok(false);
+    }
+
+    if kind == CacheKind::Shared {
+        if private {
+            return Ok(false);
+        }
+
+        // RFC 9111, section 3.5: Storing Responses to Authenticated Requests.
+        if req.header(AUTHORIZATION).is_some() && !(public || s_maxage || must_revalidate) {
+            return Ok(false);
+        }
+    }
+
+    if let Some(vary) = Vary::from_headers(res)? {
+        if vary.wildcard() {
+            return Ok(false);
+        }
+    }
+
+    Ok(public
+        || (private && kind == CacheKind::Private)
+        || max_age
+        || (s_maxage && kind == CacheKind::Shared)
+        || res.header(EXPIRES).is_some()
+        || is_heuristically_cacheable(status))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::{CACHE_CONTROL, VARY};
+
+    fn response(status: u16, cache_control: &str) -> Response {
+        let mut res = Response::new(status);
+        if !cache_control.is_empty() {
+            res.insert_header(CACHE_CONTROL, cache_control).unwrap();
+        }
+        res
+    }
+
+    #[test]
+    fn method_and_status() -> crate::Result<()> {
+        let get = Request::get("https://example.com");
+        let post = Request::post("https://example.com");
+        let kind = CacheKind::Private;
+
+        assert!(is_storable(&get, &response(200, ""), kind)?);
+        assert!(is_storable(&get, &response(404, ""), kind)?);
+        assert!(!is_storable(&post, &response(200, "max-age=60"), kind)?);
+        assert!(!is_storable(&get, &response(201, ""), kind)?);
+        assert!(is_storable(&get, &response(201, "max-age=60"), kind)?);
+        assert!(!is_storable(&get, &response(206, "max-age=60"), kind)?);
+        assert!(!is_storable(&get, &response(304, "max-age=60"), kind)?);
+        Ok(())
+    }
+
+    #[test]
+    fn no_store() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        let kind = CacheKind::Private;
+        assert!(!is_storable(&req, &response(200, "no-store"), kind)?);
+
+        req.insert_header(CACHE_CONTROL, "no-store")?;
+        assert!(!is_storable(&req, &response(200, "max-age=60"), kind)?);
+        Ok(())
+    }
+
+    #[test]
+    fn shared_cache() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        let kind = CacheKind::Shared;
+        assert!(!is_storable(&req, &response(200, "private"), kind)?);
+        assert!(is_storable(&req, &response(200, "max-age=60"), kind)?);
+
+        req.insert_header(AUTHORIZATION, "Basic bm9yaTpmaXNo")?;
+        assert!(!is_storable(&req, &response(200, "max-age=60"), kind)?);
+        assert!(is_storable(
+            &req,
+            &response(200, "public, max-age=60"),
+            kind
+        )?);
+        assert!(is_storable(&req, &response(200, "s-maxage=60"), kind)?);
+        assert!(is_storable(
+            &req,
+            &response(200, "max-age=60"),
+            CacheKind::Private
+        )?);
+        Ok(())
+    }
+
+    #[test]
+    fn must_understand() -> crate::Result<()> {
+        let req = Request::get("https://example.com");
+        let kind = CacheKind::Private;
+        let directives = "must-understand, no-store, max-age=60";
+        assert!(is_storable(&req, &response(200, directives), kind)?);
+        assert!(!is_storable(&req, &response(201, directives), kind)?);
+        Ok(())
+    }
+
+    #[test]
+    fn vary_wildcard() -> crate::Result<()> {
+        let req = Request::get("https://example.com");
+        let mut res = response(200, "max-age=60");
+        res.insert_header(VARY, "*")?;
+        assert!(!is_storable(&req, &res, CacheKind::Private)?);
+        Ok(())
+    }
+}
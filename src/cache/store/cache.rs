@@ -0,0 +1,485 @@
+use crate::cache::store::{Entry, Store};
+use crate::cache::{is_storable, Age, CacheKey, CacheKind, Freshness, Usability};
+use crate::conditional::{ETag, IfModifiedSince, IfNoneMatch, LastModified};
+use crate::headers::{CONTENT_LOCATION, LOCATION};
+use crate::{Method, Request, Response, StatusCode, Url};
+
+use std::time::SystemTime;
+
+/// The result of looking up a request in a [`Cache`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum Lookup {
+    /// A stored response may be served as-is.
+    Hit(Response),
+    /// A stale response may be served, while the conditional request is sent
+    /// in the background to revalidate it.
+    Stale {
+        /// The stale response to serve.
+        response: Response,
+        /// The conditional request to revalidate the stored response with.
+        revalidation: Request,
+    },
+    /// The stored response must be revalidated before it can be served. The
+    /// conditional request should be forwarded, and its response passed to
+    /// [`Cache::update`] if it is `304 Not Modified`.
+    Revalidate(Request),
+    /// No stored response can be used.
+    Miss,
+}
+
+/// An HTTP cache on top of a [`Store`].
+///
+/// # Specifications
+///
+/// - [RFC 9111, section 4: Constructing Responses from Caches](https://www.rfc-editor.org/rfc/rfc9111#section-4)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+/// #
+/// use http_types::cache::store::{Cache, Lookup, MemoryStore};
+/// use http_types::cache::{CacheControl, CacheDirective, CacheKind};
+/// use http_types::{Request, Response};
+/// use std::time::{Duration, SystemTime};
+///
+/// let mut cache = Cache::new(MemoryStore::new(100), CacheKind::Private);
+/// let req = Request::get("https://example.com");
+///
+/// let mut res = Response::new(200);
+/// let mut cache_control = CacheControl::new();
+/// cache_control.push(CacheDirective::MaxAge(Duration::from_secs(60)));
+/// res.insert_header(&cache_control, &cache_control);
+/// res.set_body("Hello, Nori!");
+///
+/// let now = SystemTime::now();
+/// assert!(cache.insert(&req, &mut res, now, now).await?);
+///
+/// match cache.lookup(&req, now)? {
+///     Lookup::Hit(mut res) => assert_eq!(res.body_string().await?, "Hello, Nori!"),
+///     _ => panic!("expected a cache hit"),
+/// }
+/// #
+/// # Ok(()) }) }
+/// ```
+#[derive(Debug)]
+pub struct Cache<S> {
+    store: S,
+    kind: CacheKind,
+}
+
+impl<S: Store> Cache<S> {
+    /// Create a new instance of `Cache`.
+    pub fn new(store: S, kind: CacheKind) -> Self {
+        Self { store, kind }
+    }
+
+    /// Get a reference to the underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Get a mutable reference to the underlying store.
+    pub fn store_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+
+    /// Look up a stored response for a request at `now`.
+    pub fn lookup(&mut self, req: &Request, now: SystemTime) -> crate::Result<Lookup> {
+        if !matches!(req.method(), Method::Get | Method::Head) {
+            return Ok(Lookup::Miss);
+        }
+
+        let entry = match self.select(req)? {
+            Some(entry) => entry,
+            None => return Ok(Lookup::Miss),
+        };
+        let freshness = entry.freshness(self.kind)?;
+
+        match freshness.usability(req, now)? {
+            Usability::Fresh | Usability::StaleAllowed => {
+                Ok(Lookup::Hit(serve(&entry, &freshness, now)))
+            }
+            Usability::StaleWhileRevalidate => Ok(Lookup::Stale {
+                response: serve(&entry, &freshness, now),
+                revalidation: conditional_request(req, &entry)?.unwrap_or_else(|| req.clone()),
+            }),
+            _ => match conditional_request(req, &entry)? {
+                Some(req) => Ok(Lookup::Revalidate(req)),
+                None => Ok(Lookup::Miss),
+            },
+        }
+    }
+
+    /// Look up a stored response that may be served because the origin server
+    /// could not be reached or returned an error (`stale-if-error`).
+    pub fn lookup_stale_if_error(
+        &mut self,
+        req: &Request,
+        now: SystemTime,
+    ) -> crate::Result<Option<Response>> {
+        let entry = match self.select(req)? {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+        let freshness = entry.freshness(self.kind)?;
+        if freshness.allows_stale_if_error(req, now)? {
+            Ok(Some(serve(&entry, &freshness, now)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Store a response received from the origin server, if it may be stored.
+    ///
+    /// The response body is buffered, and `res` is left with a readable copy
+    /// of it. Successful responses to unsafe methods such as `POST` invalidate
+    /// the stored responses for the same URL, and for the URLs in the
+    /// `Location` and `Content-Location` headers if they share its origin.
+    /// Returns `true` if the response was stored.
+    pub async fn insert(
+        &mut self,
+        req: &Request,
+        res: &mut Response,
+        request_time: SystemTime,
+        response_time: SystemTime,
+    ) -> crate::Result<bool> {
+        let status = res.status();
+        if !req.method().is_safe() && (status.is_success() || status.is_redirection()) {
+            // RFC 9111, section 4.4: only invalidate URLs with the same origin
+            // as the request, to prevent denial-of-service attacks.
+            self.invalidate(req, req.url().clone());
+            for name in [LOCATION, CONTENT_LOCATION] {
+                let url = match res.header(name).and_then(|values| values.get(0)) {
+                    Some(value) => req.url().join(value.as_str()).ok(),
+                    None => None,
+                };
+                if let Some(url) = url {
+                    if url.origin() == req.url().origin() {
+                        self.invalidate(req, url);
+                    }
+                }
+            }
+            return Ok(false);
+        }
+
+        if !is_storable(req, res, self.kind)? {
+            return Ok(false);
+        }
+
+        let mut entry = Entry::new(req, res, request_time, response_time).await?;
+        entry.remove_unstorable_fields(self.kind)?;
+        self.store.insert(entry);
+        Ok(true)
+    }
+
+    /// Merge a `304 Not Modified` response into the stored responses it
+    /// validates, and return the updated response for the request.
+    ///
+    /// Returns `None` if `res` is not a `304 Not Modified` response, or if no
+    /// stored response matches it.
+    pub fn update(
+        &mut self,
+        req: &Request,
+        res: &Response,
+        request_time: SystemTime,
+        response_time: SystemTime,
+    ) -> crate::Result<Option<Response>> {
+        if res.status() != StatusCode::NotModified {
+            return Ok(None);
+        }
+
+        // RFC 9111, section 4.3.4: select the stored responses to update by
+        // the validators in the 304 response.
+        let variants = self.store.get(&CacheKey::new(req));
+        let etag = ETag::from_headers(res)?;
+        let last_modified = LastModified::from_headers(res)?;
+        let mut selected = vec![];
+        for entry in variants.iter() {
+            let selects = match (&etag, &last_modified) {
+                (Some(etag), _) => match (etag, ETag::from_headers(entry)?) {
+                    (ETag::Strong(a), Some(ETag::Strong(b))) => a == &b,
+                    (ETag::Weak(a), Some(ETag::Strong(b) | ETag::Weak(b))) => a == &b,
+                    _ => false,
+                },
+                (None, Some(last_modified)) => {
+                    LastModified::from_headers(entry)?.as_ref() == Some(last_modified)
+                }
+                (None, None) => variants.len() == 1,
+            };
+            if selects {
+                selected.push(entry.clone());
+            }
+        }
+
+        let mut matched = None;
+        for mut entry in selected {
+            entry.update(res, request_time, response_time)?;
+            entry.remove_unstorable_fields(self.kind)?;
+            if matched.is_none() && entry.matches(req)? {
+                matched = Some(entry.clone());
+            }
+            self.store.insert(entry);
+        }
+
+        match matched {
+            Some(entry) => {
+                let freshness = entry.freshness(self.kind)?;
+                Ok(Some(serve(&entry, &freshness, response_time)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Remove the stored `GET` and `HEAD` responses for a URL.
+    fn invalidate(&mut self, req: &Request, url: Url) {
+        let mut key = req.clone();
+        *key.url_mut() = url;
+        key.set_method(Method::Get);
+        self.store.remove(&CacheKey::new(&key));
+        key.set_method(Method::Head);
+        self.store.remove(&CacheKey::new(&key));
+    }
+
+    /// Find the stored variant that matches the request.
+    fn select(&mut self, req: &Request) -> crate::Result<Option<Entry>> {
+        for entry in self.store.get(&CacheKey::new(req)) {
+            if entry.matches(req)? {
+                return Ok(Some(entry));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Create a response from a stored entry, with its `Age` header set.
+fn serve(entry: &Entry, freshness: &Freshness, now: SystemTime) -> Response {
+    let mut res = entry.to_response();
+    let age = Age::new(freshness.current_age(now));
+    res.insert_header(&age, &age)
+        .expect("Age is a valid header");
+    res
+}
+
+/// Turn a request into a conditional request, using the validators of the
+/// stored response. Returns `None` if the stored response has no validators.
+fn conditional_request(req: &Request, entry: &Entry) -> crate::Result<Option<Request>> {
+    let etag = ETag::from_headers(entry)?;
+    let last_modified = LastModified::from_headers(entry)?;
+    if etag.is_none() && last_modified.is_none() {
+        return Ok(None);
+    }
+
+    let mut req = req.clone();
+    if let Some(etag) = etag {
+        let mut if_none_match = IfNoneMatch::new();
+        if_none_match.push(etag);
+        req.insert_header(&if_none_match, &if_none_match)?;
+    }
+    if let Some(last_modified) = last_modified {
+        let if_modified_since = IfModifiedSince::new(last_modified.modified());
+        req.insert_header(&if_modified_since, &if_modified_since)?;
+    }
+    Ok(Some(req))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cache::store::MemoryStore;
+    use crate::headers::{AGE, CACHE_CONTROL, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH};
+    use crate::utils::fmt_http_date;
+    use std::time::Duration;
+
+    fn at(secs: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_600_000_000 + secs)
+    }
+
+    fn cache() -> Cache<MemoryStore> {
+        Cache::new(MemoryStore::new(10), CacheKind::Shared)
+    }
+
+    fn response(cache_control: &str) -> Response {
+        let mut res = Response::new(200);
+        res.insert_header(CACHE_CONTROL, cache_control).unwrap();
+        res.insert_header(ETAG, r#""v1""#).unwrap();
+        res.set_body("Hello, Nori!");
+        res
+    }
+
+    #[async_std::test]
+    async fn hit_and_revalidate() -> crate::Result<()> {
+        let mut cache = cache();
+        let req = Request::get("https://example.com");
+        assert!(matches!(cache.lookup(&req, at(0))?, Lookup::Miss));
+
+        let mut res = response("max-age=60");
+        assert!(cache.insert(&req, &mut res, at(0), at(0)).await?);
+        assert_eq!(res.body_string().await?, "Hello, Nori!");
+
+        match cache.lookup(&req, at(10))? {
+            Lookup::Hit(mut res) => {
+                assert_eq!(res[AGE], "10");
+                assert_eq!(res.body_string().await?, "Hello, Nori!");
+            }
+            lookup => panic!("expected a hit, got {:?}", lookup),
+        }
+
+        let revalidation = match cache.lookup(&req, at(100))? {
+            Lookup::Revalidate(req) => req,
+            lookup => panic!("expected a revalidation, got {:?}", lookup),
+        };
+        assert_eq!(revalidation[IF_NONE_MATCH], r#""v1""#);
+
+        let mut not_modified = Response::new(304);
+        not_modified.insert_header(ETAG, r#""v1""#)?;
+        not_modified.insert_header(CACHE_CONTROL, "max-age=120")?;
+        let mut res = cache
+            .update(&revalidation, &not_modified, at(100), at(100))?
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        assert_eq!(res[CACHE_CONTROL], "max-age=120");
+        assert_eq!(res.body_string().await?, "Hello, Nori!");
+
+        assert!(matches!(cache.lookup(&req, at(200))?, Lookup::Hit(_)));
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn stale_while_revalidate() -> crate::Result<()> {
+        let mut cache = cache();
+        let req = Request::get("https://example.com");
+        let mut res = response("max-age=60, stale-while-revalidate=60");
+        let last_modified = fmt_http_date(at(0));
+        res.insert_header("Last-Modified", last_modified.as_str())?;
+        cache.insert(&req, &mut res, at(0), at(0)).await?;
+
+        match cache.lookup(&req, at(90))? {
+            Lookup::Stale {
+                response,
+                revalidation,
+            } => {
+                assert_eq!(response[AGE], "90");
+                assert_eq!(revalidation[IF_MODIFIED_SINCE], last_modified.as_str());
+            }
+            lookup => panic!("expected a stale response, got {:?}", lookup),
+        }
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn stale_if_error() -> crate::Result<()> {
+        let mut cache = cache();
+        let req = Request::get("https://example.com");
+        let mut res = response("max-age=60, stale-if-error=60");
+        cache.insert(&req, &mut res, at(0), at(0)).await?;
+
+        assert!(cache.lookup_stale_if_error(&req, at(90))?.is_some());
+        assert!(cache.lookup_stale_if_error(&req, at(200))?.is_none());
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn unsafe_methods_invalidate() -> crate::Result<()> {
+        let mut cache = cache();
+        let req = Request::get("https://example.com");
+        let mut res = response("max-age=60");
+        cache.insert(&req, &mut res, at(0), at(0)).await?;
+
+        let post = Request::post("https://example.com");
+        let mut res = Response::new(201);
+        assert!(!cache.insert(&post, &mut res, at(1), at(1)).await?);
+        assert!(matches!(cache.lookup(&req, at(2))?, Lookup::Miss));
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn unsafe_methods_invalidate_locations() -> crate::Result<()> {
+        let mut cache = cache();
+        for url in &[
+            "https://example.com/cats/nori",
+            "https://example.com/cats",
+            "https://other.example/cats",
+        ] {
+            let mut res = response("max-age=60");
+            cache
+                .insert(&Request::get(*url), &mut res, at(0), at(0))
+                .await?;
+        }
+
+        let post = Request::post("https://example.com/cats/new");
+        let mut res = Response::new(201);
+        res.insert_header("Location", "/cats/nori")?;
+        res.insert_header("Content-Location", "https://other.example/cats")?;
+        assert!(!cache.insert(&post, &mut res, at(1), at(1)).await?);
+
+        let lookup = |cache: &mut Cache<_>, url| cache.lookup(&Request::get(url), at(2));
+        let nori = lookup(&mut cache, "https://example.com/cats/nori")?;
+        assert!(matches!(nori, Lookup::Miss));
+        let cats = lookup(&mut cache, "https://example.com/cats")?;
+        assert!(matches!(cats, Lookup::Hit(_)));
+        // Other origins are left alone.
+        let other = lookup(&mut cache, "https://other.example/cats")?;
+        assert!(matches!(other, Lookup::Hit(_)));
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn qualified_directives() -> crate::Result<()> {
+        let mut cache = cache();
+        let req = Request::get("https://example.com");
+        let mut res = response(r#"max-age=60, no-cache="set-cookie", private="x-user""#);
+        res.insert_header("Set-Cookie", "flavor=tuna")?;
+        res.insert_header("X-User", "nori")?;
+        res.insert_header("X-Shared", "chashu")?;
+        cache.insert(&req, &mut res, at(0), at(0)).await?;
+
+        match cache.lookup(&req, at(1))? {
+            Lookup::Hit(res) => {
+                assert!(res.header("Set-Cookie").is_none());
+                assert!(res.header("X-User").is_none());
+                assert_eq!(res["X-Shared"], "chashu");
+            }
+            lookup => panic!("expected a hit, got {:?}", lookup),
+        }
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn qualified_directives_on_update() -> crate::Result<()> {
+        let mut cache = cache();
+        let req = Request::get("https://example.com");
+        let mut res = response("max-age=60");
+        cache.insert(&req, &mut res, at(0), at(0)).await?;
+
+        let mut not_modified = Response::new(304);
+        not_modified.insert_header(ETAG, r#""v1""#)?;
+        not_modified.insert_header(CACHE_CONTROL, r#"max-age=60, private="x-user""#)?;
+        not_modified.insert_header("X-User", "nori")?;
+        let res = cache.update(&req, &not_modified, at(70), at(70))?.unwrap();
+        assert!(res.header("X-User").is_none());
+
+        match cache.lookup(&req, at(71))? {
+            Lookup::Hit(res) => assert!(res.header("X-User").is_none()),
+            lookup => panic!("expected a hit, got {:?}", lookup),
+        }
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn vary() -> crate::Result<()> {
+        let mut cache = cache();
+        let mut en = Request::get("https://example.com");
+        en.insert_header("Accept-Language", "en")?;
+        let mut res = response("max-age=60");
+        res.insert_header("Vary", "Accept-Language")?;
+        cache.insert(&en, &mut res, at(0), at(0)).await?;
+
+        let mut nl = Request::get("https://example.com");
+        nl.insert_header("Accept-Language", "nl")?;
+        assert!(matches!(cache.lookup(&en, at(1))?, Lookup::Hit(_)));
+        assert!(matches!(cache.lookup(&nl, at(1))?, Lookup::Miss));
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use crate::cache::{CacheControl, CacheDirective, CacheKey, CacheKind, Freshness};
+use crate::conditional::Vary;
+use crate::headers::{
+    HeaderName, Headers, CONNECTION, CONTENT_LENGTH, PROXY_CONNECTION, TE, TRAILER,
+    TRANSFER_ENCODING, UPGRADE,
+};
+use crate::{Body, Request, Response, StatusCode, Version};
+
+use std::time::SystemTime;
+
+/// Header fields that describe a single message, and are never stored or
+/// updated from a `304 Not Modified` response.
+const UNSTORED: [HeaderName; 7] = [
+    CONNECTION,
+    CONTENT_LENGTH,
+    PROXY_CONNECTION,
+    TE,
+    TRAILER,
+    TRANSFER_ENCODING,
+    UPGRADE,
+];
+
+/// A response held by a [`Store`](super::Store), together with the times it
+/// was requested and received.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+/// #
+/// use http_types::cache::store::Entry;
+/// use http_types::{Request, Response};
+/// use std::time::SystemTime;
+///
+/// let req = Request::get("https://example.com");
+/// let mut res = Response::new(200);
+/// res.set_body("Hello, Nori!");
+///
+/// let now = SystemTime::now();
+/// let entry = Entry::new(&req, &mut res, now, now).await?;
+/// assert_eq!(entry.body(), b"Hello, Nori!");
+///
+/// // The response body is still readable after it has been buffered.
+/// assert_eq!(res.body_string().await?, "Hello, Nori!");
+/// #
+/// # Ok(()) }) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Entry {
+    key: CacheKey,
+    status: StatusCode,
+    version: Option<Version>,
+    headers: Headers,
+    body: Vec<u8>,
+    request_time: SystemTime,
+    response_time: SystemTime,
+}
+
+impl Entry {
+    /// Create a new entry by buffering the response body.
+    ///
+    /// The body of `res` is replaced by the buffered copy, so the response can
+    /// still be forwarded afterwards.
+    pub async fn new(
+        req: &Request,
+        res: &mut Response,
+        request_time: SystemTime,
+        response_time: SystemTime,
+    ) -> crate::Result<Self> {
+        let key = match Vary::from_headers(&res)? {
+            Some(vary) => CacheKey::with_vary(req, &vary),
+            None => CacheKey::new(req),
+        };
+
+        let body = res.take_body();
+        let mime = body.mime().cloned();
+        let bytes = body.into_bytes().await?;
+        let mut body = Body::from_bytes(bytes.clone());
+        body.set_mime(mime);
+        res.set_body(body);
+
+        let mut headers = Headers::new();
+        for (name, values) in res.iter() {
+            if !UNSTORED.contains(name) {
+                headers.insert(name, values)?;
+            }
+        }
+
+        Ok(Self {
+            key,
+            status: res.status(),
+            version: res.version(),
+            headers,
+            body: bytes,
+            request_time,
+            response_time,
+        })
+    }
+
+    /// Get the key this entry is stored under.
+    pub fn key(&self) -> &CacheKey {
+        &self.key
+    }
+
+    /// Get the status code of the stored response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Get the headers of the stored response.
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    /// Get the body of the stored response.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Get the time the request that produced the response was sent.
+    pub fn request_time(&self) -> SystemTime {
+        self.request_time
+    }
+
+    /// Get the time the response was received.
+    pub fn response_time(&self) -> SystemTime {
+        self.response_time
+    }
+
+    /// Compute the freshness of the stored response.
+    pub fn freshness(&self, kind: CacheKind) -> crate::Result<Freshness> {
+        Freshness::from_parts(
+            &self.headers,
+            self.status,
+            self.request_time,
+            self.response_time,
+            kind,
+        )
+    }
+
+    /// Returns `true` if the stored response may be used for `req`, taking
+    /// the stored response's `Vary` header into account.
+    pub fn matches(&self, req: &Request) -> crate::Result<bool> {
+        let key = match Vary::from_headers(&self.headers)? {
+            Some(vary) if vary.wildcard() => return Ok(false),
+            Some(vary) => CacheKey::with_vary(req, &vary),
+            None => CacheKey::new(req),
+        };
+        Ok(key == self.key)
+    }
+
+    /// Create a response from the stored status, headers and body.
+    pub fn to_response(&self) -> Response {
+        let mut res = Response::new(self.status);
+        res.set_version(self.version);
+        let mut body = Body::from_bytes(self.body.clone());
+        body.set_mime(None);
+        res.set_body(body);
+        for (name, values) in self.headers.iter() {
+            res.insert_header(name, values)
+                .expect("stored headers are valid");
+        }
+        res
+    }
+
+    /// Remove the header fields listed by a qualified `no-cache`, which may
+    /// not be reused without revalidation, and those listed by a qualified
+    /// `private`, which shared caches may not store.
+    pub(crate) fn remove_unstorable_fields(&mut self, kind: CacheKind) -> crate::Result<()> {
+        if let Some(cache_control) = CacheControl::from_headers(&self.headers)? {
+            for directive in cache_control {
+                let names = match directive {
+                    CacheDirective::NoCacheFields(names) => names,
+                    CacheDirective::PrivateFields(names) if kind == CacheKind::Shared => names,
+                    _ => continue,
+                };
+                for name in names {
+                    self.headers.remove(name);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Update the stored headers from a `304 Not Modified` response.
+    pub(crate) fn update(
+        &mut self,
+        res: &Response,
+        request_time: SystemTime,
+        response_time: SystemTime,
+    ) -> crate::Result<()> {
+        for (name, values) in res.iter() {
+            if !UNSTORED.contains(name) {
+                self.headers.insert(name, values)?;
+            }
+        }
+        self.request_time = request_time;
+        self.response_time = response_time;
+        Ok(())
+    }
+}
+
+impl AsRef<Headers> for Entry {
+    fn as_ref(&self) -> &Headers {
+        &self.headers
+    }
+}
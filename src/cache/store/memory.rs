@@ -0,0 +1,197 @@
+use crate::cache::store::{Entry, Store};
+use crate::cache::CacheKey;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// A bounded, in-memory [`Store`] which evicts the least recently used
+/// entries first.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+/// #
+/// use http_types::cache::store::{Entry, MemoryStore, Store};
+/// use http_types::cache::CacheKey;
+/// use http_types::{Request, Response};
+/// use std::time::SystemTime;
+///
+/// let mut store = MemoryStore::new(1);
+/// let now = SystemTime::now();
+///
+/// let nori = Request::get("https://example.com/nori");
+/// let entry = Entry::new(&nori, &mut Response::new(200), now, now).await?;
+/// store.insert(entry);
+///
+/// let chashu = Request::get("https://example.com/chashu");
+/// let entry = Entry::new(&chashu, &mut Response::new(200), now, now).await?;
+/// store.insert(entry);
+///
+/// assert_eq!(store.len(), 1);
+/// assert!(store.get(&CacheKey::new(&nori)).is_empty());
+/// assert_eq!(store.get(&CacheKey::new(&chashu)).len(), 1);
+/// #
+/// # Ok(()) }) }
+/// ```
+#[derive(Debug)]
+pub struct MemoryStore {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<CacheKey, (u64, Entry)>,
+    variants: HashMap<CacheKey, Vec<CacheKey>>,
+    recency: BTreeMap<u64, CacheKey>,
+}
+
+impl MemoryStore {
+    /// Create a new store which holds at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            variants: HashMap::new(),
+            recency: BTreeMap::new(),
+        }
+    }
+
+    /// Get the maximum number of entries the store holds.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of entries in the store.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the store holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Mark an entry as most recently used.
+    fn touch(&mut self, key: &CacheKey) {
+        self.tick += 1;
+        if let Some((tick, _)) = self.entries.get_mut(key) {
+            self.recency.remove(tick);
+            *tick = self.tick;
+            self.recency.insert(self.tick, key.clone());
+        }
+    }
+
+    /// Remove a single entry by its full key.
+    fn remove_entry(&mut self, key: &CacheKey) {
+        if let Some((tick, _)) = self.entries.remove(key) {
+            self.recency.remove(&tick);
+        }
+        let primary = key.primary();
+        if let Some(keys) = self.variants.get_mut(&primary) {
+            keys.retain(|k| k != key);
+            if keys.is_empty() {
+                self.variants.remove(&primary);
+            }
+        }
+    }
+}
+
+impl Store for MemoryStore {
+    fn get(&mut self, key: &CacheKey) -> Vec<Entry> {
+        let keys = match self.variants.get(&key.primary()) {
+            Some(keys) => keys.clone(),
+            None => return vec![],
+        };
+        keys.iter()
+            .filter_map(|key| {
+                self.touch(key);
+                self.entries.get(key).map(|(_, entry)| entry.clone())
+            })
+            .collect()
+    }
+
+    fn insert(&mut self, entry: Entry) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let key = entry.key().clone();
+        self.remove_entry(&key);
+        while self.entries.len() >= self.capacity {
+            let oldest = match self.recency.values().next() {
+                Some(key) => key.clone(),
+                None => break,
+            };
+            self.remove_entry(&oldest);
+        }
+
+        self.tick += 1;
+        self.recency.insert(self.tick, key.clone());
+        self.variants
+            .entry(key.primary())
+            .or_default()
+            .push(key.clone());
+        self.entries.insert(key, (self.tick, entry));
+    }
+
+    fn remove(&mut self, key: &CacheKey) {
+        if let Some(keys) = self.variants.remove(&key.primary()) {
+            for key in keys {
+                if let Some((tick, _)) = self.entries.remove(&key) {
+                    self.recency.remove(&tick);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Request, Response};
+    use std::time::SystemTime;
+
+    async fn entry(url: &str, lang: &str) -> crate::Result<Entry> {
+        let mut req = Request::get(url);
+        req.insert_header("Accept-Language", lang)?;
+        let mut res = Response::new(200);
+        res.insert_header("Vary", "Accept-Language")?;
+        let now = SystemTime::now();
+        Entry::new(&req, &mut res, now, now).await
+    }
+
+    #[async_std::test]
+    async fn evicts_least_recently_used() -> crate::Result<()> {
+        let mut store = MemoryStore::new(2);
+        let a = entry("https://example.com/a", "en").await?;
+        let b = entry("https://example.com/b", "en").await?;
+        let c = entry("https://example.com/c", "en").await?;
+
+        store.insert(a.clone());
+        store.insert(b.clone());
+        assert_eq!(store.get(a.key()).len(), 1);
+
+        store.insert(c.clone());
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get(a.key()).len(), 1);
+        assert!(store.get(b.key()).is_empty());
+        assert_eq!(store.get(c.key()).len(), 1);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn variants() -> crate::Result<()> {
+        let mut store = MemoryStore::new(10);
+        let en = entry("https://example.com", "en").await?;
+        let nl = entry("https://example.com", "nl").await?;
+
+        store.insert(en.clone());
+        store.insert(nl.clone());
+        store.insert(nl.clone());
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get(&en.key().primary()).len(), 2);
+
+        store.remove(nl.key());
+        assert!(store.is_empty());
+        assert!(store.get(en.key()).is_empty());
+        Ok(())
+    }
+}
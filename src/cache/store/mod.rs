@@ -0,0 +1,37 @@
+//! Storage for HTTP responses.
+//!
+//! A [`Cache`] decides which responses may be stored and reused, and keeps
+//! them in a pluggable [`Store`]. [`MemoryStore`] is a bounded, in-memory
+//! store that evicts the least recently used entries first.
+//!
+//! # Specifications
+//!
+//! - [RFC 9111: HTTP Caching](https://www.rfc-editor.org/rfc/rfc9111)
+
+#[allow(clippy::module_inception)]
+mod cache;
+mod entry;
+mod memory;
+
+pub use cache::{Cache, Lookup};
+pub use entry::Entry;
+pub use memory::MemoryStore;
+
+use crate::cache::CacheKey;
+
+/// A backend that stores cached responses.
+///
+/// Entries are looked up by their primary [`CacheKey`]. Because a response
+/// that carries a `Vary` header can have several variants, a lookup returns
+/// every entry stored under the primary key; the [`Cache`] picks the variant
+/// matching the request.
+pub trait Store {
+    /// Get all stored variants for a primary cache key.
+    fn get(&mut self, key: &CacheKey) -> Vec<Entry>;
+
+    /// Store an entry, replacing any existing entry with the same key.
+    fn insert(&mut self, entry: Entry);
+
+    /// Remove all variants stored under a primary cache key.
+    fn remove(&mut self, key: &CacheKey);
+}
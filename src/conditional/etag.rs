@@ -76,6 +76,39 @@ impl ETag {
         matches!(self, Self::Weak(_))
     }
 
+    /// Returns `true` if both ETags are strong and their values are equal.
+    ///
+    /// This is the strong comparison function, used for `If-Match`.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 9110, section 8.8.3.2: Comparison](https://www.rfc-editor.org/rfc/rfc9110#section-8.8.3.2)
+    pub fn strong_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Strong(a), Self::Strong(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the values of both ETags are equal, regardless of
+    /// whether either is weak.
+    ///
+    /// This is the weak comparison function, used for `If-None-Match`.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 9110, section 8.8.3.2: Comparison](https://www.rfc-editor.org/rfc/rfc9110#section-8.8.3.2)
+    pub fn weak_eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+
+    /// Get the opaque value of the ETag, without quotes or weakness prefix.
+    pub fn value(&self) -> &str {
+        match self {
+            Self::Strong(s) | Self::Weak(s) => s,
+        }
+    }
+
     /// Create an Etag from a string.
     pub(crate) fn from_str(s: &str) -> crate::Result<Self> {
         let mut weak = false;
@@ -184,6 +217,20 @@ mod test {
         assert_eq!(format!("{}", err), msg);
     }
 
+    #[test]
+    fn comparison() {
+        // Examples from RFC 9110, section 8.8.3.2.
+        let weak_one = ETag::new_weak("1".to_string());
+        let weak_two = ETag::new_weak("2".to_string());
+        let strong_one = ETag::new("1".to_string());
+        assert!(!weak_one.strong_eq(&weak_one));
+        assert!(weak_one.weak_eq(&weak_one));
+        assert!(!weak_one.weak_eq(&weak_two));
+        assert!(!weak_one.strong_eq(&strong_one));
+        assert!(weak_one.weak_eq(&strong_one));
+        assert!(strong_one.strong_eq(&strong_one));
+    }
+
     #[test]
     fn validate_characters() {
         assert_entry_err(r#"""hello""#, "Invalid ETag header");
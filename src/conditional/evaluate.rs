@@ -0,0 +1,399 @@
+use crate::conditional::{
+    ETag, IfMatch, IfModifiedSince, IfNoneMatch, IfUnmodifiedSince, LastModified,
+};
+use crate::headers::{
+    HeaderName, Headers, CACHE_CONTROL, CONTENT_LOCATION, DATE, ETAG, EXPIRES, VARY,
+};
+use crate::{Method, Request, Response, StatusCode};
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Header fields a `304 Not Modified` response must carry if they would have
+/// been sent in a `200 OK` response.
+const NOT_MODIFIED_HEADERS: [HeaderName; 6] =
+    [CACHE_CONTROL, CONTENT_LOCATION, DATE, ETAG, EXPIRES, VARY];
+
+/// The validators of the selected representation of a resource.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::conditional::{ETag, Validators};
+/// use http_types::Response;
+///
+/// let mut res = Response::new(200);
+/// res.insert_header("ETag", r#""0xcafebeef""#);
+///
+/// let validators = Validators::from_headers(&res)?;
+/// assert_eq!(validators.etag(), Some(&ETag::new("0xcafebeef".into())));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Validators {
+    etag: Option<ETag>,
+    last_modified: Option<SystemTime>,
+    exists: bool,
+    headers: Headers,
+}
+
+impl Validators {
+    /// Create a new instance of `Validators` for an existing resource.
+    pub fn new() -> Self {
+        Self {
+            etag: None,
+            last_modified: None,
+            exists: true,
+            headers: Headers::new(),
+        }
+    }
+
+    /// Create a new instance from the headers of the response that would be
+    /// sent if the request proceeded.
+    ///
+    /// Besides `ETag` and `Last-Modified`, this keeps the header fields a
+    /// `304 Not Modified` response must repeat: `Cache-Control`,
+    /// `Content-Location`, `Date`, `Expires` and `Vary`.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Self> {
+        let headers = headers.as_ref();
+        let mut validators = Self::new();
+        validators.etag = ETag::from_headers(headers)?;
+        validators.last_modified = LastModified::from_headers(headers)?.map(|l| l.modified());
+        for name in NOT_MODIFIED_HEADERS.iter() {
+            if let Some(values) = headers.get(name) {
+                validators.headers.insert(name, values)?;
+            }
+        }
+        Ok(validators)
+    }
+
+    /// Get the entity tag of the selected representation.
+    pub fn etag(&self) -> Option<&ETag> {
+        self.etag.as_ref()
+    }
+
+    /// Set the entity tag of the selected representation.
+    pub fn set_etag(&mut self, etag: Option<ETag>) {
+        if let Some(etag) = &etag {
+            self.headers.insert(ETAG, etag.to_string()).unwrap();
+        } else {
+            self.headers.remove(ETAG);
+        }
+        self.etag = etag;
+    }
+
+    /// Get the modification date of the selected representation.
+    pub fn last_modified(&self) -> Option<SystemTime> {
+        self.last_modified
+    }
+
+    /// Set the modification date of the selected representation.
+    pub fn set_last_modified(&mut self, last_modified: Option<SystemTime>) {
+        self.last_modified = last_modified;
+    }
+
+    /// Returns `true` if the target resource has a current representation.
+    pub fn exists(&self) -> bool {
+        self.exists
+    }
+
+    /// Set whether the target resource has a current representation.
+    ///
+    /// This decides how `If-Match: *` and `If-None-Match: *` are evaluated.
+    pub fn set_exists(&mut self, exists: bool) {
+        self.exists = exists;
+    }
+}
+
+impl Default for Validators {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The result of evaluating the preconditions of a request.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum Outcome {
+    /// All preconditions passed; the request should be performed.
+    Proceed,
+    /// The selected representation has not been modified. Contains a
+    /// `304 Not Modified` response carrying the required header fields.
+    NotModified(Response),
+    /// A precondition failed; the server should respond with
+    /// `412 Precondition Failed`.
+    PreconditionFailed,
+}
+
+impl Outcome {
+    /// Returns `true` if the request should be performed.
+    pub fn is_proceed(&self) -> bool {
+        matches!(self, Self::Proceed)
+    }
+
+    /// Convert the outcome into the response to send instead of performing
+    /// the request, if any.
+    pub fn into_response(self) -> Option<Response> {
+        match self {
+            Self::Proceed => None,
+            Self::NotModified(res) => Some(res),
+            Self::PreconditionFailed => Some(Response::new(StatusCode::PreconditionFailed)),
+        }
+    }
+}
+
+/// Evaluate the preconditions of a request against the validators of the
+/// selected representation.
+///
+/// Preconditions are evaluated in the order defined by RFC 9110:
+///
+/// 1. `If-Match`, using strong comparison.
+/// 2. `If-Unmodified-Since`, when `If-Match` is absent.
+/// 3. `If-None-Match`, using weak comparison. A match yields
+///    `304 Not Modified` for `GET` and `HEAD`, and `412 Precondition Failed`
+///    for every other method.
+/// 4. `If-Modified-Since`, for `GET` and `HEAD` when `If-None-Match` is
+///    absent.
+///
+/// Date conditions are ignored if the date is invalid or the representation
+/// has no modification date. An `If-Match` that can't be parsed fails, and
+/// an `If-None-Match` that can't be parsed is ignored. `If-Range` only
+/// applies to range requests and is left to the range handling.
+///
+/// # Specifications
+///
+/// - [RFC 9110, section 13.2.2: Precedence of Preconditions](https://www.rfc-editor.org/rfc/rfc9110#section-13.2.2)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::conditional::{self, ETag, Outcome, Validators};
+/// use http_types::{Request, StatusCode};
+///
+/// let mut validators = Validators::new();
+/// validators.set_etag(Some(ETag::new("0xcafebeef".into())));
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header("If-None-Match", r#"W/"0xcafebeef""#);
+///
+/// match conditional::evaluate(&req, &validators) {
+///     Outcome::NotModified(res) => {
+///         assert_eq!(res.status(), StatusCode::NotModified);
+///         assert_eq!(res["ETag"], r#""0xcafebeef""#);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// let mut req = Request::put("https://example.com");
+/// req.insert_header("If-Match", r#""0xdeadbeef""#);
+/// let outcome = conditional::evaluate(&req, &validators);
+/// assert!(matches!(outcome, Outcome::PreconditionFailed));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn evaluate(req: &Request, validators: &Validators) -> Outcome {
+    let is_get_or_head = matches!(req.method(), Method::Get | Method::Head);
+    let last_modified = validators.last_modified.map(truncate);
+
+    // Step 1 and 2: If-Match, or If-Unmodified-Since in its absence.
+    match IfMatch::from_headers(req) {
+        Ok(Some(if_match)) => {
+            let matches = if if_match.wildcard() {
+                validators.exists
+            } else {
+                match &validators.etag {
+                    Some(etag) => if_match.iter().any(|tag| tag.strong_eq(etag)),
+                    None => false,
+                }
+            };
+            if !matches {
+                return Outcome::PreconditionFailed;
+            }
+        }
+        Err(_) => return Outcome::PreconditionFailed,
+        Ok(None) => {
+            if let (Ok(Some(since)), Some(last_modified)) =
+                (IfUnmodifiedSince::from_headers(req), last_modified)
+            {
+                if last_modified > since.modified() {
+                    return Outcome::PreconditionFailed;
+                }
+            }
+        }
+    }
+
+    // Step 3 and 4: If-None-Match, or If-Modified-Since in its absence.
+    match IfNoneMatch::from_headers(req) {
+        Ok(Some(if_none_match)) => {
+            let matches = if if_none_match.wildcard() {
+                validators.exists
+            } else {
+                match &validators.etag {
+                    Some(etag) => if_none_match.iter().any(|tag| tag.weak_eq(etag)),
+                    None => false,
+                }
+            };
+            if matches {
+                return match is_get_or_head {
+                    true => Outcome::NotModified(not_modified(validators)),
+                    false => Outcome::PreconditionFailed,
+                };
+            }
+        }
+        Ok(None) if is_get_or_head => {
+            if let (Ok(Some(since)), Some(last_modified)) =
+                (IfModifiedSince::from_headers(req), last_modified)
+            {
+                if last_modified <= since.modified() {
+                    return Outcome::NotModified(not_modified(validators));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Outcome::Proceed
+}
+
+/// Create a `304 Not Modified` response with the required header fields.
+fn not_modified(validators: &Validators) -> Response {
+    let mut res = Response::new(StatusCode::NotModified);
+    for (name, values) in validators.headers.iter() {
+        res.insert_header(name, values)
+            .expect("validator headers are valid");
+    }
+    res
+}
+
+/// Truncate a time to whole seconds, the precision of HTTP dates.
+fn truncate(time: SystemTime) -> SystemTime {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(dur) => UNIX_EPOCH + Duration::from_secs(dur.as_secs()),
+        Err(_) => time,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::fmt_http_date;
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    fn validators() -> Validators {
+        let mut validators = Validators::new();
+        validators.set_etag(Some(ETag::new_weak("v1".into())));
+        validators.set_last_modified(Some(at(1_000_000_000) + Duration::from_millis(500)));
+        validators
+    }
+
+    fn request(method: Method, name: &str, value: &str) -> Request {
+        let mut req = Request::new(method, "https://example.com");
+        req.insert_header(name, value).unwrap();
+        req
+    }
+
+    #[test]
+    fn if_match() {
+        let validators = validators();
+
+        // A weak ETag never matches with strong comparison.
+        let req = request(Method::Put, "If-Match", r#"W/"v1""#);
+        assert!(matches!(
+            evaluate(&req, &validators),
+            Outcome::PreconditionFailed
+        ));
+
+        let req = request(Method::Put, "If-Match", "*");
+        assert!(evaluate(&req, &validators).is_proceed());
+
+        let mut missing = validators.clone();
+        missing.set_exists(false);
+        assert!(matches!(
+            evaluate(&req, &missing),
+            Outcome::PreconditionFailed
+        ));
+
+        let req = request(Method::Put, "If-Match", "nonsense");
+        assert!(matches!(
+            evaluate(&req, &validators),
+            Outcome::PreconditionFailed
+        ));
+    }
+
+    #[test]
+    fn if_none_match() {
+        let mut validators = validators();
+        let mut res = Response::new(200);
+        res.insert_header("Vary", "Accept").unwrap();
+        res.insert_header("Content-Type", "text/plain").unwrap();
+        validators.headers = Validators::from_headers(&res).unwrap().headers;
+        validators.set_etag(Some(ETag::new_weak("v1".into())));
+
+        let req = request(Method::Get, "If-None-Match", r#""v0", "v1""#);
+        let res = evaluate(&req, &validators).into_response().unwrap();
+        assert_eq!(res.status(), StatusCode::NotModified);
+        assert_eq!(res["ETag"], r#"W/"v1""#);
+        assert_eq!(res["Vary"], "Accept");
+        assert!(res.header("Content-Type").is_none());
+
+        let req = request(Method::Post, "If-None-Match", "*");
+        assert!(matches!(
+            evaluate(&req, &validators),
+            Outcome::PreconditionFailed
+        ));
+
+        let req = request(Method::Get, "If-None-Match", r#""v2""#);
+        assert!(evaluate(&req, &validators).is_proceed());
+    }
+
+    #[test]
+    fn dates() {
+        let validators = validators();
+        let date = fmt_http_date(at(1_000_000_000));
+        let earlier = fmt_http_date(at(999_999_999));
+
+        let req = request(Method::Get, "If-Modified-Since", &date);
+        let outcome = evaluate(&req, &validators);
+        assert!(matches!(outcome, Outcome::NotModified(_)));
+
+        let req = request(Method::Get, "If-Modified-Since", &earlier);
+        assert!(evaluate(&req, &validators).is_proceed());
+
+        // If-Modified-Since only applies to GET and HEAD.
+        let req = request(Method::Delete, "If-Modified-Since", &date);
+        assert!(evaluate(&req, &validators).is_proceed());
+
+        let req = request(Method::Delete, "If-Unmodified-Since", &earlier);
+        assert!(matches!(
+            evaluate(&req, &validators),
+            Outcome::PreconditionFailed
+        ));
+
+        let req = request(Method::Get, "If-Modified-Since", "yesterday");
+        assert!(evaluate(&req, &validators).is_proceed());
+    }
+
+    #[test]
+    fn precedence() {
+        let validators = validators();
+        let earlier = fmt_http_date(at(999_999_999));
+
+        // If-Unmodified-Since is ignored when If-Match is present.
+        let mut req = request(Method::Get, "If-Match", "*");
+        req.insert_header("If-Unmodified-Since", &earlier).unwrap();
+        assert!(evaluate(&req, &validators).is_proceed());
+
+        // If-Modified-Since is ignored when If-None-Match is present.
+        let mut req = request(Method::Get, "If-None-Match", r#""v2""#);
+        req.insert_header("If-Modified-Since", fmt_http_date(at(2_000_000_000)))
+            .unwrap();
+        assert!(evaluate(&req, &validators).is_proceed());
+    }
+}
@@ -9,6 +9,7 @@
 //! - [MDN: HTTP Conditional Requests](https://developer.mozilla.org/en-US/docs/Web/HTTP/Conditional_requests)
 
 mod etag;
+mod evaluate;
 mod if_modified_since;
 mod if_unmodified_since;
 mod last_modified;
@@ -18,6 +19,7 @@ pub mod if_match;
 pub mod if_none_match;
 
 pub use etag::ETag;
+pub use evaluate::{evaluate, Outcome, Validators};
 pub use vary::Vary;
 
 #[doc(inline)]
@@ -1,10 +1,11 @@
 //! Client header advertising which media types the client is able to understand.
 
+use crate::content::negotiation::Match;
 use crate::headers::{HeaderName, HeaderValue, Headers, ACCEPT};
 use crate::mime::Mime;
 use crate::utils::sort_by_weight;
 use crate::{
-    content::{ContentType, MediaTypeProposal},
+    content::{ContentType, MediaTypeProposal, Negotiation, Offer},
     headers::Header,
 };
 use crate::{Error, StatusCode};
@@ -120,6 +121,10 @@ impl Accept {
 
     /// Determine the most suitable `Content-Type` encoding.
     ///
+    /// Types with a structured syntax suffix match the media range of their
+    /// underlying syntax, so `application/json` accepts
+    /// `application/vnd.api+json`. Exact matches take precedence.
+    ///
     /// # Errors
     ///
     /// If no suitable encoding is found, an error with the status of `406` will be returned.
@@ -127,9 +132,15 @@ impl Accept {
         // Start by ordering the encodings.
         self.sort();
 
-        // Try and find the first encoding that matches.
+        // Try and find the first encoding that matches, falling back to
+        // types which share the structured syntax suffix.
         for accept in &self.entries {
-            if let Some(accept) = available.iter().find(|m| m.subset_eq(accept.media_type())) {
+            let range = accept.media_type();
+            if let Some(accept) = available
+                .iter()
+                .find(|m| m.subset_eq(range))
+                .or_else(|| available.iter().find(|m| m.suffix_eq(range)))
+            {
                 return Ok(accept.clone().into());
             }
         }
@@ -146,6 +157,56 @@ impl Accept {
         Err(err)
     }
 
+    /// Determine the most suitable `Content-Type`, taking the server's
+    /// preferences into account.
+    ///
+    /// Unlike `negotiate`, this doesn't reorder the entries. Each offer is
+    /// weighed using the most specific media range matching it, so
+    /// `text/html;level=1` takes precedence over `text/html`, which in turn
+    /// takes precedence over `text/*` and `*/*`. A type matched through its
+    /// structured syntax suffix, such as `application/vnd.api+json` by
+    /// `application/json`, is weighed like `application/*`. See
+    /// [`Negotiation`] for how the offers are ranked.
+    pub fn negotiate_with(&self, offers: &[Offer<Mime>]) -> Negotiation<Mime> {
+        Negotiation::new(offers, |mime| {
+            let mut found: Option<Match> = None;
+            for entry in &self.entries {
+                let range = entry.media_type();
+                let specificity = if mime.subset_eq(range) {
+                    match (range.basetype(), range.subtype()) {
+                        ("*", _) => 0,
+                        (_, "*") => 1,
+                        _ => 2,
+                    }
+                } else if mime.suffix_eq(range) {
+                    1
+                } else {
+                    continue;
+                };
+                let specificity = (specificity, range.params.len());
+                let more_specific = match &found {
+                    Some(found) => specificity > found.specificity,
+                    None => true,
+                };
+                if more_specific {
+                    found = Some(Match {
+                        weight: entry.weight().unwrap_or(1.0),
+                        directive: range.to_string(),
+                        specificity,
+                    });
+                }
+            }
+            if found.is_none() && self.wildcard {
+                found = Some(Match {
+                    weight: 1.0,
+                    directive: "*".into(),
+                    specificity: (0, 0),
+                });
+            }
+            found
+        })
+    }
+
     /// An iterator visiting all entries.
     pub fn iter(&self) -> Iter<'_> {
         Iter {
@@ -438,4 +499,21 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn negotiate_suffix() -> crate::Result<()> {
+        let problem: Mime = "application/problem+json".parse()?;
+        let vnd: Mime = "application/vnd.api+json".parse()?;
+
+        let mut accept = Accept::new();
+        accept.push(mime::JSON);
+        assert_eq!(accept.negotiate(&[mime::XML, vnd.clone()])?, vnd);
+        assert_eq!(accept.negotiate(&[vnd, mime::JSON])?, mime::JSON);
+
+        let mut accept = Accept::new();
+        accept.push("application/*+json".parse::<Mime>()?);
+        assert_eq!(accept.negotiate(&[mime::JSON, problem.clone()])?, problem);
+        assert!(accept.negotiate(&[mime::SVG]).is_err());
+        Ok(())
+    }
 }
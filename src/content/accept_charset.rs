@@ -0,0 +1,349 @@
+//! Client header advertising which charsets the client is able to understand.
+
+use crate::content::{sort_by_preference, CharsetProposal};
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, ACCEPT_CHARSET};
+use crate::{Error, StatusCode};
+
+use std::fmt::{self, Debug, Write};
+
+use std::slice;
+
+/// Client header advertising which charsets the client is able to understand.
+///
+/// # Specifications
+///
+/// - [RFC 9110, section 12.5.2: Accept-Charset](https://www.rfc-editor.org/rfc/rfc9110#section-12.5.2)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::AcceptCharset;
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header("Accept-Charset", "iso-8859-1, utf-8;q=0.7, *;q=0.1");
+/// let accept = AcceptCharset::from_headers(&req)?.unwrap();
+///
+/// let charset = accept.negotiate(&["utf-8", "ISO-8859-1"])?;
+/// assert_eq!(charset, "ISO-8859-1");
+/// #
+/// # Ok(()) }
+/// ```
+pub struct AcceptCharset {
+    entries: Vec<CharsetProposal>,
+}
+
+impl AcceptCharset {
+    /// Create a new instance of `AcceptCharset`.
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Create an instance of `AcceptCharset` from a `Headers` instance.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let mut entries = vec![];
+        let headers = match headers.as_ref().get(ACCEPT_CHARSET) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        for value in headers {
+            for part in value.as_str().trim().split(',') {
+                let part = part.trim();
+
+                // Handle empty strings.
+                if part.is_empty() {
+                    continue;
+                }
+
+                entries.push(CharsetProposal::from_str(part)?);
+            }
+        }
+
+        Ok(Some(Self { entries }))
+    }
+
+    /// Push a directive into the list of entries.
+    pub fn push(&mut self, prop: impl Into<CharsetProposal>) {
+        self.entries.push(prop.into());
+    }
+
+    /// Returns `true` if a wildcard directive was passed.
+    pub fn wildcard(&self) -> bool {
+        self.entries.iter().any(|entry| entry.is_wildcard())
+    }
+
+    /// Set the wildcard directive.
+    pub fn set_wildcard(&mut self, wildcard: bool) {
+        self.entries.retain(|entry| !entry.is_wildcard());
+        if wildcard {
+            self.entries.push("*".into());
+        }
+    }
+
+    /// Sort the header directives by weight.
+    ///
+    /// Headers with a higher `q=` value will be returned first. Directives
+    /// without a weight have a weight of `1.0`, and directives with the same
+    /// weight keep the order they were declared in.
+    pub fn sort(&mut self) {
+        sort_by_preference(&mut self.entries);
+    }
+
+    /// Returns `true` if the charset is acceptable to the client.
+    ///
+    /// Charsets are compared case-insensitively. The wildcard matches every
+    /// charset which isn't listed explicitly, and a weight of `0` marks a
+    /// charset as not acceptable.
+    pub fn accepts(&self, charset: &str) -> bool {
+        match self.weight_of(charset) {
+            Some(weight) => weight > 0.0,
+            None => false,
+        }
+    }
+
+    /// Determine the most suitable charset to encode the response with.
+    ///
+    /// This returns the available charset with the highest weight. When
+    /// several charsets share the same weight, the one listed first in
+    /// `available` is chosen.
+    ///
+    /// # Errors
+    ///
+    /// If no suitable charset is found, an error with the status of `406` will be returned.
+    pub fn negotiate<'a>(&self, available: &[&'a str]) -> crate::Result<&'a str> {
+        let mut selected: Option<(&'a str, f32)> = None;
+        for charset in available {
+            let weight = match self.weight_of(charset) {
+                Some(weight) if weight > 0.0 => weight,
+                _ => continue,
+            };
+            match selected {
+                Some((_, best)) if best >= weight => {}
+                _ => selected = Some((charset, weight)),
+            }
+        }
+
+        match selected {
+            Some((charset, _)) => Ok(charset),
+            None => {
+                let mut err = Error::new_adhoc("No suitable charset found");
+                err.set_status(StatusCode::NotAcceptable);
+                Err(err)
+            }
+        }
+    }
+
+    /// Get the weight the client assigned to a charset, falling back to the
+    /// weight of the wildcard.
+    fn weight_of(&self, charset: &str) -> Option<f32> {
+        let weight = |entry: &CharsetProposal| entry.weight().unwrap_or(1.0);
+        self.entries
+            .iter()
+            .find(|entry| *entry == charset)
+            .or_else(|| self.entries.iter().find(|entry| entry.is_wildcard()))
+            .map(weight)
+    }
+
+    /// An iterator visiting all entries.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// An iterator visiting all entries.
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        IterMut {
+            inner: self.entries.iter_mut(),
+        }
+    }
+}
+
+impl Header for AcceptCharset {
+    fn header_name(&self) -> HeaderName {
+        ACCEPT_CHARSET
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, directive) in self.entries.iter().enumerate() {
+            let directive: HeaderValue = directive.clone().into();
+            match n {
+                0 => write!(output, "{}", directive).unwrap(),
+                _ => write!(output, ", {}", directive).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl IntoIterator for AcceptCharset {
+    type Item = CharsetProposal;
+    type IntoIter = IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.entries.into_iter(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a AcceptCharset {
+    type Item = &'a CharsetProposal;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut AcceptCharset {
+    type Item = &'a mut CharsetProposal;
+    type IntoIter = IterMut<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A borrowing iterator over entries in `AcceptCharset`.
+#[derive(Debug)]
+pub struct IntoIter {
+    inner: std::vec::IntoIter<CharsetProposal>,
+}
+
+impl Iterator for IntoIter {
+    type Item = CharsetProposal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// A lending iterator over entries in `AcceptCharset`.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, CharsetProposal>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a CharsetProposal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// A mutable iterator over entries in `AcceptCharset`.
+#[derive(Debug)]
+pub struct IterMut<'a> {
+    inner: slice::IterMut<'a, CharsetProposal>,
+}
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = &'a mut CharsetProposal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl Debug for AcceptCharset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for directive in &self.entries {
+            list.entry(directive);
+        }
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Request;
+
+    fn accept(value: &str) -> crate::Result<AcceptCharset> {
+        let mut req = Request::get("https://example.com");
+        req.insert_header("Accept-Charset", value)?;
+        Ok(AcceptCharset::from_headers(req)?.unwrap())
+    }
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let mut accept = AcceptCharset::new();
+        accept.push("utf-8");
+        accept.push(CharsetProposal::new("iso-8859-1", Some(0.5))?);
+        accept.set_wildcard(true);
+
+        let mut req = Request::get("https://example.com");
+        accept.apply_header(&mut req);
+        assert_eq!(req["Accept-Charset"], "utf-8, iso-8859-1;q=0.500, *");
+
+        let accept = AcceptCharset::from_headers(req)?.unwrap();
+        assert!(accept.wildcard());
+        assert_eq!(accept.iter().count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn reorder_based_on_weight_and_location() -> crate::Result<()> {
+        let mut accept = accept("shift_jis;q=0.5, utf-8, iso-8859-1")?;
+        accept.sort();
+        let order: Vec<_> = accept.iter().map(|e| e.charset().to_owned()).collect();
+        assert_eq!(order, ["utf-8", "iso-8859-1", "shift_jis"]);
+        Ok(())
+    }
+
+    #[test]
+    fn negotiate() -> crate::Result<()> {
+        let accept = accept("utf-8;q=0.5, Shift_JIS")?;
+        assert_eq!(accept.negotiate(&["utf-8", "shift_jis"])?, "shift_jis");
+        assert_eq!(accept.negotiate(&["utf-8"])?, "utf-8");
+
+        let err = accept.negotiate(&["iso-8859-1"]).unwrap_err();
+        assert_eq!(err.status(), 406);
+        Ok(())
+    }
+
+    #[test]
+    fn wildcard_and_exclusion() -> crate::Result<()> {
+        let accept = accept("*;q=0.3, utf-8;q=0")?;
+        assert!(!accept.accepts("UTF-8"));
+        assert!(accept.accepts("windows-1252"));
+        assert_eq!(
+            accept.negotiate(&["utf-8", "windows-1252"])?,
+            "windows-1252"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(accept("utf-8;q=x").unwrap_err().status(), 400);
+        assert_eq!(accept("utf 8").unwrap_err().status(), 400);
+    }
+}
@@ -1,9 +1,10 @@
 //! Client header advertising available compression algorithms.
 
+use crate::content::negotiation::Match;
 use crate::headers::{HeaderName, HeaderValue, Headers, ACCEPT_ENCODING};
-use crate::utils::sort_by_weight;
+use crate::utils::{parse_weight, sort_by_weight};
 use crate::{
-    content::{ContentEncoding, Encoding, EncodingProposal},
+    content::{ContentEncoding, Encoding, EncodingProposal, Negotiation, Offer},
     headers::Header,
 };
 use crate::{Error, StatusCode};
@@ -41,6 +42,7 @@ use std::slice;
 /// ```
 pub struct AcceptEncoding {
     wildcard: bool,
+    wildcard_weight: Option<f32>,
     entries: Vec<EncodingProposal>,
 }
 
@@ -50,6 +52,7 @@ impl AcceptEncoding {
         Self {
             entries: vec![],
             wildcard: false,
+            wildcard_weight: None,
         }
     }
 
@@ -62,6 +65,7 @@ impl AcceptEncoding {
         };
 
         let mut wildcard = false;
+        let mut wildcard_weight = None;
 
         for value in headers {
             for part in value.as_str().trim().split(',') {
@@ -70,8 +74,16 @@ impl AcceptEncoding {
                 // Handle empty strings, and wildcard directives.
                 if part.is_empty() {
                     continue;
-                } else if part == "*" {
+                } else if let Some(params) = wildcard_params(part) {
                     wildcard = true;
+                    wildcard_weight = params.map(parse_weight).transpose()?;
+                    if let Some(weight) = wildcard_weight {
+                        crate::ensure_status!(
+                            weight.is_sign_positive() && weight <= 1.0,
+                            400,
+                            "The wildcard should have a weight between 0.0 and 1.0"
+                        );
+                    }
                     continue;
                 }
 
@@ -83,7 +95,11 @@ impl AcceptEncoding {
             }
         }
 
-        Ok(Some(Self { wildcard, entries }))
+        Ok(Some(Self {
+            wildcard,
+            wildcard_weight,
+            entries,
+        }))
     }
 
     /// Push a directive into the list of entries.
@@ -98,7 +114,32 @@ impl AcceptEncoding {
 
     /// Set the wildcard directive.
     pub fn set_wildcard(&mut self, wildcard: bool) {
-        self.wildcard = wildcard
+        self.wildcard = wildcard;
+        self.wildcard_weight = None;
+    }
+
+    /// Get the weight of the wildcard directive, if one was passed.
+    pub fn wildcard_weight(&self) -> Option<f32> {
+        self.wildcard_weight
+    }
+
+    /// Set the weight of the wildcard directive.
+    ///
+    /// This enables the wildcard directive. A weight of `0` excludes every
+    /// encoding which isn't listed explicitly.
+    ///
+    /// # Errors
+    ///
+    /// If the weight isn't between `0.0` and `1.0`, an error with the status
+    /// of `500` will be returned.
+    pub fn set_wildcard_weight(&mut self, weight: f32) -> crate::Result<()> {
+        crate::ensure!(
+            weight.is_sign_positive() && weight <= 1.0,
+            "The wildcard should have a weight between 0.0 and 1.0"
+        );
+        self.wildcard = true;
+        self.wildcard_weight = Some(weight);
+        Ok(())
     }
 
     /// Sort the header directives by weight.
@@ -127,7 +168,7 @@ impl AcceptEncoding {
         }
 
         // If no encoding matches and wildcard is set, send whichever encoding we got.
-        if self.wildcard {
+        if self.wildcard && self.wildcard_weight != Some(0.0) {
             if let Some(encoding) = available.iter().next() {
                 return Ok(encoding.into());
             }
@@ -138,6 +179,51 @@ impl AcceptEncoding {
         Err(err)
     }
 
+    /// Determine the most suitable `Content-Encoding`, taking the server's
+    /// preferences into account.
+    ///
+    /// Unlike `negotiate`, this doesn't reorder the entries. An encoding
+    /// listed explicitly takes precedence over the wildcard. See
+    /// [`Negotiation`] for how the offers are ranked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::content::{AcceptEncoding, Encoding, Offer};
+    /// use http_types::Request;
+    ///
+    /// let mut req = Request::get("https://example.com");
+    /// req.insert_header("Accept-Encoding", "gzip, br");
+    /// let accept = AcceptEncoding::from_headers(&req)?.unwrap();
+    ///
+    /// // Prefer Brotli over Gzip when the client accepts both equally.
+    /// let offers = [Offer::new(Encoding::Brotli, 1.0)?, Offer::new(Encoding::Gzip, 0.9)?];
+    /// let encoding = accept.negotiate_with(&offers).into_chosen()?;
+    /// assert_eq!(encoding, Encoding::Brotli);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn negotiate_with(&self, offers: &[Offer<Encoding>]) -> Negotiation<Encoding> {
+        Negotiation::new(offers, |encoding| {
+            let found = self.entries.iter().find(|entry| *entry == encoding);
+            match found {
+                Some(entry) => Some(Match {
+                    weight: entry.weight().unwrap_or(1.0),
+                    directive: entry.encoding().to_string(),
+                    specificity: (1, 0),
+                }),
+                None if self.wildcard => Some(Match {
+                    weight: self.wildcard_weight.unwrap_or(1.0),
+                    directive: "*".into(),
+                    specificity: (0, 0),
+                }),
+                None => None,
+            }
+        })
+    }
+
     /// An iterator visiting all entries.
     pub fn iter(&self) -> Iter<'_> {
         Iter {
@@ -153,6 +239,16 @@ impl AcceptEncoding {
     }
 }
 
+/// Get the parameters of a wildcard directive, or `None` if the directive
+/// isn't a wildcard.
+fn wildcard_params(part: &str) -> Option<Option<&str>> {
+    let mut parts = part.splitn(2, ';');
+    match parts.next().map(str::trim) {
+        Some("*") => Some(parts.next().map(str::trim)),
+        _ => None,
+    }
+}
+
 impl Header for AcceptEncoding {
     fn header_name(&self) -> HeaderName {
         ACCEPT_ENCODING
@@ -169,9 +265,12 @@ impl Header for AcceptEncoding {
         }
 
         if self.wildcard {
-            match output.len() {
-                0 => write!(output, "*").unwrap(),
-                _ => write!(output, ", *").unwrap(),
+            if !output.is_empty() {
+                output.push_str(", ");
+            }
+            match self.wildcard_weight {
+                Some(weight) => write!(output, "*;q={:.3}", weight).unwrap(),
+                None => output.push('*'),
             }
         }
 
@@ -311,6 +410,24 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn wildcard_weight() -> crate::Result<()> {
+        let mut accept = AcceptEncoding::new();
+        accept.push(Encoding::Gzip);
+        accept.set_wildcard_weight(0.0)?;
+
+        let mut headers = Response::new(200);
+        accept.apply_header(&mut headers);
+        assert_eq!(headers["Accept-Encoding"], "gzip, *;q=0.000");
+
+        let mut accept = AcceptEncoding::from_headers(headers)?.unwrap();
+        assert!(accept.wildcard());
+        assert_eq!(accept.wildcard_weight(), Some(0.0));
+        let err = accept.negotiate(&[Encoding::Brotli]).unwrap_err();
+        assert_eq!(err.status(), 406);
+        Ok(())
+    }
+
     #[test]
     fn wildcard_and_header() -> crate::Result<()> {
         let mut accept = AcceptEncoding::new();
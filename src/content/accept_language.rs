@@ -0,0 +1,429 @@
+//! Client header advertising which languages the client is able to understand.
+
+use crate::content::{sort_by_preference, ContentLanguage, LanguageProposal, LanguageRange};
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, ACCEPT_LANGUAGE};
+use crate::{Error, StatusCode};
+
+use std::fmt::{self, Debug, Write};
+
+use std::slice;
+
+/// Client header advertising which languages the client is able to understand.
+///
+/// # Specifications
+///
+/// - [RFC 9110, section 12.5.4: Accept-Language](https://www.rfc-editor.org/rfc/rfc9110#section-12.5.4)
+/// - [RFC 4647: Matching of Language Tags](https://www.rfc-editor.org/rfc/rfc4647)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::{AcceptLanguage, LanguageRange};
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header("Accept-Language", "fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+/// let accept = AcceptLanguage::from_headers(&req)?.unwrap();
+///
+/// let available: Vec<LanguageRange> = vec!["en-US".parse()?, "fr-FR".parse()?];
+///
+/// // Basic filtering: `fr` matches `fr-FR`.
+/// let language = accept.negotiate(&available)?;
+/// assert_eq!(language.iter().next().unwrap(), "fr-FR");
+///
+/// // Lookup: neither `fr-CH`, `fr` nor `en` equals an available locale, so
+/// // the default is used.
+/// let locale = accept.lookup(&available, "en-US".parse()?);
+/// assert_eq!(locale, "en-US");
+/// #
+/// # Ok(()) }
+/// ```
+pub struct AcceptLanguage {
+    entries: Vec<LanguageProposal>,
+}
+
+impl AcceptLanguage {
+    /// Create a new instance of `AcceptLanguage`.
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Create an instance of `AcceptLanguage` from a `Headers` instance.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let mut entries = vec![];
+        let headers = match headers.as_ref().get(ACCEPT_LANGUAGE) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        for value in headers {
+            for part in value.as_str().trim().split(',') {
+                let part = part.trim();
+
+                // Handle empty strings.
+                if part.is_empty() {
+                    continue;
+                }
+
+                entries.push(LanguageProposal::from_str(part)?);
+            }
+        }
+
+        Ok(Some(Self { entries }))
+    }
+
+    /// Push a directive into the list of entries.
+    pub fn push(&mut self, prop: impl Into<LanguageProposal>) {
+        self.entries.push(prop.into());
+    }
+
+    /// Returns `true` if a wildcard directive was passed.
+    pub fn wildcard(&self) -> bool {
+        self.entries.iter().any(|entry| entry.is_wildcard())
+    }
+
+    /// Set the wildcard directive.
+    pub fn set_wildcard(&mut self, wildcard: bool) {
+        self.entries.retain(|entry| !entry.is_wildcard());
+        if wildcard {
+            self.entries.push(LanguageRange::wildcard().into());
+        }
+    }
+
+    /// Sort the header directives by weight.
+    ///
+    /// Headers with a higher `q=` value will be returned first. Directives
+    /// without a weight have a weight of `1.0`, and directives with the same
+    /// weight keep the order they were declared in.
+    pub fn sort(&mut self) {
+        sort_by_preference(&mut self.entries);
+    }
+
+    /// Determine the most suitable `Content-Language` using basic filtering.
+    ///
+    /// This returns the first available language tag that is matched by the
+    /// most preferred language range.
+    ///
+    /// # Errors
+    ///
+    /// If no suitable language is found, an error with the status of `406` will be returned.
+    pub fn negotiate(&self, available: &[LanguageRange]) -> crate::Result<ContentLanguage> {
+        match self.filter(available).into_iter().next() {
+            Some(language) => Ok(language.into()),
+            None => {
+                let mut err = Error::new_adhoc("No suitable Content-Language found");
+                err.set_status(StatusCode::NotAcceptable);
+                Err(err)
+            }
+        }
+    }
+
+    /// Select all available language tags matched by the language ranges,
+    /// ordered by preference.
+    ///
+    /// A tag is excluded when the most specific range matching it has a
+    /// weight of `0`, so `en-US, en;q=0` still selects `en-US`.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 4647, section 3.3.1: Basic Filtering](https://www.rfc-editor.org/rfc/rfc4647#section-3.3.1)
+    pub fn filter(&self, available: &[LanguageRange]) -> Vec<LanguageRange> {
+        let mut entries: Vec<&LanguageProposal> = self.entries.iter().collect();
+        sort_by_preference(&mut entries);
+
+        // On ties, the range declared first wins.
+        let excluded = |tag: &LanguageRange| {
+            let best = self
+                .entries
+                .iter()
+                .filter(|entry| entry.matches(tag))
+                .rev()
+                .max_by_key(|entry| specificity(entry.range()));
+            best.into_iter().any(|entry| entry.weight() == Some(0.0))
+        };
+
+        let mut selected: Vec<LanguageRange> = vec![];
+        for entry in entries {
+            if entry.weight() == Some(0.0) {
+                continue;
+            }
+            for tag in available {
+                if entry.matches(tag) && !excluded(tag) && !selected.contains(tag) {
+                    selected.push(tag.clone());
+                }
+            }
+        }
+        selected
+    }
+
+    /// Select the single available language tag that best matches the
+    /// language ranges, or `default` if none do.
+    ///
+    /// Each range is tried in order of preference, and progressively
+    /// truncated until it equals one of the available tags. The wildcard and
+    /// ranges with a weight of `0` are ignored.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 4647, section 3.4: Lookup](https://www.rfc-editor.org/rfc/rfc4647#section-3.4)
+    pub fn lookup(&self, available: &[LanguageRange], default: LanguageRange) -> LanguageRange {
+        let mut entries: Vec<&LanguageProposal> = self.entries.iter().collect();
+        sort_by_preference(&mut entries);
+
+        for entry in entries {
+            if entry.is_wildcard() || entry.weight() == Some(0.0) {
+                continue;
+            }
+            let mut range = Some(entry.range.clone());
+            while let Some(current) = range {
+                if let Some(tag) = available.iter().find(|tag| **tag == current) {
+                    return tag.clone();
+                }
+                range = current.truncate();
+            }
+        }
+        default
+    }
+
+    /// An iterator visiting all entries.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// An iterator visiting all entries.
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        IterMut {
+            inner: self.entries.iter_mut(),
+        }
+    }
+}
+
+/// The number of subtags in a range; the wildcard has none.
+fn specificity(range: &LanguageRange) -> usize {
+    if range.is_wildcard() {
+        0
+    } else {
+        range.subtags().count()
+    }
+}
+
+impl Header for AcceptLanguage {
+    fn header_name(&self) -> HeaderName {
+        ACCEPT_LANGUAGE
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, directive) in self.entries.iter().enumerate() {
+            let directive: HeaderValue = directive.clone().into();
+            match n {
+                0 => write!(output, "{}", directive).unwrap(),
+                _ => write!(output, ", {}", directive).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl IntoIterator for AcceptLanguage {
+    type Item = LanguageProposal;
+    type IntoIter = IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.entries.into_iter(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a AcceptLanguage {
+    type Item = &'a LanguageProposal;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut AcceptLanguage {
+    type Item = &'a mut LanguageProposal;
+    type IntoIter = IterMut<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A borrowing iterator over entries in `AcceptLanguage`.
+#[derive(Debug)]
+pub struct IntoIter {
+    inner: std::vec::IntoIter<LanguageProposal>,
+}
+
+impl Iterator for IntoIter {
+    type Item = LanguageProposal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// A lending iterator over entries in `AcceptLanguage`.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, LanguageProposal>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a LanguageProposal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// A mutable iterator over entries in `AcceptLanguage`.
+#[derive(Debug)]
+pub struct IterMut<'a> {
+    inner: slice::IterMut<'a, LanguageProposal>,
+}
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = &'a mut LanguageProposal;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl Debug for AcceptLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for directive in &self.entries {
+            list.entry(directive);
+        }
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Request;
+
+    fn accept(value: &str) -> crate::Result<AcceptLanguage> {
+        let mut req = Request::get("https://example.com");
+        req.insert_header("Accept-Language", value)?;
+        Ok(AcceptLanguage::from_headers(req)?.unwrap())
+    }
+
+    fn ranges(ranges: &[&str]) -> Vec<LanguageRange> {
+        ranges.iter().map(|r| r.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let mut accept = AcceptLanguage::new();
+        accept.push(LanguageProposal::new(LanguageRange::new("en-US")?, None)?);
+        accept.push(LanguageProposal::new(LanguageRange::new("en")?, Some(0.5))?);
+        accept.set_wildcard(true);
+
+        let mut req = Request::get("https://example.com");
+        accept.apply_header(&mut req);
+        assert_eq!(req["Accept-Language"], "en-US, en;q=0.500, *");
+
+        let accept = AcceptLanguage::from_headers(req)?.unwrap();
+        assert!(accept.wildcard());
+        assert_eq!(accept.iter().count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn reorder_based_on_weight_and_location() -> crate::Result<()> {
+        let mut accept = accept("de;q=0.5, nl, en")?;
+        accept.sort();
+        let order: Vec<_> = accept.iter().map(|e| e.range().to_string()).collect();
+        assert_eq!(order, ["nl", "en", "de"]);
+        Ok(())
+    }
+
+    #[test]
+    fn filter() -> crate::Result<()> {
+        let accept = accept("de-DE, en;q=0.8, *;q=0.1, en-GB;q=0")?;
+        let available = ranges(&["fr", "en-GB", "en-US", "de-DE-1996", "de"]);
+        assert_eq!(
+            accept.filter(&available),
+            ranges(&["de-DE-1996", "en-US", "fr", "de"])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn filter_exclusions_use_the_most_specific_range() -> crate::Result<()> {
+        let available = ranges(&["en", "en-US", "fr"]);
+
+        let wildcard = accept("en, *;q=0")?;
+        assert_eq!(wildcard.filter(&available), ranges(&["en", "en-US"]));
+
+        let prefix = accept("en-US, en;q=0")?;
+        assert_eq!(prefix.filter(&available), ranges(&["en-US"]));
+        Ok(())
+    }
+
+    #[test]
+    fn negotiate() -> crate::Result<()> {
+        let accept = accept("nl-BE, en;q=0.5")?;
+        let language = accept.negotiate(&ranges(&["en-US", "nl"]))?;
+        assert_eq!(language.iter().next().unwrap(), "en-US");
+
+        let err = accept.negotiate(&ranges(&["fr"])).unwrap_err();
+        assert_eq!(err.status(), 406);
+        Ok(())
+    }
+
+    #[test]
+    fn lookup() -> crate::Result<()> {
+        let accept = accept("zh-Hant-CN-x-private1, en;q=0.4, *")?;
+        let default = LanguageRange::new("fr")?;
+
+        let available = ranges(&["en", "zh-Hant"]);
+        assert_eq!(accept.lookup(&available, default.clone()), "zh-Hant");
+
+        let available = ranges(&["en-US", "EN"]);
+        assert_eq!(accept.lookup(&available, default.clone()), "EN");
+
+        let available = ranges(&["de"]);
+        assert_eq!(accept.lookup(&available, default), "fr");
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(accept("en_US").unwrap_err().status(), 400);
+        assert_eq!(accept("en;q=x").unwrap_err().status(), 400);
+    }
+}
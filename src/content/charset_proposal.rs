@@ -0,0 +1,130 @@
+use crate::headers::HeaderValue;
+use crate::parse_utils::parse_token;
+use crate::utils::parse_weight;
+use crate::{bail_status as bail, ensure};
+
+use std::cmp::{Ordering, PartialEq};
+
+/// A proposed charset in `AcceptCharset`.
+///
+/// Charsets are compared case-insensitively.
+#[derive(Debug, Clone)]
+pub struct CharsetProposal {
+    /// The proposed charset.
+    charset: String,
+
+    /// The weight of the proposal.
+    ///
+    /// This is a number between 0.0 and 1.0, and is max 3 decimal points.
+    weight: Option<f32>,
+}
+
+impl CharsetProposal {
+    /// Create a new instance of `CharsetProposal`.
+    ///
+    /// # Errors
+    ///
+    /// This method errors if the charset is not a valid token, or if the
+    /// weight is not between `0.0` and `1.0`.
+    pub fn new(charset: impl Into<String>, weight: Option<f32>) -> crate::Result<Self> {
+        let charset = charset.into();
+        if !matches!(parse_token(&charset), (Some(_), "")) {
+            bail!(400, "Invalid charset `{}`", charset);
+        }
+        if let Some(weight) = weight {
+            ensure!(
+                weight.is_sign_positive() && weight <= 1.0,
+                "CharsetProposal should have a weight between 0.0 and 1.0"
+            )
+        }
+
+        Ok(Self { charset, weight })
+    }
+
+    /// Get the proposed charset.
+    pub fn charset(&self) -> &str {
+        &self.charset
+    }
+
+    /// Get the weight of the proposal.
+    pub fn weight(&self) -> Option<f32> {
+        self.weight
+    }
+
+    /// Returns `true` if this is the wildcard charset `*`.
+    pub fn is_wildcard(&self) -> bool {
+        self.charset == "*"
+    }
+
+    pub(crate) fn from_str(s: &str) -> crate::Result<Self> {
+        let mut parts = s.split(';');
+        let charset = parts.next().unwrap().trim();
+        let weight = parts.next().map(|s| parse_weight(s.trim())).transpose()?;
+        Self::new(charset, weight)
+    }
+}
+
+impl PartialEq for CharsetProposal {
+    fn eq(&self, other: &Self) -> bool {
+        self.charset.eq_ignore_ascii_case(&other.charset) && self.weight == other.weight
+    }
+}
+
+impl PartialEq<str> for CharsetProposal {
+    fn eq(&self, other: &str) -> bool {
+        self.charset.eq_ignore_ascii_case(other)
+    }
+}
+
+impl<'a> PartialEq<&'a str> for CharsetProposal {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.charset.eq_ignore_ascii_case(other)
+    }
+}
+
+impl<'a> From<&'a str> for CharsetProposal {
+    fn from(charset: &'a str) -> Self {
+        Self::new(charset, None).unwrap()
+    }
+}
+
+// NOTE: A proposal without a weight has the default weight of 1.0.
+impl PartialOrd for CharsetProposal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.weight
+            .unwrap_or(1.0)
+            .partial_cmp(&other.weight.unwrap_or(1.0))
+    }
+}
+
+impl From<CharsetProposal> for HeaderValue {
+    fn from(entry: CharsetProposal) -> HeaderValue {
+        let s = match entry.weight {
+            Some(weight) => format!("{};q={:.3}", entry.charset, weight),
+            None => entry.charset,
+        };
+        // SAFETY: the internal string is validated to be a token.
+        unsafe { HeaderValue::from_bytes_unchecked(s.into_bytes()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let proposal = CharsetProposal::from_str("ISO-8859-1;q=0.7")?;
+        assert_eq!(proposal, "iso-8859-1");
+        assert_eq!(proposal.weight(), Some(0.7));
+        Ok(())
+    }
+
+    #[test]
+    fn error_codes() {
+        let err = CharsetProposal::new("utf-8", Some(1.1)).unwrap_err();
+        assert_eq!(err.status(), 500);
+        let err = CharsetProposal::new("utf 8", None).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+}
@@ -0,0 +1,161 @@
+//! Describe the natural languages of the intended audience.
+
+use crate::bail_status as bail;
+use crate::content::LanguageRange;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, CONTENT_LANGUAGE};
+
+use std::fmt::Write;
+use std::slice;
+
+/// Describe the natural languages of the intended audience.
+///
+/// # Specifications
+///
+/// - [RFC 9110, section 8.5: Content-Language](https://www.rfc-editor.org/rfc/rfc9110#section-8.5)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::ContentLanguage;
+/// use http_types::Response;
+///
+/// let mut languages = ContentLanguage::new();
+/// languages.push("mi".parse()?);
+/// languages.push("en".parse()?);
+///
+/// let mut res = Response::new(200);
+/// res.insert_header(&languages, &languages);
+/// assert_eq!(res["Content-Language"], "mi, en");
+///
+/// let languages = ContentLanguage::from_headers(res)?.unwrap();
+/// assert_eq!(languages.iter().next().unwrap(), "mi");
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContentLanguage {
+    entries: Vec<LanguageRange>,
+}
+
+impl ContentLanguage {
+    /// Create a new instance of `ContentLanguage`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(CONTENT_LANGUAGE) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut entries = vec![];
+        for value in headers {
+            for part in value.as_str().trim().split(',') {
+                let part = part.trim();
+                if part.is_empty() {
+                    continue;
+                }
+                let tag = LanguageRange::new(part)?;
+                if tag.is_wildcard() {
+                    bail!(400, "Content-Language does not accept a wildcard");
+                }
+                entries.push(tag);
+            }
+        }
+
+        Ok(Some(Self { entries }))
+    }
+
+    /// Push a language tag into the list of entries.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the language tag is the wildcard `*`.
+    pub fn push(&mut self, tag: LanguageRange) {
+        assert!(
+            !tag.is_wildcard(),
+            "Content-Language does not accept a wildcard"
+        );
+        self.entries.push(tag);
+    }
+
+    /// An iterator visiting all language tags.
+    pub fn iter(&self) -> slice::Iter<'_, LanguageRange> {
+        self.entries.iter()
+    }
+}
+
+impl Header for ContentLanguage {
+    fn header_name(&self) -> HeaderName {
+        CONTENT_LANGUAGE
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, tag) in self.entries.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", tag).unwrap(),
+                _ => write!(output, ", {}", tag).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl From<LanguageRange> for ContentLanguage {
+    fn from(tag: LanguageRange) -> Self {
+        let mut languages = Self::new();
+        languages.push(tag);
+        languages
+    }
+}
+
+impl IntoIterator for ContentLanguage {
+    type Item = LanguageRange;
+    type IntoIter = std::vec::IntoIter<LanguageRange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ContentLanguage {
+    type Item = &'a LanguageRange;
+    type IntoIter = slice::Iter<'a, LanguageRange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Response;
+
+    #[test]
+    fn multiple_headers() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.append_header("Content-Language", "de-DE")?;
+        res.append_header("Content-Language", "en-CA, fr")?;
+        let languages = ContentLanguage::from_headers(res)?.unwrap();
+        let languages: Vec<_> = languages.iter().map(|l| l.as_str()).collect();
+        assert_eq!(languages, ["de-DE", "en-CA", "fr"]);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_wildcard() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.insert_header("Content-Language", "*")?;
+        let err = ContentLanguage::from_headers(res).unwrap_err();
+        assert_eq!(err.status(), 400);
+        Ok(())
+    }
+}
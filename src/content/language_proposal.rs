@@ -0,0 +1,129 @@
+use crate::content::LanguageRange;
+use crate::ensure;
+use crate::headers::HeaderValue;
+use crate::utils::parse_weight;
+
+use std::cmp::{Ordering, PartialEq};
+use std::ops::{Deref, DerefMut};
+
+/// A proposed `LanguageRange` in `AcceptLanguage`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageProposal {
+    /// The proposed language range.
+    pub(crate) range: LanguageRange,
+
+    /// The weight of the proposal.
+    ///
+    /// This is a number between 0.0 and 1.0, and is max 3 decimal points.
+    weight: Option<f32>,
+}
+
+impl LanguageProposal {
+    /// Create a new instance of `LanguageProposal`.
+    pub fn new(range: impl Into<LanguageRange>, weight: Option<f32>) -> crate::Result<Self> {
+        if let Some(weight) = weight {
+            ensure!(
+                weight.is_sign_positive() && weight <= 1.0,
+                "LanguageProposal should have a weight between 0.0 and 1.0"
+            )
+        }
+
+        Ok(Self {
+            range: range.into(),
+            weight,
+        })
+    }
+
+    /// Get the proposed language range.
+    pub fn range(&self) -> &LanguageRange {
+        &self.range
+    }
+
+    /// Get the weight of the proposal.
+    pub fn weight(&self) -> Option<f32> {
+        self.weight
+    }
+
+    pub(crate) fn from_str(s: &str) -> crate::Result<Self> {
+        let mut parts = s.split(';');
+        let range = LanguageRange::new(parts.next().unwrap().trim())?;
+        let weight = parts.next().map(|s| parse_weight(s.trim())).transpose()?;
+        Self::new(range, weight)
+    }
+}
+
+impl From<LanguageRange> for LanguageProposal {
+    fn from(range: LanguageRange) -> Self {
+        Self {
+            range,
+            weight: None,
+        }
+    }
+}
+
+impl PartialEq<LanguageRange> for LanguageProposal {
+    fn eq(&self, other: &LanguageRange) -> bool {
+        self.range == *other
+    }
+}
+
+impl PartialEq<LanguageRange> for &LanguageProposal {
+    fn eq(&self, other: &LanguageRange) -> bool {
+        self.range == *other
+    }
+}
+
+impl Deref for LanguageProposal {
+    type Target = LanguageRange;
+    fn deref(&self) -> &Self::Target {
+        &self.range
+    }
+}
+
+impl DerefMut for LanguageProposal {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.range
+    }
+}
+
+// NOTE: A proposal without a weight has the default weight of 1.0. Unlike
+// encodings, languages are listed in order of preference, so ties are
+// resolved by position when sorting.
+impl PartialOrd for LanguageProposal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.weight
+            .unwrap_or(1.0)
+            .partial_cmp(&other.weight.unwrap_or(1.0))
+    }
+}
+
+impl From<LanguageProposal> for HeaderValue {
+    fn from(entry: LanguageProposal) -> HeaderValue {
+        let s = match entry.weight {
+            Some(weight) => format!("{};q={:.3}", entry.range, weight),
+            None => entry.range.to_string(),
+        };
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(s.into_bytes()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let proposal = LanguageProposal::from_str("en-GB;q=0.8")?;
+        assert_eq!(proposal.range(), &LanguageRange::new("en-gb")?);
+        assert_eq!(proposal.weight(), Some(0.8));
+        Ok(())
+    }
+
+    #[test]
+    fn error_code_500() {
+        let range = LanguageRange::new("en").unwrap();
+        let err = LanguageProposal::new(range, Some(1.1)).unwrap_err();
+        assert_eq!(err.status(), 500);
+    }
+}
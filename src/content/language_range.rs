@@ -0,0 +1,222 @@
+use crate::bail_status as bail;
+use crate::headers::HeaderValue;
+
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// A BCP 47 language tag or language range, such as `en-US` or `*`.
+///
+/// Language tags are compared case-insensitively, but keep the casing they
+/// were created with.
+///
+/// # Specifications
+///
+/// - [RFC 4647, section 2.1: Basic Language Range](https://www.rfc-editor.org/rfc/rfc4647#section-2.1)
+/// - [RFC 5646: Tags for Identifying Languages](https://www.rfc-editor.org/rfc/rfc5646)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::LanguageRange;
+///
+/// let range: LanguageRange = "de-DE".parse()?;
+/// assert!(range.matches(&"de-de-1996".parse()?));
+/// assert!(!range.matches(&"de-Deva".parse()?));
+/// assert_eq!(range.subtags().collect::<Vec<_>>(), ["de", "DE"]);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LanguageRange {
+    inner: String,
+}
+
+impl LanguageRange {
+    /// Create a new language range.
+    ///
+    /// # Errors
+    ///
+    /// This method errors if the input is not `*`, or made up of subtags of
+    /// one to eight alphanumeric characters separated by `-`, the first of
+    /// which is alphabetic.
+    pub fn new(s: impl Into<String>) -> crate::Result<Self> {
+        let inner = s.into();
+        if inner != "*" {
+            for (n, subtag) in inner.split('-').enumerate() {
+                let valid_char = |c: u8| match n {
+                    0 => c.is_ascii_alphabetic(),
+                    _ => c.is_ascii_alphanumeric(),
+                };
+                if subtag.is_empty() || subtag.len() > 8 || !subtag.bytes().all(valid_char) {
+                    bail!(400, "Invalid language range `{}`", inner);
+                }
+            }
+        }
+        Ok(Self { inner })
+    }
+
+    /// Create the wildcard language range `*`.
+    pub fn wildcard() -> Self {
+        Self { inner: "*".into() }
+    }
+
+    /// Returns `true` if this is the wildcard language range `*`.
+    pub fn is_wildcard(&self) -> bool {
+        self.inner == "*"
+    }
+
+    /// Get the language range as a string.
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// An iterator over the subtags of the language range.
+    pub fn subtags(&self) -> impl Iterator<Item = &str> {
+        self.inner.split('-')
+    }
+
+    /// Get the primary language subtag, such as `en` for `en-US`.
+    pub fn primary(&self) -> &str {
+        self.subtags().next().unwrap()
+    }
+
+    /// Returns `true` if the language tag is matched by this range using
+    /// basic filtering.
+    ///
+    /// A range matches a tag if it equals the tag, or if it equals a prefix
+    /// of the tag that is followed by `-`. The wildcard matches every tag.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 4647, section 3.3.1: Basic Filtering](https://www.rfc-editor.org/rfc/rfc4647#section-3.3.1)
+    pub fn matches(&self, tag: &LanguageRange) -> bool {
+        if self.is_wildcard() {
+            return true;
+        }
+        let (range, tag) = (self.inner.as_bytes(), tag.inner.as_bytes());
+        match tag.get(..range.len()) {
+            Some(prefix) => {
+                prefix.eq_ignore_ascii_case(range)
+                    && (tag.len() == range.len() || tag[range.len()] == b'-')
+            }
+            None => false,
+        }
+    }
+
+    /// Remove the last subtag, along with a preceding single-character
+    /// subtag, as done by the lookup scheme. Returns `None` once only the
+    /// primary subtag is left.
+    pub(crate) fn truncate(&self) -> Option<Self> {
+        let mut end = self.inner.rfind('-')?;
+        let rest = &self.inner[..end];
+        if let Some(start) = rest.rfind('-') {
+            if rest.len() - start == 2 {
+                end = start;
+            }
+        }
+        Some(Self {
+            inner: self.inner[..end].to_owned(),
+        })
+    }
+}
+
+impl PartialEq for LanguageRange {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.eq_ignore_ascii_case(&other.inner)
+    }
+}
+
+impl Eq for LanguageRange {}
+
+impl Hash for LanguageRange {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.to_ascii_lowercase().hash(state);
+    }
+}
+
+impl PartialEq<str> for LanguageRange {
+    fn eq(&self, other: &str) -> bool {
+        self.inner.eq_ignore_ascii_case(other)
+    }
+}
+
+impl<'a> PartialEq<&'a str> for LanguageRange {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.inner.eq_ignore_ascii_case(other)
+    }
+}
+
+impl FromStr for LanguageRange {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for LanguageRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.inner)
+    }
+}
+
+impl From<LanguageRange> for HeaderValue {
+    fn from(range: LanguageRange) -> Self {
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(range.inner.into_bytes()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        assert!(LanguageRange::new("en").is_ok());
+        assert!(LanguageRange::new("zh-Hant-TW").is_ok());
+        assert!(LanguageRange::new("sl-rozaj-biske-1994").is_ok());
+        assert!(LanguageRange::new("*").is_ok());
+        assert!(LanguageRange::new("").is_err());
+        assert!(LanguageRange::new("en-").is_err());
+        assert!(LanguageRange::new("1en").is_err());
+        assert!(LanguageRange::new("en-toolongsubtag").is_err());
+        assert!(LanguageRange::new("en_US").is_err());
+    }
+
+    #[test]
+    fn basic_filtering() -> crate::Result<()> {
+        let range = LanguageRange::new("en")?;
+        assert!(range.matches(&"en".parse()?));
+        assert!(range.matches(&"EN-gb".parse()?));
+        assert!(!range.matches(&"eng".parse()?));
+        assert!(LanguageRange::wildcard().matches(&"fr".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn truncate() -> crate::Result<()> {
+        // Example from RFC 4647, section 3.4.
+        let range = LanguageRange::new("zh-Hant-CN-x-private1-private2")?;
+        let mut steps = vec![];
+        let mut next = Some(range);
+        while let Some(range) = next {
+            next = range.truncate();
+            steps.push(range.to_string());
+        }
+        assert_eq!(
+            steps,
+            [
+                "zh-Hant-CN-x-private1-private2",
+                "zh-Hant-CN-x-private1",
+                "zh-Hant-CN",
+                "zh-Hant",
+                "zh",
+            ]
+        );
+        Ok(())
+    }
+}
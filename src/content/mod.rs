@@ -32,25 +32,50 @@
 //! ```
 
 pub mod accept;
+pub mod accept_charset;
 pub mod accept_encoding;
+pub mod accept_language;
 pub mod content_encoding;
 
+mod charset_proposal;
+mod content_language;
 mod content_length;
 mod content_location;
 mod content_type;
 mod encoding;
 mod encoding_proposal;
+mod language_proposal;
+mod language_range;
 mod media_type_proposal;
+mod negotiation;
+mod unsupported_media_type;
 
 #[doc(inline)]
 pub use accept::Accept;
 #[doc(inline)]
+pub use accept_charset::AcceptCharset;
+#[doc(inline)]
 pub use accept_encoding::AcceptEncoding;
 #[doc(inline)]
+pub use accept_language::AcceptLanguage;
+pub use charset_proposal::CharsetProposal;
+#[doc(inline)]
 pub use content_encoding::ContentEncoding;
+pub use content_language::ContentLanguage;
 pub use content_length::ContentLength;
 pub use content_location::ContentLocation;
 pub use content_type::ContentType;
 pub use encoding::Encoding;
 pub use encoding_proposal::EncodingProposal;
+pub use language_proposal::LanguageProposal;
+pub use language_range::LanguageRange;
 pub use media_type_proposal::MediaTypeProposal;
+pub use negotiation::{Candidate, Negotiation, Offer, Verdict};
+pub use unsupported_media_type::UnsupportedMediaType;
+
+use std::cmp::Ordering;
+
+/// Stable sort by descending weight, so ties keep their declared order.
+pub(crate) fn sort_by_preference<T: PartialOrd>(entries: &mut [T]) {
+    entries.sort_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+}
@@ -0,0 +1,453 @@
+//! Server-driven content negotiation that takes the server's preferences into
+//! account.
+
+use crate::{ensure, Error, StatusCode};
+
+use std::fmt::{self, Display};
+use std::slice;
+
+/// A representation the server is able to produce, together with the
+/// server's own preference for it.
+///
+/// The preference is a source quality value (`qs`) between `0.0` and `1.0`,
+/// which is multiplied by the weight the client assigned to the
+/// representation during negotiation. Offers created through `From` have a
+/// quality of `1.0`.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::Offer;
+/// use http_types::mime;
+///
+/// let offers = [Offer::new(mime::JSON, 1.0)?, Offer::new(mime::XML, 0.5)?];
+/// assert_eq!(offers[1].quality(), 0.5);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Offer<T> {
+    value: T,
+    quality: f32,
+}
+
+impl<T> Offer<T> {
+    /// Create a new instance of `Offer`.
+    pub fn new(value: T, quality: f32) -> crate::Result<Self> {
+        ensure!(
+            quality.is_sign_positive() && quality <= 1.0,
+            "Offer should have a quality between 0.0 and 1.0"
+        );
+        Ok(Self { value, quality })
+    }
+
+    /// Get the offered value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Get the server's quality value for the offer.
+    pub fn quality(&self) -> f32 {
+        self.quality
+    }
+}
+
+impl<T> From<T> for Offer<T> {
+    fn from(value: T) -> Self {
+        Self {
+            value,
+            quality: 1.0,
+        }
+    }
+}
+
+/// Why a candidate was chosen or rejected during negotiation.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The candidate had the highest score, and was chosen.
+    Chosen,
+    /// The candidate was acceptable, but another candidate was chosen.
+    Outranked,
+    /// None of the client's directives matched the candidate.
+    Unmatched,
+    /// The client excluded the candidate with a weight of `0`.
+    Excluded,
+    /// The server offered the candidate with a quality of `0`.
+    Declined,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Verdict::Chosen => "chosen",
+            Verdict::Outranked => "outranked",
+            Verdict::Unmatched => "not matched by the client",
+            Verdict::Excluded => "excluded by the client",
+            Verdict::Declined => "declined by the server",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A single offer, as evaluated during negotiation.
+#[derive(Debug, Clone)]
+pub struct Candidate<T> {
+    value: T,
+    weight: Option<f32>,
+    quality: f32,
+    directive: Option<String>,
+    specificity: (u8, usize),
+    verdict: Verdict,
+}
+
+impl<T> Candidate<T> {
+    /// Get the offered value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Get the weight of the most specific client directive matching the
+    /// candidate, if any matched.
+    pub fn weight(&self) -> Option<f32> {
+        self.weight
+    }
+
+    /// Get the server's quality value for the candidate.
+    pub fn quality(&self) -> f32 {
+        self.quality
+    }
+
+    /// Get the combined score: the client's weight multiplied by the server's
+    /// quality value.
+    pub fn score(&self) -> f32 {
+        self.weight.unwrap_or(0.0) * self.quality
+    }
+
+    /// Get the client directive which determined the weight, such as
+    /// `text/*`.
+    pub fn directive(&self) -> Option<&str> {
+        self.directive.as_deref()
+    }
+
+    /// Get the verdict for the candidate.
+    pub fn verdict(&self) -> Verdict {
+        self.verdict
+    }
+}
+
+impl<T: Display> Display for Candidate<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.value, self.verdict)?;
+        match (&self.directive, self.weight) {
+            (Some(directive), Some(weight)) => write!(
+                f,
+                " (q={:.3} from `{}` × qs={:.3} = {:.3})",
+                weight,
+                directive,
+                self.quality,
+                self.score()
+            ),
+            _ => write!(f, " (qs={:.3})", self.quality),
+        }
+    }
+}
+
+/// The outcome of negotiating a set of offers against a client's preferences.
+///
+/// Each offer is scored by multiplying the weight of the most specific client
+/// directive matching it by the server's quality value. The candidate with the
+/// highest score is chosen. Ties are broken in favor of the candidate matched
+/// by the more specific directive, then by the directive with the most
+/// parameters, and finally by the order the offers were passed in.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::{Accept, Offer, Verdict};
+/// use http_types::{mime, Request};
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header("Accept", "application/xml, application/json;q=0.9");
+/// let accept = Accept::from_headers(&req)?.unwrap();
+///
+/// let offers = [Offer::new(mime::JSON, 1.0)?, Offer::new(mime::XML, 0.5)?];
+/// let negotiation = accept.negotiate_with(&offers);
+/// assert_eq!(negotiation.chosen(), Some(&mime::JSON));
+///
+/// let verdicts: Vec<_> = negotiation.candidates().map(|c| c.verdict()).collect();
+/// assert_eq!(verdicts, [Verdict::Chosen, Verdict::Outranked]);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Negotiation<T> {
+    candidates: Vec<Candidate<T>>,
+    chosen: Option<usize>,
+}
+
+impl<T> Negotiation<T> {
+    /// Evaluate each offer using `matcher`, which returns the weight and
+    /// specificity of the most specific client directive matching an offer.
+    pub(crate) fn new<F>(offers: &[Offer<T>], matcher: F) -> Self
+    where
+        T: Clone,
+        F: Fn(&T) -> Option<Match>,
+    {
+        let mut candidates: Vec<Candidate<T>> = offers
+            .iter()
+            .map(|offer| {
+                let found = matcher(&offer.value);
+                let verdict = match &found {
+                    _ if offer.quality == 0.0 => Verdict::Declined,
+                    None => Verdict::Unmatched,
+                    Some(found) if found.weight == 0.0 => Verdict::Excluded,
+                    Some(_) => Verdict::Outranked,
+                };
+                let (weight, directive, specificity) = match found {
+                    Some(found) => (Some(found.weight), Some(found.directive), found.specificity),
+                    None => (None, None, (0, 0)),
+                };
+                Candidate {
+                    value: offer.value.clone(),
+                    weight,
+                    quality: offer.quality,
+                    directive,
+                    specificity,
+                    verdict,
+                }
+            })
+            .collect();
+
+        let mut chosen: Option<usize> = None;
+        for (index, candidate) in candidates.iter().enumerate() {
+            if candidate.verdict != Verdict::Outranked {
+                continue;
+            }
+            let key = (candidate.score(), candidate.specificity);
+            match chosen.map(|i| &candidates[i]) {
+                Some(best) if (best.score(), best.specificity) >= key => {}
+                _ => chosen = Some(index),
+            }
+        }
+        if let Some(index) = chosen {
+            candidates[index].verdict = Verdict::Chosen;
+        }
+
+        Self { candidates, chosen }
+    }
+
+    /// Get the chosen value, if any candidate was acceptable.
+    pub fn chosen(&self) -> Option<&T> {
+        self.chosen.map(|index| &self.candidates[index].value)
+    }
+
+    /// Get the chosen value.
+    ///
+    /// # Errors
+    ///
+    /// If no candidate was acceptable, an error with the status of `406` will be returned.
+    pub fn into_chosen(mut self) -> crate::Result<T> {
+        match self.chosen {
+            Some(index) => Ok(self.candidates.swap_remove(index).value),
+            None => {
+                let mut err = Error::new_adhoc("No acceptable representation found");
+                err.set_status(StatusCode::NotAcceptable);
+                Err(err)
+            }
+        }
+    }
+
+    /// An iterator visiting all candidates, in the order they were offered.
+    pub fn candidates(&self) -> slice::Iter<'_, Candidate<T>> {
+        self.candidates.iter()
+    }
+}
+
+/// The client directive matching an offer.
+pub(crate) struct Match {
+    /// The weight of the directive, where a missing weight is `1.0`.
+    pub(crate) weight: f32,
+    /// The directive as sent by the client.
+    pub(crate) directive: String,
+    /// How specific the directive is, followed by its number of parameters.
+    pub(crate) specificity: (u8, usize),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::content::{Accept, AcceptEncoding, Encoding};
+    use crate::mime::{self, Mime};
+    use crate::Request;
+
+    fn accept(value: &str) -> crate::Result<Accept> {
+        let mut req = Request::get("https://example.com");
+        req.insert_header("Accept", value)?;
+        Ok(Accept::from_headers(req)?.unwrap())
+    }
+
+    fn verdicts<T>(negotiation: &Negotiation<T>) -> Vec<Verdict> {
+        negotiation.candidates().map(|c| c.verdict()).collect()
+    }
+
+    #[test]
+    fn server_preference() -> crate::Result<()> {
+        let accept = accept("application/json;q=0.6, application/xml;q=0.8")?;
+        let offers = [Offer::new(mime::JSON, 1.0)?, Offer::new(mime::XML, 0.5)?];
+        let negotiation = accept.negotiate_with(&offers);
+        assert_eq!(negotiation.chosen(), Some(&mime::JSON));
+        assert_eq!(negotiation.candidates().nth(1).unwrap().score(), 0.4);
+
+        // The entries are left untouched.
+        let entries: Vec<_> = accept.iter().map(|e| e.media_type().essence()).collect();
+        assert_eq!(entries, ["application/json", "application/xml"]);
+        Ok(())
+    }
+
+    #[test]
+    fn most_specific_range_wins() -> crate::Result<()> {
+        let accept = accept("text/*;q=0.3, text/html;q=0.7, text/html;level=1, */*;q=0.5")?;
+        let level1: Mime = "text/html;level=1".parse()?;
+        let level2: Mime = "text/html;level=2".parse()?;
+        let offers = [
+            Offer::from(mime::PLAIN),
+            Offer::from(level2),
+            Offer::from(mime::PNG),
+            Offer::from(level1.clone()),
+        ];
+        let negotiation = accept.negotiate_with(&offers);
+        let weights: Vec<_> = negotiation.candidates().map(|c| c.weight()).collect();
+        assert_eq!(weights, [Some(0.3), Some(0.7), Some(0.5), Some(1.0)]);
+        assert_eq!(negotiation.chosen(), Some(&level1));
+        Ok(())
+    }
+
+    #[test]
+    fn tie_breaks() -> crate::Result<()> {
+        // Equal scores prefer the more specific range.
+        let offers = [Offer::new(mime::HTML, 0.5)?, Offer::from(mime::PLAIN)];
+        let negotiation = accept("text/*, text/plain;q=0.5")?.negotiate_with(&offers);
+        assert_eq!(negotiation.chosen(), Some(&mime::PLAIN));
+
+        // Then the range with the most parameters.
+        let level1: Mime = "text/html;level=1".parse()?;
+        let offers = [Offer::from(mime::PLAIN), Offer::from(level1.clone())];
+        let negotiation =
+            accept("text/html;level=1;q=0.5, text/plain;q=0.5")?.negotiate_with(&offers);
+        assert_eq!(negotiation.chosen(), Some(&level1));
+
+        // And finally the order of the offers.
+        let negotiation = accept("*/*")?.negotiate_with(&offers);
+        assert_eq!(negotiation.chosen(), Some(&mime::PLAIN));
+        Ok(())
+    }
+
+    #[test]
+    fn rejections() -> crate::Result<()> {
+        let accept = accept("text/html, text/plain;q=0, application/json")?;
+        let offers = [
+            Offer::from(mime::PLAIN),
+            Offer::from(mime::PNG),
+            Offer::new(mime::JSON, 0.0)?,
+            Offer::new(mime::HTML, 0.2)?,
+        ];
+        let negotiation = accept.negotiate_with(&offers);
+        assert_eq!(
+            verdicts(&negotiation),
+            [
+                Verdict::Excluded,
+                Verdict::Unmatched,
+                Verdict::Declined,
+                Verdict::Chosen
+            ]
+        );
+
+        let explanation: Vec<_> = negotiation.candidates().map(|c| c.to_string()).collect();
+        assert_eq!(
+            explanation[3],
+            "text/html;charset=utf-8: chosen (q=1.000 from `text/html` × qs=0.200 = 0.200)"
+        );
+        assert_eq!(
+            explanation[1],
+            "image/png: not matched by the client (qs=1.000)"
+        );
+
+        let err = accept
+            .negotiate_with(&offers[..3])
+            .into_chosen()
+            .unwrap_err();
+        assert_eq!(err.status(), 406);
+        Ok(())
+    }
+
+    #[test]
+    fn encodings() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        req.insert_header("Accept-Encoding", "gzip;q=0.8, identity;q=0, *")?;
+        let accept = AcceptEncoding::from_headers(req)?.unwrap();
+
+        let offers = [
+            Offer::from(Encoding::Identity),
+            Offer::from(Encoding::Gzip),
+            Offer::new(Encoding::Brotli, 0.7)?,
+        ];
+        let negotiation = accept.negotiate_with(&offers);
+        assert_eq!(
+            verdicts(&negotiation),
+            [Verdict::Excluded, Verdict::Chosen, Verdict::Outranked]
+        );
+        assert_eq!(
+            negotiation.candidates().nth(2).unwrap().directive(),
+            Some("*")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn encodings_excluded_by_wildcard() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        req.insert_header("Accept-Encoding", "gzip;q=0.8, *;q=0")?;
+        let accept = AcceptEncoding::from_headers(req)?.unwrap();
+
+        let offers = [
+            Offer::from(Encoding::Brotli),
+            Offer::new(Encoding::Gzip, 0.5)?,
+        ];
+        let negotiation = accept.negotiate_with(&offers);
+        assert_eq!(verdicts(&negotiation), [Verdict::Excluded, Verdict::Chosen]);
+
+        let err = accept
+            .negotiate_with(&offers[..1])
+            .into_chosen()
+            .unwrap_err();
+        assert_eq!(err.status(), 406);
+        Ok(())
+    }
+
+    #[test]
+    fn encodings_weighted_wildcard() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        req.insert_header("Accept-Encoding", "gzip;q=0.8, *;q=0.5")?;
+        let accept = AcceptEncoding::from_headers(req)?.unwrap();
+
+        let offers = [Offer::from(Encoding::Brotli), Offer::from(Encoding::Gzip)];
+        let negotiation = accept.negotiate_with(&offers);
+        assert_eq!(
+            verdicts(&negotiation),
+            [Verdict::Outranked, Verdict::Chosen]
+        );
+        assert_eq!(negotiation.candidates().next().unwrap().weight(), Some(0.5));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_quality() {
+        let err = Offer::new(mime::JSON, 1.5).unwrap_err();
+        assert_eq!(err.status(), 500);
+    }
+}
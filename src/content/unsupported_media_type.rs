@@ -0,0 +1,150 @@
+use crate::headers::{HeaderValue, Headers, ACCEPT_PATCH, ACCEPT_POST};
+use crate::mime::Mime;
+use crate::{Error, StatusCode};
+
+use std::fmt::{self, Display, Write};
+
+/// The error returned when a request body has a `Content-Type` the server
+/// doesn't support.
+///
+/// This is wrapped in an [`Error`] with the status of `415`, and can be
+/// retrieved using [`Error::downcast_ref`] to advertise the supported types.
+///
+/// # Specifications
+///
+/// - [RFC 9110, section 15.5.16: 415 Unsupported Media Type](https://www.rfc-editor.org/rfc/rfc9110#section-15.5.16)
+/// - [RFC 5789, section 3.1: Accept-Patch](https://www.rfc-editor.org/rfc/rfc5789#section-3.1)
+/// - [Linked Data Platform 1.0, section 7.1: Accept-Post](https://www.w3.org/TR/ldp/#header-accept-post)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+/// use http_types::content::UnsupportedMediaType;
+/// use http_types::{Body, Request, Response};
+///
+/// let mut req = Request::post("https://example.com");
+/// req.set_body(Body::from_string("name=chashu".into()));
+///
+/// let err = req.body_json_strict::<String>().await.unwrap_err();
+/// assert_eq!(err.status(), 415);
+///
+/// let mut res = Response::new(err.status());
+/// let err = err.downcast_ref::<UnsupportedMediaType>().unwrap();
+/// err.apply_accept_post(&mut res);
+/// assert_eq!(res["Accept-Post"], "application/json");
+/// # Ok(()) }) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnsupportedMediaType {
+    found: Option<Mime>,
+    supported: Vec<Mime>,
+}
+
+impl UnsupportedMediaType {
+    /// Create a new instance of `UnsupportedMediaType`.
+    pub fn new(found: Option<Mime>, supported: Vec<Mime>) -> Self {
+        Self { found, supported }
+    }
+
+    /// Check the declared content type against the supported types.
+    ///
+    /// `accepts` decides which content types are part of the supported
+    /// family, while `supported` lists the types advertised in the error.
+    pub(crate) fn check(
+        found: Option<Mime>,
+        supported: &[Mime],
+        accepts: impl Fn(&Mime) -> bool,
+    ) -> crate::Result<()> {
+        match &found {
+            Some(mime) if accepts(mime) => Ok(()),
+            _ => Err(Error::new(
+                StatusCode::UnsupportedMediaType,
+                Self::new(found, supported.to_vec()),
+            )),
+        }
+    }
+
+    /// Get the content type which was declared, if any.
+    pub fn found(&self) -> Option<&Mime> {
+        self.found.as_ref()
+    }
+
+    /// Get the supported content types.
+    pub fn supported(&self) -> &[Mime] {
+        &self.supported
+    }
+
+    /// Set the `Accept-Post` header, advertising the supported types in
+    /// response to a `POST` request.
+    pub fn apply_accept_post(&self, mut headers: impl AsMut<Headers>) {
+        headers
+            .as_mut()
+            .insert(ACCEPT_POST, self.header_value())
+            .unwrap();
+    }
+
+    /// Set the `Accept-Patch` header, advertising the supported types in
+    /// response to a `PATCH` request.
+    pub fn apply_accept_patch(&self, mut headers: impl AsMut<Headers>) {
+        headers
+            .as_mut()
+            .insert(ACCEPT_PATCH, self.header_value())
+            .unwrap();
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, mime) in self.supported.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", mime).unwrap(),
+                _ => write!(output, ", {}", mime).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl Display for UnsupportedMediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.found {
+            Some(mime) => write!(f, "Unsupported Content-Type `{}`", mime)?,
+            None => write!(f, "Missing Content-Type")?,
+        }
+        write!(f, ", expected {}", self.header_value())
+    }
+}
+
+impl std::error::Error for UnsupportedMediaType {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mime;
+    use crate::Response;
+
+    #[test]
+    fn check() -> crate::Result<()> {
+        let problem: Mime = "application/problem+json".parse()?;
+        UnsupportedMediaType::check(Some(problem), &[mime::JSON], Mime::is_json)?;
+
+        let err =
+            UnsupportedMediaType::check(None, &[mime::JSON, mime::XML], Mime::is_json).unwrap_err();
+        assert_eq!(err.status(), 415);
+        assert_eq!(
+            err.to_string(),
+            "Missing Content-Type, expected application/json, application/xml;charset=utf-8"
+        );
+
+        let mut res = Response::new(415);
+        let err = err.downcast_ref::<UnsupportedMediaType>().unwrap();
+        err.apply_accept_patch(&mut res);
+        assert_eq!(
+            res["Accept-Patch"],
+            "application/json, application/xml;charset=utf-8"
+        );
+        Ok(())
+    }
+}
@@ -66,6 +66,10 @@ pub const ACCEPT_CHARSET: HeaderName = HeaderName::from_lowercase_str("accept-ch
 pub const ACCEPT_ENCODING: HeaderName = HeaderName::from_lowercase_str("accept-encoding");
 ///  The `Accept-Language` Header
 pub const ACCEPT_LANGUAGE: HeaderName = HeaderName::from_lowercase_str("accept-language");
+///  The `Accept-Patch` Header
+pub const ACCEPT_PATCH: HeaderName = HeaderName::from_lowercase_str("accept-patch");
+///  The `Accept-Post` Header
+pub const ACCEPT_POST: HeaderName = HeaderName::from_lowercase_str("accept-post");
 ///  The `Accept-Ranges` Header
 pub const ACCEPT_RANGES: HeaderName = HeaderName::from_lowercase_str("accept-ranges");
 
@@ -78,9 +82,18 @@ pub const ALLOW: HeaderName = HeaderName::from_lowercase_str("allow");
 ///  The `Authorization` Header
 pub const AUTHORIZATION: HeaderName = HeaderName::from_lowercase_str("authorization");
 
+///  The `Baggage` Header
+pub const BAGGAGE: HeaderName = HeaderName::from_lowercase_str("baggage");
+
 ///  The `Cache-Control` Header
 pub const CACHE_CONTROL: HeaderName = HeaderName::from_lowercase_str("cache-control");
 
+///  The `Cache-Status` Header
+pub const CACHE_STATUS: HeaderName = HeaderName::from_lowercase_str("cache-status");
+
+///  The `CDN-Cache-Control` Header
+pub const CDN_CACHE_CONTROL: HeaderName = HeaderName::from_lowercase_str("cdn-cache-control");
+
 ///  The `Clear-Site-Data` Header
 pub const CLEAR_SITE_DATA: HeaderName = HeaderName::from_lowercase_str("clear-site-data");
 
@@ -117,6 +130,9 @@ pub const IF_RANGE: HeaderName = HeaderName::from_lowercase_str("if-range");
 ///  The `If-Unmodified-Since` Header
 pub const IF_UNMODIFIED_SINCE: HeaderName = HeaderName::from_lowercase_str("if-unmodified-since");
 
+///  The `Keep-Alive` Header
+pub const KEEP_ALIVE: HeaderName = HeaderName::from_lowercase_str("keep-alive");
+
 ///  The `Last-Modified` Header
 pub const LAST_MODIFIED: HeaderName = HeaderName::from_lowercase_str("last-modified");
 
@@ -138,12 +154,33 @@ pub const PROXY_AUTHORIZATION: HeaderName = HeaderName::from_lowercase_str("prox
 /// The `Proxy-Connection` Header
 pub const PROXY_CONNECTION: HeaderName = HeaderName::from_lowercase_str("proxy-connection");
 
+/// The `Proxy-Status` Header
+pub const PROXY_STATUS: HeaderName = HeaderName::from_lowercase_str("proxy-status");
+
 ///  The `Referer` Header
 pub const REFERER: HeaderName = HeaderName::from_lowercase_str("referer");
 
 ///  The `Retry-After` Header
 pub const RETRY_AFTER: HeaderName = HeaderName::from_lowercase_str("retry-after");
 
+///  The `Sec-WebSocket-Accept` Header
+pub const SEC_WEBSOCKET_ACCEPT: HeaderName = HeaderName::from_lowercase_str("sec-websocket-accept");
+
+///  The `Sec-WebSocket-Extensions` Header
+pub const SEC_WEBSOCKET_EXTENSIONS: HeaderName =
+    HeaderName::from_lowercase_str("sec-websocket-extensions");
+
+///  The `Sec-WebSocket-Key` Header
+pub const SEC_WEBSOCKET_KEY: HeaderName = HeaderName::from_lowercase_str("sec-websocket-key");
+
+///  The `Sec-WebSocket-Protocol` Header
+pub const SEC_WEBSOCKET_PROTOCOL: HeaderName =
+    HeaderName::from_lowercase_str("sec-websocket-protocol");
+
+///  The `Sec-WebSocket-Version` Header
+pub const SEC_WEBSOCKET_VERSION: HeaderName =
+    HeaderName::from_lowercase_str("sec-websocket-version");
+
 ///  The `Server` Header
 pub const SERVER: HeaderName = HeaderName::from_lowercase_str("server");
 
@@ -157,6 +194,9 @@ pub const SOURCE_MAP: HeaderName = HeaderName::from_lowercase_str("sourcemap");
 pub const STRICT_TRANSPORT_SECURITY: HeaderName =
     HeaderName::from_lowercase_str("strict-transport-security");
 
+///  The `Surrogate-Control` Header
+pub const SURROGATE_CONTROL: HeaderName = HeaderName::from_lowercase_str("surrogate-control");
+
 ///  The `Te` Header
 pub const TE: HeaderName = HeaderName::from_lowercase_str("te");
 
@@ -166,6 +206,9 @@ pub const TIMING_ALLOW_ORIGIN: HeaderName = HeaderName::from_lowercase_str("timi
 ///  The `Traceparent` Header
 pub const TRACEPARENT: HeaderName = HeaderName::from_lowercase_str("traceparent");
 
+///  The `Tracestate` Header
+pub const TRACESTATE: HeaderName = HeaderName::from_lowercase_str("tracestate");
+
 ///  The `Trailer` Header
 pub const TRAILER: HeaderName = HeaderName::from_lowercase_str("trailer");
 
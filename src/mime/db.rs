@@ -0,0 +1,3793 @@
+// This file is generated by scripts/generate_mime_db.py
+// Do not edit manually
+
+/// Extensions and the media type they map to, sorted by extension.
+#[rustfmt::skip]
+pub(crate) static EXTENSIONS: &[(&str, &str)] = &[
+    ("123", "application/vnd.lotus-1-2-3"),
+    ("1905.1", "application/vnd.ieee.1905"),
+    ("1clr", "application/clr"),
+    ("1km", "application/vnd.1000minds.decision-model+xml"),
+    ("210", "application/p21"),
+    ("3dm", "text/vnd.in3d.3dml"),
+    ("3dml", "text/vnd.in3d.3dml"),
+    ("3mf", "application/vnd.ms-3mfdocument"),
+    ("3tz", "application/vnd.maxar.archive.3tz+zip"),
+    ("726", "audio/32kadpcm"),
+    ("7z", "application/x-7z-compressed"),
+    ("a", "text/vnd.a"),
+    ("a2l", "application/a2l"),
+    ("aa3", "audio/atrac3"),
+    ("aac", "audio/aac"),
+    ("aal", "audio/atrac-advanced-lossless"),
+    ("abc", "text/vnd.abc"),
+    ("abw", "application/x-abiword"),
+    ("ac", "application/pkix-attr-cert"),
+    ("ac2", "application/vnd.banana-accounting"),
+    ("ac3", "audio/ac3"),
+    ("acc", "application/vnd.americandynamics.acc"),
+    ("acn", "audio/asc"),
+    ("acu", "application/vnd.acucobol"),
+    ("acutc", "application/vnd.acucorp"),
+    ("adts", "audio/aac"),
+    ("aep", "application/vnd.audiograph"),
+    ("afp", "application/vnd.afpc.modca"),
+    ("age", "application/vnd.age"),
+    ("ahead", "application/vnd.ahead.space"),
+    ("ai", "application/postscript"),
+    ("aif", "audio/x-aiff"),
+    ("aifc", "audio/x-aiff"),
+    ("aiff", "audio/x-aiff"),
+    ("aion", "application/vnd.veritone.aion+json"),
+    ("ait", "application/vnd.dvb.ait"),
+    ("alc", "chemical/x-alchemy"),
+    ("ami", "application/vnd.amiga.ami"),
+    ("aml", "application/aml"),
+    ("amlx", "application/automationml-amlx+zip"),
+    ("amr", "audio/amr"),
+    ("anx", "application/annodex"),
+    ("apex", "application/vnd.apexlang"),
+    ("apexlang", "application/vnd.apexlang"),
+    ("apk", "application/vnd.android.package-archive"),
+    ("apkg", "application/vnd.anki"),
+    ("apng", "image/apng"),
+    ("appcache", "text/cache-manifest"),
+    ("apr", "application/vnd.lotus-approach"),
+    ("apxml", "application/auth-policy+xml"),
+    ("arrow", "application/vnd.apache.arrow.file"),
+    ("arrows", "application/vnd.apache.arrow.stream"),
+    ("art", "image/x-jg"),
+    ("artisan", "application/vnd.artisan+json"),
+    ("asc", "application/pgp-keys"),
+    ("ascii", "text/vnd.ascii-art"),
+    ("asf", "application/vnd.ms-asf"),
+    ("asice", "application/vnd.etsi.asic-e+zip"),
+    ("asics", "application/vnd.etsi.asic-s+zip"),
+    ("asn", "chemical/x-ncbi-asn1"),
+    ("aso", "application/vnd.accpac.simply.aso"),
+    ("ass", "audio/aac"),
+    ("at3", "audio/atrac3"),
+    ("atc", "application/vnd.acucorp"),
+    ("atf", "application/atf"),
+    ("atfx", "application/atfx"),
+    ("atom", "application/atom+xml"),
+    ("atomcat", "application/atomcat+xml"),
+    ("atomdeleted", "application/atomdeleted+xml"),
+    ("atomsrv", "application/atomserv+xml"),
+    ("atomsvc", "application/atomsvc+xml"),
+    ("atx", "audio/atrac-x"),
+    ("atxml", "application/atxml"),
+    ("au", "audio/basic"),
+    ("auc", "application/tamp-apex-update-confirm"),
+    ("avci", "image/avci"),
+    ("avcs", "image/avcs"),
+    ("avi", "video/x-msvideo"),
+    ("avif", "image/avif"),
+    ("awb", "audio/amr-wb"),
+    ("axa", "audio/annodex"),
+    ("axv", "video/annodex"),
+    ("azf", "application/vnd.airzip.filesecure.azf"),
+    ("azs", "application/vnd.airzip.filesecure.azs"),
+    ("azv", "image/vnd.airzip.accelerator.azv"),
+    ("azw3", "application/vnd.amazon.mobi8-ebook"),
+    ("b", "chemical/x-molconn-z"),
+    ("b16", "image/vnd.pco.b16"),
+    ("bak", "application/x-trash"),
+    ("bar", "application/vnd.qualcomm.brew-app-res"),
+    ("bat", "application/x-msdos-program"),
+    ("bcpio", "application/x-bcpio"),
+    ("bdm", "application/vnd.syncml.dm+wbxml"),
+    ("bed", "application/vnd.realvnc.bed"),
+    ("bh2", "application/vnd.fujitsu.oasysprs"),
+    ("bib", "text/x-bibtex"),
+    ("bik", "video/vnd.radgamettools.bink"),
+    ("bin", "application/octet-stream"),
+    ("bk2", "video/vnd.radgamettools.bink"),
+    ("bkm", "application/vnd.nervana"),
+    ("bmed", "multipart/vnd.bint.med-plus"),
+    ("bmi", "application/vnd.bmi"),
+    ("bmml", "application/vnd.balsamiq.bmml+xml"),
+    ("bmp", "image/bmp"),
+    ("bmpr", "application/vnd.balsamiq.bmpr"),
+    ("boo", "text/x-boo"),
+    ("book", "application/x-maker"),
+    ("box", "application/vnd.previewsystems.box"),
+    ("bpd", "application/vnd.hbci"),
+    ("br", "application/x-brotli"),
+    ("brf", "text/plain"),
+    ("bsd", "chemical/x-crossfire"),
+    ("bsp", "model/vnd.valve.source.compiled-map"),
+    ("btf", "image/prs.btif"),
+    ("btif", "image/prs.btif"),
+    ("c", "text/x-csrc"),
+    ("c++", "text/x-c++src"),
+    ("c11amc", "application/vnd.cluetrust.cartomobile-config"),
+    ("c11amz", "application/vnd.cluetrust.cartomobile-config-pkg"),
+    ("c3d", "chemical/x-chem3d"),
+    ("c3ex", "application/cccex"),
+    ("c4d", "application/vnd.clonk.c4group"),
+    ("c4f", "application/vnd.clonk.c4group"),
+    ("c4g", "application/vnd.clonk.c4group"),
+    ("c4p", "application/vnd.clonk.c4group"),
+    ("c4u", "application/vnd.clonk.c4group"),
+    ("c9r", "application/vnd.cryptomator.encrypted"),
+    ("c9s", "application/vnd.cryptomator.encrypted"),
+    ("cab", "application/vnd.ms-cab-compressed"),
+    ("cac", "chemical/x-cache"),
+    ("cache", "chemical/x-cache"),
+    ("cap", "application/vnd.tcpdump.pcap"),
+    ("car", "application/vnd.ipld.car"),
+    ("carjson", "application/vnd.eu.kasparian.car+json"),
+    ("cascii", "chemical/x-cactvs-binary"),
+    ("cat", "application/vnd.ms-pki.seccat"),
+    ("cbin", "chemical/x-cactvs-binary"),
+    ("cbor", "application/cbor"),
+    ("cbr", "application/vnd.comicbook-rar"),
+    ("cbz", "application/vnd.comicbook+zip"),
+    ("cc", "text/x-c++src"),
+    ("ccc", "text/vnd.net2phone.commcenter.command"),
+    ("ccmp", "application/ccmp+xml"),
+    ("ccxml", "application/ccxml+xml"),
+    ("cda", "application/x-cdf"),
+    ("cdbcmsg", "application/vnd.contact.cmsg"),
+    ("cdf", "application/x-cdf"),
+    ("cdfx", "application/cdfx+xml"),
+    ("cdkey", "application/vnd.mediastation.cdkey"),
+    ("cdmia", "application/cdmi-capability"),
+    ("cdmic", "application/cdmi-container"),
+    ("cdmid", "application/cdmi-domain"),
+    ("cdmio", "application/cdmi-object"),
+    ("cdmiq", "application/cdmi-queue"),
+    ("cdr", "image/x-coreldraw"),
+    ("cdt", "image/x-coreldrawtemplate"),
+    ("cdx", "chemical/x-cdx"),
+    ("cdxml", "application/vnd.chemdraw+xml"),
+    ("cdy", "application/vnd.cinderella"),
+    ("cea", "application/cea"),
+    ("cef", "chemical/x-cxf"),
+    ("cellml", "application/cellml+xml"),
+    ("cer", "application/pkix-cert"),
+    ("cgm", "image/cgm"),
+    ("chm", "application/vnd.ms-htmlhelp"),
+    ("chrt", "application/vnd.kde.kchart"),
+    ("cif", "application/vnd.multiad.creator.cif"),
+    ("cii", "application/vnd.anser-web-certificate-issue-initiation"),
+    ("cil", "application/vnd.ms-artgalry"),
+    ("cl", "application/simple-filter+xml"),
+    ("cla", "application/vnd.claymore"),
+    ("class", "application/java-vm"),
+    ("cld", "model/vnd.cld"),
+    ("clkk", "application/vnd.crick.clicker.keyboard"),
+    ("clkp", "application/vnd.crick.clicker.palette"),
+    ("clkt", "application/vnd.crick.clicker.template"),
+    ("clkw", "application/vnd.crick.clicker.wordbank"),
+    ("clkx", "application/vnd.crick.clicker"),
+    ("cls", "text/x-tex"),
+    ("clue", "application/clue_info+xml"),
+    ("cmc", "application/vnd.cosmocaller"),
+    ("cmdf", "chemical/x-cmdf"),
+    ("cml", "chemical/x-cml"),
+    ("cmp", "application/vnd.yellowriver-custom-menu"),
+    ("cmsc", "application/cms"),
+    ("cnd", "text/jcr-cnd"),
+    ("cod", "application/vnd.rim.cod"),
+    ("coffee", "application/vnd.coffeescript"),
+    ("com", "application/x-msdos-program"),
+    ("copyright", "text/vnd.debian.copyright"),
+    ("coswid", "application/swid+cbor"),
+    ("cpa", "chemical/x-compass"),
+    ("cpio", "application/x-cpio"),
+    ("cpkg", "application/vnd.xmpie.cpkg"),
+    ("cpl", "application/cpl+xml"),
+    ("cpp", "text/x-c++src"),
+    ("cpt", "application/mac-compactpro"),
+    ("cql", "text/cql"),
+    ("cr2", "image/x-canon-cr2"),
+    ("crl", "application/pkix-crl"),
+    ("crt", "application/x-x509-ca-cert"),
+    ("crtr", "application/vnd.multiad.creator"),
+    ("crw", "image/x-canon-crw"),
+    ("cryptomator", "application/vnd.cryptomator.vault"),
+    ("cryptonote", "application/vnd.rig.cryptonote"),
+    ("csd", "audio/csound"),
+    ("csf", "chemical/x-cache-csf"),
+    ("csh", "application/x-csh"),
+    ("csl", "application/vnd.citationstyles.style+xml"),
+    ("csm", "chemical/x-csml"),
+    ("csml", "chemical/x-csml"),
+    ("csp", "application/vnd.commonspace"),
+    ("csrattrs", "application/csrattrs"),
+    ("css", "text/css"),
+    ("cst", "application/vnd.commonspace"),
+    ("csv", "text/csv"),
+    ("csvs", "text/csv-schema"),
+    ("ctab", "chemical/x-cactvs-binary"),
+    ("ctx", "chemical/x-ctx"),
+    ("cu", "application/cu-seeme"),
+    ("cub", "chemical/x-gaussian-cube"),
+    ("cuc", "application/tamp-community-update-confirm"),
+    ("curl", "text/vnd.curl"),
+    ("cw", "application/prs.cww"),
+    ("cwl", "application/cwl"),
+    ("cwl.json", "application/cwl+json"),
+    ("cww", "application/prs.cww"),
+    ("cxf", "chemical/x-cxf"),
+    ("cxx", "text/x-c++src"),
+    ("d", "text/x-dsrc"),
+    ("dae", "model/vnd.collada+xml"),
+    ("daf", "application/vnd.mobius.daf"),
+    ("dart", "application/vnd.dart"),
+    ("dataless", "application/vnd.fdsn.seed"),
+    ("davmount", "application/davmount+xml"),
+    ("dbf", "application/vnd.dbf"),
+    ("dcd", "application/dcd"),
+    ("dcm", "application/dicom"),
+    ("dcr", "application/x-director"),
+    ("dd2", "application/vnd.oma.dd2+xml"),
+    ("ddd", "application/vnd.fujixerox.ddd"),
+    ("ddeb", "application/vnd.debian.binary-package"),
+    ("ddf", "application/vnd.syncml.dmddf+xml"),
+    ("deb", "application/vnd.debian.binary-package"),
+    ("deploy", "application/octet-stream"),
+    ("dfac", "application/vnd.dreamfactory"),
+    ("dif", "video/dv"),
+    ("diff", "text/x-diff"),
+    ("dii", "application/dii"),
+    ("dim", "application/vnd.fastcopy-disk-image"),
+    ("dir", "application/x-director"),
+    ("dis", "application/vnd.mobius.dis"),
+    ("dist", "application/vnd.apple.installer+xml"),
+    ("distz", "application/vnd.apple.installer+xml"),
+    ("dit", "application/dit"),
+    ("dive", "application/vnd.patentdive"),
+    ("djv", "image/vnd.djvu"),
+    ("djvu", "image/vnd.djvu"),
+    ("dl", "application/vnd.datalog"),
+    ("dll", "application/x-msdos-program"),
+    ("dls", "audio/dls"),
+    ("dmg", "application/x-apple-diskimage"),
+    ("dmp", "application/vnd.tcpdump.pcap"),
+    ("dms", "text/vnd.dmclientscript"),
+    ("dna", "application/vnd.dna"),
+    ("doc", "application/msword"),
+    ("docjson", "application/vnd.document+json"),
+    ("docm", "application/vnd.ms-word.document.macroenabled.12"),
+    ("docx", "application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
+    ("dor", "model/vnd.gdl"),
+    ("dot", "text/vnd.graphviz"),
+    ("dotm", "application/vnd.ms-word.template.macroenabled.12"),
+    ("dotx", "application/vnd.openxmlformats-officedocument.wordprocessingml.template"),
+    ("dp", "application/vnd.osgi.dp"),
+    ("dpg", "application/vnd.dpgraph"),
+    ("dpgraph", "application/vnd.dpgraph"),
+    ("dpkg", "application/vnd.xmpie.dpkg"),
+    ("dpx", "image/dpx"),
+    ("drle", "image/dicom-rle"),
+    ("dsc", "text/prs.lines.tag"),
+    ("dsm", "application/vnd.desmume.movie"),
+    ("dssc", "application/dssc+der"),
+    ("dtd", "application/xml-dtd"),
+    ("dts", "audio/vnd.dts"),
+    ("dtshd", "audio/vnd.dts.hd"),
+    ("dv", "video/dv"),
+    ("dvb", "video/vnd.dvb.file"),
+    ("dvc", "application/dvcs"),
+    ("dvi", "application/x-dvi"),
+    ("dwd", "application/atsc-dwd+xml"),
+    ("dwf", "model/vnd.dwf"),
+    ("dwg", "image/vnd.dwg"),
+    ("dx", "chemical/x-jcamp-dx"),
+    ("dxf", "image/vnd.dxf"),
+    ("dxp", "application/vnd.spotfire.dxp"),
+    ("dxr", "application/x-director"),
+    ("dzr", "application/vnd.dzr"),
+    ("ebuild", "application/vnd.gentoo.ebuild"),
+    ("ecelp4800", "audio/vnd.nuera.ecelp4800"),
+    ("ecelp7470", "audio/vnd.nuera.ecelp7470"),
+    ("ecelp9600", "audio/vnd.nuera.ecelp9600"),
+    ("ecig", "application/vnd.evolv.ecig.settings"),
+    ("ecigprofile", "application/vnd.evolv.ecig.profile"),
+    ("ecigtheme", "application/vnd.evolv.ecig.theme"),
+    ("eclass", "application/vnd.gentoo.eclass"),
+    ("edm", "application/vnd.novadigm.edm"),
+    ("edx", "application/vnd.novadigm.edx"),
+    ("efi", "application/efi"),
+    ("efif", "application/vnd.picsel"),
+    ("ei6", "application/vnd.pg.osasli"),
+    ("eln", "application/vnd.eln+zip"),
+    ("emb", "chemical/x-embl-dl-nucleotide"),
+    ("embl", "chemical/x-embl-dl-nucleotide"),
+    ("emf", "image/emf"),
+    ("eml", "message/rfc822"),
+    ("emm", "application/vnd.ibm.electronic-media"),
+    ("emma", "application/emma+xml"),
+    ("emotionml", "application/emotionml+xml"),
+    ("ent", "application/xml-external-parsed-entity"),
+    ("entity", "application/vnd.nervana"),
+    ("enw", "audio/evrcnw"),
+    ("eol", "audio/vnd.digital-winds"),
+    ("eot", "application/vnd.ms-fontobject"),
+    ("ep", "application/vnd.bluetooth.ep.oob"),
+    ("eps", "application/postscript"),
+    ("eps2", "application/postscript"),
+    ("eps3", "application/postscript"),
+    ("epsf", "application/postscript"),
+    ("epsi", "application/postscript"),
+    ("epub", "application/epub+zip"),
+    ("erf", "image/x-epson-erf"),
+    ("es", "text/javascript"),
+    ("es3", "application/vnd.eszigno3+xml"),
+    ("esa", "application/vnd.osgi.subsystem"),
+    ("esf", "application/vnd.epson.esf"),
+    ("espass", "application/vnd.espass-espass+zip"),
+    ("et3", "application/vnd.eszigno3+xml"),
+    ("etx", "text/x-setext"),
+    ("evb", "audio/evrcb"),
+    ("evc", "audio/evrc"),
+    ("evw", "audio/evrcwb"),
+    ("exe", "application/x-msdos-program"),
+    ("exi", "application/exi"),
+    ("exp", "application/express"),
+    ("exr", "image/aces"),
+    ("ext", "application/vnd.novadigm.ext"),
+    ("ez", "application/andrew-inset"),
+    ("ez2", "application/vnd.ezpix-album"),
+    ("ez3", "application/vnd.ezpix-package"),
+    ("fb", "application/x-maker"),
+    ("fbdoc", "application/x-maker"),
+    ("fbs", "image/vnd.fastbidsheet"),
+    ("fcdt", "application/vnd.adobe.formscentral.fcdt"),
+    ("fch", "chemical/x-gaussian-checkpoint"),
+    ("fchk", "chemical/x-gaussian-checkpoint"),
+    ("fcs", "application/vnd.isac.fcs"),
+    ("fdf", "application/fdf"),
+    ("fdt", "application/fdt+xml"),
+    ("fe_launch", "application/vnd.denovo.fcselayout-link"),
+    ("fg5", "application/vnd.fujitsu.oasysgp"),
+    ("fig", "application/x-xfig"),
+    ("finf", "application/fastinfoset"),
+    ("fit", "image/fits"),
+    ("fits", "image/fits"),
+    ("fla", "application/vnd.dtg.local.flash"),
+    ("flac", "audio/flac"),
+    ("flb", "application/vnd.ficlab.flb+zip"),
+    ("fli", "video/fli"),
+    ("flo", "application/vnd.micrografx.flo"),
+    ("flt", "text/vnd.ficlab.flt"),
+    ("flv", "video/x-flv"),
+    ("flw", "application/vnd.kde.kivio"),
+    ("flx", "text/vnd.fmi.flexstor"),
+    ("fly", "text/vnd.fly"),
+    ("fm", "application/vnd.framemaker"),
+    ("fo", "application/vnd.software602.filler.form+xml"),
+    ("fpx", "image/vnd.fpx"),
+    ("frame", "application/x-maker"),
+    ("frm", "application/x-maker"),
+    ("fsc", "application/vnd.fsc.weblaunch"),
+    ("fst", "image/vnd.fst"),
+    ("ftc", "application/vnd.fluxtime.clip"),
+    ("fti", "application/vnd.anser-web-funds-transfer-initiation"),
+    ("fts", "image/fits"),
+    ("fvt", "video/vnd.fvt"),
+    ("fxp", "application/vnd.adobe.fxp"),
+    ("fxpl", "application/vnd.adobe.fxp"),
+    ("fzs", "application/vnd.fuzzysheet"),
+    ("g2w", "application/vnd.geoplan"),
+    ("g3w", "application/vnd.geospace"),
+    ("gac", "application/vnd.groove-account"),
+    ("gal", "chemical/x-gaussian-log"),
+    ("gam", "chemical/x-gamess-input"),
+    ("gamin", "chemical/x-gamess-input"),
+    ("gan", "application/x-ganttproject"),
+    ("gau", "chemical/x-gaussian-input"),
+    ("gbr", "application/rpki-ghostbusters"),
+    ("gcd", "text/x-pcs-gcd"),
+    ("gcf", "application/x-graphing-calculator"),
+    ("gcg", "chemical/x-gcg8-sequence"),
+    ("gdl", "model/vnd.gdl"),
+    ("gdz", "application/vnd.familysearch.gedcom+zip"),
+    ("ged", "text/vnd.familysearch.gedcom"),
+    ("gen", "chemical/x-genbank"),
+    ("genozip", "application/vnd.genozip"),
+    ("geo", "application/vnd.dynageo"),
+    ("geojson", "application/geo+json"),
+    ("gex", "application/vnd.geometry-explorer"),
+    ("gf", "application/x-tex-gf"),
+    ("gff3", "text/gff3"),
+    ("ggb", "application/vnd.geogebra.file"),
+    ("ggs", "application/vnd.geogebra.slides"),
+    ("ggt", "application/vnd.geogebra.tool"),
+    ("ghf", "application/vnd.groove-help"),
+    ("gif", "image/gif"),
+    ("gim", "application/vnd.groove-identity-message"),
+    ("gjc", "chemical/x-gaussian-input"),
+    ("gjf", "chemical/x-gaussian-input"),
+    ("gl", "video/gl"),
+    ("glb", "model/gltf-binary"),
+    ("glbin", "application/gltf-buffer"),
+    ("glbuf", "application/gltf-buffer"),
+    ("gltf", "model/gltf+json"),
+    ("gml", "application/gml+xml"),
+    ("gnumeric", "application/x-gnumeric"),
+    ("gph", "application/vnd.flographit"),
+    ("gpkg", "application/geopackage+sqlite3"),
+    ("gpkg.tar", "application/vnd.gentoo.gpkg"),
+    ("gpt", "chemical/x-mopac-graph"),
+    ("gqf", "application/vnd.grafeq"),
+    ("gqs", "application/vnd.grafeq"),
+    ("gram", "application/srgs"),
+    ("grd", "application/vnd.gentics.grd+json"),
+    ("gre", "application/vnd.geometry-explorer"),
+    ("grv", "application/vnd.groove-injector"),
+    ("grxml", "application/srgs+xml"),
+    ("gsf", "application/x-font"),
+    ("gsheet", "application/urc-grpsheet+xml"),
+    ("gsm", "audio/x-gsm"),
+    ("gtar", "application/x-gtar"),
+    ("gtm", "application/vnd.groove-tool-message"),
+    ("gtw", "model/vnd.gtw"),
+    ("gv", "text/vnd.graphviz"),
+    ("gxt", "application/vnd.geonext"),
+    ("gz", "application/gzip"),
+    ("h", "text/x-chdr"),
+    ("h++", "text/x-c++hdr"),
+    ("hal", "application/vnd.hal+xml"),
+    ("hans", "text/vnd.hans"),
+    ("hbc", "application/vnd.hbci"),
+    ("hbci", "application/vnd.hbci"),
+    ("hdf", "application/x-hdf"),
+    ("hdr", "image/vnd.radiance"),
+    ("hdt", "application/vnd.hdt"),
+    ("heic", "image/heic"),
+    ("heics", "image/heic-sequence"),
+    ("heif", "image/heif"),
+    ("heifs", "image/heif-sequence"),
+    ("hej2", "image/hej2k"),
+    ("held", "application/atsc-held+xml"),
+    ("hgl", "text/vnd.hgl"),
+    ("hh", "text/x-c++hdr"),
+    ("hif", "image/avif"),
+    ("hin", "chemical/x-hin"),
+    ("hpgl", "application/vnd.hp-hpgl"),
+    ("hpi", "application/vnd.hp-hpid"),
+    ("hpid", "application/vnd.hp-hpid"),
+    ("hpp", "text/x-c++hdr"),
+    ("hps", "application/vnd.hp-hps"),
+    ("hpub", "application/prs.hpub+zip"),
+    ("hqx", "application/mac-binhex40"),
+    ("hs", "text/x-haskell"),
+    ("hsj2", "image/hsj2"),
+    ("hta", "application/hta"),
+    ("htc", "text/x-component"),
+    ("htke", "application/vnd.kenameaapp"),
+    ("htm", "text/html"),
+    ("html", "text/html"),
+    ("hvd", "application/vnd.yamaha.hv-dic"),
+    ("hvp", "application/vnd.yamaha.hv-voice"),
+    ("hvs", "application/vnd.yamaha.hv-script"),
+    ("hwp", "application/x-hwp"),
+    ("hxx", "text/x-c++hdr"),
+    ("i2g", "application/vnd.intergeo"),
+    ("ic0", "application/vnd.commerce-battelle"),
+    ("ic1", "application/vnd.commerce-battelle"),
+    ("ic2", "application/vnd.commerce-battelle"),
+    ("ic3", "application/vnd.commerce-battelle"),
+    ("ic4", "application/vnd.commerce-battelle"),
+    ("ic5", "application/vnd.commerce-battelle"),
+    ("ic6", "application/vnd.commerce-battelle"),
+    ("ic7", "application/vnd.commerce-battelle"),
+    ("ic8", "application/vnd.commerce-battelle"),
+    ("ica", "application/x-ica"),
+    ("icc", "application/vnd.iccprofile"),
+    ("icd", "application/vnd.commerce-battelle"),
+    ("icf", "application/vnd.commerce-battelle"),
+    ("icm", "application/vnd.iccprofile"),
+    ("ico", "image/vnd.microsoft.icon"),
+    ("ics", "text/calendar"),
+    ("ief", "image/ief"),
+    ("ifb", "text/calendar"),
+    ("ifc", "application/p21"),
+    ("ifm", "application/vnd.shana.informed.formdata"),
+    ("iges", "model/iges"),
+    ("igl", "application/vnd.igloader"),
+    ("igm", "application/vnd.insors.igm"),
+    ("ign", "application/vnd.coreos.ignition+json"),
+    ("ignition", "application/vnd.coreos.ignition+json"),
+    ("igs", "model/iges"),
+    ("igx", "application/vnd.micrografx.igx"),
+    ("iif", "application/vnd.shana.informed.interchange"),
+    ("iii", "application/x-iphone"),
+    ("imf", "application/vnd.imagemeter.folder+zip"),
+    ("imgcal", "application/vnd.3lightssoftware.imagescal"),
+    ("imi", "application/vnd.imagemeter.image+zip"),
+    ("imp", "application/vnd.accpac.simply.imp"),
+    ("ims", "application/vnd.ms-ims"),
+    ("imscc", "application/vnd.ims.imsccv1p1"),
+    ("info", "application/x-info"),
+    ("ink", "application/inkml+xml"),
+    ("inkml", "application/inkml+xml"),
+    ("inp", "chemical/x-gamess-input"),
+    ("ins", "application/x-internet-signup"),
+    ("iota", "application/vnd.astraea-software.iota"),
+    ("ipfix", "application/ipfix"),
+    ("ipk", "application/vnd.shana.informed.package"),
+    ("irm", "application/vnd.ibm.rights-management"),
+    ("irp", "application/vnd.irepository.package+xml"),
+    ("ism", "model/vnd.gdl"),
+    ("iso", "application/x-iso9660-image"),
+    ("isp", "application/x-internet-signup"),
+    ("ist", "chemical/x-isostar"),
+    ("istc", "application/vnd.veryant.thin"),
+    ("istr", "chemical/x-isostar"),
+    ("isws", "application/vnd.veryant.thin"),
+    ("itp", "application/vnd.shana.informed.formtemplate"),
+    ("its", "application/its+xml"),
+    ("ivp", "application/vnd.immervision-ivp"),
+    ("ivu", "application/vnd.immervision-ivu"),
+    ("jad", "text/vnd.sun.j2me.app-descriptor"),
+    ("jam", "application/vnd.jam"),
+    ("jar", "application/java-archive"),
+    ("java", "text/x-java"),
+    ("jdx", "chemical/x-jcamp-dx"),
+    ("jfif", "image/jpeg"),
+    ("jhc", "image/jphc"),
+    ("jisp", "application/vnd.jisp"),
+    ("jls", "image/jls"),
+    ("jlt", "application/vnd.hp-jlyt"),
+    ("jmz", "application/x-jmol"),
+    ("jng", "image/x-jng"),
+    ("jnlp", "application/x-java-jnlp-file"),
+    ("joda", "application/vnd.joost.joda-archive"),
+    ("jp2", "image/jp2"),
+    ("jpe", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("jpf", "image/jpx"),
+    ("jpg", "image/jpeg"),
+    ("jpg2", "image/jp2"),
+    ("jpgm", "image/jpm"),
+    ("jph", "image/jph"),
+    ("jphc", "image/jphc"),
+    ("jpm", "image/jpm"),
+    ("jpx", "image/jpx"),
+    ("jrd", "application/jrd+json"),
+    ("js", "text/javascript"),
+    ("json", "application/json"),
+    ("json-patch", "application/json-patch+json"),
+    ("jsonld", "application/ld+json"),
+    ("jsontd", "application/td+json"),
+    ("jsontm", "application/tm+json"),
+    ("jt", "model/jt"),
+    ("jtd", "text/vnd.esmertec.theme-descriptor"),
+    ("jxl", "image/jxl"),
+    ("jxr", "image/jxr"),
+    ("jxra", "image/jxra"),
+    ("jxrs", "image/jxrs"),
+    ("jxs", "image/jxs"),
+    ("jxsc", "image/jxsc"),
+    ("jxsi", "image/jxsi"),
+    ("jxss", "image/jxss"),
+    ("karbon", "application/vnd.kde.karbon"),
+    ("kcm", "application/vnd.nervana"),
+    ("key", "application/pgp-keys"),
+    ("keynote", "application/vnd.apple.keynote"),
+    ("kfo", "application/vnd.kde.kformula"),
+    ("kia", "application/vnd.kidspiration"),
+    ("kil", "application/x-killustrator"),
+    ("kin", "chemical/x-kinemage"),
+    ("kml", "application/vnd.google-earth.kml+xml"),
+    ("kmz", "application/vnd.google-earth.kmz"),
+    ("kne", "application/vnd.kinar"),
+    ("knp", "application/vnd.kinar"),
+    ("kom", "application/vnd.hbci"),
+    ("kon", "application/vnd.kde.kontour"),
+    ("koz", "audio/vnd.audiokoz"),
+    ("kpr", "application/vnd.kde.kpresenter"),
+    ("kpt", "application/vnd.kde.kpresenter"),
+    ("ksp", "application/vnd.kde.kspread"),
+    ("ktr", "application/vnd.kahootz"),
+    ("ktx", "image/ktx"),
+    ("ktx2", "image/ktx2"),
+    ("ktz", "application/vnd.kahootz"),
+    ("kwd", "application/vnd.kde.kword"),
+    ("kwt", "application/vnd.kde.kword"),
+    ("l16", "audio/l16"),
+    ("las", "application/vnd.las"),
+    ("lasjson", "application/vnd.las.las+json"),
+    ("lasxml", "application/vnd.las.las+xml"),
+    ("latex", "application/x-latex"),
+    ("lbc", "audio/ilbc"),
+    ("lbd", "application/vnd.llamagraphics.life-balance.desktop"),
+    ("lbe", "application/vnd.llamagraphics.life-balance.exchange+xml"),
+    ("lca", "application/vnd.logipipe.circuit+zip"),
+    ("lcs", "application/vnd.logipipe.circuit+zip"),
+    ("le", "application/vnd.bluetooth.le.oob"),
+    ("les", "application/vnd.hhe.lesson-player"),
+    ("lgr", "application/lgr+xml"),
+    ("lha", "application/x-lha"),
+    ("lhs", "text/x-literate-haskell"),
+    ("lhzd", "application/vnd.belightsoft.lhzd+zip"),
+    ("lhzl", "application/vnd.belightsoft.lhzl+zip"),
+    ("lin", "application/bbolin"),
+    ("line", "application/vnd.nebumind.line"),
+    ("link66", "application/vnd.route66.link66+xml"),
+    ("list3820", "application/vnd.afpc.modca"),
+    ("listafp", "application/vnd.afpc.modca"),
+    ("lmp", "model/vnd.gdl"),
+    ("loas", "audio/usac"),
+    ("loom", "application/vnd.loom"),
+    ("lostsyncxml", "application/lostsync+xml"),
+    ("lostxml", "application/lost+xml"),
+    ("lpf", "application/lpf+zip"),
+    ("lrm", "application/vnd.ms-lrm"),
+    ("lsf", "video/x-la-asf"),
+    ("lsx", "video/x-la-asf"),
+    ("ltx", "text/x-tex"),
+    ("lvp", "audio/vnd.lucent.voice"),
+    ("lwp", "application/vnd.lotus-wordpro"),
+    ("lxf", "application/lxf"),
+    ("ly", "text/x-lilypond"),
+    ("lyx", "application/x-lyx"),
+    ("lzh", "application/x-lzh"),
+    ("lzx", "application/x-lzx"),
+    ("m", "application/vnd.wolfram.mathematica.package"),
+    ("m1v", "video/mpeg"),
+    ("m21", "application/mp21"),
+    ("m2v", "video/mpeg"),
+    ("m3g", "application/m3g"),
+    ("m3u", "audio/mpegurl"),
+    ("m3u8", "application/vnd.apple.mpegurl"),
+    ("m4a", "audio/mp4"),
+    ("m4s", "video/iso.segment"),
+    ("m4u", "video/vnd.mpegurl"),
+    ("m4v", "video/mp4"),
+    ("ma", "application/mathematica"),
+    ("mads", "application/mads+xml"),
+    ("maei", "application/mmt-aei+xml"),
+    ("mag", "application/vnd.ecowin.chart"),
+    ("mail", "message/rfc822"),
+    ("maker", "application/x-maker"),
+    ("man", "application/x-troff-man"),
+    ("manifest", "text/cache-manifest"),
+    ("map", "application/json"),
+    ("markdown", "text/markdown"),
+    ("mb", "application/mathematica"),
+    ("mbk", "application/vnd.mobius.mbk"),
+    ("mbox", "application/mbox"),
+    ("mc1", "application/vnd.medcalcdata"),
+    ("mc2", "text/vnd.senx.warpscript"),
+    ("mcd", "application/vnd.mcd"),
+    ("mcif", "chemical/x-mmcif"),
+    ("mcm", "chemical/x-macmolecule"),
+    ("md", "text/markdown"),
+    ("mdb", "application/msaccess"),
+    ("mdc", "application/vnd.marlin.drm.mdcf"),
+    ("mdi", "image/vnd.ms-modi"),
+    ("me", "application/x-troff-me"),
+    ("mesh", "model/mesh"),
+    ("meta4", "application/metalink4+xml"),
+    ("mets", "application/mets+xml"),
+    ("mf4", "application/mf4"),
+    ("mfm", "application/vnd.mfmp"),
+    ("mft", "application/rpki-manifest"),
+    ("mgp", "application/vnd.osgeo.mapguide.package"),
+    ("mgz", "application/vnd.proteus.magazine"),
+    ("mhas", "audio/mhas"),
+    ("mid", "audio/sp-midi"),
+    ("mif", "application/vnd.mif"),
+    ("miz", "text/mizar"),
+    ("mj2", "video/mj2"),
+    ("mjp2", "video/mj2"),
+    ("mjs", "text/javascript"),
+    ("mkv", "video/x-matroska"),
+    ("ml2", "application/vnd.sybyl.mol2"),
+    ("mlp", "audio/vnd.dolby.mlp"),
+    ("mm", "application/x-freemind"),
+    ("mmd", "application/vnd.chipnuts.karaoke-mmd"),
+    ("mmdb", "application/vnd.maxmind.maxmind-db"),
+    ("mmf", "application/vnd.smaf"),
+    ("mml", "application/mathml+xml"),
+    ("mmod", "chemical/x-macromodel-input"),
+    ("mmr", "image/vnd.fujixerox.edmics-mmr"),
+    ("mng", "video/x-mng"),
+    ("moc", "text/x-moc"),
+    ("mod", "application/xml-dtd"),
+    ("model-inter", "application/vnd.vd-study"),
+    ("mods", "application/mods+xml"),
+    ("mol", "chemical/x-mdl-molfile"),
+    ("mol2", "application/vnd.sybyl.mol2"),
+    ("moml", "model/vnd.moml+xml"),
+    ("moo", "chemical/x-mopac-out"),
+    ("mop", "chemical/x-mopac-input"),
+    ("mopcrt", "chemical/x-mopac-input"),
+    ("mov", "video/quicktime"),
+    ("movie", "video/x-sgi-movie"),
+    ("mp1", "audio/mpeg"),
+    ("mp2", "audio/mpeg"),
+    ("mp21", "application/mp21"),
+    ("mp3", "audio/mpeg"),
+    ("mp4", "video/mp4"),
+    ("mpc", "application/vnd.mophun.certificate"),
+    ("mpd", "application/dash+xml"),
+    ("mpdd", "application/dashdelta"),
+    ("mpe", "video/mpeg"),
+    ("mpeg", "video/mpeg"),
+    ("mpega", "audio/mpeg"),
+    ("mpf", "text/vnd.ms-mediapackage"),
+    ("mpg", "video/mpeg"),
+    ("mpg4", "video/mp4"),
+    ("mpga", "audio/mpeg"),
+    ("mph", "application/x-comsol"),
+    ("mpkg", "application/vnd.apple.installer+xml"),
+    ("mpm", "application/vnd.blueice.multipass"),
+    ("mpn", "application/vnd.mophun.application"),
+    ("mpp", "application/vnd.ms-project"),
+    ("mpt", "application/vnd.ms-project"),
+    ("mpv", "video/x-matroska"),
+    ("mpw", "application/vnd.exstream-empower+zip"),
+    ("mpy", "application/vnd.ibm.minipay"),
+    ("mqy", "application/vnd.mobius.mqy"),
+    ("mrc", "application/marc"),
+    ("mrcx", "application/marcxml+xml"),
+    ("ms", "application/x-troff-ms"),
+    ("msa", "application/vnd.msa-disk-image"),
+    ("msd", "application/vnd.fdsn.mseed"),
+    ("mseed", "application/vnd.fdsn.mseed"),
+    ("mseq", "application/vnd.mseq"),
+    ("msf", "application/vnd.epson.msf"),
+    ("msh", "model/mesh"),
+    ("msi", "application/x-msi"),
+    ("msl", "application/vnd.mobius.msl"),
+    ("msm", "model/vnd.gdl"),
+    ("msp", "application/octet-stream"),
+    ("msty", "application/vnd.muvee.style"),
+    ("msu", "application/octet-stream"),
+    ("mtl", "model/mtl"),
+    ("mts", "model/vnd.mts"),
+    ("multitrack", "audio/vnd.presonus.multitrack"),
+    ("mus", "application/vnd.musician"),
+    ("musd", "application/mmt-usd+xml"),
+    ("mvb", "chemical/x-mopac-vib"),
+    ("mvt", "application/vnd.mapbox-vector-tile"),
+    ("mwc", "application/vnd.dpgraph"),
+    ("mwf", "application/vnd.mfer"),
+    ("mxf", "application/mxf"),
+    ("mxi", "application/vnd.vd-study"),
+    ("mxl", "application/vnd.recordare.musicxml"),
+    ("mxmf", "audio/mobile-xmf"),
+    ("mxml", "application/xv+xml"),
+    ("mxs", "application/vnd.triscape.mxs"),
+    ("mxu", "video/vnd.mpegurl"),
+    ("n3", "text/n3"),
+    ("nb", "application/vnd.wolfram.mathematica"),
+    ("nbp", "application/vnd.wolfram.player"),
+    ("nc", "application/x-netcdf"),
+    ("ndc", "application/vnd.osa.netdeploy"),
+    ("ndl", "application/vnd.lotus-notes"),
+    ("nds", "application/vnd.nintendo.nitro.rom"),
+    ("nebul", "application/vnd.nebumind.line"),
+    ("nef", "image/x-nikon-nef"),
+    ("ngdat", "application/vnd.nokia.n-gage.data"),
+    ("nim", "video/vnd.nokia.interleaved-multimedia"),
+    ("nimn", "application/vnd.nimn"),
+    ("nitf", "application/vnd.nitf"),
+    ("nlu", "application/vnd.neurolanguage.nlu"),
+    ("nml", "application/vnd.enliven"),
+    ("nnd", "application/vnd.noblenet-directory"),
+    ("nns", "application/vnd.noblenet-sealer"),
+    ("nnw", "application/vnd.noblenet-web"),
+    ("notebook", "application/vnd.smart.notebook"),
+    ("nq", "application/n-quads"),
+    ("ns2", "application/vnd.lotus-notes"),
+    ("ns3", "application/vnd.lotus-notes"),
+    ("ns4", "application/vnd.lotus-notes"),
+    ("nsf", "application/vnd.lotus-notes"),
+    ("nsg", "application/vnd.lotus-notes"),
+    ("nsh", "application/vnd.lotus-notes"),
+    ("nt", "application/n-triples"),
+    ("ntf", "application/vnd.lotus-notes"),
+    ("numbers", "application/vnd.apple.numbers"),
+    ("nwc", "application/x-nwc"),
+    ("o", "application/x-object"),
+    ("oa2", "application/vnd.fujitsu.oasys2"),
+    ("oa3", "application/vnd.fujitsu.oasys3"),
+    ("oas", "application/vnd.fujitsu.oasys"),
+    ("obg", "application/vnd.openblox.game-binary"),
+    ("obgx", "application/vnd.openblox.game+xml"),
+    ("obj", "model/obj"),
+    ("oda", "application/oda"),
+    ("odb", "application/vnd.oasis.opendocument.base"),
+    ("odc", "application/vnd.oasis.opendocument.chart"),
+    ("odd", "application/tei+xml"),
+    ("odf", "application/vnd.oasis.opendocument.formula"),
+    ("odg", "application/vnd.oasis.opendocument.graphics"),
+    ("odi", "application/vnd.oasis.opendocument.image"),
+    ("odm", "application/vnd.oasis.opendocument.text-master"),
+    ("odp", "application/vnd.oasis.opendocument.presentation"),
+    ("ods", "application/vnd.oasis.opendocument.spreadsheet"),
+    ("odt", "application/vnd.oasis.opendocument.text"),
+    ("odx", "application/odx"),
+    ("oeb", "application/vnd.openeye.oeb"),
+    ("oga", "audio/ogg"),
+    ("ogex", "model/vnd.opengex"),
+    ("ogg", "audio/ogg"),
+    ("ogv", "video/ogg"),
+    ("ogx", "application/ogg"),
+    ("old", "application/x-trash"),
+    ("omg", "audio/atrac3"),
+    ("one", "application/onenote"),
+    ("onepkg", "application/onenote"),
+    ("onetmp", "application/onenote"),
+    ("onetoc2", "application/onenote"),
+    ("opf", "application/oebps-package+xml"),
+    ("oprc", "application/vnd.palm"),
+    ("opus", "audio/ogg"),
+    ("or2", "application/vnd.lotus-organizer"),
+    ("or3", "application/vnd.lotus-organizer"),
+    ("orc", "audio/csound"),
+    ("orf", "image/x-olympus-orf"),
+    ("org", "application/vnd.lotus-organizer"),
+    ("orq", "application/ocsp-request"),
+    ("ors", "application/ocsp-response"),
+    ("osf", "application/vnd.yamaha.openscoreformat"),
+    ("osm", "application/vnd.openstreetmap.data+xml"),
+    ("ota", "application/vnd.android.ota"),
+    ("otc", "application/vnd.oasis.opendocument.chart-template"),
+    ("otf", "font/otf"),
+    ("otg", "application/vnd.oasis.opendocument.graphics-template"),
+    ("oth", "application/vnd.oasis.opendocument.text-web"),
+    ("oti", "application/vnd.oasis.opendocument.image-template"),
+    ("otp", "application/vnd.oasis.opendocument.presentation-template"),
+    ("ots", "application/vnd.oasis.opendocument.spreadsheet-template"),
+    ("ott", "application/vnd.oasis.opendocument.text-template"),
+    ("ovl", "application/vnd.afpc.modca-overlay"),
+    ("oxlicg", "application/vnd.oxli.countgraph"),
+    ("oxps", "application/oxps"),
+    ("oxt", "application/vnd.openofficeorg.extension"),
+    ("oza", "application/x-oz-application"),
+    ("p", "text/x-pascal"),
+    ("p10", "application/pkcs10"),
+    ("p12", "application/pkcs12"),
+    ("p21", "application/p21"),
+    ("p2p", "application/vnd.wfa.p2p"),
+    ("p7c", "application/pkcs7-mime"),
+    ("p7m", "application/pkcs7-mime"),
+    ("p7r", "application/x-pkcs7-certreqresp"),
+    ("p7s", "application/pkcs7-signature"),
+    ("p7z", "application/pkcs7-mime"),
+    ("p8", "application/pkcs8"),
+    ("p8e", "application/pkcs8-encrypted"),
+    ("pac", "application/x-ns-proxy-autoconfig"),
+    ("package", "application/vnd.autopackage"),
+    ("pages", "application/vnd.apple.pages"),
+    ("pas", "text/x-pascal"),
+    ("pat", "image/x-coreldrawpattern"),
+    ("patch", "text/x-diff"),
+    ("paw", "application/vnd.pawaafile"),
+    ("pbd", "application/vnd.powerbuilder6"),
+    ("pbm", "image/x-portable-bitmap"),
+    ("pcap", "application/vnd.tcpdump.pcap"),
+    ("pcf", "application/x-font-pcf"),
+    ("pcf.z", "application/x-font-pcf"),
+    ("pcl", "application/vnd.hp-pcl"),
+    ("pcx", "image/vnd.zbrush.pcx"),
+    ("pdb", "application/vnd.palm"),
+    ("pdf", "application/pdf"),
+    ("pdx", "application/pdx"),
+    ("pem", "application/pem-certificate-chain"),
+    ("pfa", "application/x-font"),
+    ("pfb", "application/x-font"),
+    ("pfr", "application/font-tdpfr"),
+    ("pfx", "application/pkcs12"),
+    ("pgb", "image/vnd.globalgraphics.pgb"),
+    ("pgm", "image/x-portable-graymap"),
+    ("pgn", "application/vnd.chess-pgn"),
+    ("pgp", "application/pgp-encrypted"),
+    ("pil", "application/vnd.piaccess.application-licence"),
+    ("pk", "application/x-tex-pk"),
+    ("pkd", "application/vnd.hbci"),
+    ("pkg", "application/vnd.apple.installer+xml"),
+    ("pki", "application/pkixcmp"),
+    ("pkipath", "application/pkix-pkipath"),
+    ("pl", "text/x-perl"),
+    ("plb", "application/vnd.3gpp.pic-bw-large"),
+    ("plc", "application/vnd.mobius.plc"),
+    ("plf", "application/vnd.pocketlearn"),
+    ("plj", "audio/vnd.everad.plj"),
+    ("plp", "application/vnd.panoply"),
+    ("pls", "audio/x-scpls"),
+    ("pm", "text/x-perl"),
+    ("pml", "application/vnd.ctc-posml"),
+    ("png", "image/png"),
+    ("pnm", "image/x-portable-anymap"),
+    ("portpkg", "application/vnd.macports.portpkg"),
+    ("pot", "text/plain"),
+    ("potm", "application/vnd.ms-powerpoint.template.macroenabled.12"),
+    ("potx", "application/vnd.openxmlformats-officedocument.presentationml.template"),
+    ("ppam", "application/vnd.ms-powerpoint.addin.macroenabled.12"),
+    ("ppd", "application/vnd.cups-ppd"),
+    ("ppkg", "application/vnd.xmpie.ppkg"),
+    ("ppm", "image/x-portable-pixmap"),
+    ("pps", "application/vnd.ms-powerpoint"),
+    ("ppsm", "application/vnd.ms-powerpoint.slideshow.macroenabled.12"),
+    ("ppsx", "application/vnd.openxmlformats-officedocument.presentationml.slideshow"),
+    ("ppt", "application/vnd.ms-powerpoint"),
+    ("pptm", "application/vnd.ms-powerpoint.presentation.macroenabled.12"),
+    ("ppttc", "application/vnd.think-cell.ppttc+json"),
+    ("pptx", "application/vnd.openxmlformats-officedocument.presentationml.presentation"),
+    ("pqa", "application/vnd.palm"),
+    ("prc", "model/prc"),
+    ("pre", "application/vnd.lotus-freelance"),
+    ("preminet", "application/vnd.preminet"),
+    ("prf", "application/pics-rules"),
+    ("provn", "text/provenance-notation"),
+    ("provx", "application/provenance+xml"),
+    ("prt", "chemical/x-ncbi-asn1-ascii"),
+    ("prz", "application/vnd.lotus-freelance"),
+    ("ps", "application/postscript"),
+    ("psb", "application/vnd.3gpp.pic-bw-small"),
+    ("psd", "image/vnd.adobe.photoshop"),
+    ("pseg3820", "application/vnd.afpc.modca"),
+    ("psfs", "application/vnd.psfs"),
+    ("psg", "application/vnd.afpc.modca-pagesegment"),
+    ("psid", "audio/prs.sid"),
+    ("pskcxml", "application/pskc+xml"),
+    ("pt", "application/vnd.snesdev-page-table"),
+    ("pti", "image/prs.pti"),
+    ("ptid", "application/vnd.pvi.ptid1"),
+    ("ptrom", "application/vnd.snesdev-page-table"),
+    ("pub", "application/vnd.exstream-package"),
+    ("pvb", "application/vnd.3gpp.pic-bw-var"),
+    ("pwn", "application/vnd.3m.post-it-notes"),
+    ("py", "text/x-python"),
+    ("pya", "audio/vnd.ms-playready.media.pya"),
+    ("pyc", "application/x-python-code"),
+    ("pyo", "application/x-python-code"),
+    ("pyox", "model/vnd.pytha.pyox"),
+    ("pyv", "video/vnd.ms-playready.media.pyv"),
+    ("qam", "application/vnd.epson.quickanime"),
+    ("qbo", "application/vnd.intu.qbo"),
+    ("qca", "application/vnd.ericsson.quickcall"),
+    ("qcall", "application/vnd.ericsson.quickcall"),
+    ("qcp", "audio/evrc-qcp"),
+    ("qfx", "application/vnd.intu.qfx"),
+    ("qgs", "application/x-qgis"),
+    ("qps", "application/vnd.publishare-delta-tree"),
+    ("qt", "video/quicktime"),
+    ("qtl", "application/x-quicktimeplayer"),
+    ("quiz", "application/vnd.quobject-quoxdocument"),
+    ("quox", "application/vnd.quobject-quoxdocument"),
+    ("qvd", "application/vnd.theqvd"),
+    ("qwd", "application/vnd.quark.quarkxpress"),
+    ("qwt", "application/vnd.quark.quarkxpress"),
+    ("qxb", "application/vnd.quark.quarkxpress"),
+    ("qxd", "application/vnd.quark.quarkxpress"),
+    ("qxl", "application/vnd.quark.quarkxpress"),
+    ("qxt", "application/vnd.quark.quarkxpress"),
+    ("ra", "audio/x-pn-realaudio"),
+    ("ram", "audio/x-pn-realaudio"),
+    ("rapd", "application/route-apd+xml"),
+    ("rar", "application/vnd.rar"),
+    ("ras", "image/x-cmu-raster"),
+    ("rb", "application/x-ruby"),
+    ("rcprofile", "application/vnd.ipunplugged.rcprofile"),
+    ("rct", "application/prs.nprend"),
+    ("rd", "chemical/x-mdl-rdfile"),
+    ("rdf", "application/rdf+xml"),
+    ("rdf-crypt", "application/prs.rdf-xml-crypt"),
+    ("rdp", "application/x-rdp"),
+    ("rdz", "application/vnd.data-vision.rdz"),
+    ("relo", "application/p2p-overlay+xml"),
+    ("reload", "application/vnd.resilient.logic"),
+    ("rep", "application/vnd.businessobjects"),
+    ("request", "application/vnd.nervana"),
+    ("rfcxml", "application/rfc+xml"),
+    ("rgb", "image/x-rgb"),
+    ("rgbe", "image/vnd.radiance"),
+    ("rif", "application/reginfo+xml"),
+    ("rip", "audio/vnd.rip"),
+    ("rl", "application/resource-lists+xml"),
+    ("rlc", "image/vnd.fujixerox.edmics-rlc"),
+    ("rld", "application/resource-lists-diff+xml"),
+    ("rlm", "application/vnd.resilient.logic"),
+    ("rm", "audio/x-pn-realaudio"),
+    ("rms", "application/vnd.jcp.javame.midlet-rms"),
+    ("rnc", "application/relax-ng-compact-syntax"),
+    ("rnd", "application/prs.nprend"),
+    ("roa", "application/rpki-roa"),
+    ("roff", "text/troff"),
+    ("ros", "chemical/x-rosdal"),
+    ("rp9", "application/vnd.cloanto.rp9"),
+    ("rpm", "application/x-redhat-package-manager"),
+    ("rpss", "application/vnd.nokia.radio-presets"),
+    ("rpst", "application/vnd.nokia.radio-preset"),
+    ("rq", "application/sparql-query"),
+    ("rs", "application/rls-services+xml"),
+    ("rsat", "application/atsc-rsat+xml"),
+    ("rsheet", "application/urc-ressheet+xml"),
+    ("rsm", "model/vnd.gdl"),
+    ("rss", "application/x-rss+xml"),
+    ("rst", "text/prs.fallenstein.rst"),
+    ("rtf", "application/rtf"),
+    ("rusd", "application/route-usd+xml"),
+    ("rxn", "chemical/x-mdl-rxnfile"),
+    ("rxt", "application/vnd.medicalholodeck.recordxr"),
+    ("s11", "video/vnd.sealed.mpeg1"),
+    ("s14", "video/vnd.sealed.mpeg4"),
+    ("s1a", "application/vnd.sealedmedia.softseal.pdf"),
+    ("s1e", "application/vnd.sealed.xls"),
+    ("s1g", "image/vnd.sealedmedia.softseal.gif"),
+    ("s1h", "application/vnd.sealedmedia.softseal.html"),
+    ("s1j", "image/vnd.sealedmedia.softseal.jpg"),
+    ("s1m", "audio/vnd.sealedmedia.softseal.mpeg"),
+    ("s1n", "image/vnd.sealed.png"),
+    ("s1p", "application/vnd.sealed.ppt"),
+    ("s1q", "video/vnd.sealedmedia.softseal.mov"),
+    ("s1w", "application/vnd.sealed.doc"),
+    ("s3df", "application/vnd.sealed.3df"),
+    ("sac", "application/tamp-sequence-adjust-confirm"),
+    ("saf", "application/vnd.yamaha.smaf-audio"),
+    ("sam", "application/vnd.lotus-wordpro"),
+    ("sar", "application/vnd.sar"),
+    ("sarif", "application/sarif+json"),
+    ("sarif-external-properties", "application/sarif-external-properties+json"),
+    ("sarif-external-properties.json", "application/sarif-external-properties+json"),
+    ("sarif.json", "application/sarif+json"),
+    ("sc", "application/vnd.ibm.secure-container"),
+    ("scala", "text/x-scala"),
+    ("scd", "application/vnd.scribus"),
+    ("sce", "application/vnd.etsi.asic-e+zip"),
+    ("sci", "application/x-scilab"),
+    ("scim", "application/scim+json"),
+    ("scl", "application/vnd.sycle+xml"),
+    ("scld", "application/vnd.doremir.scorecloud-binary-document"),
+    ("scm", "application/vnd.lotus-screencam"),
+    ("sco", "audio/csound"),
+    ("scq", "application/scvp-cv-request"),
+    ("scr", "application/x-silverlight"),
+    ("scs", "application/scvp-cv-response"),
+    ("scsf", "application/vnd.sealed.csf"),
+    ("sd", "chemical/x-mdl-sdfile"),
+    ("sd2", "audio/x-sd2"),
+    ("sda", "application/vnd.stardivision.draw"),
+    ("sdc", "application/vnd.stardivision.calc"),
+    ("sdd", "application/vnd.stardivision.impress"),
+    ("sdf", "application/vnd.kinar"),
+    ("sdkd", "application/vnd.solent.sdkm+xml"),
+    ("sdkm", "application/vnd.solent.sdkm+xml"),
+    ("sdo", "application/vnd.sealed.doc"),
+    ("sdoc", "application/vnd.sealed.doc"),
+    ("sdp", "application/sdp"),
+    ("sds", "application/vnd.stardivision.chart"),
+    ("sdw", "application/vnd.stardivision.writer"),
+    ("see", "application/vnd.seemail"),
+    ("seed", "application/vnd.fdsn.seed"),
+    ("sem", "application/vnd.sealed.eml"),
+    ("sema", "application/vnd.sema"),
+    ("semd", "application/vnd.semd"),
+    ("semf", "application/vnd.semf"),
+    ("seml", "application/vnd.sealed.eml"),
+    ("senml", "application/senml+json"),
+    ("senml-etchc", "application/senml-etch+cbor"),
+    ("senml-etchj", "application/senml-etch+json"),
+    ("senmlc", "application/senml+cbor"),
+    ("senmle", "application/senml-exi"),
+    ("senmlx", "application/senml+xml"),
+    ("sensml", "application/sensml+json"),
+    ("sensmlc", "application/sensml+cbor"),
+    ("sensmle", "application/sensml-exi"),
+    ("sensmlx", "application/sensml+xml"),
+    ("ser", "application/java-serialized-object"),
+    ("sfc", "application/vnd.nintendo.snes.rom"),
+    ("sfd", "application/vnd.font-fontforge-sfd"),
+    ("sfd-hdstx", "application/vnd.hydrostatix.sof-data"),
+    ("sfs", "application/vnd.spotfire.sfs"),
+    ("sfv", "text/x-sfv"),
+    ("sgf", "application/x-go-sgf"),
+    ("sgi", "image/vnd.sealedmedia.softseal.gif"),
+    ("sgif", "image/vnd.sealedmedia.softseal.gif"),
+    ("sgl", "application/vnd.stardivision.writer-global"),
+    ("sgm", "text/sgml"),
+    ("sgml", "text/sgml"),
+    ("sh", "application/x-sh"),
+    ("shaclc", "text/shaclc"),
+    ("shar", "application/x-shar"),
+    ("shc", "text/shaclc"),
+    ("shex", "text/shex"),
+    ("shf", "application/shf+xml"),
+    ("shp", "application/vnd.shp"),
+    ("shtml", "text/html"),
+    ("shx", "application/vnd.shx"),
+    ("si", "text/vnd.wap.si"),
+    ("sic", "application/vnd.wap.sic"),
+    ("sid", "audio/prs.sid"),
+    ("sieve", "application/sieve"),
+    ("sig", "application/pgp-signature"),
+    ("sik", "application/x-trash"),
+    ("silo", "model/mesh"),
+    ("sis", "application/vnd.symbian.install"),
+    ("sit", "application/x-stuffit"),
+    ("sitx", "application/x-stuffit"),
+    ("siv", "application/sieve"),
+    ("sjp", "image/vnd.sealedmedia.softseal.jpg"),
+    ("sjpg", "image/vnd.sealedmedia.softseal.jpg"),
+    ("skd", "application/vnd.koan"),
+    ("skm", "application/vnd.koan"),
+    ("skp", "application/vnd.koan"),
+    ("skt", "application/vnd.koan"),
+    ("sl", "text/vnd.wap.sl"),
+    ("sla", "application/vnd.scribus"),
+    ("slaz", "application/vnd.scribus"),
+    ("slc", "application/vnd.wap.slc"),
+    ("sldm", "application/vnd.ms-powerpoint.slide.macroenabled.12"),
+    ("sldx", "application/vnd.openxmlformats-officedocument.presentationml.slide"),
+    ("sls", "application/route-s-tsid+xml"),
+    ("slt", "application/vnd.epson.salt"),
+    ("sm", "application/vnd.stepmania.stepchart"),
+    ("smc", "application/vnd.nintendo.snes.rom"),
+    ("smf", "application/vnd.stardivision.math"),
+    ("smh", "application/vnd.sealed.mht"),
+    ("smht", "application/vnd.sealed.mht"),
+    ("smi", "application/smil+xml"),
+    ("smil", "application/smil+xml"),
+    ("smk", "video/vnd.radgamettools.smacker"),
+    ("sml", "application/smil+xml"),
+    ("smo", "video/vnd.sealedmedia.softseal.mov"),
+    ("smov", "video/vnd.sealedmedia.softseal.mov"),
+    ("smp", "audio/vnd.sealedmedia.softseal.mpeg"),
+    ("smp3", "audio/vnd.sealedmedia.softseal.mpeg"),
+    ("smpg", "video/vnd.sealed.mpeg1"),
+    ("sms", "application/vnd.3gpp2.sms"),
+    ("smv", "audio/smv"),
+    ("smzip", "application/vnd.stepmania.package"),
+    ("snd", "audio/basic"),
+    ("soa", "text/dns"),
+    ("soc", "application/sgml-open-catalog"),
+    ("sofa", "audio/sofa"),
+    ("sos", "text/vnd.sosi"),
+    ("spc", "chemical/x-galactic-spc"),
+    ("spd", "application/vnd.sealedmedia.softseal.pdf"),
+    ("spdf", "application/vnd.sealedmedia.softseal.pdf"),
+    ("spdx", "text/spdx"),
+    ("spdx.json", "application/spdx+json"),
+    ("spf", "application/vnd.yamaha.smaf-phrase"),
+    ("spl", "application/futuresplash"),
+    ("spn", "image/vnd.sealed.png"),
+    ("spng", "image/vnd.sealed.png"),
+    ("spo", "text/vnd.in3d.spot"),
+    ("spot", "text/vnd.in3d.spot"),
+    ("spp", "application/scvp-vp-response"),
+    ("sppt", "application/vnd.sealed.ppt"),
+    ("spq", "application/scvp-vp-request"),
+    ("spx", "audio/ogg"),
+    ("sql", "application/sql"),
+    ("sqlite", "application/vnd.sqlite3"),
+    ("sqlite3", "application/vnd.sqlite3"),
+    ("sr", "application/vnd.sigrok.session"),
+    ("src", "application/x-wais-source"),
+    ("srt", "text/plain"),
+    ("sru", "application/sru+xml"),
+    ("srx", "application/sparql-results+xml"),
+    ("sse", "application/vnd.kodak-descriptor"),
+    ("ssf", "application/vnd.epson.ssf"),
+    ("ssml", "application/ssml+xml"),
+    ("ssv", "application/vnd.shade-save-file"),
+    ("ssvc", "application/vnd.crypto-shade-file"),
+    ("ssw", "video/vnd.sealed.swf"),
+    ("sswf", "video/vnd.sealed.swf"),
+    ("st", "application/vnd.sailingtracker.track"),
+    ("stc", "application/vnd.sun.xml.calc.template"),
+    ("std", "application/vnd.sun.xml.draw.template"),
+    ("step", "model/step"),
+    ("stf", "application/vnd.wt.stf"),
+    ("sti", "application/vnd.sun.xml.impress.template"),
+    ("stif", "application/vnd.sealed.tiff"),
+    ("stix", "application/stix+json"),
+    ("stk", "application/hyperstudio"),
+    ("stl", "model/stl"),
+    ("stml", "application/vnd.sealedmedia.softseal.html"),
+    ("stp", "model/step"),
+    ("stpnc", "application/p21"),
+    ("stpx", "model/step+xml"),
+    ("stpxz", "model/step-xml+zip"),
+    ("stpz", "model/step+zip"),
+    ("str", "application/vnd.pg.format"),
+    ("study-inter", "application/vnd.vd-study"),
+    ("stw", "application/vnd.sun.xml.writer.template"),
+    ("sty", "text/x-tex"),
+    ("sus", "application/vnd.sus-calendar"),
+    ("susp", "application/vnd.sus-calendar"),
+    ("sv4cpio", "application/x-sv4cpio"),
+    ("sv4crc", "application/x-sv4crc"),
+    ("svc", "application/vnd.dvb.service"),
+    ("svg", "image/svg+xml"),
+    ("svgz", "image/svg+xml"),
+    ("sw", "chemical/x-swissprot"),
+    ("swf", "application/vnd.adobe.flash.movie"),
+    ("swi", "application/vnd.aristanetworks.swi"),
+    ("swidtag", "application/swid+xml"),
+    ("sxc", "application/vnd.sun.xml.calc"),
+    ("sxd", "application/vnd.sun.xml.draw"),
+    ("sxg", "application/vnd.sun.xml.writer.global"),
+    ("sxi", "application/vnd.sun.xml.impress"),
+    ("sxl", "application/vnd.sealed.xls"),
+    ("sxls", "application/vnd.sealed.xls"),
+    ("sxm", "application/vnd.sun.xml.math"),
+    ("sxw", "application/vnd.sun.xml.writer"),
+    ("sy2", "application/vnd.sybyl.mol2"),
+    ("syft.json", "application/vnd.syft+json"),
+    ("t", "text/troff"),
+    ("tag", "text/prs.lines.tag"),
+    ("taglet", "application/vnd.mynfc"),
+    ("tam", "application/vnd.onepager"),
+    ("tamp", "application/vnd.onepagertamp"),
+    ("tamx", "application/vnd.onepagertamx"),
+    ("tao", "application/vnd.tao.intent-module-archive"),
+    ("tap", "image/vnd.tencent.tap"),
+    ("tar", "application/x-tar"),
+    ("tat", "application/vnd.onepagertat"),
+    ("tatp", "application/vnd.onepagertatp"),
+    ("tatx", "application/vnd.onepagertatx"),
+    ("tau", "application/tamp-apex-update"),
+    ("taz", "application/x-gtar-compressed"),
+    ("tcap", "application/vnd.3gpp2.tcap"),
+    ("tcl", "application/x-tcl"),
+    ("tcu", "application/tamp-community-update"),
+    ("td", "application/urc-targetdesc+xml"),
+    ("teacher", "application/vnd.smart.teacher"),
+    ("tei", "application/tei+xml"),
+    ("teicorpus", "application/tei+xml"),
+    ("ter", "application/tamp-error"),
+    ("tex", "text/x-tex"),
+    ("texi", "application/x-texinfo"),
+    ("texinfo", "application/x-texinfo"),
+    ("text", "text/plain"),
+    ("tfi", "application/thraud+xml"),
+    ("tfx", "image/tiff-fx"),
+    ("tgf", "chemical/x-mdl-tgf"),
+    ("tgz", "application/x-gtar-compressed"),
+    ("thmx", "application/vnd.ms-officetheme"),
+    ("tif", "image/tiff"),
+    ("tiff", "image/tiff"),
+    ("tk", "text/x-tcl"),
+    ("tlclient", "application/vnd.cendio.thinlinc.clientconf"),
+    ("tm", "text/texmacs"),
+    ("tm.json", "application/tm+json"),
+    ("tm.jsonld", "application/tm+json"),
+    ("tmo", "application/vnd.tmobile-livetv"),
+    ("tnef", "application/vnd.ms-tnef"),
+    ("tnf", "application/vnd.ms-tnef"),
+    ("torrent", "application/x-bittorrent"),
+    ("tpl", "application/vnd.groove-tool-template"),
+    ("tpt", "application/vnd.trid.tpt"),
+    ("tr", "text/troff"),
+    ("tra", "application/vnd.trueapp"),
+    ("tree", "application/vnd.rainstor.data"),
+    ("trig", "application/trig"),
+    ("ts", "video/mp2t"),
+    ("tsa", "application/tamp-sequence-adjust"),
+    ("tsd", "application/timestamped-data"),
+    ("tsp", "application/dsptype"),
+    ("tsq", "application/timestamp-query"),
+    ("tsr", "application/timestamp-reply"),
+    ("tst", "application/vnd.etsi.timestamp-token"),
+    ("tsv", "text/tab-separated-values"),
+    ("ttc", "font/collection"),
+    ("ttf", "font/ttf"),
+    ("ttl", "text/turtle"),
+    ("ttml", "application/ttml+xml"),
+    ("tuc", "application/tamp-update-confirm"),
+    ("tur", "application/tamp-update"),
+    ("twd", "application/vnd.simtech-mindmapper"),
+    ("twds", "application/vnd.simtech-mindmapper"),
+    ("txd", "application/vnd.genomatix.tuxedo"),
+    ("txf", "application/vnd.mobius.txf"),
+    ("txt", "text/plain"),
+    ("u3d", "model/u3d"),
+    ("u8dsn", "message/global-delivery-status"),
+    ("u8hdr", "message/global-headers"),
+    ("u8mdn", "message/global-disposition-notification"),
+    ("u8msg", "message/global"),
+    ("udeb", "application/vnd.debian.binary-package"),
+    ("ufd", "application/vnd.ufdl"),
+    ("ufdl", "application/vnd.ufdl"),
+    ("uis", "application/urc-uisocketdesc+xml"),
+    ("umj", "application/vnd.umajin"),
+    ("unityweb", "application/vnd.unity"),
+    ("uo", "application/vnd.uoml+xml"),
+    ("uoml", "application/vnd.uoml+xml"),
+    ("upa", "application/vnd.hbci"),
+    ("uri", "text/uri-list"),
+    ("urim", "application/vnd.uri-map"),
+    ("urimap", "application/vnd.uri-map"),
+    ("uris", "text/uri-list"),
+    ("usda", "model/vnd.usda"),
+    ("usdz", "model/vnd.usdz+zip"),
+    ("ustar", "application/x-ustar"),
+    ("utz", "application/vnd.uiq.theme"),
+    ("uva", "audio/vnd.dece.audio"),
+    ("uvd", "application/vnd.dece.data"),
+    ("uvf", "application/vnd.dece.data"),
+    ("uvg", "image/vnd.dece.graphic"),
+    ("uvh", "video/vnd.dece.hd"),
+    ("uvi", "image/vnd.dece.graphic"),
+    ("uvm", "video/vnd.dece.mobile"),
+    ("uvp", "video/vnd.dece.pd"),
+    ("uvs", "video/vnd.dece.sd"),
+    ("uvt", "application/vnd.dece.ttml+xml"),
+    ("uvu", "video/vnd.dece.mp4"),
+    ("uvv", "video/vnd.dece.video"),
+    ("uvva", "audio/vnd.dece.audio"),
+    ("uvvd", "application/vnd.dece.data"),
+    ("uvvf", "application/vnd.dece.data"),
+    ("uvvg", "image/vnd.dece.graphic"),
+    ("uvvh", "video/vnd.dece.hd"),
+    ("uvvi", "image/vnd.dece.graphic"),
+    ("uvvm", "video/vnd.dece.mobile"),
+    ("uvvp", "video/vnd.dece.pd"),
+    ("uvvs", "video/vnd.dece.sd"),
+    ("uvvt", "application/vnd.dece.ttml+xml"),
+    ("uvvu", "video/vnd.dece.mp4"),
+    ("uvvv", "video/vnd.dece.video"),
+    ("uvvx", "application/vnd.dece.unspecified"),
+    ("uvvz", "application/vnd.dece.zip"),
+    ("uvx", "application/vnd.dece.unspecified"),
+    ("uvz", "application/vnd.dece.zip"),
+    ("val", "chemical/x-ncbi-asn1-binary"),
+    ("vbk", "audio/vnd.nortel.vbk"),
+    ("vbox", "application/vnd.previewsystems.box"),
+    ("vcard", "text/vcard"),
+    ("vcd", "application/x-cdlink"),
+    ("vcf", "text/vcard"),
+    ("vcg", "application/vnd.groove-vcard"),
+    ("vcj", "application/voucher-cms+json"),
+    ("vcs", "text/x-vcalendar"),
+    ("vcx", "application/vnd.vcx"),
+    ("vds", "model/vnd.sap.vds"),
+    ("ves", "application/vnd.ves.encrypted"),
+    ("vew", "application/vnd.lotus-approach"),
+    ("vfk", "text/vnd.exchangeable"),
+    ("vfr", "application/vnd.tml"),
+    ("viaframe", "application/vnd.tml"),
+    ("vis", "application/vnd.visionary"),
+    ("viv", "video/vnd.vivo"),
+    ("vmd", "chemical/x-vmd"),
+    ("vms", "chemical/x-vamas-iso14976"),
+    ("vmt", "application/vnd.valve.source.material"),
+    ("vpm", "multipart/voice-message"),
+    ("vrm", "model/vrml"),
+    ("vrml", "model/vrml"),
+    ("vsc", "application/vnd.vidsoft.vidconference"),
+    ("vsd", "application/vnd.visio"),
+    ("vsf", "application/vnd.vsf"),
+    ("vss", "application/vnd.visio"),
+    ("vst", "application/vnd.visio"),
+    ("vsw", "application/vnd.visio"),
+    ("vtf", "image/vnd.valve.source.texture"),
+    ("vtnstd", "application/vnd.veritone.aion+json"),
+    ("vtt", "text/vtt"),
+    ("vtu", "model/vnd.vtu"),
+    ("vwx", "application/vnd.vectorworks"),
+    ("vxml", "application/voicexml+xml"),
+    ("wad", "application/x-doom"),
+    ("wadl", "application/vnd.sun.wadl+xml"),
+    ("wafl", "application/vnd.wasmflow.wafl"),
+    ("wasm", "application/wasm"),
+    ("wav", "audio/wav"),
+    ("wax", "audio/x-ms-wax"),
+    ("wbmp", "image/vnd.wap.wbmp"),
+    ("wbs", "application/vnd.criticaltools.wbs+xml"),
+    ("wbxml", "application/vnd.wap.wbxml"),
+    ("wcm", "application/vnd.ms-works"),
+    ("wdb", "application/vnd.ms-works"),
+    ("webm", "video/webm"),
+    ("webmanifest", "application/manifest+json"),
+    ("webp", "image/webp"),
+    ("wg", "application/vnd.pmi.widget"),
+    ("wgsl", "text/wgsl"),
+    ("wgt", "application/widget"),
+    ("wif", "application/watcherinfo+xml"),
+    ("win", "model/vnd.gdl"),
+    ("wk", "application/x-123"),
+    ("wk1", "application/vnd.lotus-1-2-3"),
+    ("wk3", "application/vnd.lotus-1-2-3"),
+    ("wk4", "application/vnd.lotus-1-2-3"),
+    ("wks", "application/vnd.ms-works"),
+    ("wlnk", "application/link-format"),
+    ("wm", "video/x-ms-wm"),
+    ("wma", "audio/x-ms-wma"),
+    ("wmc", "application/vnd.wmc"),
+    ("wmd", "application/x-ms-wmd"),
+    ("wmf", "image/wmf"),
+    ("wml", "text/vnd.wap.wml"),
+    ("wmlc", "application/vnd.wap.wmlc"),
+    ("wmls", "text/vnd.wap.wmlscript"),
+    ("wmlsc", "application/vnd.wap.wmlscriptc"),
+    ("wmv", "video/x-ms-wmv"),
+    ("wmx", "video/x-ms-wmx"),
+    ("wmz", "application/x-ms-wmz"),
+    ("woff", "font/woff"),
+    ("woff2", "font/woff2"),
+    ("wpd", "application/vnd.wordperfect"),
+    ("wpl", "application/vnd.ms-wpl"),
+    ("wps", "application/vnd.ms-works"),
+    ("wqd", "application/vnd.wqd"),
+    ("wrl", "model/vrml"),
+    ("wsc", "application/vnd.wfa.wsc"),
+    ("wsdl", "application/wsdl+xml"),
+    ("wspolicy", "application/wspolicy+xml"),
+    ("wtb", "application/vnd.webturbo"),
+    ("wv", "application/vnd.wv.csp+wbxml"),
+    ("wvx", "video/x-ms-wvx"),
+    ("wz", "application/x-wingz"),
+    ("x3d", "model/x3d+xml"),
+    ("x3db", "model/x3d+fastinfoset"),
+    ("x3dv", "model/x3d-vrml"),
+    ("x3dvz", "model/x3d-vrml"),
+    ("x3dz", "model/x3d+xml"),
+    ("x_b", "model/vnd.parasolid.transmit.binary"),
+    ("x_t", "model/vnd.parasolid.transmit.text"),
+    ("xar", "application/vnd.xara"),
+    ("xav", "application/xcap-att+xml"),
+    ("xbd", "application/vnd.fujixerox.docuworks.binder"),
+    ("xbm", "image/x-xbitmap"),
+    ("xca", "application/xcap-caps+xml"),
+    ("xcf", "image/x-xcf"),
+    ("xcos", "application/x-scilab-xcos"),
+    ("xcs", "application/calendar+xml"),
+    ("xct", "application/vnd.fujixerox.docuworks.container"),
+    ("xdd", "application/bacnet-xdd+zip"),
+    ("xdf", "application/xcap-diff+xml"),
+    ("xdm", "application/vnd.syncml.dm+xml"),
+    ("xdp", "application/vnd.adobe.xdp+xml"),
+    ("xdssc", "application/dssc+xml"),
+    ("xdw", "application/vnd.fujixerox.docuworks"),
+    ("xel", "application/xcap-el+xml"),
+    ("xer", "application/xcap-error+xml"),
+    ("xfd", "application/vnd.xfdl"),
+    ("xfdf", "application/xfdf"),
+    ("xfdl", "application/vnd.xfdl"),
+    ("xhe", "audio/usac"),
+    ("xht", "application/xhtml+xml"),
+    ("xhtm", "application/xhtml+xml"),
+    ("xhtml", "application/xhtml+xml"),
+    ("xhvml", "application/xv+xml"),
+    ("xif", "image/vnd.xiff"),
+    ("xla", "application/vnd.ms-excel"),
+    ("xlam", "application/vnd.ms-excel.addin.macroenabled.12"),
+    ("xlc", "application/vnd.ms-excel"),
+    ("xlf", "application/xliff+xml"),
+    ("xlim", "application/vnd.xmpie.xlim"),
+    ("xlm", "application/vnd.ms-excel"),
+    ("xls", "application/vnd.ms-excel"),
+    ("xlsb", "application/vnd.ms-excel.sheet.binary.macroenabled.12"),
+    ("xlsm", "application/vnd.ms-excel.sheet.macroenabled.12"),
+    ("xlsx", "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+    ("xlt", "application/vnd.ms-excel"),
+    ("xltm", "application/vnd.ms-excel.template.macroenabled.12"),
+    ("xltx", "application/vnd.openxmlformats-officedocument.spreadsheetml.template"),
+    ("xlw", "application/vnd.ms-excel"),
+    ("xml", "application/xml"),
+    ("xmls", "application/dskpp+xml"),
+    ("xmt_bin", "model/vnd.parasolid.transmit.binary"),
+    ("xmt_txt", "model/vnd.parasolid.transmit.text"),
+    ("xns", "application/xcap-ns+xml"),
+    ("xo", "application/vnd.olpc-sugar"),
+    ("xodp", "application/vnd.collabio.xodocuments.presentation"),
+    ("xods", "application/vnd.collabio.xodocuments.spreadsheet"),
+    ("xodt", "application/vnd.collabio.xodocuments.document"),
+    ("xop", "application/xop+xml"),
+    ("xotp", "application/vnd.collabio.xodocuments.presentation-template"),
+    ("xots", "application/vnd.collabio.xodocuments.spreadsheet-template"),
+    ("xott", "application/vnd.collabio.xodocuments.document-template"),
+    ("xpak", "application/vnd.gentoo.xpak"),
+    ("xpi", "application/x-xpinstall"),
+    ("xpm", "image/x-xpixmap"),
+    ("xpr", "application/vnd.is-xpr"),
+    ("xps", "application/vnd.ms-xpsdocument"),
+    ("xpw", "application/vnd.intercon.formnet"),
+    ("xpx", "application/vnd.intercon.formnet"),
+    ("xsf", "application/prs.xsf+xml"),
+    ("xsl", "application/xslt+xml"),
+    ("xslt", "application/xslt+xml"),
+    ("xsm", "application/vnd.syncml+xml"),
+    ("xspf", "application/xspf+xml"),
+    ("xtel", "chemical/x-xtel"),
+    ("xul", "application/vnd.mozilla.xul+xml"),
+    ("xvm", "application/xv+xml"),
+    ("xvml", "application/xv+xml"),
+    ("xwd", "image/x-xwindowdump"),
+    ("xyz", "chemical/x-xyz"),
+    ("xyze", "image/vnd.radiance"),
+    ("xz", "application/x-xz"),
+    ("yang", "application/yang"),
+    ("yin", "application/yin+xml"),
+    ("yme", "application/vnd.yaoweme"),
+    ("yt", "video/vnd.youtube.yt"),
+    ("zaz", "application/vnd.zzazz.deck+xml"),
+    ("zfc", "application/vnd.filmit.zfc"),
+    ("zfo", "application/vnd.software602.filler.form-xml-zip"),
+    ("zip", "application/zip"),
+    ("zir", "application/vnd.zul"),
+    ("zirz", "application/vnd.zul"),
+    ("zmm", "application/vnd.handheld-entertainment+xml"),
+    ("zmt", "chemical/x-mopac-input"),
+    ("zone", "text/dns"),
+    ("zst", "application/zstd"),
+];
+
+/// Media types, their extensions, and whether they are compressible,
+/// sorted by media type.
+#[rustfmt::skip]
+pub(crate) static TYPES: &[(&str, &[&str], bool)] = &[
+    ("application/1d-interleaved-parityfec", &[], false),
+    ("application/3gpdash-qoe-report+xml", &[], true),
+    ("application/3gpp-ims+xml", &[], true),
+    ("application/3gpphal+json", &[], true),
+    ("application/3gpphalforms+json", &[], true),
+    ("application/a2l", &["a2l"], false),
+    ("application/ace+cbor", &[], false),
+    ("application/ace+json", &[], true),
+    ("application/activemessage", &[], false),
+    ("application/activity+json", &[], true),
+    ("application/aif+cbor", &[], false),
+    ("application/aif+json", &[], true),
+    ("application/alto-cdni+json", &[], true),
+    ("application/alto-cdnifilter+json", &[], true),
+    ("application/alto-costmap+json", &[], true),
+    ("application/alto-costmapfilter+json", &[], true),
+    ("application/alto-directory+json", &[], true),
+    ("application/alto-endpointcost+json", &[], true),
+    ("application/alto-endpointcostparams+json", &[], true),
+    ("application/alto-endpointprop+json", &[], true),
+    ("application/alto-endpointpropparams+json", &[], true),
+    ("application/alto-error+json", &[], true),
+    ("application/alto-networkmap+json", &[], true),
+    ("application/alto-networkmapfilter+json", &[], true),
+    ("application/alto-propmap+json", &[], true),
+    ("application/alto-propmapparams+json", &[], true),
+    ("application/alto-updatestreamcontrol+json", &[], true),
+    ("application/alto-updatestreamparams+json", &[], true),
+    ("application/aml", &["aml"], false),
+    ("application/andrew-inset", &["ez"], false),
+    ("application/annodex", &["anx"], false),
+    ("application/applefile", &[], false),
+    ("application/at+jwt", &[], false),
+    ("application/atf", &["atf"], false),
+    ("application/atfx", &["atfx"], false),
+    ("application/atom+xml", &["atom"], true),
+    ("application/atomcat+xml", &["atomcat"], true),
+    ("application/atomdeleted+xml", &["atomdeleted"], true),
+    ("application/atomicmail", &[], false),
+    ("application/atomserv+xml", &["atomsrv"], true),
+    ("application/atomsvc+xml", &["atomsvc"], true),
+    ("application/atsc-dwd+xml", &["dwd"], true),
+    ("application/atsc-dynamic-event-message", &[], false),
+    ("application/atsc-held+xml", &["held"], true),
+    ("application/atsc-rdt+json", &[], true),
+    ("application/atsc-rsat+xml", &["rsat"], true),
+    ("application/atxml", &["atxml"], false),
+    ("application/auth-policy+xml", &["apxml"], true),
+    ("application/automationml-aml+xml", &[], true),
+    ("application/automationml-amlx+zip", &["amlx"], false),
+    ("application/bacnet-xdd+zip", &["xdd"], false),
+    ("application/batch-smtp", &[], false),
+    ("application/bbolin", &["lin"], false),
+    ("application/beep+xml", &[], true),
+    ("application/calendar+json", &[], true),
+    ("application/calendar+xml", &["xcs"], true),
+    ("application/call-completion", &[], false),
+    ("application/cals-1840", &[], false),
+    ("application/captive+json", &[], true),
+    ("application/cbor", &["cbor"], false),
+    ("application/cbor-seq", &[], false),
+    ("application/cccex", &["c3ex"], false),
+    ("application/ccmp+xml", &["ccmp"], true),
+    ("application/ccxml+xml", &["ccxml"], true),
+    ("application/cda+xml", &[], true),
+    ("application/cdfx+xml", &["cdfx"], true),
+    ("application/cdmi-capability", &["cdmia"], false),
+    ("application/cdmi-container", &["cdmic"], false),
+    ("application/cdmi-domain", &["cdmid"], false),
+    ("application/cdmi-object", &["cdmio"], false),
+    ("application/cdmi-queue", &["cdmiq"], false),
+    ("application/cdni", &[], false),
+    ("application/cea", &["cea"], false),
+    ("application/cea-2018+xml", &[], true),
+    ("application/cellml+xml", &["cellml", "cml"], true),
+    ("application/cfw", &[], false),
+    ("application/city+json", &[], true),
+    ("application/clr", &["1clr"], false),
+    ("application/clue+xml", &[], true),
+    ("application/clue_info+xml", &["clue"], true),
+    ("application/cms", &["cmsc"], false),
+    ("application/cnrp+xml", &[], true),
+    ("application/coap-group+json", &[], true),
+    ("application/coap-payload", &[], false),
+    ("application/commonground", &[], false),
+    ("application/concise-problem-details+cbor", &[], false),
+    ("application/conference-info+xml", &[], true),
+    ("application/cose", &[], false),
+    ("application/cose-key", &[], false),
+    ("application/cose-key-set", &[], false),
+    ("application/cose-x509", &[], false),
+    ("application/cpl+xml", &["cpl"], true),
+    ("application/csrattrs", &["csrattrs"], false),
+    ("application/csta+xml", &[], true),
+    ("application/cstadata+xml", &[], true),
+    ("application/csvm+json", &[], true),
+    ("application/cu-seeme", &["cu"], false),
+    ("application/cwl", &["cwl"], false),
+    ("application/cwl+json", &["cwl.json"], true),
+    ("application/cwt", &[], false),
+    ("application/cybercash", &[], false),
+    ("application/dash+xml", &["mpd"], true),
+    ("application/dash-patch+xml", &[], true),
+    ("application/dashdelta", &["mpdd"], false),
+    ("application/davmount+xml", &["davmount"], true),
+    ("application/dca-rft", &[], false),
+    ("application/dcd", &["dcd"], false),
+    ("application/dec-dx", &[], false),
+    ("application/dialog-info+xml", &[], true),
+    ("application/dicom", &["dcm"], false),
+    ("application/dicom+json", &[], true),
+    ("application/dicom+xml", &[], true),
+    ("application/dii", &["dii"], false),
+    ("application/dit", &["dit"], false),
+    ("application/dns", &[], false),
+    ("application/dns+json", &[], true),
+    ("application/dns-message", &[], false),
+    ("application/dots+cbor", &[], false),
+    ("application/dskpp+xml", &["xmls"], true),
+    ("application/dsptype", &["tsp"], false),
+    ("application/dssc+der", &["dssc"], false),
+    ("application/dssc+xml", &["xdssc"], true),
+    ("application/dvcs", &["dvc"], false),
+    ("application/edi-consent", &[], false),
+    ("application/edi-x12", &[], false),
+    ("application/edifact", &[], false),
+    ("application/efi", &["efi"], false),
+    ("application/elm+json", &[], true),
+    ("application/elm+xml", &[], true),
+    ("application/emergencycalldata.cap+xml", &[], true),
+    ("application/emergencycalldata.comment+xml", &[], true),
+    ("application/emergencycalldata.control+xml", &[], true),
+    ("application/emergencycalldata.deviceinfo+xml", &[], true),
+    ("application/emergencycalldata.ecall.msd", &[], false),
+    ("application/emergencycalldata.legacyesn+json", &[], true),
+    ("application/emergencycalldata.providerinfo+xml", &[], true),
+    ("application/emergencycalldata.serviceinfo+xml", &[], true),
+    ("application/emergencycalldata.subscriberinfo+xml", &[], true),
+    ("application/emergencycalldata.veds+xml", &[], true),
+    ("application/emma+xml", &["emma"], true),
+    ("application/emotionml+xml", &["emotionml"], true),
+    ("application/encaprtp", &[], false),
+    ("application/epp+xml", &[], true),
+    ("application/epub+zip", &["epub"], false),
+    ("application/eshop", &[], false),
+    ("application/example", &[], false),
+    ("application/exi", &["exi"], false),
+    ("application/expect-ct-report+json", &[], true),
+    ("application/express", &["exp"], false),
+    ("application/fastinfoset", &["finf"], false),
+    ("application/fastsoap", &[], false),
+    ("application/fdf", &["fdf"], false),
+    ("application/fdt+xml", &["fdt"], true),
+    ("application/fhir+json", &[], true),
+    ("application/fhir+xml", &[], true),
+    ("application/fits", &[], false),
+    ("application/flexfec", &[], false),
+    ("application/font-tdpfr", &["pfr"], false),
+    ("application/framework-attributes+xml", &[], true),
+    ("application/futuresplash", &["spl"], false),
+    ("application/geo+json", &["geojson"], true),
+    ("application/geo+json-seq", &[], false),
+    ("application/geopackage+sqlite3", &["gpkg"], false),
+    ("application/geoxacml+xml", &[], true),
+    ("application/gltf-buffer", &["glbin", "glbuf"], false),
+    ("application/gml+xml", &["gml"], true),
+    ("application/gzip", &["gz"], false),
+    ("application/h224", &[], false),
+    ("application/held+xml", &[], true),
+    ("application/hl7v2+xml", &[], true),
+    ("application/hta", &["hta"], false),
+    ("application/http", &[], false),
+    ("application/hyperstudio", &["stk"], false),
+    ("application/ibe-key-request+xml", &[], true),
+    ("application/ibe-pkg-reply+xml", &[], true),
+    ("application/ibe-pp-data", &[], false),
+    ("application/iges", &[], false),
+    ("application/im-iscomposing+xml", &[], true),
+    ("application/index", &[], false),
+    ("application/index.cmd", &[], false),
+    ("application/index.obj", &[], false),
+    ("application/index.response", &[], false),
+    ("application/index.vnd", &[], false),
+    ("application/inkml+xml", &["ink", "inkml"], true),
+    ("application/iotp", &[], false),
+    ("application/ipfix", &["ipfix"], false),
+    ("application/ipp", &[], false),
+    ("application/isup", &[], false),
+    ("application/its+xml", &["its"], true),
+    ("application/java-archive", &["jar"], false),
+    ("application/java-serialized-object", &["ser"], false),
+    ("application/java-vm", &["class"], false),
+    ("application/jf2feed+json", &[], true),
+    ("application/jose", &[], false),
+    ("application/jose+json", &[], true),
+    ("application/jrd+json", &["jrd"], true),
+    ("application/jscalendar+json", &[], true),
+    ("application/json", &["json", "map"], true),
+    ("application/json-patch+json", &["json-patch"], true),
+    ("application/json-seq", &[], false),
+    ("application/jwk+json", &[], true),
+    ("application/jwk-set+json", &[], true),
+    ("application/jwt", &[], false),
+    ("application/kpml-request+xml", &[], true),
+    ("application/kpml-response+xml", &[], true),
+    ("application/ld+json", &["jsonld"], true),
+    ("application/lgr+xml", &["lgr"], true),
+    ("application/link-format", &["wlnk"], false),
+    ("application/linkset", &[], false),
+    ("application/linkset+json", &[], true),
+    ("application/load-control+xml", &[], true),
+    ("application/logout+jwt", &[], false),
+    ("application/lost+xml", &["lostxml"], true),
+    ("application/lostsync+xml", &["lostsyncxml"], true),
+    ("application/lpf+zip", &["lpf"], false),
+    ("application/lxf", &["lxf"], false),
+    ("application/m3g", &["m3g"], false),
+    ("application/mac-binhex40", &["hqx"], false),
+    ("application/mac-compactpro", &["cpt"], false),
+    ("application/macwriteii", &[], false),
+    ("application/mads+xml", &["mads"], true),
+    ("application/manifest+json", &["webmanifest"], true),
+    ("application/marc", &["mrc"], false),
+    ("application/marcxml+xml", &["mrcx"], true),
+    ("application/mathematica", &["ma", "mb"], false),
+    ("application/mathml+xml", &["mml"], true),
+    ("application/mathml-content+xml", &[], true),
+    ("application/mathml-presentation+xml", &[], true),
+    ("application/mbms-associated-procedure-description+xml", &[], true),
+    ("application/mbms-deregister+xml", &[], true),
+    ("application/mbms-envelope+xml", &[], true),
+    ("application/mbms-msk+xml", &[], true),
+    ("application/mbms-msk-response+xml", &[], true),
+    ("application/mbms-protection-description+xml", &[], true),
+    ("application/mbms-reception-report+xml", &[], true),
+    ("application/mbms-register+xml", &[], true),
+    ("application/mbms-register-response+xml", &[], true),
+    ("application/mbms-schedule+xml", &[], true),
+    ("application/mbms-user-service-description+xml", &[], true),
+    ("application/mbox", &["mbox"], false),
+    ("application/media-policy-dataset+xml", &[], true),
+    ("application/media_control+xml", &[], true),
+    ("application/mediaservercontrol+xml", &[], true),
+    ("application/merge-patch+json", &[], true),
+    ("application/metalink4+xml", &["meta4"], true),
+    ("application/mets+xml", &["mets"], true),
+    ("application/mf4", &["mf4"], false),
+    ("application/mikey", &[], false),
+    ("application/mipc", &[], false),
+    ("application/missing-blocks+cbor-seq", &[], false),
+    ("application/mmt-aei+xml", &["maei"], true),
+    ("application/mmt-usd+xml", &["musd"], true),
+    ("application/mods+xml", &["mods"], true),
+    ("application/moss-keys", &[], false),
+    ("application/moss-signature", &[], false),
+    ("application/mosskey-data", &[], false),
+    ("application/mosskey-request", &[], false),
+    ("application/mp21", &["m21", "mp21"], false),
+    ("application/mp4", &[], false),
+    ("application/mpeg4-generic", &[], false),
+    ("application/mpeg4-iod", &[], false),
+    ("application/mpeg4-iod-xmt", &[], false),
+    ("application/mrb-consumer+xml", &[], true),
+    ("application/mrb-publish+xml", &[], true),
+    ("application/msaccess", &["mdb"], false),
+    ("application/msc-ivr+xml", &[], true),
+    ("application/msc-mixer+xml", &[], true),
+    ("application/msword", &["doc"], false),
+    ("application/mud+json", &[], true),
+    ("application/multipart-core", &[], false),
+    ("application/mxf", &["mxf"], false),
+    ("application/n-quads", &["nq"], false),
+    ("application/n-triples", &["nt"], false),
+    ("application/nasdata", &[], false),
+    ("application/news-checkgroups", &[], false),
+    ("application/news-groupinfo", &[], false),
+    ("application/news-transmission", &[], false),
+    ("application/nlsml+xml", &[], true),
+    ("application/node", &[], false),
+    ("application/nss", &[], false),
+    ("application/oauth-authz-req+jwt", &[], false),
+    ("application/oblivious-dns-message", &[], false),
+    ("application/ocsp-request", &["orq"], false),
+    ("application/ocsp-response", &["ors"], false),
+    ("application/octet-stream", &["bin", "deploy", "msu", "msp"], false),
+    ("application/oda", &["oda"], false),
+    ("application/odm+xml", &[], true),
+    ("application/odx", &["odx"], false),
+    ("application/oebps-package+xml", &["opf"], true),
+    ("application/ogg", &["ogx"], false),
+    ("application/onenote", &["one", "onetoc2", "onetmp", "onepkg"], false),
+    ("application/opc-nodeset+xml", &[], true),
+    ("application/oscore", &[], false),
+    ("application/oxps", &["oxps"], false),
+    ("application/p21", &["p21", "stpnc", "210", "ifc"], false),
+    ("application/p21+zip", &[], false),
+    ("application/p2p-overlay+xml", &["relo"], true),
+    ("application/parityfec", &[], false),
+    ("application/passport", &[], false),
+    ("application/patch-ops-error+xml", &[], true),
+    ("application/pdf", &["pdf"], false),
+    ("application/pdx", &["pdx"], false),
+    ("application/pem-certificate-chain", &["pem"], false),
+    ("application/pgp-encrypted", &["pgp"], false),
+    ("application/pgp-keys", &["asc", "key"], false),
+    ("application/pgp-signature", &["sig"], false),
+    ("application/pics-rules", &["prf"], false),
+    ("application/pidf+xml", &[], true),
+    ("application/pidf-diff+xml", &[], true),
+    ("application/pkcs10", &["p10"], false),
+    ("application/pkcs12", &["p12", "pfx"], false),
+    ("application/pkcs7-mime", &["p7m", "p7c", "p7z"], false),
+    ("application/pkcs7-signature", &["p7s"], false),
+    ("application/pkcs8", &["p8"], false),
+    ("application/pkcs8-encrypted", &["p8e"], false),
+    ("application/pkix-attr-cert", &["ac"], false),
+    ("application/pkix-cert", &["cer"], false),
+    ("application/pkix-crl", &["crl"], false),
+    ("application/pkix-pkipath", &["pkipath"], false),
+    ("application/pkixcmp", &["pki"], false),
+    ("application/pls+xml", &[], true),
+    ("application/poc-settings+xml", &[], true),
+    ("application/postscript", &["ps", "ai", "eps", "epsi", "epsf", "eps2", "eps3"], true),
+    ("application/ppsp-tracker+json", &[], true),
+    ("application/problem+json", &[], true),
+    ("application/problem+xml", &[], true),
+    ("application/provenance+xml", &["provx"], true),
+    ("application/prs.alvestrand.titrax-sheet", &[], false),
+    ("application/prs.cww", &["cw", "cww"], false),
+    ("application/prs.cyn", &[], false),
+    ("application/prs.hpub+zip", &["hpub"], false),
+    ("application/prs.nprend", &["rnd", "rct"], false),
+    ("application/prs.plucker", &[], false),
+    ("application/prs.rdf-xml-crypt", &["rdf-crypt"], false),
+    ("application/prs.xsf+xml", &["xsf"], true),
+    ("application/pskc+xml", &["pskcxml"], true),
+    ("application/pvd+json", &[], true),
+    ("application/qsig", &[], false),
+    ("application/raptorfec", &[], false),
+    ("application/rdap+json", &[], true),
+    ("application/rdf+xml", &["rdf"], true),
+    ("application/reginfo+xml", &["rif"], true),
+    ("application/relax-ng-compact-syntax", &["rnc"], false),
+    ("application/reputon+json", &[], true),
+    ("application/resource-lists+xml", &["rl"], true),
+    ("application/resource-lists-diff+xml", &["rld"], true),
+    ("application/rfc+xml", &["rfcxml"], true),
+    ("application/riscos", &[], false),
+    ("application/rlmi+xml", &[], true),
+    ("application/rls-services+xml", &["rs"], true),
+    ("application/route-apd+xml", &["rapd"], true),
+    ("application/route-s-tsid+xml", &["sls"], true),
+    ("application/route-usd+xml", &["rusd"], true),
+    ("application/rpki-checklist", &[], false),
+    ("application/rpki-ghostbusters", &["gbr"], false),
+    ("application/rpki-manifest", &["mft"], false),
+    ("application/rpki-publication", &[], false),
+    ("application/rpki-roa", &["roa"], false),
+    ("application/rpki-updown", &[], false),
+    ("application/rtf", &["rtf"], true),
+    ("application/rtploopback", &[], false),
+    ("application/rtx", &[], false),
+    ("application/samlassertion+xml", &[], true),
+    ("application/samlmetadata+xml", &[], true),
+    ("application/sarif+json", &["sarif", "sarif.json"], true),
+    ("application/sarif-external-properties+json", &["sarif-external-properties", "sarif-external-properties.json"], true),
+    ("application/sbe", &[], false),
+    ("application/sbml+xml", &[], true),
+    ("application/scaip+xml", &[], true),
+    ("application/scim+json", &["scim"], true),
+    ("application/scvp-cv-request", &["scq"], false),
+    ("application/scvp-cv-response", &["scs"], false),
+    ("application/scvp-vp-request", &["spq"], false),
+    ("application/scvp-vp-response", &["spp"], false),
+    ("application/sdp", &["sdp"], false),
+    ("application/secevent+jwt", &[], false),
+    ("application/senml+cbor", &["senmlc"], false),
+    ("application/senml+json", &["senml"], true),
+    ("application/senml+xml", &["senmlx"], true),
+    ("application/senml-etch+cbor", &["senml-etchc"], false),
+    ("application/senml-etch+json", &["senml-etchj"], true),
+    ("application/senml-exi", &["senmle"], false),
+    ("application/sensml+cbor", &["sensmlc"], false),
+    ("application/sensml+json", &["sensml"], true),
+    ("application/sensml+xml", &["sensmlx"], true),
+    ("application/sensml-exi", &["sensmle"], false),
+    ("application/sep+xml", &[], true),
+    ("application/sep-exi", &[], false),
+    ("application/session-info", &[], false),
+    ("application/set-payment", &[], false),
+    ("application/set-payment-initiation", &[], false),
+    ("application/set-registration", &[], false),
+    ("application/set-registration-initiation", &[], false),
+    ("application/sgml", &[], false),
+    ("application/sgml-open-catalog", &["soc"], false),
+    ("application/shf+xml", &["shf"], true),
+    ("application/sieve", &["siv", "sieve"], false),
+    ("application/simple-filter+xml", &["cl"], true),
+    ("application/simple-message-summary", &[], false),
+    ("application/simplesymbolcontainer", &[], false),
+    ("application/sipc", &[], false),
+    ("application/slate", &[], false),
+    ("application/smil+xml", &["smil", "smi", "sml"], true),
+    ("application/smpte336m", &[], false),
+    ("application/soap+fastinfoset", &[], false),
+    ("application/soap+xml", &[], true),
+    ("application/sparql-query", &["rq"], false),
+    ("application/sparql-results+xml", &["srx"], true),
+    ("application/spdx+json", &["spdx.json"], true),
+    ("application/spirits-event+xml", &[], true),
+    ("application/sql", &["sql"], false),
+    ("application/srgs", &["gram"], false),
+    ("application/srgs+xml", &["grxml"], true),
+    ("application/sru+xml", &["sru"], true),
+    ("application/ssml+xml", &["ssml"], true),
+    ("application/stix+json", &["stix"], true),
+    ("application/swid+cbor", &["coswid"], false),
+    ("application/swid+xml", &["swidtag"], true),
+    ("application/tamp-apex-update", &["tau"], false),
+    ("application/tamp-apex-update-confirm", &["auc"], false),
+    ("application/tamp-community-update", &["tcu"], false),
+    ("application/tamp-community-update-confirm", &["cuc"], false),
+    ("application/tamp-error", &["ter"], false),
+    ("application/tamp-sequence-adjust", &["tsa"], false),
+    ("application/tamp-sequence-adjust-confirm", &["sac"], false),
+    ("application/tamp-status-query", &[], false),
+    ("application/tamp-status-response", &[], false),
+    ("application/tamp-update", &["tur"], false),
+    ("application/tamp-update-confirm", &["tuc"], false),
+    ("application/taxii+json", &[], true),
+    ("application/td+json", &["jsontd"], true),
+    ("application/tei+xml", &["tei", "teicorpus", "odd"], true),
+    ("application/tetra_isi", &[], false),
+    ("application/thraud+xml", &["tfi"], true),
+    ("application/timestamp-query", &["tsq"], false),
+    ("application/timestamp-reply", &["tsr"], false),
+    ("application/timestamped-data", &["tsd"], false),
+    ("application/tlsrpt+gzip", &[], false),
+    ("application/tlsrpt+json", &[], true),
+    ("application/tm+json", &["tm.jsonld", "tm.json", "jsontm"], true),
+    ("application/tnauthlist", &[], false),
+    ("application/token-introspection+jwt", &[], false),
+    ("application/trickle-ice-sdpfrag", &[], false),
+    ("application/trig", &["trig"], false),
+    ("application/ttml+xml", &["ttml"], true),
+    ("application/tve-trigger", &[], false),
+    ("application/tzif", &[], false),
+    ("application/tzif-leap", &[], false),
+    ("application/ulpfec", &[], false),
+    ("application/urc-grpsheet+xml", &["gsheet"], true),
+    ("application/urc-ressheet+xml", &["rsheet"], true),
+    ("application/urc-targetdesc+xml", &["td"], true),
+    ("application/urc-uisocketdesc+xml", &["uis"], true),
+    ("application/vcard+json", &[], true),
+    ("application/vcard+xml", &[], true),
+    ("application/vemmi", &[], false),
+    ("application/vnd.1000minds.decision-model+xml", &["1km"], true),
+    ("application/vnd.3gpp-prose+xml", &[], true),
+    ("application/vnd.3gpp-prose-pc3a+xml", &[], true),
+    ("application/vnd.3gpp-prose-pc3ach+xml", &[], true),
+    ("application/vnd.3gpp-prose-pc3ch+xml", &[], true),
+    ("application/vnd.3gpp-prose-pc8+xml", &[], true),
+    ("application/vnd.3gpp-v2x-local-service-information", &[], false),
+    ("application/vnd.3gpp.5gnas", &[], false),
+    ("application/vnd.3gpp.access-transfer-events+xml", &[], true),
+    ("application/vnd.3gpp.bsf+xml", &[], true),
+    ("application/vnd.3gpp.gmop+xml", &[], true),
+    ("application/vnd.3gpp.gtpc", &[], false),
+    ("application/vnd.3gpp.interworking-data", &[], false),
+    ("application/vnd.3gpp.lpp", &[], false),
+    ("application/vnd.3gpp.mc-signalling-ear", &[], false),
+    ("application/vnd.3gpp.mcdata-affiliation-command+xml", &[], true),
+    ("application/vnd.3gpp.mcdata-info+xml", &[], true),
+    ("application/vnd.3gpp.mcdata-msgstore-ctrl-request+xml", &[], true),
+    ("application/vnd.3gpp.mcdata-payload", &[], false),
+    ("application/vnd.3gpp.mcdata-regroup+xml", &[], true),
+    ("application/vnd.3gpp.mcdata-service-config+xml", &[], true),
+    ("application/vnd.3gpp.mcdata-signalling", &[], false),
+    ("application/vnd.3gpp.mcdata-ue-config+xml", &[], true),
+    ("application/vnd.3gpp.mcdata-user-profile+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-affiliation-command+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-floor-request+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-info+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-location-info+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-mbms-usage-info+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-service-config+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-signed+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-ue-config+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-ue-init-config+xml", &[], true),
+    ("application/vnd.3gpp.mcptt-user-profile+xml", &[], true),
+    ("application/vnd.3gpp.mcvideo-affiliation-command+xml", &[], true),
+    ("application/vnd.3gpp.mcvideo-info+xml", &[], true),
+    ("application/vnd.3gpp.mcvideo-location-info+xml", &[], true),
+    ("application/vnd.3gpp.mcvideo-mbms-usage-info+xml", &[], true),
+    ("application/vnd.3gpp.mcvideo-service-config+xml", &[], true),
+    ("application/vnd.3gpp.mcvideo-transmission-request+xml", &[], true),
+    ("application/vnd.3gpp.mcvideo-ue-config+xml", &[], true),
+    ("application/vnd.3gpp.mcvideo-user-profile+xml", &[], true),
+    ("application/vnd.3gpp.mid-call+xml", &[], true),
+    ("application/vnd.3gpp.ngap", &[], false),
+    ("application/vnd.3gpp.pfcp", &[], false),
+    ("application/vnd.3gpp.pic-bw-large", &["plb"], false),
+    ("application/vnd.3gpp.pic-bw-small", &["psb"], false),
+    ("application/vnd.3gpp.pic-bw-var", &["pvb"], false),
+    ("application/vnd.3gpp.s1ap", &[], false),
+    ("application/vnd.3gpp.sms", &[], false),
+    ("application/vnd.3gpp.sms+xml", &[], true),
+    ("application/vnd.3gpp.srvcc-ext+xml", &[], true),
+    ("application/vnd.3gpp.srvcc-info+xml", &[], true),
+    ("application/vnd.3gpp.state-and-event-info+xml", &[], true),
+    ("application/vnd.3gpp.ussd+xml", &[], true),
+    ("application/vnd.3gpp2.bcmcsinfo+xml", &[], true),
+    ("application/vnd.3gpp2.sms", &["sms"], false),
+    ("application/vnd.3gpp2.tcap", &["tcap"], false),
+    ("application/vnd.3lightssoftware.imagescal", &["imgcal"], false),
+    ("application/vnd.3m.post-it-notes", &["pwn"], false),
+    ("application/vnd.accpac.simply.aso", &["aso"], false),
+    ("application/vnd.accpac.simply.imp", &["imp"], false),
+    ("application/vnd.acucobol", &["acu"], false),
+    ("application/vnd.acucorp", &["atc", "acutc"], false),
+    ("application/vnd.adobe.flash.movie", &["swf"], false),
+    ("application/vnd.adobe.formscentral.fcdt", &["fcdt"], false),
+    ("application/vnd.adobe.fxp", &["fxp", "fxpl"], false),
+    ("application/vnd.adobe.partial-upload", &[], false),
+    ("application/vnd.adobe.xdp+xml", &["xdp"], true),
+    ("application/vnd.aether.imp", &[], false),
+    ("application/vnd.afpc.afplinedata", &[], false),
+    ("application/vnd.afpc.afplinedata-pagedef", &[], false),
+    ("application/vnd.afpc.cmoca-cmresource", &[], false),
+    ("application/vnd.afpc.foca-charset", &[], false),
+    ("application/vnd.afpc.foca-codedfont", &[], false),
+    ("application/vnd.afpc.foca-codepage", &[], false),
+    ("application/vnd.afpc.modca", &["list3820", "listafp", "afp", "pseg3820"], false),
+    ("application/vnd.afpc.modca-formdef", &[], false),
+    ("application/vnd.afpc.modca-mediummap", &[], false),
+    ("application/vnd.afpc.modca-objectcontainer", &[], false),
+    ("application/vnd.afpc.modca-overlay", &["ovl"], false),
+    ("application/vnd.afpc.modca-pagesegment", &["psg"], false),
+    ("application/vnd.age", &["age"], false),
+    ("application/vnd.ah-barcode", &[], false),
+    ("application/vnd.ahead.space", &["ahead"], false),
+    ("application/vnd.airzip.filesecure.azf", &["azf"], false),
+    ("application/vnd.airzip.filesecure.azs", &["azs"], false),
+    ("application/vnd.amadeus+json", &[], true),
+    ("application/vnd.amazon.mobi8-ebook", &["azw3"], false),
+    ("application/vnd.americandynamics.acc", &["acc"], false),
+    ("application/vnd.amiga.ami", &["ami"], false),
+    ("application/vnd.amundsen.maze+xml", &[], true),
+    ("application/vnd.android.ota", &["ota"], false),
+    ("application/vnd.android.package-archive", &["apk"], false),
+    ("application/vnd.anki", &["apkg"], false),
+    ("application/vnd.anser-web-certificate-issue-initiation", &["cii"], false),
+    ("application/vnd.anser-web-funds-transfer-initiation", &["fti"], false),
+    ("application/vnd.antix.game-component", &[], false),
+    ("application/vnd.apache.arrow.file", &["arrow"], false),
+    ("application/vnd.apache.arrow.stream", &["arrows"], false),
+    ("application/vnd.apache.thrift.binary", &[], false),
+    ("application/vnd.apache.thrift.compact", &[], false),
+    ("application/vnd.apache.thrift.json", &[], false),
+    ("application/vnd.apexlang", &["apexlang", "apex"], false),
+    ("application/vnd.api+json", &[], true),
+    ("application/vnd.aplextor.warrp+json", &[], true),
+    ("application/vnd.apothekende.reservation+json", &[], true),
+    ("application/vnd.apple.installer+xml", &["dist", "distz", "pkg", "mpkg"], true),
+    ("application/vnd.apple.keynote", &["keynote"], false),
+    ("application/vnd.apple.mpegurl", &["m3u8"], false),
+    ("application/vnd.apple.numbers", &["numbers"], false),
+    ("application/vnd.apple.pages", &["pages"], false),
+    ("application/vnd.aristanetworks.swi", &["swi"], false),
+    ("application/vnd.artisan+json", &["artisan"], true),
+    ("application/vnd.artsquare", &[], false),
+    ("application/vnd.astraea-software.iota", &["iota"], false),
+    ("application/vnd.audiograph", &["aep"], false),
+    ("application/vnd.autopackage", &["package"], false),
+    ("application/vnd.avalon+json", &[], true),
+    ("application/vnd.avistar+xml", &[], true),
+    ("application/vnd.balsamiq.bmml+xml", &["bmml"], true),
+    ("application/vnd.balsamiq.bmpr", &["bmpr"], false),
+    ("application/vnd.banana-accounting", &["ac2"], false),
+    ("application/vnd.bbf.usp.error", &[], false),
+    ("application/vnd.bbf.usp.msg", &[], false),
+    ("application/vnd.bbf.usp.msg+json", &[], true),
+    ("application/vnd.bekitzur-stech+json", &[], true),
+    ("application/vnd.belightsoft.lhzd+zip", &["lhzd"], false),
+    ("application/vnd.belightsoft.lhzl+zip", &["lhzl"], false),
+    ("application/vnd.bint.med-content", &[], false),
+    ("application/vnd.biopax.rdf+xml", &[], true),
+    ("application/vnd.blink-idb-value-wrapper", &[], false),
+    ("application/vnd.blueice.multipass", &["mpm"], false),
+    ("application/vnd.bluetooth.ep.oob", &["ep"], false),
+    ("application/vnd.bluetooth.le.oob", &["le"], false),
+    ("application/vnd.bmi", &["bmi"], false),
+    ("application/vnd.bpf", &[], false),
+    ("application/vnd.bpf3", &[], false),
+    ("application/vnd.businessobjects", &["rep"], false),
+    ("application/vnd.byu.uapi+json", &[], true),
+    ("application/vnd.cab-jscript", &[], false),
+    ("application/vnd.canon-cpdl", &[], false),
+    ("application/vnd.canon-lips", &[], false),
+    ("application/vnd.capasystems-pg+json", &[], true),
+    ("application/vnd.cendio.thinlinc.clientconf", &["tlclient"], false),
+    ("application/vnd.century-systems.tcp_stream", &[], false),
+    ("application/vnd.chemdraw+xml", &["cdxml"], true),
+    ("application/vnd.chess-pgn", &["pgn"], false),
+    ("application/vnd.chipnuts.karaoke-mmd", &["mmd"], false),
+    ("application/vnd.ciedi", &[], false),
+    ("application/vnd.cinderella", &["cdy"], false),
+    ("application/vnd.cirpack.isdn-ext", &[], false),
+    ("application/vnd.citationstyles.style+xml", &["csl"], true),
+    ("application/vnd.claymore", &["cla"], false),
+    ("application/vnd.cloanto.rp9", &["rp9"], false),
+    ("application/vnd.clonk.c4group", &["c4g", "c4d", "c4f", "c4p", "c4u"], false),
+    ("application/vnd.cluetrust.cartomobile-config", &["c11amc"], false),
+    ("application/vnd.cluetrust.cartomobile-config-pkg", &["c11amz"], false),
+    ("application/vnd.cncf.helm.chart.content.v1.tar+gzip", &[], false),
+    ("application/vnd.cncf.helm.chart.provenance.v1.prov", &[], false),
+    ("application/vnd.coffeescript", &["coffee"], false),
+    ("application/vnd.collabio.xodocuments.document", &["xodt"], false),
+    ("application/vnd.collabio.xodocuments.document-template", &["xott"], false),
+    ("application/vnd.collabio.xodocuments.presentation", &["xodp"], false),
+    ("application/vnd.collabio.xodocuments.presentation-template", &["xotp"], false),
+    ("application/vnd.collabio.xodocuments.spreadsheet", &["xods"], false),
+    ("application/vnd.collabio.xodocuments.spreadsheet-template", &["xots"], false),
+    ("application/vnd.collection+json", &[], true),
+    ("application/vnd.collection.doc+json", &[], true),
+    ("application/vnd.collection.next+json", &[], true),
+    ("application/vnd.comicbook+zip", &["cbz"], false),
+    ("application/vnd.comicbook-rar", &["cbr"], false),
+    ("application/vnd.commerce-battelle", &["icf", "icd", "ic0", "ic1", "ic2", "ic3", "ic4", "ic5", "ic6", "ic7", "ic8"], false),
+    ("application/vnd.commonspace", &["csp", "cst"], false),
+    ("application/vnd.contact.cmsg", &["cdbcmsg"], false),
+    ("application/vnd.coreos.ignition+json", &["ign", "ignition"], true),
+    ("application/vnd.cosmocaller", &["cmc"], false),
+    ("application/vnd.crick.clicker", &["clkx"], false),
+    ("application/vnd.crick.clicker.keyboard", &["clkk"], false),
+    ("application/vnd.crick.clicker.palette", &["clkp"], false),
+    ("application/vnd.crick.clicker.template", &["clkt"], false),
+    ("application/vnd.crick.clicker.wordbank", &["clkw"], false),
+    ("application/vnd.criticaltools.wbs+xml", &["wbs"], true),
+    ("application/vnd.cryptii.pipe+json", &[], true),
+    ("application/vnd.crypto-shade-file", &["ssvc"], false),
+    ("application/vnd.cryptomator.encrypted", &["c9r", "c9s"], false),
+    ("application/vnd.cryptomator.vault", &["cryptomator"], false),
+    ("application/vnd.ctc-posml", &["pml"], false),
+    ("application/vnd.ctct.ws+xml", &[], true),
+    ("application/vnd.cups-pdf", &[], false),
+    ("application/vnd.cups-postscript", &[], false),
+    ("application/vnd.cups-ppd", &["ppd"], false),
+    ("application/vnd.cups-raster", &[], false),
+    ("application/vnd.cups-raw", &[], false),
+    ("application/vnd.curl", &[], false),
+    ("application/vnd.cyan.dean.root+xml", &[], true),
+    ("application/vnd.cybank", &[], false),
+    ("application/vnd.cyclonedx+json", &[], true),
+    ("application/vnd.cyclonedx+xml", &[], true),
+    ("application/vnd.d2l.coursepackage1p0+zip", &[], false),
+    ("application/vnd.d3m-dataset", &[], false),
+    ("application/vnd.d3m-problem", &[], false),
+    ("application/vnd.dart", &["dart"], false),
+    ("application/vnd.data-vision.rdz", &["rdz"], false),
+    ("application/vnd.datalog", &["dl"], false),
+    ("application/vnd.datapackage+json", &[], true),
+    ("application/vnd.dataresource+json", &[], true),
+    ("application/vnd.dbf", &["dbf"], false),
+    ("application/vnd.debian.binary-package", &["deb", "ddeb", "udeb"], false),
+    ("application/vnd.dece.data", &["uvf", "uvvf", "uvd", "uvvd"], false),
+    ("application/vnd.dece.ttml+xml", &["uvt", "uvvt"], true),
+    ("application/vnd.dece.unspecified", &["uvx", "uvvx"], false),
+    ("application/vnd.dece.zip", &["uvz", "uvvz"], false),
+    ("application/vnd.denovo.fcselayout-link", &["fe_launch"], false),
+    ("application/vnd.desmume.movie", &["dsm"], false),
+    ("application/vnd.dir-bi.plate-dl-nosuffix", &[], false),
+    ("application/vnd.dm.delegation+xml", &[], true),
+    ("application/vnd.dna", &["dna"], false),
+    ("application/vnd.document+json", &["docjson"], true),
+    ("application/vnd.dolby.mobile.1", &[], false),
+    ("application/vnd.dolby.mobile.2", &[], false),
+    ("application/vnd.doremir.scorecloud-binary-document", &["scld"], false),
+    ("application/vnd.dpgraph", &["dpg", "mwc", "dpgraph"], false),
+    ("application/vnd.dreamfactory", &["dfac"], false),
+    ("application/vnd.drive+json", &[], true),
+    ("application/vnd.dtg.local", &[], false),
+    ("application/vnd.dtg.local.flash", &["fla"], false),
+    ("application/vnd.dtg.local.html", &[], false),
+    ("application/vnd.dvb.ait", &["ait"], false),
+    ("application/vnd.dvb.dvbisl+xml", &[], true),
+    ("application/vnd.dvb.dvbj", &[], false),
+    ("application/vnd.dvb.esgcontainer", &[], false),
+    ("application/vnd.dvb.ipdcdftnotifaccess", &[], false),
+    ("application/vnd.dvb.ipdcesgaccess", &[], false),
+    ("application/vnd.dvb.ipdcesgaccess2", &[], false),
+    ("application/vnd.dvb.ipdcesgpdd", &[], false),
+    ("application/vnd.dvb.ipdcroaming", &[], false),
+    ("application/vnd.dvb.iptv.alfec-base", &[], false),
+    ("application/vnd.dvb.iptv.alfec-enhancement", &[], false),
+    ("application/vnd.dvb.notif-aggregate-root+xml", &[], true),
+    ("application/vnd.dvb.notif-container+xml", &[], true),
+    ("application/vnd.dvb.notif-generic+xml", &[], true),
+    ("application/vnd.dvb.notif-ia-msglist+xml", &[], true),
+    ("application/vnd.dvb.notif-ia-registration-request+xml", &[], true),
+    ("application/vnd.dvb.notif-ia-registration-response+xml", &[], true),
+    ("application/vnd.dvb.notif-init+xml", &[], true),
+    ("application/vnd.dvb.pfr", &[], false),
+    ("application/vnd.dvb.service", &["svc"], false),
+    ("application/vnd.dxr", &[], false),
+    ("application/vnd.dynageo", &["geo"], false),
+    ("application/vnd.dzr", &["dzr"], false),
+    ("application/vnd.easykaraoke.cdgdownload", &[], false),
+    ("application/vnd.ecdis-update", &[], false),
+    ("application/vnd.ecip.rlp", &[], false),
+    ("application/vnd.eclipse.ditto+json", &[], true),
+    ("application/vnd.ecowin.chart", &["mag"], false),
+    ("application/vnd.ecowin.filerequest", &[], false),
+    ("application/vnd.ecowin.fileupdate", &[], false),
+    ("application/vnd.ecowin.series", &[], false),
+    ("application/vnd.ecowin.seriesrequest", &[], false),
+    ("application/vnd.ecowin.seriesupdate", &[], false),
+    ("application/vnd.efi.img", &[], false),
+    ("application/vnd.efi.iso", &[], false),
+    ("application/vnd.eln+zip", &["eln"], false),
+    ("application/vnd.emclient.accessrequest+xml", &[], true),
+    ("application/vnd.enliven", &["nml"], false),
+    ("application/vnd.enphase.envoy", &[], false),
+    ("application/vnd.eprints.data+xml", &[], true),
+    ("application/vnd.epson.esf", &["esf"], false),
+    ("application/vnd.epson.msf", &["msf"], false),
+    ("application/vnd.epson.quickanime", &["qam"], false),
+    ("application/vnd.epson.salt", &["slt"], false),
+    ("application/vnd.epson.ssf", &["ssf"], false),
+    ("application/vnd.ericsson.quickcall", &["qcall", "qca"], false),
+    ("application/vnd.espass-espass+zip", &["espass"], false),
+    ("application/vnd.eszigno3+xml", &["es3", "et3"], true),
+    ("application/vnd.etsi.aoc+xml", &[], true),
+    ("application/vnd.etsi.asic-e+zip", &["asice", "sce"], false),
+    ("application/vnd.etsi.asic-s+zip", &["asics"], false),
+    ("application/vnd.etsi.cug+xml", &[], true),
+    ("application/vnd.etsi.iptvcommand+xml", &[], true),
+    ("application/vnd.etsi.iptvdiscovery+xml", &[], true),
+    ("application/vnd.etsi.iptvprofile+xml", &[], true),
+    ("application/vnd.etsi.iptvsad-bc+xml", &[], true),
+    ("application/vnd.etsi.iptvsad-cod+xml", &[], true),
+    ("application/vnd.etsi.iptvsad-npvr+xml", &[], true),
+    ("application/vnd.etsi.iptvservice+xml", &[], true),
+    ("application/vnd.etsi.iptvsync+xml", &[], true),
+    ("application/vnd.etsi.iptvueprofile+xml", &[], true),
+    ("application/vnd.etsi.mcid+xml", &[], true),
+    ("application/vnd.etsi.mheg5", &[], false),
+    ("application/vnd.etsi.overload-control-policy-dataset+xml", &[], true),
+    ("application/vnd.etsi.pstn+xml", &[], true),
+    ("application/vnd.etsi.sci+xml", &[], true),
+    ("application/vnd.etsi.simservs+xml", &[], true),
+    ("application/vnd.etsi.timestamp-token", &["tst"], false),
+    ("application/vnd.etsi.tsl+xml", &[], true),
+    ("application/vnd.etsi.tsl.der", &[], false),
+    ("application/vnd.eu.kasparian.car+json", &["carjson"], true),
+    ("application/vnd.eudora.data", &[], false),
+    ("application/vnd.evolv.ecig.profile", &["ecigprofile"], false),
+    ("application/vnd.evolv.ecig.settings", &["ecig"], false),
+    ("application/vnd.evolv.ecig.theme", &["ecigtheme"], false),
+    ("application/vnd.exstream-empower+zip", &["mpw"], false),
+    ("application/vnd.exstream-package", &["pub"], false),
+    ("application/vnd.ezpix-album", &["ez2"], false),
+    ("application/vnd.ezpix-package", &["ez3"], false),
+    ("application/vnd.f-secure.mobile", &[], false),
+    ("application/vnd.familysearch.gedcom+zip", &["gdz"], false),
+    ("application/vnd.fastcopy-disk-image", &["dim"], false),
+    ("application/vnd.fdsn.mseed", &["msd", "mseed"], false),
+    ("application/vnd.fdsn.seed", &["seed", "dataless"], false),
+    ("application/vnd.ffsns", &[], false),
+    ("application/vnd.ficlab.flb+zip", &["flb"], false),
+    ("application/vnd.filmit.zfc", &["zfc"], false),
+    ("application/vnd.fints", &[], false),
+    ("application/vnd.firemonkeys.cloudcell", &[], false),
+    ("application/vnd.flographit", &["gph"], false),
+    ("application/vnd.fluxtime.clip", &["ftc"], false),
+    ("application/vnd.font-fontforge-sfd", &["sfd"], false),
+    ("application/vnd.framemaker", &["fm"], false),
+    ("application/vnd.fsc.weblaunch", &["fsc"], false),
+    ("application/vnd.fujifilm.fb.docuworks", &[], false),
+    ("application/vnd.fujifilm.fb.docuworks.binder", &[], false),
+    ("application/vnd.fujifilm.fb.docuworks.container", &[], false),
+    ("application/vnd.fujifilm.fb.jfi+xml", &[], true),
+    ("application/vnd.fujitsu.oasys", &["oas"], false),
+    ("application/vnd.fujitsu.oasys2", &["oa2"], false),
+    ("application/vnd.fujitsu.oasys3", &["oa3"], false),
+    ("application/vnd.fujitsu.oasysgp", &["fg5"], false),
+    ("application/vnd.fujitsu.oasysprs", &["bh2"], false),
+    ("application/vnd.fujixerox.art-ex", &[], false),
+    ("application/vnd.fujixerox.art4", &[], false),
+    ("application/vnd.fujixerox.ddd", &["ddd"], false),
+    ("application/vnd.fujixerox.docuworks", &["xdw"], false),
+    ("application/vnd.fujixerox.docuworks.binder", &["xbd"], false),
+    ("application/vnd.fujixerox.docuworks.container", &["xct"], false),
+    ("application/vnd.fujixerox.hbpl", &[], false),
+    ("application/vnd.fut-misnet", &[], false),
+    ("application/vnd.futoin+cbor", &[], false),
+    ("application/vnd.futoin+json", &[], true),
+    ("application/vnd.fuzzysheet", &["fzs"], false),
+    ("application/vnd.genomatix.tuxedo", &["txd"], false),
+    ("application/vnd.genozip", &["genozip"], false),
+    ("application/vnd.gentics.grd+json", &["grd"], true),
+    ("application/vnd.gentoo.catmetadata+xml", &[], true),
+    ("application/vnd.gentoo.ebuild", &["ebuild"], false),
+    ("application/vnd.gentoo.eclass", &["eclass"], false),
+    ("application/vnd.gentoo.gpkg", &["gpkg.tar"], false),
+    ("application/vnd.gentoo.manifest", &[], false),
+    ("application/vnd.gentoo.pkgmetadata+xml", &[], true),
+    ("application/vnd.gentoo.xpak", &["xpak"], false),
+    ("application/vnd.geogebra.file", &["ggb"], false),
+    ("application/vnd.geogebra.slides", &["ggs"], false),
+    ("application/vnd.geogebra.tool", &["ggt"], false),
+    ("application/vnd.geometry-explorer", &["gex", "gre"], false),
+    ("application/vnd.geonext", &["gxt"], false),
+    ("application/vnd.geoplan", &["g2w"], false),
+    ("application/vnd.geospace", &["g3w"], false),
+    ("application/vnd.gerber", &[], false),
+    ("application/vnd.globalplatform.card-content-mgt", &[], false),
+    ("application/vnd.globalplatform.card-content-mgt-response", &[], false),
+    ("application/vnd.gnu.taler.exchange+json", &[], true),
+    ("application/vnd.gnu.taler.merchant+json", &[], true),
+    ("application/vnd.google-earth.kml+xml", &["kml"], true),
+    ("application/vnd.google-earth.kmz", &["kmz"], false),
+    ("application/vnd.gov.sk.e-form+xml", &[], true),
+    ("application/vnd.gov.sk.e-form+zip", &[], false),
+    ("application/vnd.gov.sk.xmldatacontainer+xml", &[], true),
+    ("application/vnd.gpxsee.map+xml", &[], true),
+    ("application/vnd.grafeq", &["gqf", "gqs"], false),
+    ("application/vnd.gridmp", &[], false),
+    ("application/vnd.groove-account", &["gac"], false),
+    ("application/vnd.groove-help", &["ghf"], false),
+    ("application/vnd.groove-identity-message", &["gim"], false),
+    ("application/vnd.groove-injector", &["grv"], false),
+    ("application/vnd.groove-tool-message", &["gtm"], false),
+    ("application/vnd.groove-tool-template", &["tpl"], false),
+    ("application/vnd.groove-vcard", &["vcg"], false),
+    ("application/vnd.hal+json", &[], true),
+    ("application/vnd.hal+xml", &["hal"], true),
+    ("application/vnd.handheld-entertainment+xml", &["zmm"], true),
+    ("application/vnd.hbci", &["hbci", "hbc", "kom", "upa", "pkd", "bpd"], false),
+    ("application/vnd.hc+json", &[], true),
+    ("application/vnd.hcl-bireports", &[], false),
+    ("application/vnd.hdt", &["hdt"], false),
+    ("application/vnd.heroku+json", &[], true),
+    ("application/vnd.hhe.lesson-player", &["les"], false),
+    ("application/vnd.hp-hpgl", &["hpgl"], false),
+    ("application/vnd.hp-hpid", &["hpi", "hpid"], false),
+    ("application/vnd.hp-hps", &["hps"], false),
+    ("application/vnd.hp-jlyt", &["jlt"], false),
+    ("application/vnd.hp-pcl", &["pcl"], false),
+    ("application/vnd.hp-pclxl", &[], false),
+    ("application/vnd.httphone", &[], false),
+    ("application/vnd.hydrostatix.sof-data", &["sfd-hdstx"], false),
+    ("application/vnd.hyper+json", &[], true),
+    ("application/vnd.hyper-item+json", &[], true),
+    ("application/vnd.hyperdrive+json", &[], true),
+    ("application/vnd.hzn-3d-crossword", &[], false),
+    ("application/vnd.ibm.electronic-media", &["emm"], false),
+    ("application/vnd.ibm.minipay", &["mpy"], false),
+    ("application/vnd.ibm.rights-management", &["irm"], false),
+    ("application/vnd.ibm.secure-container", &["sc"], false),
+    ("application/vnd.iccprofile", &["icc", "icm"], false),
+    ("application/vnd.ieee.1905", &["1905.1"], false),
+    ("application/vnd.igloader", &["igl"], false),
+    ("application/vnd.imagemeter.folder+zip", &["imf"], false),
+    ("application/vnd.imagemeter.image+zip", &["imi"], false),
+    ("application/vnd.immervision-ivp", &["ivp"], false),
+    ("application/vnd.immervision-ivu", &["ivu"], false),
+    ("application/vnd.ims.imsccv1p1", &["imscc"], false),
+    ("application/vnd.ims.imsccv1p2", &[], false),
+    ("application/vnd.ims.imsccv1p3", &[], false),
+    ("application/vnd.ims.lis.v2.result+json", &[], true),
+    ("application/vnd.ims.lti.v2.toolconsumerprofile+json", &[], true),
+    ("application/vnd.ims.lti.v2.toolproxy+json", &[], true),
+    ("application/vnd.ims.lti.v2.toolproxy.id+json", &[], true),
+    ("application/vnd.ims.lti.v2.toolsettings+json", &[], true),
+    ("application/vnd.ims.lti.v2.toolsettings.simple+json", &[], true),
+    ("application/vnd.informedcontrol.rms+xml", &[], true),
+    ("application/vnd.infotech.project", &[], false),
+    ("application/vnd.infotech.project+xml", &[], true),
+    ("application/vnd.innopath.wamp.notification", &[], false),
+    ("application/vnd.insors.igm", &["igm"], false),
+    ("application/vnd.intercon.formnet", &["xpw", "xpx"], false),
+    ("application/vnd.intergeo", &["i2g"], false),
+    ("application/vnd.intertrust.digibox", &[], false),
+    ("application/vnd.intertrust.nncp", &[], false),
+    ("application/vnd.intu.qbo", &["qbo"], false),
+    ("application/vnd.intu.qfx", &["qfx"], false),
+    ("application/vnd.ipld.car", &["car"], false),
+    ("application/vnd.ipld.dag-cbor", &[], false),
+    ("application/vnd.ipld.dag-json", &[], false),
+    ("application/vnd.ipld.raw", &[], false),
+    ("application/vnd.iptc.g2.catalogitem+xml", &[], true),
+    ("application/vnd.iptc.g2.conceptitem+xml", &[], true),
+    ("application/vnd.iptc.g2.knowledgeitem+xml", &[], true),
+    ("application/vnd.iptc.g2.newsitem+xml", &[], true),
+    ("application/vnd.iptc.g2.newsmessage+xml", &[], true),
+    ("application/vnd.iptc.g2.packageitem+xml", &[], true),
+    ("application/vnd.iptc.g2.planningitem+xml", &[], true),
+    ("application/vnd.ipunplugged.rcprofile", &["rcprofile"], false),
+    ("application/vnd.irepository.package+xml", &["irp"], true),
+    ("application/vnd.is-xpr", &["xpr"], false),
+    ("application/vnd.isac.fcs", &["fcs"], false),
+    ("application/vnd.iso11783-10+zip", &[], false),
+    ("application/vnd.jam", &["jam"], false),
+    ("application/vnd.japannet-directory-service", &[], false),
+    ("application/vnd.japannet-jpnstore-wakeup", &[], false),
+    ("application/vnd.japannet-payment-wakeup", &[], false),
+    ("application/vnd.japannet-registration", &[], false),
+    ("application/vnd.japannet-registration-wakeup", &[], false),
+    ("application/vnd.japannet-setstore-wakeup", &[], false),
+    ("application/vnd.japannet-verification", &[], false),
+    ("application/vnd.japannet-verification-wakeup", &[], false),
+    ("application/vnd.jcp.javame.midlet-rms", &["rms"], false),
+    ("application/vnd.jisp", &["jisp"], false),
+    ("application/vnd.joost.joda-archive", &["joda"], false),
+    ("application/vnd.jsk.isdn-ngn", &[], false),
+    ("application/vnd.kahootz", &["ktz", "ktr"], false),
+    ("application/vnd.kde.karbon", &["karbon"], false),
+    ("application/vnd.kde.kchart", &["chrt"], false),
+    ("application/vnd.kde.kformula", &["kfo"], false),
+    ("application/vnd.kde.kivio", &["flw"], false),
+    ("application/vnd.kde.kontour", &["kon"], false),
+    ("application/vnd.kde.kpresenter", &["kpr", "kpt"], false),
+    ("application/vnd.kde.kspread", &["ksp"], false),
+    ("application/vnd.kde.kword", &["kwd", "kwt"], false),
+    ("application/vnd.kenameaapp", &["htke"], false),
+    ("application/vnd.kidspiration", &["kia"], false),
+    ("application/vnd.kinar", &["kne", "knp", "sdf"], false),
+    ("application/vnd.koan", &["skp", "skd", "skm", "skt"], false),
+    ("application/vnd.kodak-descriptor", &["sse"], false),
+    ("application/vnd.las", &["las"], false),
+    ("application/vnd.las.las+json", &["lasjson"], true),
+    ("application/vnd.las.las+xml", &["lasxml"], true),
+    ("application/vnd.laszip", &[], false),
+    ("application/vnd.leap+json", &[], true),
+    ("application/vnd.liberty-request+xml", &[], true),
+    ("application/vnd.llamagraphics.life-balance.desktop", &["lbd"], false),
+    ("application/vnd.llamagraphics.life-balance.exchange+xml", &["lbe"], true),
+    ("application/vnd.logipipe.circuit+zip", &["lcs", "lca"], false),
+    ("application/vnd.loom", &["loom"], false),
+    ("application/vnd.lotus-1-2-3", &["123", "wk4", "wk3", "wk1"], false),
+    ("application/vnd.lotus-approach", &["apr", "vew"], false),
+    ("application/vnd.lotus-freelance", &["prz", "pre"], false),
+    ("application/vnd.lotus-notes", &["nsf", "ntf", "ndl", "ns4", "ns3", "ns2", "nsh", "nsg"], false),
+    ("application/vnd.lotus-organizer", &["or3", "or2", "org"], false),
+    ("application/vnd.lotus-screencam", &["scm"], false),
+    ("application/vnd.lotus-wordpro", &["lwp", "sam"], false),
+    ("application/vnd.macports.portpkg", &["portpkg"], false),
+    ("application/vnd.mapbox-vector-tile", &["mvt"], false),
+    ("application/vnd.marlin.drm.actiontoken+xml", &[], true),
+    ("application/vnd.marlin.drm.conftoken+xml", &[], true),
+    ("application/vnd.marlin.drm.license+xml", &[], true),
+    ("application/vnd.marlin.drm.mdcf", &["mdc"], false),
+    ("application/vnd.mason+json", &[], true),
+    ("application/vnd.maxar.archive.3tz+zip", &["3tz"], false),
+    ("application/vnd.maxmind.maxmind-db", &["mmdb"], false),
+    ("application/vnd.mcd", &["mcd"], false),
+    ("application/vnd.medcalcdata", &["mc1"], false),
+    ("application/vnd.mediastation.cdkey", &["cdkey"], false),
+    ("application/vnd.medicalholodeck.recordxr", &["rxt"], false),
+    ("application/vnd.meridian-slingshot", &[], false),
+    ("application/vnd.mfer", &["mwf"], false),
+    ("application/vnd.mfmp", &["mfm"], false),
+    ("application/vnd.micro+json", &[], true),
+    ("application/vnd.micrografx.flo", &["flo"], false),
+    ("application/vnd.micrografx.igx", &["igx"], false),
+    ("application/vnd.microsoft.portable-executable", &[], false),
+    ("application/vnd.microsoft.windows.thumbnail-cache", &[], false),
+    ("application/vnd.miele+json", &[], true),
+    ("application/vnd.mif", &["mif"], false),
+    ("application/vnd.minisoft-hp3000-save", &[], false),
+    ("application/vnd.mitsubishi.misty-guard.trustweb", &[], false),
+    ("application/vnd.mobius.daf", &["daf"], false),
+    ("application/vnd.mobius.dis", &["dis"], false),
+    ("application/vnd.mobius.mbk", &["mbk"], false),
+    ("application/vnd.mobius.mqy", &["mqy"], false),
+    ("application/vnd.mobius.msl", &["msl"], false),
+    ("application/vnd.mobius.plc", &["plc"], false),
+    ("application/vnd.mobius.txf", &["txf"], false),
+    ("application/vnd.mophun.application", &["mpn"], false),
+    ("application/vnd.mophun.certificate", &["mpc"], false),
+    ("application/vnd.motorola.flexsuite", &[], false),
+    ("application/vnd.motorola.flexsuite.adsi", &[], false),
+    ("application/vnd.motorola.flexsuite.fis", &[], false),
+    ("application/vnd.motorola.flexsuite.gotap", &[], false),
+    ("application/vnd.motorola.flexsuite.kmr", &[], false),
+    ("application/vnd.motorola.flexsuite.ttc", &[], false),
+    ("application/vnd.motorola.flexsuite.wem", &[], false),
+    ("application/vnd.motorola.iprm", &[], false),
+    ("application/vnd.mozilla.xul+xml", &["xul"], true),
+    ("application/vnd.ms-3mfdocument", &["3mf"], false),
+    ("application/vnd.ms-artgalry", &["cil"], false),
+    ("application/vnd.ms-asf", &["asf"], false),
+    ("application/vnd.ms-cab-compressed", &["cab"], false),
+    ("application/vnd.ms-excel", &["xls", "xlm", "xla", "xlc", "xlt", "xlw"], false),
+    ("application/vnd.ms-excel.addin.macroenabled.12", &["xlam"], false),
+    ("application/vnd.ms-excel.sheet.binary.macroenabled.12", &["xlsb"], false),
+    ("application/vnd.ms-excel.sheet.macroenabled.12", &["xlsm"], false),
+    ("application/vnd.ms-excel.template.macroenabled.12", &["xltm"], false),
+    ("application/vnd.ms-fontobject", &["eot"], true),
+    ("application/vnd.ms-htmlhelp", &["chm"], false),
+    ("application/vnd.ms-ims", &["ims"], false),
+    ("application/vnd.ms-lrm", &["lrm"], false),
+    ("application/vnd.ms-office.activex+xml", &[], true),
+    ("application/vnd.ms-officetheme", &["thmx"], false),
+    ("application/vnd.ms-pki.seccat", &["cat"], false),
+    ("application/vnd.ms-playready.initiator+xml", &[], true),
+    ("application/vnd.ms-powerpoint", &["ppt", "pps"], false),
+    ("application/vnd.ms-powerpoint.addin.macroenabled.12", &["ppam"], false),
+    ("application/vnd.ms-powerpoint.presentation.macroenabled.12", &["pptm"], false),
+    ("application/vnd.ms-powerpoint.slide.macroenabled.12", &["sldm"], false),
+    ("application/vnd.ms-powerpoint.slideshow.macroenabled.12", &["ppsm"], false),
+    ("application/vnd.ms-powerpoint.template.macroenabled.12", &["potm"], false),
+    ("application/vnd.ms-printdevicecapabilities+xml", &[], true),
+    ("application/vnd.ms-printschematicket+xml", &[], true),
+    ("application/vnd.ms-project", &["mpp", "mpt"], false),
+    ("application/vnd.ms-tnef", &["tnef", "tnf"], false),
+    ("application/vnd.ms-windows.devicepairing", &[], false),
+    ("application/vnd.ms-windows.nwprinting.oob", &[], false),
+    ("application/vnd.ms-windows.printerpairing", &[], false),
+    ("application/vnd.ms-windows.wsd.oob", &[], false),
+    ("application/vnd.ms-wmdrm.lic-chlg-req", &[], false),
+    ("application/vnd.ms-wmdrm.lic-resp", &[], false),
+    ("application/vnd.ms-wmdrm.meter-chlg-req", &[], false),
+    ("application/vnd.ms-wmdrm.meter-resp", &[], false),
+    ("application/vnd.ms-word.document.macroenabled.12", &["docm"], false),
+    ("application/vnd.ms-word.template.macroenabled.12", &["dotm"], false),
+    ("application/vnd.ms-works", &["wcm", "wdb", "wks", "wps"], false),
+    ("application/vnd.ms-wpl", &["wpl"], false),
+    ("application/vnd.ms-xpsdocument", &["xps"], false),
+    ("application/vnd.msa-disk-image", &["msa"], false),
+    ("application/vnd.mseq", &["mseq"], false),
+    ("application/vnd.msign", &[], false),
+    ("application/vnd.multiad.creator", &["crtr"], false),
+    ("application/vnd.multiad.creator.cif", &["cif"], false),
+    ("application/vnd.music-niff", &[], false),
+    ("application/vnd.musician", &["mus"], false),
+    ("application/vnd.muvee.style", &["msty"], false),
+    ("application/vnd.mynfc", &["taglet"], false),
+    ("application/vnd.nacamar.ybrid+json", &[], true),
+    ("application/vnd.ncd.control", &[], false),
+    ("application/vnd.ncd.reference", &[], false),
+    ("application/vnd.nearst.inv+json", &[], true),
+    ("application/vnd.nebumind.line", &["nebul", "line"], false),
+    ("application/vnd.nervana", &["entity", "request", "bkm", "kcm"], false),
+    ("application/vnd.netfpx", &[], false),
+    ("application/vnd.neurolanguage.nlu", &["nlu"], false),
+    ("application/vnd.nimn", &["nimn"], false),
+    ("application/vnd.nintendo.nitro.rom", &["nds"], false),
+    ("application/vnd.nintendo.snes.rom", &["sfc", "smc"], false),
+    ("application/vnd.nitf", &["nitf"], false),
+    ("application/vnd.noblenet-directory", &["nnd"], false),
+    ("application/vnd.noblenet-sealer", &["nns"], false),
+    ("application/vnd.noblenet-web", &["nnw"], false),
+    ("application/vnd.nokia.catalogs", &[], false),
+    ("application/vnd.nokia.conml+wbxml", &[], false),
+    ("application/vnd.nokia.conml+xml", &[], true),
+    ("application/vnd.nokia.iptv.config+xml", &[], true),
+    ("application/vnd.nokia.isds-radio-presets", &[], false),
+    ("application/vnd.nokia.landmark+wbxml", &[], false),
+    ("application/vnd.nokia.landmark+xml", &[], true),
+    ("application/vnd.nokia.landmarkcollection+xml", &[], true),
+    ("application/vnd.nokia.n-gage.ac+xml", &[], true),
+    ("application/vnd.nokia.n-gage.data", &["ngdat"], false),
+    ("application/vnd.nokia.ncd", &[], false),
+    ("application/vnd.nokia.pcd+wbxml", &[], false),
+    ("application/vnd.nokia.pcd+xml", &[], true),
+    ("application/vnd.nokia.radio-preset", &["rpst"], false),
+    ("application/vnd.nokia.radio-presets", &["rpss"], false),
+    ("application/vnd.novadigm.edm", &["edm"], false),
+    ("application/vnd.novadigm.edx", &["edx"], false),
+    ("application/vnd.novadigm.ext", &["ext"], false),
+    ("application/vnd.ntt-local.content-share", &[], false),
+    ("application/vnd.ntt-local.file-transfer", &[], false),
+    ("application/vnd.ntt-local.ogw_remote-access", &[], false),
+    ("application/vnd.ntt-local.sip-ta_remote", &[], false),
+    ("application/vnd.ntt-local.sip-ta_tcp_stream", &[], false),
+    ("application/vnd.oasis.opendocument.base", &["odb"], false),
+    ("application/vnd.oasis.opendocument.chart", &["odc"], false),
+    ("application/vnd.oasis.opendocument.chart-template", &["otc"], false),
+    ("application/vnd.oasis.opendocument.formula", &["odf"], false),
+    ("application/vnd.oasis.opendocument.formula-template", &[], false),
+    ("application/vnd.oasis.opendocument.graphics", &["odg"], false),
+    ("application/vnd.oasis.opendocument.graphics-template", &["otg"], false),
+    ("application/vnd.oasis.opendocument.image", &["odi"], false),
+    ("application/vnd.oasis.opendocument.image-template", &["oti"], false),
+    ("application/vnd.oasis.opendocument.presentation", &["odp"], false),
+    ("application/vnd.oasis.opendocument.presentation-template", &["otp"], false),
+    ("application/vnd.oasis.opendocument.spreadsheet", &["ods"], false),
+    ("application/vnd.oasis.opendocument.spreadsheet-template", &["ots"], false),
+    ("application/vnd.oasis.opendocument.text", &["odt"], false),
+    ("application/vnd.oasis.opendocument.text-master", &["odm"], false),
+    ("application/vnd.oasis.opendocument.text-template", &["ott"], false),
+    ("application/vnd.oasis.opendocument.text-web", &["oth"], false),
+    ("application/vnd.obn", &[], false),
+    ("application/vnd.ocf+cbor", &[], false),
+    ("application/vnd.oci.image.manifest.v1+json", &[], true),
+    ("application/vnd.oftn.l10n+json", &[], true),
+    ("application/vnd.oipf.contentaccessdownload+xml", &[], true),
+    ("application/vnd.oipf.contentaccessstreaming+xml", &[], true),
+    ("application/vnd.oipf.cspg-hexbinary", &[], false),
+    ("application/vnd.oipf.dae.svg+xml", &[], true),
+    ("application/vnd.oipf.dae.xhtml+xml", &[], true),
+    ("application/vnd.oipf.mippvcontrolmessage+xml", &[], true),
+    ("application/vnd.oipf.pae.gem", &[], false),
+    ("application/vnd.oipf.spdiscovery+xml", &[], true),
+    ("application/vnd.oipf.spdlist+xml", &[], true),
+    ("application/vnd.oipf.ueprofile+xml", &[], true),
+    ("application/vnd.oipf.userprofile+xml", &[], true),
+    ("application/vnd.olpc-sugar", &["xo"], false),
+    ("application/vnd.oma-scws-config", &[], false),
+    ("application/vnd.oma-scws-http-request", &[], false),
+    ("application/vnd.oma-scws-http-response", &[], false),
+    ("application/vnd.oma.bcast.associated-procedure-parameter+xml", &[], true),
+    ("application/vnd.oma.bcast.drm-trigger+xml", &[], true),
+    ("application/vnd.oma.bcast.imd+xml", &[], true),
+    ("application/vnd.oma.bcast.ltkm", &[], false),
+    ("application/vnd.oma.bcast.notification+xml", &[], true),
+    ("application/vnd.oma.bcast.provisioningtrigger", &[], false),
+    ("application/vnd.oma.bcast.sgboot", &[], false),
+    ("application/vnd.oma.bcast.sgdd+xml", &[], true),
+    ("application/vnd.oma.bcast.sgdu", &[], false),
+    ("application/vnd.oma.bcast.simple-symbol-container", &[], false),
+    ("application/vnd.oma.bcast.smartcard-trigger+xml", &[], true),
+    ("application/vnd.oma.bcast.sprov+xml", &[], true),
+    ("application/vnd.oma.bcast.stkm", &[], false),
+    ("application/vnd.oma.cab-address-book+xml", &[], true),
+    ("application/vnd.oma.cab-feature-handler+xml", &[], true),
+    ("application/vnd.oma.cab-pcc+xml", &[], true),
+    ("application/vnd.oma.cab-subs-invite+xml", &[], true),
+    ("application/vnd.oma.cab-user-prefs+xml", &[], true),
+    ("application/vnd.oma.dcd", &[], false),
+    ("application/vnd.oma.dcdc", &[], false),
+    ("application/vnd.oma.dd2+xml", &["dd2"], true),
+    ("application/vnd.oma.drm.risd+xml", &[], true),
+    ("application/vnd.oma.group-usage-list+xml", &[], true),
+    ("application/vnd.oma.lwm2m+cbor", &[], false),
+    ("application/vnd.oma.lwm2m+json", &[], true),
+    ("application/vnd.oma.lwm2m+tlv", &[], false),
+    ("application/vnd.oma.pal+xml", &[], true),
+    ("application/vnd.oma.poc.detailed-progress-report+xml", &[], true),
+    ("application/vnd.oma.poc.final-report+xml", &[], true),
+    ("application/vnd.oma.poc.groups+xml", &[], true),
+    ("application/vnd.oma.poc.invocation-descriptor+xml", &[], true),
+    ("application/vnd.oma.poc.optimized-progress-report+xml", &[], true),
+    ("application/vnd.oma.push", &[], false),
+    ("application/vnd.oma.scidm.messages+xml", &[], true),
+    ("application/vnd.oma.xcap-directory+xml", &[], true),
+    ("application/vnd.omads-email+xml", &[], true),
+    ("application/vnd.omads-file+xml", &[], true),
+    ("application/vnd.omads-folder+xml", &[], true),
+    ("application/vnd.omaloc-supl-init", &[], false),
+    ("application/vnd.onepager", &["tam"], false),
+    ("application/vnd.onepagertamp", &["tamp"], false),
+    ("application/vnd.onepagertamx", &["tamx"], false),
+    ("application/vnd.onepagertat", &["tat"], false),
+    ("application/vnd.onepagertatp", &["tatp"], false),
+    ("application/vnd.onepagertatx", &["tatx"], false),
+    ("application/vnd.onvif.metadata", &[], false),
+    ("application/vnd.openblox.game+xml", &["obgx"], true),
+    ("application/vnd.openblox.game-binary", &["obg"], false),
+    ("application/vnd.openeye.oeb", &["oeb"], false),
+    ("application/vnd.openofficeorg.extension", &["oxt"], false),
+    ("application/vnd.openstreetmap.data+xml", &["osm"], true),
+    ("application/vnd.opentimestamps.ots", &[], false),
+    ("application/vnd.openxmlformats-officedocument.custom-properties+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.customxmlproperties+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.drawing+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.drawingml.chart+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.drawingml.chartshapes+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.drawingml.diagramcolors+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.drawingml.diagramdata+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.drawingml.diagramlayout+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.drawingml.diagramstyle+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.extended-properties+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.commentauthors+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.comments+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.handoutmaster+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.notesmaster+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.notesslide+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.presentation", &["pptx"], false),
+    ("application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.presprops+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.slide", &["sldx"], false),
+    ("application/vnd.openxmlformats-officedocument.presentationml.slide+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.slidelayout+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.slidemaster+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.slideshow", &["ppsx"], false),
+    ("application/vnd.openxmlformats-officedocument.presentationml.slideshow.main+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.slideupdateinfo+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.tablestyles+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.tags+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.template", &["potx"], false),
+    ("application/vnd.openxmlformats-officedocument.presentationml.template.main+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.presentationml.viewprops+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.calcchain+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.connections+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.dialogsheet+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.externallink+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.pivotcachedefinition+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.pivotcacherecords+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.pivottable+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.querytable+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.revisionheaders+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.revisionlog+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.sharedstrings+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet", &["xlsx"], false),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.sheetmetadata+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.tablesinglecells+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.template", &["xltx"], false),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.usernames+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.volatiledependencies+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.theme+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.themeoverride+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.vmldrawing", &[], false),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.comments+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.document", &["docx"], false),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.document.glossary+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.endnotes+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.fonttable+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.settings+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.template", &["dotx"], false),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.template.main+xml", &[], true),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.websettings+xml", &[], true),
+    ("application/vnd.openxmlformats-package.core-properties+xml", &[], true),
+    ("application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml", &[], true),
+    ("application/vnd.openxmlformats-package.relationships+xml", &[], true),
+    ("application/vnd.oracle.resource+json", &[], true),
+    ("application/vnd.orange.indata", &[], false),
+    ("application/vnd.osa.netdeploy", &["ndc"], false),
+    ("application/vnd.osgeo.mapguide.package", &["mgp"], false),
+    ("application/vnd.osgi.bundle", &[], false),
+    ("application/vnd.osgi.dp", &["dp"], false),
+    ("application/vnd.osgi.subsystem", &["esa"], false),
+    ("application/vnd.otps.ct-kip+xml", &[], true),
+    ("application/vnd.oxli.countgraph", &["oxlicg"], false),
+    ("application/vnd.pagerduty+json", &[], true),
+    ("application/vnd.palm", &["pdb", "pqa", "oprc"], false),
+    ("application/vnd.panoply", &["plp"], false),
+    ("application/vnd.paos.xml", &[], false),
+    ("application/vnd.patentdive", &["dive"], false),
+    ("application/vnd.patientecommsdoc", &[], false),
+    ("application/vnd.pawaafile", &["paw"], false),
+    ("application/vnd.pcos", &[], false),
+    ("application/vnd.pg.format", &["str"], false),
+    ("application/vnd.pg.osasli", &["ei6"], false),
+    ("application/vnd.piaccess.application-licence", &["pil"], false),
+    ("application/vnd.picsel", &["efif"], false),
+    ("application/vnd.pmi.widget", &["wg"], false),
+    ("application/vnd.poc.group-advertisement+xml", &[], true),
+    ("application/vnd.pocketlearn", &["plf"], false),
+    ("application/vnd.powerbuilder6", &["pbd"], false),
+    ("application/vnd.powerbuilder6-s", &[], false),
+    ("application/vnd.powerbuilder7", &[], false),
+    ("application/vnd.powerbuilder7-s", &[], false),
+    ("application/vnd.powerbuilder75", &[], false),
+    ("application/vnd.powerbuilder75-s", &[], false),
+    ("application/vnd.preminet", &["preminet"], false),
+    ("application/vnd.previewsystems.box", &["box", "vbox"], false),
+    ("application/vnd.proteus.magazine", &["mgz"], false),
+    ("application/vnd.psfs", &["psfs"], false),
+    ("application/vnd.publishare-delta-tree", &["qps"], false),
+    ("application/vnd.pvi.ptid1", &["ptid"], false),
+    ("application/vnd.pwg-multiplexed", &[], false),
+    ("application/vnd.pwg-xhtml-print+xml", &[], true),
+    ("application/vnd.qualcomm.brew-app-res", &["bar"], false),
+    ("application/vnd.quarantainenet", &[], false),
+    ("application/vnd.quark.quarkxpress", &["qxd", "qxt", "qwd", "qwt", "qxl", "qxb"], false),
+    ("application/vnd.quobject-quoxdocument", &["quox", "quiz"], false),
+    ("application/vnd.radisys.moml+xml", &[], true),
+    ("application/vnd.radisys.msml+xml", &[], true),
+    ("application/vnd.radisys.msml-audit+xml", &[], true),
+    ("application/vnd.radisys.msml-audit-conf+xml", &[], true),
+    ("application/vnd.radisys.msml-audit-conn+xml", &[], true),
+    ("application/vnd.radisys.msml-audit-dialog+xml", &[], true),
+    ("application/vnd.radisys.msml-audit-stream+xml", &[], true),
+    ("application/vnd.radisys.msml-conf+xml", &[], true),
+    ("application/vnd.radisys.msml-dialog+xml", &[], true),
+    ("application/vnd.radisys.msml-dialog-base+xml", &[], true),
+    ("application/vnd.radisys.msml-dialog-fax-detect+xml", &[], true),
+    ("application/vnd.radisys.msml-dialog-fax-sendrecv+xml", &[], true),
+    ("application/vnd.radisys.msml-dialog-group+xml", &[], true),
+    ("application/vnd.radisys.msml-dialog-speech+xml", &[], true),
+    ("application/vnd.radisys.msml-dialog-transform+xml", &[], true),
+    ("application/vnd.rainstor.data", &["tree"], false),
+    ("application/vnd.rapid", &[], false),
+    ("application/vnd.rar", &["rar"], false),
+    ("application/vnd.realvnc.bed", &["bed"], false),
+    ("application/vnd.recordare.musicxml", &["mxl"], false),
+    ("application/vnd.recordare.musicxml+xml", &[], true),
+    ("application/vnd.renlearn.rlprint", &[], false),
+    ("application/vnd.resilient.logic", &["rlm", "reload"], false),
+    ("application/vnd.restful+json", &[], true),
+    ("application/vnd.rig.cryptonote", &["cryptonote"], false),
+    ("application/vnd.rim.cod", &["cod"], false),
+    ("application/vnd.route66.link66+xml", &["link66"], true),
+    ("application/vnd.rs-274x", &[], false),
+    ("application/vnd.ruckus.download", &[], false),
+    ("application/vnd.s3sms", &[], false),
+    ("application/vnd.sailingtracker.track", &["st"], false),
+    ("application/vnd.sar", &["sar"], false),
+    ("application/vnd.sbm.cid", &[], false),
+    ("application/vnd.sbm.mid2", &[], false),
+    ("application/vnd.scribus", &["scd", "sla", "slaz"], false),
+    ("application/vnd.sealed.3df", &["s3df"], false),
+    ("application/vnd.sealed.csf", &["scsf"], false),
+    ("application/vnd.sealed.doc", &["sdoc", "sdo", "s1w"], false),
+    ("application/vnd.sealed.eml", &["seml", "sem"], false),
+    ("application/vnd.sealed.mht", &["smht", "smh"], false),
+    ("application/vnd.sealed.net", &[], false),
+    ("application/vnd.sealed.ppt", &["sppt", "s1p"], false),
+    ("application/vnd.sealed.tiff", &["stif"], false),
+    ("application/vnd.sealed.xls", &["sxls", "sxl", "s1e"], false),
+    ("application/vnd.sealedmedia.softseal.html", &["stml", "s1h"], false),
+    ("application/vnd.sealedmedia.softseal.pdf", &["spdf", "spd", "s1a"], false),
+    ("application/vnd.seemail", &["see"], false),
+    ("application/vnd.seis+json", &[], true),
+    ("application/vnd.sema", &["sema"], false),
+    ("application/vnd.semd", &["semd"], false),
+    ("application/vnd.semf", &["semf"], false),
+    ("application/vnd.shade-save-file", &["ssv"], false),
+    ("application/vnd.shana.informed.formdata", &["ifm"], false),
+    ("application/vnd.shana.informed.formtemplate", &["itp"], false),
+    ("application/vnd.shana.informed.interchange", &["iif"], false),
+    ("application/vnd.shana.informed.package", &["ipk"], false),
+    ("application/vnd.shootproof+json", &[], true),
+    ("application/vnd.shopkick+json", &[], true),
+    ("application/vnd.shp", &["shp"], false),
+    ("application/vnd.shx", &["shx"], false),
+    ("application/vnd.sigrok.session", &["sr"], false),
+    ("application/vnd.simtech-mindmapper", &["twd", "twds"], false),
+    ("application/vnd.siren+json", &[], true),
+    ("application/vnd.smaf", &["mmf"], false),
+    ("application/vnd.smart.notebook", &["notebook"], false),
+    ("application/vnd.smart.teacher", &["teacher"], false),
+    ("application/vnd.snesdev-page-table", &["ptrom", "pt"], false),
+    ("application/vnd.software602.filler.form+xml", &["fo"], true),
+    ("application/vnd.software602.filler.form-xml-zip", &["zfo"], false),
+    ("application/vnd.solent.sdkm+xml", &["sdkm", "sdkd"], true),
+    ("application/vnd.spotfire.dxp", &["dxp"], false),
+    ("application/vnd.spotfire.sfs", &["sfs"], false),
+    ("application/vnd.sqlite3", &["sqlite", "sqlite3"], false),
+    ("application/vnd.sss-cod", &[], false),
+    ("application/vnd.sss-dtf", &[], false),
+    ("application/vnd.sss-ntf", &[], false),
+    ("application/vnd.stardivision.calc", &["sdc"], false),
+    ("application/vnd.stardivision.chart", &["sds"], false),
+    ("application/vnd.stardivision.draw", &["sda"], false),
+    ("application/vnd.stardivision.impress", &["sdd"], false),
+    ("application/vnd.stardivision.math", &["smf"], false),
+    ("application/vnd.stardivision.writer", &["sdw"], false),
+    ("application/vnd.stardivision.writer-global", &["sgl"], false),
+    ("application/vnd.stepmania.package", &["smzip"], false),
+    ("application/vnd.stepmania.stepchart", &["sm"], false),
+    ("application/vnd.street-stream", &[], false),
+    ("application/vnd.sun.wadl+xml", &["wadl"], true),
+    ("application/vnd.sun.xml.calc", &["sxc"], false),
+    ("application/vnd.sun.xml.calc.template", &["stc"], false),
+    ("application/vnd.sun.xml.draw", &["sxd"], false),
+    ("application/vnd.sun.xml.draw.template", &["std"], false),
+    ("application/vnd.sun.xml.impress", &["sxi"], false),
+    ("application/vnd.sun.xml.impress.template", &["sti"], false),
+    ("application/vnd.sun.xml.math", &["sxm"], false),
+    ("application/vnd.sun.xml.writer", &["sxw"], false),
+    ("application/vnd.sun.xml.writer.global", &["sxg"], false),
+    ("application/vnd.sun.xml.writer.template", &["stw"], false),
+    ("application/vnd.sus-calendar", &["sus", "susp"], false),
+    ("application/vnd.svd", &[], false),
+    ("application/vnd.swiftview-ics", &[], false),
+    ("application/vnd.sybyl.mol2", &["ml2", "mol2", "sy2"], false),
+    ("application/vnd.sycle+xml", &["scl"], true),
+    ("application/vnd.syft+json", &["syft.json"], true),
+    ("application/vnd.symbian.install", &["sis"], false),
+    ("application/vnd.syncml+xml", &["xsm"], true),
+    ("application/vnd.syncml.dm+wbxml", &["bdm"], false),
+    ("application/vnd.syncml.dm+xml", &["xdm"], true),
+    ("application/vnd.syncml.dm.notification", &[], false),
+    ("application/vnd.syncml.dmddf+wbxml", &[], false),
+    ("application/vnd.syncml.dmddf+xml", &["ddf"], true),
+    ("application/vnd.syncml.dmtnds+wbxml", &[], false),
+    ("application/vnd.syncml.dmtnds+xml", &[], true),
+    ("application/vnd.syncml.ds.notification", &[], false),
+    ("application/vnd.tableschema+json", &[], true),
+    ("application/vnd.tao.intent-module-archive", &["tao"], false),
+    ("application/vnd.tcpdump.pcap", &["pcap", "cap", "dmp"], false),
+    ("application/vnd.theqvd", &["qvd"], false),
+    ("application/vnd.think-cell.ppttc+json", &["ppttc"], true),
+    ("application/vnd.tmd.mediaflex.api+xml", &[], true),
+    ("application/vnd.tml", &["vfr", "viaframe"], false),
+    ("application/vnd.tmobile-livetv", &["tmo"], false),
+    ("application/vnd.tri.onesource", &[], false),
+    ("application/vnd.trid.tpt", &["tpt"], false),
+    ("application/vnd.triscape.mxs", &["mxs"], false),
+    ("application/vnd.trueapp", &["tra"], false),
+    ("application/vnd.truedoc", &[], false),
+    ("application/vnd.ubisoft.webplayer", &[], false),
+    ("application/vnd.ufdl", &["ufdl", "ufd", "frm"], false),
+    ("application/vnd.uiq.theme", &["utz"], false),
+    ("application/vnd.umajin", &["umj"], false),
+    ("application/vnd.unity", &["unityweb"], false),
+    ("application/vnd.uoml+xml", &["uoml", "uo"], true),
+    ("application/vnd.uplanet.alert", &[], false),
+    ("application/vnd.uplanet.alert-wbxml", &[], false),
+    ("application/vnd.uplanet.bearer-choice", &[], false),
+    ("application/vnd.uplanet.bearer-choice-wbxml", &[], false),
+    ("application/vnd.uplanet.cacheop", &[], false),
+    ("application/vnd.uplanet.cacheop-wbxml", &[], false),
+    ("application/vnd.uplanet.channel", &[], false),
+    ("application/vnd.uplanet.channel-wbxml", &[], false),
+    ("application/vnd.uplanet.list", &[], false),
+    ("application/vnd.uplanet.list-wbxml", &[], false),
+    ("application/vnd.uplanet.listcmd", &[], false),
+    ("application/vnd.uplanet.listcmd-wbxml", &[], false),
+    ("application/vnd.uplanet.signal", &[], false),
+    ("application/vnd.uri-map", &["urim", "urimap"], false),
+    ("application/vnd.valve.source.material", &["vmt"], false),
+    ("application/vnd.vcx", &["vcx"], false),
+    ("application/vnd.vd-study", &["mxi", "study-inter", "model-inter"], false),
+    ("application/vnd.vectorworks", &["vwx"], false),
+    ("application/vnd.vel+json", &[], true),
+    ("application/vnd.verimatrix.vcas", &[], false),
+    ("application/vnd.veritone.aion+json", &["aion", "vtnstd"], true),
+    ("application/vnd.veryant.thin", &["istc", "isws"], false),
+    ("application/vnd.ves.encrypted", &["ves"], false),
+    ("application/vnd.vidsoft.vidconference", &["vsc"], false),
+    ("application/vnd.visio", &["vsd", "vst", "vsw", "vss"], false),
+    ("application/vnd.visionary", &["vis"], false),
+    ("application/vnd.vividence.scriptfile", &[], false),
+    ("application/vnd.vsf", &["vsf"], false),
+    ("application/vnd.wap.sic", &["sic"], false),
+    ("application/vnd.wap.slc", &["slc"], false),
+    ("application/vnd.wap.wbxml", &["wbxml"], false),
+    ("application/vnd.wap.wmlc", &["wmlc"], false),
+    ("application/vnd.wap.wmlscriptc", &["wmlsc"], false),
+    ("application/vnd.wasmflow.wafl", &["wafl"], false),
+    ("application/vnd.webturbo", &["wtb"], false),
+    ("application/vnd.wfa.dpp", &[], false),
+    ("application/vnd.wfa.p2p", &["p2p"], false),
+    ("application/vnd.wfa.wsc", &["wsc"], false),
+    ("application/vnd.windows.devicepairing", &[], false),
+    ("application/vnd.wmc", &["wmc"], false),
+    ("application/vnd.wmf.bootstrap", &[], false),
+    ("application/vnd.wolfram.mathematica", &["nb"], false),
+    ("application/vnd.wolfram.mathematica.package", &["m"], false),
+    ("application/vnd.wolfram.player", &["nbp"], false),
+    ("application/vnd.wordlift", &[], false),
+    ("application/vnd.wordperfect", &["wpd"], false),
+    ("application/vnd.wqd", &["wqd"], false),
+    ("application/vnd.wrq-hp3000-labelled", &[], false),
+    ("application/vnd.wt.stf", &["stf"], false),
+    ("application/vnd.wv.csp+wbxml", &["wv"], false),
+    ("application/vnd.wv.csp+xml", &[], true),
+    ("application/vnd.wv.ssp+xml", &[], true),
+    ("application/vnd.xacml+json", &[], true),
+    ("application/vnd.xara", &["xar"], false),
+    ("application/vnd.xfdl", &["xfdl", "xfd"], false),
+    ("application/vnd.xfdl.webform", &[], false),
+    ("application/vnd.xmi+xml", &[], true),
+    ("application/vnd.xmpie.cpkg", &["cpkg"], false),
+    ("application/vnd.xmpie.dpkg", &["dpkg"], false),
+    ("application/vnd.xmpie.plan", &[], false),
+    ("application/vnd.xmpie.ppkg", &["ppkg"], false),
+    ("application/vnd.xmpie.xlim", &["xlim"], false),
+    ("application/vnd.yamaha.hv-dic", &["hvd"], false),
+    ("application/vnd.yamaha.hv-script", &["hvs"], false),
+    ("application/vnd.yamaha.hv-voice", &["hvp"], false),
+    ("application/vnd.yamaha.openscoreformat", &["osf"], false),
+    ("application/vnd.yamaha.openscoreformat.osfpvg+xml", &[], true),
+    ("application/vnd.yamaha.remote-setup", &[], false),
+    ("application/vnd.yamaha.smaf-audio", &["saf"], false),
+    ("application/vnd.yamaha.smaf-phrase", &["spf"], false),
+    ("application/vnd.yamaha.through-ngn", &[], false),
+    ("application/vnd.yamaha.tunnel-udpencap", &[], false),
+    ("application/vnd.yaoweme", &["yme"], false),
+    ("application/vnd.yellowriver-custom-menu", &["cmp"], false),
+    ("application/vnd.zul", &["zir", "zirz"], false),
+    ("application/vnd.zzazz.deck+xml", &["zaz"], true),
+    ("application/voicexml+xml", &["vxml"], true),
+    ("application/voucher-cms+json", &["vcj"], true),
+    ("application/vq-rtcpxr", &[], false),
+    ("application/wasm", &["wasm"], true),
+    ("application/watcherinfo+xml", &["wif"], true),
+    ("application/webpush-options+json", &[], true),
+    ("application/whoispp-query", &[], false),
+    ("application/whoispp-response", &[], false),
+    ("application/widget", &["wgt"], false),
+    ("application/wita", &[], false),
+    ("application/wordperfect5.1", &[], false),
+    ("application/wsdl+xml", &["wsdl"], true),
+    ("application/wspolicy+xml", &["wspolicy"], true),
+    ("application/x-123", &["wk"], false),
+    ("application/x-7z-compressed", &["7z"], false),
+    ("application/x-abiword", &["abw"], false),
+    ("application/x-apple-diskimage", &["dmg"], false),
+    ("application/x-bcpio", &["bcpio"], false),
+    ("application/x-bittorrent", &["torrent"], false),
+    ("application/x-brotli", &["br"], false),
+    ("application/x-cdf", &["cdf", "cda"], false),
+    ("application/x-cdlink", &["vcd"], false),
+    ("application/x-comsol", &["mph"], false),
+    ("application/x-cpio", &["cpio"], false),
+    ("application/x-csh", &["csh"], true),
+    ("application/x-director", &["dcr", "dir", "dxr"], false),
+    ("application/x-doom", &["wad"], false),
+    ("application/x-dvi", &["dvi"], false),
+    ("application/x-font", &["pfa", "pfb", "gsf"], false),
+    ("application/x-font-pcf", &["pcf", "pcf.z"], false),
+    ("application/x-freemind", &["mm"], false),
+    ("application/x-ganttproject", &["gan"], false),
+    ("application/x-gnumeric", &["gnumeric"], false),
+    ("application/x-go-sgf", &["sgf"], false),
+    ("application/x-graphing-calculator", &["gcf"], false),
+    ("application/x-gtar", &["gtar"], false),
+    ("application/x-gtar-compressed", &["tgz", "taz"], false),
+    ("application/x-hdf", &["hdf"], false),
+    ("application/x-hwp", &["hwp"], false),
+    ("application/x-ica", &["ica"], false),
+    ("application/x-info", &["info"], false),
+    ("application/x-internet-signup", &["ins", "isp"], false),
+    ("application/x-iphone", &["iii"], false),
+    ("application/x-iso9660-image", &["iso"], false),
+    ("application/x-java-jnlp-file", &["jnlp"], false),
+    ("application/x-jmol", &["jmz"], false),
+    ("application/x-killustrator", &["kil"], false),
+    ("application/x-latex", &["latex"], true),
+    ("application/x-lha", &["lha"], false),
+    ("application/x-lyx", &["lyx"], false),
+    ("application/x-lzh", &["lzh"], false),
+    ("application/x-lzx", &["lzx"], false),
+    ("application/x-maker", &["frm", "maker", "frame", "fm", "fb", "book", "fbdoc"], false),
+    ("application/x-ms-wmd", &["wmd"], false),
+    ("application/x-ms-wmz", &["wmz"], false),
+    ("application/x-msdos-program", &["com", "exe", "bat", "dll"], false),
+    ("application/x-msi", &["msi"], false),
+    ("application/x-netcdf", &["nc"], false),
+    ("application/x-ns-proxy-autoconfig", &["pac"], false),
+    ("application/x-nwc", &["nwc"], false),
+    ("application/x-object", &["o"], false),
+    ("application/x-oz-application", &["oza"], false),
+    ("application/x-pkcs7-certreqresp", &["p7r"], false),
+    ("application/x-pki-message", &[], false),
+    ("application/x-python-code", &["pyc", "pyo"], false),
+    ("application/x-qgis", &["qgs", "shp", "shx"], false),
+    ("application/x-quicktimeplayer", &["qtl"], false),
+    ("application/x-rdp", &["rdp"], false),
+    ("application/x-redhat-package-manager", &["rpm"], false),
+    ("application/x-rss+xml", &["rss"], true),
+    ("application/x-ruby", &["rb"], false),
+    ("application/x-scilab", &["sci", "sce"], false),
+    ("application/x-scilab-xcos", &["xcos"], false),
+    ("application/x-sh", &["sh"], true),
+    ("application/x-shar", &["shar"], false),
+    ("application/x-silverlight", &["scr"], false),
+    ("application/x-stuffit", &["sit", "sitx"], false),
+    ("application/x-sv4cpio", &["sv4cpio"], false),
+    ("application/x-sv4crc", &["sv4crc"], false),
+    ("application/x-tar", &["tar"], true),
+    ("application/x-tcl", &["tcl"], false),
+    ("application/x-tex-gf", &["gf"], false),
+    ("application/x-tex-pk", &["pk"], false),
+    ("application/x-texinfo", &["texinfo", "texi"], false),
+    ("application/x-trash", &["bak", "old", "sik"], false),
+    ("application/x-troff-man", &["man"], false),
+    ("application/x-troff-me", &["me"], false),
+    ("application/x-troff-ms", &["ms"], false),
+    ("application/x-ustar", &["ustar"], false),
+    ("application/x-wais-source", &["src"], false),
+    ("application/x-wingz", &["wz"], false),
+    ("application/x-www-form-urlencoded", &[], false),
+    ("application/x-x509-ca-cert", &["crt"], false),
+    ("application/x-x509-ca-ra-cert", &[], false),
+    ("application/x-x509-next-ca-cert", &[], false),
+    ("application/x-xfig", &["fig"], false),
+    ("application/x-xpinstall", &["xpi"], false),
+    ("application/x-xz", &["xz"], false),
+    ("application/x400-bp", &[], false),
+    ("application/xacml+xml", &[], true),
+    ("application/xcap-att+xml", &["xav"], true),
+    ("application/xcap-caps+xml", &["xca"], true),
+    ("application/xcap-diff+xml", &["xdf"], true),
+    ("application/xcap-el+xml", &["xel"], true),
+    ("application/xcap-error+xml", &["xer"], true),
+    ("application/xcap-ns+xml", &["xns"], true),
+    ("application/xcon-conference-info+xml", &[], true),
+    ("application/xcon-conference-info-diff+xml", &[], true),
+    ("application/xenc+xml", &[], true),
+    ("application/xfdf", &["xfdf"], false),
+    ("application/xhtml+xml", &["xhtml", "xhtm", "xht"], true),
+    ("application/xliff+xml", &["xlf"], true),
+    ("application/xml", &["xml"], true),
+    ("application/xml-dtd", &["dtd", "mod"], true),
+    ("application/xml-external-parsed-entity", &["ent"], false),
+    ("application/xml-patch+xml", &[], true),
+    ("application/xmpp+xml", &[], true),
+    ("application/xop+xml", &["xop"], true),
+    ("application/xslt+xml", &["xsl", "xslt"], true),
+    ("application/xspf+xml", &["xspf"], true),
+    ("application/xv+xml", &["mxml", "xhvml", "xvml", "xvm"], true),
+    ("application/yang", &["yang"], false),
+    ("application/yang-data+cbor", &[], false),
+    ("application/yang-data+json", &[], true),
+    ("application/yang-data+xml", &[], true),
+    ("application/yang-patch+json", &[], true),
+    ("application/yang-patch+xml", &[], true),
+    ("application/yin+xml", &["yin"], true),
+    ("application/zip", &["zip"], false),
+    ("application/zlib", &[], false),
+    ("application/zstd", &["zst"], false),
+    ("audio/1d-interleaved-parityfec", &[], false),
+    ("audio/32kadpcm", &["726"], false),
+    ("audio/3gpp", &[], false),
+    ("audio/3gpp2", &[], false),
+    ("audio/aac", &["adts", "aac", "ass"], false),
+    ("audio/ac3", &["ac3"], false),
+    ("audio/amr", &["amr"], false),
+    ("audio/amr-wb", &["awb"], false),
+    ("audio/amr-wb+", &[], false),
+    ("audio/annodex", &["axa"], false),
+    ("audio/aptx", &[], false),
+    ("audio/asc", &["acn"], false),
+    ("audio/atrac-advanced-lossless", &["aal"], false),
+    ("audio/atrac-x", &["atx"], false),
+    ("audio/atrac3", &["at3", "aa3", "omg"], false),
+    ("audio/basic", &["au", "snd"], false),
+    ("audio/bv16", &[], false),
+    ("audio/bv32", &[], false),
+    ("audio/clearmode", &[], false),
+    ("audio/cn", &[], false),
+    ("audio/csound", &["csd", "orc", "sco"], false),
+    ("audio/dat12", &[], false),
+    ("audio/dls", &["dls"], false),
+    ("audio/dsr-es201108", &[], false),
+    ("audio/dsr-es202050", &[], false),
+    ("audio/dsr-es202211", &[], false),
+    ("audio/dsr-es202212", &[], false),
+    ("audio/dv", &[], false),
+    ("audio/dvi4", &[], false),
+    ("audio/eac3", &[], false),
+    ("audio/encaprtp", &[], false),
+    ("audio/evrc", &["evc"], false),
+    ("audio/evrc-qcp", &["qcp"], false),
+    ("audio/evrc0", &[], false),
+    ("audio/evrc1", &[], false),
+    ("audio/evrcb", &["evb"], false),
+    ("audio/evrcb0", &[], false),
+    ("audio/evrcb1", &[], false),
+    ("audio/evrcnw", &["enw"], false),
+    ("audio/evrcnw0", &[], false),
+    ("audio/evrcnw1", &[], false),
+    ("audio/evrcwb", &["evw"], false),
+    ("audio/evrcwb0", &[], false),
+    ("audio/evrcwb1", &[], false),
+    ("audio/evs", &[], false),
+    ("audio/example", &[], false),
+    ("audio/flac", &["flac"], false),
+    ("audio/flexfec", &[], false),
+    ("audio/fwdred", &[], false),
+    ("audio/g711-0", &[], false),
+    ("audio/g719", &[], false),
+    ("audio/g722", &[], false),
+    ("audio/g7221", &[], false),
+    ("audio/g723", &[], false),
+    ("audio/g726-16", &[], false),
+    ("audio/g726-24", &[], false),
+    ("audio/g726-32", &[], false),
+    ("audio/g726-40", &[], false),
+    ("audio/g728", &[], false),
+    ("audio/g729", &[], false),
+    ("audio/g7291", &[], false),
+    ("audio/g729d", &[], false),
+    ("audio/g729e", &[], false),
+    ("audio/gsm", &[], false),
+    ("audio/gsm-efr", &[], false),
+    ("audio/gsm-hr-08", &[], false),
+    ("audio/ilbc", &["lbc"], false),
+    ("audio/ip-mr_v2.5", &[], false),
+    ("audio/l16", &["l16"], false),
+    ("audio/l20", &[], false),
+    ("audio/l24", &[], false),
+    ("audio/l8", &[], false),
+    ("audio/lpc", &[], false),
+    ("audio/melp", &[], false),
+    ("audio/melp1200", &[], false),
+    ("audio/melp2400", &[], false),
+    ("audio/melp600", &[], false),
+    ("audio/mhas", &["mhas"], false),
+    ("audio/mobile-xmf", &["mxmf"], false),
+    ("audio/mp4", &["m4a"], false),
+    ("audio/mp4a-latm", &[], false),
+    ("audio/mpa", &[], false),
+    ("audio/mpa-robust", &[], false),
+    ("audio/mpeg", &["mpga", "mpega", "mp1", "mp2", "mp3"], false),
+    ("audio/mpeg4-generic", &[], false),
+    ("audio/mpegurl", &["m3u"], false),
+    ("audio/ogg", &["oga", "ogg", "opus", "spx"], false),
+    ("audio/opus", &[], false),
+    ("audio/parityfec", &[], false),
+    ("audio/pcma", &[], false),
+    ("audio/pcma-wb", &[], false),
+    ("audio/pcmu", &[], false),
+    ("audio/pcmu-wb", &[], false),
+    ("audio/prs.sid", &["sid", "psid"], false),
+    ("audio/qcelp", &[], false),
+    ("audio/raptorfec", &[], false),
+    ("audio/red", &[], false),
+    ("audio/rtp-enc-aescm128", &[], false),
+    ("audio/rtp-midi", &[], false),
+    ("audio/rtploopback", &[], false),
+    ("audio/rtx", &[], false),
+    ("audio/scip", &[], false),
+    ("audio/smv", &["smv"], false),
+    ("audio/smv-qcp", &[], false),
+    ("audio/smv0", &[], false),
+    ("audio/sofa", &["sofa"], false),
+    ("audio/sp-midi", &["mid"], false),
+    ("audio/speex", &[], false),
+    ("audio/t140c", &[], false),
+    ("audio/t38", &[], false),
+    ("audio/telephone-event", &[], false),
+    ("audio/tetra_acelp", &[], false),
+    ("audio/tetra_acelp_bb", &[], false),
+    ("audio/tone", &[], false),
+    ("audio/tsvcis", &[], false),
+    ("audio/uemclip", &[], false),
+    ("audio/ulpfec", &[], false),
+    ("audio/usac", &["loas", "xhe"], false),
+    ("audio/vdvi", &[], false),
+    ("audio/vmr-wb", &[], false),
+    ("audio/vnd.3gpp.iufp", &[], false),
+    ("audio/vnd.4sb", &[], false),
+    ("audio/vnd.audiokoz", &["koz"], false),
+    ("audio/vnd.celp", &[], false),
+    ("audio/vnd.cisco.nse", &[], false),
+    ("audio/vnd.cmles.radio-events", &[], false),
+    ("audio/vnd.cns.anp1", &[], false),
+    ("audio/vnd.cns.inf1", &[], false),
+    ("audio/vnd.dece.audio", &["uva", "uvva"], false),
+    ("audio/vnd.digital-winds", &["eol"], false),
+    ("audio/vnd.dlna.adts", &[], false),
+    ("audio/vnd.dolby.heaac.1", &[], false),
+    ("audio/vnd.dolby.heaac.2", &[], false),
+    ("audio/vnd.dolby.mlp", &["mlp"], false),
+    ("audio/vnd.dolby.mps", &[], false),
+    ("audio/vnd.dolby.pl2", &[], false),
+    ("audio/vnd.dolby.pl2x", &[], false),
+    ("audio/vnd.dolby.pl2z", &[], false),
+    ("audio/vnd.dolby.pulse.1", &[], false),
+    ("audio/vnd.dra", &[], false),
+    ("audio/vnd.dts", &["dts"], false),
+    ("audio/vnd.dts.hd", &["dtshd"], false),
+    ("audio/vnd.dts.uhd", &[], false),
+    ("audio/vnd.dvb.file", &[], false),
+    ("audio/vnd.everad.plj", &["plj"], false),
+    ("audio/vnd.hns.audio", &[], false),
+    ("audio/vnd.lucent.voice", &["lvp"], false),
+    ("audio/vnd.ms-playready.media.pya", &["pya"], false),
+    ("audio/vnd.nokia.mobile-xmf", &[], false),
+    ("audio/vnd.nortel.vbk", &["vbk"], false),
+    ("audio/vnd.nuera.ecelp4800", &["ecelp4800"], false),
+    ("audio/vnd.nuera.ecelp7470", &["ecelp7470"], false),
+    ("audio/vnd.nuera.ecelp9600", &["ecelp9600"], false),
+    ("audio/vnd.octel.sbc", &[], false),
+    ("audio/vnd.presonus.multitrack", &["multitrack"], false),
+    ("audio/vnd.rhetorex.32kadpcm", &[], false),
+    ("audio/vnd.rip", &["rip"], false),
+    ("audio/vnd.sealedmedia.softseal.mpeg", &["smp3", "smp", "s1m"], false),
+    ("audio/vnd.vmx.cvsd", &[], false),
+    ("audio/vorbis", &[], false),
+    ("audio/vorbis-config", &[], false),
+    ("audio/wav", &["wav"], false),
+    ("audio/x-aiff", &["aif", "aiff", "aifc"], false),
+    ("audio/x-gsm", &["gsm"], false),
+    ("audio/x-ms-wax", &["wax"], false),
+    ("audio/x-ms-wma", &["wma"], false),
+    ("audio/x-pn-realaudio", &["ra", "rm", "ram"], false),
+    ("audio/x-scpls", &["pls"], false),
+    ("audio/x-sd2", &["sd2"], false),
+    ("audio/x-wav", &["wav"], false),
+    ("chemical/x-alchemy", &["alc"], false),
+    ("chemical/x-cache", &["cac", "cache"], false),
+    ("chemical/x-cache-csf", &["csf"], false),
+    ("chemical/x-cactvs-binary", &["cbin", "cascii", "ctab"], false),
+    ("chemical/x-cdx", &["cdx"], false),
+    ("chemical/x-cerius", &[], false),
+    ("chemical/x-chem3d", &["c3d"], false),
+    ("chemical/x-chemdraw", &["chm"], false),
+    ("chemical/x-cif", &["cif"], false),
+    ("chemical/x-cmdf", &["cmdf"], false),
+    ("chemical/x-cml", &["cml"], false),
+    ("chemical/x-compass", &["cpa"], false),
+    ("chemical/x-crossfire", &["bsd"], false),
+    ("chemical/x-csml", &["csml", "csm"], false),
+    ("chemical/x-ctx", &["ctx"], false),
+    ("chemical/x-cxf", &["cxf", "cef"], false),
+    ("chemical/x-embl-dl-nucleotide", &["emb", "embl"], false),
+    ("chemical/x-galactic-spc", &["spc"], false),
+    ("chemical/x-gamess-input", &["inp", "gam", "gamin"], false),
+    ("chemical/x-gaussian-checkpoint", &["fch", "fchk"], false),
+    ("chemical/x-gaussian-cube", &["cub"], false),
+    ("chemical/x-gaussian-input", &["gau", "gjc", "gjf"], false),
+    ("chemical/x-gaussian-log", &["gal"], false),
+    ("chemical/x-gcg8-sequence", &["gcg"], false),
+    ("chemical/x-genbank", &["gen"], false),
+    ("chemical/x-hin", &["hin"], false),
+    ("chemical/x-isostar", &["istr", "ist"], false),
+    ("chemical/x-jcamp-dx", &["jdx", "dx"], false),
+    ("chemical/x-kinemage", &["kin"], false),
+    ("chemical/x-macmolecule", &["mcm"], false),
+    ("chemical/x-macromodel-input", &["mmod"], false),
+    ("chemical/x-mdl-molfile", &["mol"], false),
+    ("chemical/x-mdl-rdfile", &["rd"], false),
+    ("chemical/x-mdl-rxnfile", &["rxn"], false),
+    ("chemical/x-mdl-sdfile", &["sd", "sdf"], false),
+    ("chemical/x-mdl-tgf", &["tgf"], false),
+    ("chemical/x-mmcif", &["mcif"], false),
+    ("chemical/x-molconn-z", &["b"], false),
+    ("chemical/x-mopac-graph", &["gpt"], false),
+    ("chemical/x-mopac-input", &["mop", "mopcrt", "mpc", "zmt"], false),
+    ("chemical/x-mopac-out", &["moo"], false),
+    ("chemical/x-mopac-vib", &["mvb"], false),
+    ("chemical/x-ncbi-asn1", &["asn"], false),
+    ("chemical/x-ncbi-asn1-ascii", &["prt"], false),
+    ("chemical/x-ncbi-asn1-binary", &["val", "aso"], false),
+    ("chemical/x-ncbi-asn1-spec", &["asn"], false),
+    ("chemical/x-pdb", &["pdb"], false),
+    ("chemical/x-rosdal", &["ros"], false),
+    ("chemical/x-swissprot", &["sw"], false),
+    ("chemical/x-vamas-iso14976", &["vms"], false),
+    ("chemical/x-vmd", &["vmd"], false),
+    ("chemical/x-xtel", &["xtel"], false),
+    ("chemical/x-xyz", &["xyz"], false),
+    ("font/collection", &["ttc"], false),
+    ("font/otf", &["otf"], true),
+    ("font/sfnt", &[], false),
+    ("font/ttf", &["ttf"], true),
+    ("font/woff", &["woff"], false),
+    ("font/woff2", &["woff2"], false),
+    ("image/aces", &["exr"], false),
+    ("image/apng", &["apng"], false),
+    ("image/avci", &["avci"], false),
+    ("image/avcs", &["avcs"], false),
+    ("image/avif", &["avif", "hif"], false),
+    ("image/bmp", &["bmp"], true),
+    ("image/cgm", &["cgm"], false),
+    ("image/dicom-rle", &["drle"], false),
+    ("image/dpx", &["dpx"], false),
+    ("image/emf", &["emf"], false),
+    ("image/example", &[], false),
+    ("image/fits", &["fits", "fit", "fts"], false),
+    ("image/g3fax", &[], false),
+    ("image/gif", &["gif"], false),
+    ("image/heic", &["heic"], false),
+    ("image/heic-sequence", &["heics"], false),
+    ("image/heif", &["heif"], false),
+    ("image/heif-sequence", &["heifs"], false),
+    ("image/hej2k", &["hej2"], false),
+    ("image/hsj2", &["hsj2"], false),
+    ("image/ief", &["ief"], false),
+    ("image/jls", &["jls"], false),
+    ("image/jp2", &["jp2", "jpg2"], false),
+    ("image/jpeg", &["jpeg", "jpg", "jpe", "jfif"], false),
+    ("image/jph", &["jph"], false),
+    ("image/jphc", &["jhc", "jphc"], false),
+    ("image/jpm", &["jpm", "jpgm"], false),
+    ("image/jpx", &["jpx", "jpf"], false),
+    ("image/jxl", &["jxl"], false),
+    ("image/jxr", &["jxr"], false),
+    ("image/jxra", &["jxra"], false),
+    ("image/jxrs", &["jxrs"], false),
+    ("image/jxs", &["jxs"], false),
+    ("image/jxsc", &["jxsc"], false),
+    ("image/jxsi", &["jxsi"], false),
+    ("image/jxss", &["jxss"], false),
+    ("image/ktx", &["ktx"], false),
+    ("image/ktx2", &["ktx2"], false),
+    ("image/naplps", &[], false),
+    ("image/png", &["png"], false),
+    ("image/prs.btif", &["btif", "btf"], false),
+    ("image/prs.pti", &["pti"], false),
+    ("image/pwg-raster", &[], false),
+    ("image/svg+xml", &["svg", "svgz"], true),
+    ("image/t38", &[], false),
+    ("image/tiff", &["tiff", "tif"], false),
+    ("image/tiff-fx", &["tfx"], false),
+    ("image/vnd.adobe.photoshop", &["psd"], false),
+    ("image/vnd.airzip.accelerator.azv", &["azv"], false),
+    ("image/vnd.cns.inf2", &[], false),
+    ("image/vnd.dece.graphic", &["uvi", "uvvi", "uvg", "uvvg"], false),
+    ("image/vnd.djvu", &["djvu", "djv"], false),
+    ("image/vnd.dvb.subtitle", &[], false),
+    ("image/vnd.dwg", &["dwg"], false),
+    ("image/vnd.dxf", &["dxf"], false),
+    ("image/vnd.fastbidsheet", &["fbs"], false),
+    ("image/vnd.fpx", &["fpx"], false),
+    ("image/vnd.fst", &["fst"], false),
+    ("image/vnd.fujixerox.edmics-mmr", &["mmr"], false),
+    ("image/vnd.fujixerox.edmics-rlc", &["rlc"], false),
+    ("image/vnd.globalgraphics.pgb", &["pgb"], false),
+    ("image/vnd.microsoft.icon", &["ico"], true),
+    ("image/vnd.mix", &[], false),
+    ("image/vnd.ms-modi", &["mdi"], false),
+    ("image/vnd.net-fpx", &[], false),
+    ("image/vnd.pco.b16", &["b16"], false),
+    ("image/vnd.radiance", &["hdr", "rgbe", "xyze"], false),
+    ("image/vnd.sealed.png", &["spng", "spn", "s1n"], false),
+    ("image/vnd.sealedmedia.softseal.gif", &["sgif", "sgi", "s1g"], false),
+    ("image/vnd.sealedmedia.softseal.jpg", &["sjpg", "sjp", "s1j"], false),
+    ("image/vnd.svf", &[], false),
+    ("image/vnd.tencent.tap", &["tap"], false),
+    ("image/vnd.valve.source.texture", &["vtf"], false),
+    ("image/vnd.wap.wbmp", &["wbmp"], false),
+    ("image/vnd.xiff", &["xif"], false),
+    ("image/vnd.zbrush.pcx", &["pcx"], false),
+    ("image/webp", &["webp"], false),
+    ("image/wmf", &["wmf"], false),
+    ("image/x-canon-cr2", &["cr2"], false),
+    ("image/x-canon-crw", &["crw"], false),
+    ("image/x-cmu-raster", &["ras"], false),
+    ("image/x-coreldraw", &["cdr"], false),
+    ("image/x-coreldrawpattern", &["pat"], false),
+    ("image/x-coreldrawtemplate", &["cdt"], false),
+    ("image/x-corelphotopaint", &["cpt"], false),
+    ("image/x-epson-erf", &["erf"], false),
+    ("image/x-jg", &["art"], false),
+    ("image/x-jng", &["jng"], false),
+    ("image/x-nikon-nef", &["nef"], false),
+    ("image/x-olympus-orf", &["orf"], false),
+    ("image/x-portable-anymap", &["pnm"], false),
+    ("image/x-portable-bitmap", &["pbm"], false),
+    ("image/x-portable-graymap", &["pgm"], false),
+    ("image/x-portable-pixmap", &["ppm"], false),
+    ("image/x-rgb", &["rgb"], false),
+    ("image/x-xbitmap", &["xbm"], false),
+    ("image/x-xcf", &["xcf"], false),
+    ("image/x-xpixmap", &["xpm"], false),
+    ("image/x-xwindowdump", &["xwd"], false),
+    ("inode/blockdevice", &[], false),
+    ("inode/chardevice", &[], false),
+    ("inode/directory", &[], false),
+    ("inode/directory-locked", &[], false),
+    ("inode/fifo", &[], false),
+    ("inode/socket", &[], false),
+    ("message/bhttp", &[], false),
+    ("message/cpim", &[], false),
+    ("message/delivery-status", &[], false),
+    ("message/disposition-notification", &[], false),
+    ("message/example", &[], false),
+    ("message/external-body", &[], false),
+    ("message/feedback-report", &[], false),
+    ("message/global", &["u8msg"], false),
+    ("message/global-delivery-status", &["u8dsn"], false),
+    ("message/global-disposition-notification", &["u8mdn"], false),
+    ("message/global-headers", &["u8hdr"], false),
+    ("message/http", &[], false),
+    ("message/imdn+xml", &[], true),
+    ("message/partial", &[], false),
+    ("message/rfc822", &["eml", "mail", "art"], false),
+    ("message/s-http", &[], false),
+    ("message/sip", &[], false),
+    ("message/sipfrag", &[], false),
+    ("message/tracking-status", &[], false),
+    ("message/vnd.wfa.wsc", &[], false),
+    ("model/3mf", &[], false),
+    ("model/e57", &[], false),
+    ("model/example", &[], false),
+    ("model/gltf+json", &["gltf"], true),
+    ("model/gltf-binary", &["glb"], false),
+    ("model/iges", &["igs", "iges"], false),
+    ("model/jt", &["jt"], false),
+    ("model/mesh", &["msh", "mesh", "silo"], false),
+    ("model/mtl", &["mtl"], false),
+    ("model/obj", &["obj"], false),
+    ("model/prc", &["prc"], false),
+    ("model/step", &["stp", "step"], false),
+    ("model/step+xml", &["stpx"], true),
+    ("model/step+zip", &["stpz"], false),
+    ("model/step-xml+zip", &["stpxz"], false),
+    ("model/stl", &["stl"], false),
+    ("model/u3d", &["u3d"], false),
+    ("model/vnd.cld", &["cld"], false),
+    ("model/vnd.collada+xml", &["dae"], true),
+    ("model/vnd.dwf", &["dwf"], false),
+    ("model/vnd.flatland.3dml", &[], false),
+    ("model/vnd.gdl", &["gdl", "gsm", "win", "dor", "lmp", "rsm", "msm", "ism"], false),
+    ("model/vnd.gs-gdl", &[], false),
+    ("model/vnd.gtw", &["gtw"], false),
+    ("model/vnd.moml+xml", &["moml"], true),
+    ("model/vnd.mts", &["mts"], false),
+    ("model/vnd.opengex", &["ogex"], false),
+    ("model/vnd.parasolid.transmit.binary", &["x_b", "xmt_bin"], false),
+    ("model/vnd.parasolid.transmit.text", &["x_t", "xmt_txt"], false),
+    ("model/vnd.pytha.pyox", &["pyox"], false),
+    ("model/vnd.rosette.annotated-data-model", &[], false),
+    ("model/vnd.sap.vds", &["vds"], false),
+    ("model/vnd.usda", &["usda"], false),
+    ("model/vnd.usdz+zip", &["usdz"], false),
+    ("model/vnd.valve.source.compiled-map", &["bsp"], false),
+    ("model/vnd.vtu", &["vtu"], false),
+    ("model/vrml", &["wrl", "vrm", "vrml"], false),
+    ("model/x3d+fastinfoset", &["x3db"], false),
+    ("model/x3d+xml", &["x3d", "x3dz"], true),
+    ("model/x3d-vrml", &["x3dv", "x3dvz"], false),
+    ("multipart/alternative", &[], false),
+    ("multipart/appledouble", &[], false),
+    ("multipart/byteranges", &[], false),
+    ("multipart/digest", &[], false),
+    ("multipart/encrypted", &[], false),
+    ("multipart/example", &[], false),
+    ("multipart/form-data", &[], false),
+    ("multipart/header-set", &[], false),
+    ("multipart/mixed", &[], false),
+    ("multipart/multilingual", &[], false),
+    ("multipart/parallel", &[], false),
+    ("multipart/related", &[], false),
+    ("multipart/report", &[], false),
+    ("multipart/signed", &[], false),
+    ("multipart/vnd.bint.med-plus", &["bmed"], false),
+    ("multipart/voice-message", &["vpm"], false),
+    ("multipart/x-mixed-replace", &[], false),
+    ("text/1d-interleaved-parityfec", &[], true),
+    ("text/cache-manifest", &["appcache", "manifest"], true),
+    ("text/calendar", &["ics", "ifb"], true),
+    ("text/cql", &["cql"], true),
+    ("text/cql-extension", &[], true),
+    ("text/cql-identifier", &[], true),
+    ("text/css", &["css"], true),
+    ("text/csv", &["csv"], true),
+    ("text/csv-schema", &["csvs"], true),
+    ("text/dns", &["soa", "zone"], true),
+    ("text/encaprtp", &[], true),
+    ("text/enriched", &[], true),
+    ("text/example", &[], true),
+    ("text/fhirpath", &[], true),
+    ("text/flexfec", &[], true),
+    ("text/fwdred", &[], true),
+    ("text/gff3", &["gff3"], true),
+    ("text/grammar-ref-list", &[], true),
+    ("text/hl7v2", &[], true),
+    ("text/html", &["html", "htm", "shtml"], true),
+    ("text/javascript", &["es", "js", "mjs"], true),
+    ("text/jcr-cnd", &["cnd"], true),
+    ("text/markdown", &["md", "markdown"], true),
+    ("text/mizar", &["miz"], true),
+    ("text/n3", &["n3"], true),
+    ("text/parameters", &[], true),
+    ("text/parityfec", &[], true),
+    ("text/plain", &["txt", "text", "pot", "brf", "srt"], true),
+    ("text/provenance-notation", &["provn"], true),
+    ("text/prs.fallenstein.rst", &["rst"], true),
+    ("text/prs.lines.tag", &["tag", "dsc"], true),
+    ("text/prs.prop.logic", &[], true),
+    ("text/raptorfec", &[], true),
+    ("text/red", &[], true),
+    ("text/rfc822-headers", &[], true),
+    ("text/rtf", &[], true),
+    ("text/rtp-enc-aescm128", &[], true),
+    ("text/rtploopback", &[], true),
+    ("text/rtx", &[], true),
+    ("text/sgml", &["sgml", "sgm"], true),
+    ("text/shaclc", &["shaclc", "shc"], true),
+    ("text/shex", &["shex"], true),
+    ("text/spdx", &["spdx"], true),
+    ("text/strings", &[], true),
+    ("text/t140", &[], true),
+    ("text/tab-separated-values", &["tsv"], true),
+    ("text/texmacs", &["tm"], true),
+    ("text/troff", &["t", "tr", "roff"], true),
+    ("text/turtle", &["ttl"], true),
+    ("text/ulpfec", &[], true),
+    ("text/uri-list", &["uris", "uri"], true),
+    ("text/vcard", &["vcf", "vcard"], true),
+    ("text/vnd.a", &["a"], true),
+    ("text/vnd.abc", &["abc"], true),
+    ("text/vnd.ascii-art", &["ascii"], true),
+    ("text/vnd.curl", &["curl"], true),
+    ("text/vnd.debian.copyright", &["copyright"], true),
+    ("text/vnd.dmclientscript", &["dms"], true),
+    ("text/vnd.dvb.subtitle", &[], true),
+    ("text/vnd.esmertec.theme-descriptor", &["jtd"], true),
+    ("text/vnd.exchangeable", &["vfk"], true),
+    ("text/vnd.familysearch.gedcom", &["ged"], true),
+    ("text/vnd.ficlab.flt", &["flt"], true),
+    ("text/vnd.fly", &["fly"], true),
+    ("text/vnd.fmi.flexstor", &["flx"], true),
+    ("text/vnd.gml", &[], true),
+    ("text/vnd.graphviz", &["gv", "dot"], true),
+    ("text/vnd.hans", &["hans"], true),
+    ("text/vnd.hgl", &["hgl"], true),
+    ("text/vnd.in3d.3dml", &["3dml", "3dm"], true),
+    ("text/vnd.in3d.spot", &["spot", "spo"], true),
+    ("text/vnd.iptc.newsml", &[], true),
+    ("text/vnd.iptc.nitf", &[], true),
+    ("text/vnd.latex-z", &[], true),
+    ("text/vnd.motorola.reflex", &[], true),
+    ("text/vnd.ms-mediapackage", &["mpf"], true),
+    ("text/vnd.net2phone.commcenter.command", &["ccc"], true),
+    ("text/vnd.radisys.msml-basic-layout", &[], true),
+    ("text/vnd.senx.warpscript", &["mc2"], true),
+    ("text/vnd.sosi", &["sos"], true),
+    ("text/vnd.sun.j2me.app-descriptor", &["jad"], true),
+    ("text/vnd.trolltech.linguist", &["ts"], true),
+    ("text/vnd.wap.si", &["si"], true),
+    ("text/vnd.wap.sl", &["sl"], true),
+    ("text/vnd.wap.wml", &["wml"], true),
+    ("text/vnd.wap.wmlscript", &["wmls"], true),
+    ("text/vtt", &["vtt"], true),
+    ("text/wgsl", &["wgsl"], true),
+    ("text/x-bibtex", &["bib"], true),
+    ("text/x-boo", &["boo"], true),
+    ("text/x-c++hdr", &["h++", "hpp", "hxx", "hh"], true),
+    ("text/x-c++src", &["c++", "cpp", "cxx", "cc"], true),
+    ("text/x-chdr", &["h"], true),
+    ("text/x-component", &["htc"], true),
+    ("text/x-csh", &["csh"], true),
+    ("text/x-csrc", &["c"], true),
+    ("text/x-diff", &["diff", "patch"], true),
+    ("text/x-dsrc", &["d"], true),
+    ("text/x-haskell", &["hs"], true),
+    ("text/x-java", &["java"], true),
+    ("text/x-lilypond", &["ly"], true),
+    ("text/x-literate-haskell", &["lhs"], true),
+    ("text/x-moc", &["moc"], true),
+    ("text/x-pascal", &["p", "pas"], true),
+    ("text/x-pcs-gcd", &["gcd"], true),
+    ("text/x-perl", &["pl", "pm"], true),
+    ("text/x-python", &["py"], true),
+    ("text/x-scala", &["scala"], true),
+    ("text/x-setext", &["etx"], true),
+    ("text/x-sfv", &["sfv"], true),
+    ("text/x-sh", &["sh"], true),
+    ("text/x-tcl", &["tcl", "tk"], true),
+    ("text/x-tex", &["tex", "ltx", "sty", "cls"], true),
+    ("text/x-vcalendar", &["vcs"], true),
+    ("text/xml", &[], true),
+    ("text/xml-dtd", &[], true),
+    ("text/xml-external-parsed-entity", &[], true),
+    ("video/1d-interleaved-parityfec", &[], false),
+    ("video/3gpp", &[], false),
+    ("video/3gpp-tt", &[], false),
+    ("video/3gpp2", &[], false),
+    ("video/annodex", &["axv"], false),
+    ("video/av1", &[], false),
+    ("video/bmpeg", &[], false),
+    ("video/bt656", &[], false),
+    ("video/celb", &[], false),
+    ("video/dv", &["dif", "dv"], false),
+    ("video/encaprtp", &[], false),
+    ("video/example", &[], false),
+    ("video/ffv1", &[], false),
+    ("video/flexfec", &[], false),
+    ("video/fli", &["fli"], false),
+    ("video/gl", &["gl"], false),
+    ("video/h261", &[], false),
+    ("video/h263", &[], false),
+    ("video/h263-1998", &[], false),
+    ("video/h263-2000", &[], false),
+    ("video/h264", &[], false),
+    ("video/h264-rcdo", &[], false),
+    ("video/h264-svc", &[], false),
+    ("video/h265", &[], false),
+    ("video/h266", &[], false),
+    ("video/iso.segment", &["m4s"], false),
+    ("video/jpeg", &[], false),
+    ("video/jpeg2000", &[], false),
+    ("video/jxsv", &[], false),
+    ("video/mj2", &["mj2", "mjp2"], false),
+    ("video/mp1s", &[], false),
+    ("video/mp2p", &[], false),
+    ("video/mp2t", &["ts"], false),
+    ("video/mp4", &["mp4", "mpg4", "m4v"], false),
+    ("video/mp4v-es", &[], false),
+    ("video/mpeg", &["mpeg", "mpg", "mpe", "m1v", "m2v"], false),
+    ("video/mpeg4-generic", &[], false),
+    ("video/mpv", &[], false),
+    ("video/nv", &[], false),
+    ("video/ogg", &["ogv"], false),
+    ("video/parityfec", &[], false),
+    ("video/pointer", &[], false),
+    ("video/quicktime", &["qt", "mov"], false),
+    ("video/raptorfec", &[], false),
+    ("video/raw", &[], false),
+    ("video/rtp-enc-aescm128", &[], false),
+    ("video/rtploopback", &[], false),
+    ("video/rtx", &[], false),
+    ("video/scip", &[], false),
+    ("video/smpte291", &[], false),
+    ("video/smpte292m", &[], false),
+    ("video/ulpfec", &[], false),
+    ("video/vc1", &[], false),
+    ("video/vc2", &[], false),
+    ("video/vnd.cctv", &[], false),
+    ("video/vnd.dece.hd", &["uvh", "uvvh"], false),
+    ("video/vnd.dece.mobile", &["uvm", "uvvm"], false),
+    ("video/vnd.dece.mp4", &["uvu", "uvvu"], false),
+    ("video/vnd.dece.pd", &["uvp", "uvvp"], false),
+    ("video/vnd.dece.sd", &["uvs", "uvvs"], false),
+    ("video/vnd.dece.video", &["uvv", "uvvv"], false),
+    ("video/vnd.directv.mpeg", &[], false),
+    ("video/vnd.directv.mpeg-tts", &[], false),
+    ("video/vnd.dlna.mpeg-tts", &[], false),
+    ("video/vnd.dvb.file", &["dvb"], false),
+    ("video/vnd.fvt", &["fvt"], false),
+    ("video/vnd.hns.video", &[], false),
+    ("video/vnd.iptvforum.1dparityfec-1010", &[], false),
+    ("video/vnd.iptvforum.1dparityfec-2005", &[], false),
+    ("video/vnd.iptvforum.2dparityfec-1010", &[], false),
+    ("video/vnd.iptvforum.2dparityfec-2005", &[], false),
+    ("video/vnd.iptvforum.ttsavc", &[], false),
+    ("video/vnd.iptvforum.ttsmpeg2", &[], false),
+    ("video/vnd.motorola.video", &[], false),
+    ("video/vnd.motorola.videop", &[], false),
+    ("video/vnd.mpegurl", &["mxu", "m4u"], false),
+    ("video/vnd.ms-playready.media.pyv", &["pyv"], false),
+    ("video/vnd.nokia.interleaved-multimedia", &["nim"], false),
+    ("video/vnd.nokia.mp4vr", &[], false),
+    ("video/vnd.nokia.videovoip", &[], false),
+    ("video/vnd.objectvideo", &[], false),
+    ("video/vnd.radgamettools.bink", &["bik", "bk2"], false),
+    ("video/vnd.radgamettools.smacker", &["smk"], false),
+    ("video/vnd.sealed.mpeg1", &["smpg", "s11"], false),
+    ("video/vnd.sealed.mpeg4", &["s14"], false),
+    ("video/vnd.sealed.swf", &["sswf", "ssw"], false),
+    ("video/vnd.sealedmedia.softseal.mov", &["smov", "smo", "s1q"], false),
+    ("video/vnd.uvvu.mp4", &[], false),
+    ("video/vnd.vivo", &["viv"], false),
+    ("video/vnd.youtube.yt", &["yt"], false),
+    ("video/vp8", &[], false),
+    ("video/vp9", &[], false),
+    ("video/webm", &["webm"], false),
+    ("video/x-flv", &["flv"], false),
+    ("video/x-la-asf", &["lsf", "lsx"], false),
+    ("video/x-matroska", &["mpv", "mkv"], false),
+    ("video/x-mng", &["mng"], false),
+    ("video/x-ms-wm", &["wm"], false),
+    ("video/x-ms-wmv", &["wmv"], false),
+    ("video/x-ms-wmx", &["wmx"], false),
+    ("video/x-ms-wvx", &["wvx"], false),
+    ("video/x-msvideo", &["avi"], false),
+    ("video/x-sgi-movie", &["movie"], false),
+];
@@ -3,13 +3,17 @@
 //! [Read more](https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types).
 
 mod constants;
+#[cfg(feature = "mime-db")]
+mod db;
 mod parse;
+mod sniff;
 
 pub use constants::*;
 
 use std::borrow::Cow;
 use std::fmt::{self, Debug, Display};
 use std::option;
+use std::path::Path;
 use std::str::FromStr;
 
 use crate::headers::{HeaderValue, ToHeaderValues};
@@ -50,9 +54,60 @@ impl Mime {
         Mime::from_str(mime)
     }
 
+    /// Determine the mime type of a resource the way browsers do.
+    ///
+    /// This follows the [WHATWG MIME Sniffing
+    /// standard](https://mimesniff.spec.whatwg.org/). `supplied_type` is the
+    /// type declared by the `Content-Type` header, and `bytes` should hold at
+    /// least the first 1445 bytes of the resource, or all of it if it's
+    /// shorter. If `no_sniff` is set, which is the case when
+    /// `X-Content-Type-Options: nosniff` was sent, the supplied type is
+    /// trusted, and resources without a supplied type are never sniffed as a
+    /// scriptable type such as HTML.
+    ///
+    /// Unlike [`Mime::sniff`], this always returns a mime type, falling back
+    /// to `text/plain` or `application/octet-stream`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{mime, security, Response};
+    /// use http_types::mime::Mime;
+    ///
+    /// let mime = Mime::sniff_with(None, b"<!DOCTYPE html><p>Hello", false);
+    /// assert_eq!(mime.essence(), "text/html");
+    ///
+    /// // A feed labeled as HTML.
+    /// let mime = Mime::sniff_with(Some(&mime::HTML), b"<rss version=\"2.0\">", false);
+    /// assert_eq!(mime.essence(), "application/rss+xml");
+    ///
+    /// let mut res = Response::new(200);
+    /// security::nosniff(&mut res);
+    /// let no_sniff = security::is_nosniff(&res);
+    /// let mime = Mime::sniff_with(None, b"<!DOCTYPE html><p>Hello", no_sniff);
+    /// assert_eq!(mime.essence(), "text/plain");
+    /// ```
+    pub fn sniff_with(supplied_type: Option<&Mime>, bytes: &[u8], no_sniff: bool) -> Self {
+        sniff::sniff(supplied_type, bytes, no_sniff)
+    }
+
     /// Guess the mime type from a file extension
+    ///
+    /// With the `mime-db` feature enabled, extensions which aren't part of
+    /// the built-in list are looked up in a table generated from the IANA
+    /// media types registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::mime::{self, Mime};
+    ///
+    /// assert_eq!(Mime::from_extension("html"), Some(mime::HTML));
+    /// assert_eq!(Mime::from_extension("unknown"), None);
+    /// ```
     pub fn from_extension(extension: impl AsRef<str>) -> Option<Self> {
-        match extension.as_ref() {
+        let extension = extension.as_ref();
+        let mime = match extension {
             "7z" => Some(SEVENZIP),
             "atom" => Some(ATOM),
             "avi" => Some(AVI),
@@ -82,6 +137,101 @@ impl Mime {
             "xml" => Some(XML),
             "zip" => Some(ZIP),
             _ => None,
+        };
+
+        #[cfg(feature = "mime-db")]
+        let mime = mime.or_else(|| {
+            let extension = extension.to_ascii_lowercase();
+            let index = db::EXTENSIONS
+                .binary_search_by(|(ext, _)| (*ext).cmp(&extension))
+                .ok()?;
+            Some(Self::from_essence(db::EXTENSIONS[index].1))
+        });
+
+        mime
+    }
+
+    /// Guess the mime type from the extension of a file path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::mime::{self, Mime};
+    ///
+    /// assert_eq!(Mime::from_path("assets/style.css"), Some(mime::CSS));
+    /// assert_eq!(Mime::from_path("README"), None);
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?;
+        Self::from_extension(extension)
+    }
+
+    /// Get the file extensions registered for this mime type, with the
+    /// preferred extension first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::mime;
+    ///
+    /// assert_eq!(mime::JPEG.extensions(), ["jpeg", "jpg", "jpe", "jfif"]);
+    /// ```
+    #[cfg(feature = "mime-db")]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "mime-db")))]
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match Self::db_entry(self.essence()) {
+            Some((_, extensions, _)) => extensions,
+            None => &[],
+        }
+    }
+
+    /// Returns `true` if content of this mime type is likely to benefit from
+    /// compression.
+    ///
+    /// This is `false` for formats which are already compressed, such as
+    /// most images, audio, video and archives, which allows compression
+    /// adapters to skip them. Types which aren't in the table are considered
+    /// compressible if they are text, or use the `+json`, `+xml`, `+text` or
+    /// `+yaml` structured syntax suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::mime;
+    ///
+    /// assert!(mime::HTML.is_compressible());
+    /// assert!(!mime::PNG.is_compressible());
+    /// ```
+    #[cfg(feature = "mime-db")]
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "mime-db")))]
+    pub fn is_compressible(&self) -> bool {
+        match Self::db_entry(self.essence()) {
+            Some((_, _, compressible)) => *compressible,
+            // Keep in sync with `compressible` in `scripts/generate_mime_db.py`.
+            None => {
+                self.basetype() == "text"
+                    || matches!(self.suffix(), Some("json" | "xml" | "text" | "yaml"))
+            }
+        }
+    }
+
+    #[cfg(feature = "mime-db")]
+    fn db_entry(essence: &str) -> Option<&'static (&'static str, &'static [&'static str], bool)> {
+        let index = db::TYPES
+            .binary_search_by(|(entry, _, _)| (*entry).cmp(essence))
+            .ok()?;
+        Some(&db::TYPES[index])
+    }
+
+    /// Create a mime type without parameters from a static, lowercase essence.
+    pub(crate) fn from_essence(essence: &'static str) -> Self {
+        let slash = essence.find('/').unwrap();
+        Self {
+            essence: Cow::Borrowed(essence),
+            basetype: Cow::Borrowed(&essence[..slash]),
+            subtype: Cow::Borrowed(&essence[slash + 1..]),
+            is_utf8: false,
+            params: vec![],
         }
     }
 
@@ -103,6 +253,84 @@ impl Mime {
         &self.essence
     }
 
+    /// Access the Mime's structured syntax suffix, such as `json` for
+    /// `application/problem+json`.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 6838, section 4.2.8: Structured Syntax Name Suffixes](https://www.rfc-editor.org/rfc/rfc6838#section-4.2.8)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::mime::{self, Mime};
+    ///
+    /// let mime: Mime = "application/vnd.api+json".parse().unwrap();
+    /// assert_eq!(mime.suffix(), Some("json"));
+    /// assert_eq!(mime::JSON.suffix(), None);
+    /// ```
+    pub fn suffix(&self) -> Option<&str> {
+        let (name, suffix) = self.subtype.rsplit_once('+')?;
+        match name.is_empty() || suffix.is_empty() {
+            true => None,
+            false => Some(suffix),
+        }
+    }
+
+    /// Returns `true` if this is `application/json`, or a type using the
+    /// `+json` suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::mime::{self, Mime};
+    ///
+    /// assert!(mime::JSON.is_json());
+    /// assert!(Mime::from("application/problem+json").is_json());
+    /// assert!(!mime::PLAIN.is_json());
+    /// ```
+    pub fn is_json(&self) -> bool {
+        self.essence() == "application/json" || self.suffix() == Some("json")
+    }
+
+    /// Returns `true` if this is `application/xml` or `text/xml`, or a type
+    /// using the `+xml` suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::mime;
+    ///
+    /// assert!(mime::XML.is_xml());
+    /// assert!(mime::SVG.is_xml());
+    /// assert!(!mime::JSON.is_xml());
+    /// ```
+    pub fn is_xml(&self) -> bool {
+        matches!(self.essence(), "application/xml" | "text/xml") || self.suffix() == Some("xml")
+    }
+
+    /// Check if this mime type can be processed as the media range by its
+    /// structured syntax suffix.
+    ///
+    /// `application/json` accepts any `+json` type, and `application/*+json`
+    /// any `application` type with the `+json` suffix.
+    pub(crate) fn suffix_eq(&self, range: &Mime) -> bool {
+        let suffix = match self.suffix() {
+            Some(suffix) => suffix,
+            None => return false,
+        };
+        if !range.params.is_empty() {
+            return false;
+        }
+        match range.subtype().strip_prefix("*+") {
+            Some(range_suffix) => {
+                range_suffix == suffix
+                    && (range.basetype() == "*" || range.basetype() == self.basetype())
+            }
+            None => range.basetype() == "application" && range.subtype() == suffix,
+        }
+    }
+
     /// Get a reference to a param.
     pub fn param(&self, name: impl Into<ParamName>) -> Option<&ParamValue> {
         let name: ParamName = name.into();
@@ -270,3 +498,54 @@ impl PartialEq<str> for ParamValue {
         self.0 == other
     }
 }
+
+#[cfg(all(test, feature = "mime-db"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn db_is_sorted() {
+        assert!(db::EXTENSIONS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(db::TYPES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn extension_lookups() {
+        // The built-in list takes precedence over the table.
+        assert_eq!(Mime::from_extension("html"), Some(HTML));
+        assert_eq!(Mime::from_extension("PNG"), Some(PNG));
+        assert_eq!(
+            Mime::from_path("docs/report.pdf").unwrap().essence(),
+            "application/pdf"
+        );
+        assert_eq!(
+            Mime::from_extension("webmanifest").unwrap().subtype(),
+            "manifest+json"
+        );
+
+        let mime: Mime = "text/markdown; charset=utf-8".parse().unwrap();
+        assert_eq!(mime.extensions(), ["md", "markdown"]);
+        assert!(Mime::from_str("application/x-unknown")
+            .unwrap()
+            .extensions()
+            .is_empty());
+    }
+
+    #[test]
+    fn compressible() {
+        assert!(JSON.is_compressible());
+        assert!(SVG.is_compressible());
+        assert!(!ZIP.is_compressible());
+        assert!(!Mime::from_extension("mp4").unwrap().is_compressible());
+        for suffix in &["json", "xml", "text", "yaml"] {
+            let mime = Mime::from_str(&format!("application/x-unknown+{}", suffix)).unwrap();
+            assert!(mime.is_compressible(), "{}", mime);
+        }
+        assert!(!Mime::from_str("application/x-unknown")
+            .unwrap()
+            .is_compressible());
+        assert!(!Mime::from_str("application/x-unknown+zip")
+            .unwrap()
+            .is_compressible());
+    }
+}
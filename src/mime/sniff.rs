@@ -0,0 +1,623 @@
+//! The [WHATWG MIME sniffing algorithm](https://mimesniff.spec.whatwg.org/).
+
+use super::Mime;
+
+/// The number of bytes of a resource considered when sniffing.
+pub(crate) const RESOURCE_HEADER_LEN: usize = 1445;
+
+/// Determine the computed mime type of a resource.
+/// Follows the [WHATWG MIME sniffing algorithm](https://mimesniff.spec.whatwg.org/#determining-the-computed-mime-type-of-a-resource)
+pub(crate) fn sniff(supplied: Option<&Mime>, bytes: &[u8], no_sniff: bool) -> Mime {
+    let bytes = &bytes[..bytes.len().min(RESOURCE_HEADER_LEN)];
+
+    // 1.
+    let supplied = match supplied {
+        Some(mime)
+            if !matches!(
+                mime.essence(),
+                "unknown/unknown" | "application/unknown" | "*/*"
+            ) =>
+        {
+            mime
+        }
+        _ => return Mime::from_essence(identify_unknown(bytes, !no_sniff)),
+    };
+
+    // 2.
+    if no_sniff {
+        return supplied.clone();
+    }
+
+    // 3.
+    if is_apache_bug(supplied) {
+        return Mime::from_essence(text_or_binary(bytes));
+    }
+
+    // 4.
+    if supplied.is_xml() {
+        return supplied.clone();
+    }
+
+    // 5.
+    if supplied.essence() == "text/html" {
+        return match feed_or_html(bytes) {
+            Some(essence) => Mime::from_essence(essence),
+            None => supplied.clone(),
+        };
+    }
+
+    // 6.
+    if supplied.basetype() == "image" {
+        if let Some(essence) = match_image(bytes) {
+            return Mime::from_essence(essence);
+        }
+    }
+
+    // 7.
+    if matches!(supplied.basetype(), "audio" | "video") {
+        if let Some(essence) = match_audio_video(bytes) {
+            return Mime::from_essence(essence);
+        }
+    }
+
+    // 8.
+    supplied.clone()
+}
+
+/// Servers such as Apache send `text/plain`, optionally with an ISO-8859-1 or
+/// UTF-8 charset, for any resource they don't know the type of. These are
+/// checked for binary content.
+fn is_apache_bug(mime: &Mime) -> bool {
+    if mime.essence() != "text/plain" {
+        return false;
+    }
+    match (mime.params.as_slice(), mime.is_utf8) {
+        ([], _) => true,
+        ([(name, value)], false) => {
+            name.as_str() == "charset" && value.as_str().eq_ignore_ascii_case("iso-8859-1")
+        }
+        _ => false,
+    }
+}
+
+/// <https://mimesniff.spec.whatwg.org/#whitespace-byte>
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b'\t' | b'\n' | 0x0C | b'\r' | b' ')
+}
+
+/// <https://mimesniff.spec.whatwg.org/#binary-data-byte>
+fn is_binary(byte: u8) -> bool {
+    matches!(byte, 0x00..=0x08 | 0x0B | 0x0E..=0x1A | 0x1C..=0x1F)
+}
+
+/// <https://mimesniff.spec.whatwg.org/#tag-terminating-byte>
+fn is_tag_terminating(byte: u8) -> bool {
+    matches!(byte, b' ' | b'>')
+}
+
+/// A byte pattern with a mask, matched against the start of a resource.
+/// Follows the [WHATWG pattern matching algorithm](https://mimesniff.spec.whatwg.org/#pattern-matching-algorithm)
+struct Pattern {
+    pattern: &'static [u8],
+    mask: &'static [u8],
+    skip_whitespace: bool,
+    essence: &'static str,
+}
+
+impl Pattern {
+    const fn new(pattern: &'static [u8], mask: &'static [u8], essence: &'static str) -> Self {
+        Self {
+            pattern,
+            mask,
+            skip_whitespace: false,
+            essence,
+        }
+    }
+
+    fn matches(&self, bytes: &[u8]) -> bool {
+        let mut s = 0;
+        if self.skip_whitespace {
+            while s < bytes.len() && is_whitespace(bytes[s]) {
+                s += 1;
+            }
+        }
+        match bytes.get(s..s + self.pattern.len()) {
+            Some(input) => input
+                .iter()
+                .zip(self.pattern.iter().zip(self.mask))
+                .all(|(byte, (pattern, mask))| byte & mask == *pattern),
+            None => false,
+        }
+    }
+}
+
+fn match_patterns(patterns: &[Pattern], bytes: &[u8]) -> Option<&'static str> {
+    patterns
+        .iter()
+        .find(|pattern| pattern.matches(bytes))
+        .map(|pattern| pattern.essence)
+}
+
+/// Tags which identify HTML, matched case-insensitively after leading
+/// whitespace, and followed by a tag-terminating byte.
+const HTML_TAGS: &[&[u8]] = &[
+    b"<!DOCTYPE HTML",
+    b"<HTML",
+    b"<HEAD",
+    b"<SCRIPT",
+    b"<IFRAME",
+    b"<H1",
+    b"<DIV",
+    b"<FONT",
+    b"<TABLE",
+    b"<A",
+    b"<STYLE",
+    b"<TITLE",
+    b"<B",
+    b"<BODY",
+    b"<BR",
+    b"<P",
+    b"<!--",
+];
+
+fn match_html(bytes: &[u8]) -> bool {
+    let start = bytes
+        .iter()
+        .position(|byte| !is_whitespace(*byte))
+        .unwrap_or(bytes.len());
+    let bytes = &bytes[start..];
+    HTML_TAGS.iter().any(|tag| match bytes.get(..=tag.len()) {
+        Some(input) => {
+            input[..tag.len()].eq_ignore_ascii_case(tag) && is_tag_terminating(input[tag.len()])
+        }
+        None => false,
+    })
+}
+
+const SCRIPTABLE_PATTERNS: &[Pattern] = &[
+    Pattern {
+        pattern: b"<?xml",
+        mask: &[0xFF; 5],
+        skip_whitespace: true,
+        essence: "text/xml",
+    },
+    Pattern::new(b"%PDF-", &[0xFF; 5], "application/pdf"),
+];
+
+const TEXT_PATTERNS: &[Pattern] = &[
+    Pattern::new(b"%!PS-Adobe-", &[0xFF; 11], "application/postscript"),
+    Pattern::new(
+        &[0xFE, 0xFF, 0x00, 0x00],
+        &[0xFF, 0xFF, 0x00, 0x00],
+        "text/plain",
+    ),
+    Pattern::new(
+        &[0xFF, 0xFE, 0x00, 0x00],
+        &[0xFF, 0xFF, 0x00, 0x00],
+        "text/plain",
+    ),
+    Pattern::new(
+        &[0xEF, 0xBB, 0xBF, 0x00],
+        &[0xFF, 0xFF, 0xFF, 0x00],
+        "text/plain",
+    ),
+];
+
+/// <https://mimesniff.spec.whatwg.org/#matching-an-image-type-pattern>
+const IMAGE_PATTERNS: &[Pattern] = &[
+    Pattern::new(&[0x00, 0x00, 0x01, 0x00], &[0xFF; 4], "image/x-icon"),
+    Pattern::new(&[0x00, 0x00, 0x02, 0x00], &[0xFF; 4], "image/x-icon"),
+    Pattern::new(b"BM", &[0xFF; 2], "image/bmp"),
+    Pattern::new(b"GIF87a", &[0xFF; 6], "image/gif"),
+    Pattern::new(b"GIF89a", &[0xFF; 6], "image/gif"),
+    Pattern::new(
+        b"RIFF\0\0\0\0WEBPVP",
+        &[
+            0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        ],
+        "image/webp",
+    ),
+    Pattern::new(b"\x89PNG\r\n\x1A\n", &[0xFF; 8], "image/png"),
+    Pattern::new(&[0xFF, 0xD8, 0xFF], &[0xFF; 3], "image/jpeg"),
+];
+
+/// <https://mimesniff.spec.whatwg.org/#matching-an-audio-or-video-type-pattern>
+const AUDIO_VIDEO_PATTERNS: &[Pattern] = &[
+    Pattern::new(
+        b"FORM\0\0\0\0AIFF",
+        &[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF],
+        "audio/aiff",
+    ),
+    Pattern::new(b"ID3", &[0xFF; 3], "audio/mpeg"),
+    Pattern::new(b"OggS\0", &[0xFF; 5], "application/ogg"),
+    Pattern::new(b"MThd\0\0\0\x06", &[0xFF; 8], "audio/midi"),
+    Pattern::new(
+        b"RIFF\0\0\0\0AVI ",
+        &[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF],
+        "video/avi",
+    ),
+    Pattern::new(
+        b"RIFF\0\0\0\0WAVE",
+        &[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF],
+        "audio/wave",
+    ),
+];
+
+/// <https://mimesniff.spec.whatwg.org/#matching-an-archive-type-pattern>
+const ARCHIVE_PATTERNS: &[Pattern] = &[
+    Pattern::new(&[0x1F, 0x8B, 0x08], &[0xFF; 3], "application/x-gzip"),
+    Pattern::new(b"PK\x03\x04", &[0xFF; 4], "application/zip"),
+    Pattern::new(
+        b"Rar \x1A\x07\x00",
+        &[0xFF; 7],
+        "application/x-rar-compressed",
+    ),
+];
+
+fn match_image(bytes: &[u8]) -> Option<&'static str> {
+    match_patterns(IMAGE_PATTERNS, bytes)
+}
+
+fn match_audio_video(bytes: &[u8]) -> Option<&'static str> {
+    if let Some(essence) = match_patterns(AUDIO_VIDEO_PATTERNS, bytes) {
+        Some(essence)
+    } else if is_mp4(bytes) {
+        Some("video/mp4")
+    } else if is_webm(bytes) {
+        Some("video/webm")
+    } else if is_mp3_without_id3(bytes) {
+        Some("audio/mpeg")
+    } else {
+        None
+    }
+}
+
+/// Identify the mime type of a resource without a supplied type.
+/// Follows the [WHATWG rules for identifying an unknown MIME type](https://mimesniff.spec.whatwg.org/#rules-for-identifying-an-unknown-mime-type)
+fn identify_unknown(bytes: &[u8], sniff_scriptable: bool) -> &'static str {
+    // 1.
+    if sniff_scriptable {
+        if match_html(bytes) {
+            return "text/html";
+        }
+        if let Some(essence) = match_patterns(SCRIPTABLE_PATTERNS, bytes) {
+            return essence;
+        }
+    }
+
+    // 2.
+    if let Some(essence) = match_patterns(TEXT_PATTERNS, bytes) {
+        return essence;
+    }
+
+    // 3-5.
+    if let Some(essence) = match_image(bytes)
+        .or_else(|| match_audio_video(bytes))
+        .or_else(|| match_patterns(ARCHIVE_PATTERNS, bytes))
+    {
+        return essence;
+    }
+
+    // 6-7.
+    text_or_binary_fallback(bytes)
+}
+
+/// Follows the [WHATWG rules for sniffing a mislabeled binary resource](https://mimesniff.spec.whatwg.org/#rules-for-text-or-binary)
+fn text_or_binary(bytes: &[u8]) -> &'static str {
+    // 2-3.
+    if bytes.starts_with(&[0xFE, 0xFF])
+        || bytes.starts_with(&[0xFF, 0xFE])
+        || bytes.starts_with(&[0xEF, 0xBB, 0xBF])
+    {
+        return "text/plain";
+    }
+
+    // 4-5.
+    text_or_binary_fallback(bytes)
+}
+
+fn text_or_binary_fallback(bytes: &[u8]) -> &'static str {
+    match bytes.iter().any(|byte| is_binary(*byte)) {
+        true => "application/octet-stream",
+        false => "text/plain",
+    }
+}
+
+/// Detect RSS and Atom feeds which are labeled as HTML. Returns `None` if the
+/// resource should be treated as HTML.
+/// Follows the [WHATWG rules for sniffing a mislabeled feed](https://mimesniff.spec.whatwg.org/#rules-for-distinguishing-if-a-resource-is-a-feed-or-html)
+fn feed_or_html(bytes: &[u8]) -> Option<&'static str> {
+    const RSS_NS: &[u8] = b"http://purl.org/rss/1.0/";
+    const RDF_NS: &[u8] = b"http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+
+    // 2-3.
+    let mut s = match bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        true => 3,
+        false => 0,
+    };
+
+    // 4.
+    loop {
+        // 1.
+        loop {
+            match bytes.get(s)? {
+                b'<' => {
+                    s += 1;
+                    break;
+                }
+                byte if is_whitespace(*byte) => s += 1,
+                _ => return None,
+            }
+        }
+
+        // 2.
+        let rest = &bytes[s..];
+        if rest.starts_with(b"!--") {
+            s += 3 + find(&bytes[s + 3..], b"-->")? + 3;
+        } else if rest.starts_with(b"!") {
+            s += 1 + find(&bytes[s + 1..], b">")? + 1;
+        } else if rest.starts_with(b"?") {
+            s += 1 + find(&bytes[s + 1..], b"?>")? + 2;
+        } else if rest.starts_with(b"rss") {
+            return Some("application/rss+xml");
+        } else if rest.starts_with(b"feed") {
+            return Some("application/atom+xml");
+        } else if rest.starts_with(b"rdf:RDF") {
+            let rest = &rest[7..];
+            let (first, second) = match (find(rest, RSS_NS), find(rest, RDF_NS)) {
+                (Some(rss), Some(rdf)) if rss < rdf => (rss + RSS_NS.len(), RDF_NS),
+                (Some(_), Some(rdf)) => (rdf + RDF_NS.len(), RSS_NS),
+                (Some(rss), None) => (rss + RSS_NS.len(), RDF_NS),
+                (None, Some(rdf)) => (rdf + RDF_NS.len(), RSS_NS),
+                (None, None) => return None,
+            };
+            find(&rest[first..], second)?;
+            return Some("application/rss+xml");
+        } else {
+            return None;
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// <https://mimesniff.spec.whatwg.org/#signature-for-mp4>
+fn is_mp4(bytes: &[u8]) -> bool {
+    // 2.
+    if bytes.len() < 12 {
+        return false;
+    }
+
+    // 3-4.
+    let box_size = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
+    if bytes.len() < box_size || box_size & 0b11 != 0 {
+        return false;
+    }
+
+    // 5-6.
+    if &bytes[4..8] != b"ftyp" {
+        return false;
+    }
+    if &bytes[8..11] == b"mp4" {
+        return true;
+    }
+
+    // 7-8.
+    (16..box_size)
+        .step_by(4)
+        .any(|n| bytes.get(n..n + 3) == Some(b"mp4"))
+}
+
+/// <https://mimesniff.spec.whatwg.org/#signature-for-webm>
+fn is_webm(bytes: &[u8]) -> bool {
+    // 2-3.
+    if bytes.len() < 4 || bytes[..4] != [0x1A, 0x45, 0xDF, 0xA3] {
+        return false;
+    }
+
+    // 4-5.
+    let mut iter = 4;
+    while iter < bytes.len() && iter < 38 {
+        // 1.
+        if bytes[iter..].starts_with(&[0x42, 0x82]) {
+            // 1-2.
+            iter += 2;
+            if iter >= bytes.len() {
+                break;
+            }
+
+            // 3-5.
+            iter += vint_size(&bytes[iter..]);
+            if iter >= bytes.len().saturating_sub(4) {
+                break;
+            }
+
+            // 6.
+            let padding = bytes[iter..].iter().take_while(|byte| **byte == 0).count();
+            return bytes[iter + padding..].starts_with(b"webm");
+        }
+
+        // 2.
+        iter += 1;
+    }
+    false
+}
+
+/// Get the number of bytes taken by an EBML variable size integer.
+/// Follows the [WHATWG algorithm to parse a vint](https://mimesniff.spec.whatwg.org/#parse-a-vint)
+fn vint_size(bytes: &[u8]) -> usize {
+    let first = bytes[0];
+    let mut size = 1;
+    while size < 8 && first & (0x80 >> (size - 1)) == 0 {
+        size += 1;
+    }
+    size
+}
+
+/// <https://mimesniff.spec.whatwg.org/#signature-for-mp3-without-id3>
+fn is_mp3_without_id3(bytes: &[u8]) -> bool {
+    // 2.
+    let size = match mp3_frame_size(bytes, 0) {
+        Some(size) => size,
+        None => return false,
+    };
+
+    // 3-6.
+    if size < 4 || size > bytes.len() {
+        return false;
+    }
+
+    // 7.
+    mp3_frame_size(bytes, size).is_some()
+}
+
+/// Match an MP3 frame header at offset `s`, returning the size of the frame.
+/// Follows the [WHATWG algorithm to match an mp3 header](https://mimesniff.spec.whatwg.org/#match-an-mp3-header)
+fn mp3_frame_size(bytes: &[u8], s: usize) -> Option<usize> {
+    const MP3_RATES: [u32; 15] = [
+        0, 32000, 40000, 48000, 56000, 64000, 80000, 96000, 112000, 128000, 160000, 192000, 224000,
+        256000, 320000,
+    ];
+    const MP25_RATES: [u32; 15] = [
+        0, 8000, 16000, 24000, 32000, 40000, 48000, 56000, 64000, 80000, 96000, 112000, 128000,
+        144000, 160000,
+    ];
+    const SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+    // 1-3.
+    let header = bytes.get(s..s + 4)?;
+    if header[0] != 0xFF || header[1] & 0xE0 != 0xE0 {
+        return None;
+    }
+
+    // 4.
+    let layer = (header[1] & 0x06) >> 1;
+    if layer == 0 {
+        return None;
+    }
+
+    // 5.
+    let bit_rate = ((header[2] & 0xF0) >> 4) as usize;
+    if bit_rate == 15 {
+        return None;
+    }
+
+    // 6.
+    let sample_rate = ((header[2] & 0x0C) >> 2) as usize;
+    if sample_rate == 3 {
+        return None;
+    }
+
+    // Compute the frame size.
+    let version = (header[1] & 0x18) >> 3;
+    let (bit_rate, scale) = match version {
+        3 => (MP3_RATES[bit_rate], 144),
+        _ => (MP25_RATES[bit_rate], 72),
+    };
+    let padding = ((header[2] & 0x02) >> 1) as u32;
+    Some((scale * bit_rate / SAMPLE_RATES[sample_rate] + padding) as usize)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mime;
+
+    fn sniff_str(supplied: Option<&str>, bytes: &[u8]) -> String {
+        let supplied: Option<Mime> = supplied.map(|s| s.parse().unwrap());
+        sniff(supplied.as_ref(), bytes, false).to_string()
+    }
+
+    #[test]
+    fn unknown_type() {
+        assert_eq!(sniff_str(None, b"  <!doctype html>"), "text/html");
+        assert_eq!(sniff_str(None, b"\n<p>hello</p>"), "text/html");
+        assert_eq!(sniff_str(None, b"<pre>hello"), "text/plain");
+        assert_eq!(sniff_str(None, b"<?xml version=\"1.0\"?>"), "text/xml");
+        assert_eq!(sniff_str(None, b"%PDF-1.7"), "application/pdf");
+        assert_eq!(sniff_str(Some("*/*"), b"\x89PNG\r\n\x1A\n..."), "image/png");
+        assert_eq!(sniff_str(None, b"PK\x03\x04"), "application/zip");
+        assert_eq!(sniff_str(None, b"hello"), "text/plain");
+        assert_eq!(sniff_str(None, b"hel\x00lo"), "application/octet-stream");
+        assert_eq!(sniff_str(None, b""), "text/plain");
+    }
+
+    #[test]
+    fn no_sniff() {
+        // Scriptable types aren't sniffed, but the supplied type is kept.
+        let mime = sniff(None, b"<html>", true);
+        assert_eq!(mime.essence(), "text/plain");
+        let mime = sniff(Some(&mime::CSS), b"<html>", true);
+        assert_eq!(mime, mime::CSS);
+    }
+
+    #[test]
+    fn apache_bug() {
+        let plain = Some("text/plain");
+        assert_eq!(
+            sniff_str(plain, b"GIF89a\x00\x01"),
+            "application/octet-stream"
+        );
+        assert_eq!(sniff_str(plain, b"\xFF\xFEh\x00"), "text/plain");
+        assert_eq!(
+            sniff_str(Some("text/plain;charset=windows-1252"), b"\x00"),
+            "text/plain;charset=windows-1252"
+        );
+    }
+
+    #[test]
+    fn feeds() {
+        let html = Some("text/html");
+        assert_eq!(
+            sniff_str(
+                html,
+                b"<?xml version=\"1.0\"?>\n<!-- a > b -->\n<rss version=\"2.0\">"
+            ),
+            "application/rss+xml"
+        );
+        assert_eq!(
+            sniff_str(html, b"<feed xmlns=\"http://www.w3.org/2005/Atom\">"),
+            "application/atom+xml"
+        );
+        assert_eq!(
+            sniff_str(
+                html,
+                b"<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" \
+                  xmlns=\"http://purl.org/rss/1.0/\">"
+            ),
+            "application/rss+xml"
+        );
+        assert_eq!(sniff_str(html, b"<!DOCTYPE html><html>"), "text/html");
+        assert_eq!(sniff_str(Some("image/svg+xml"), b"<html>"), "image/svg+xml");
+    }
+
+    #[test]
+    fn media() {
+        assert_eq!(sniff_str(Some("image/png"), b"GIF87a"), "image/gif");
+        assert_eq!(sniff_str(Some("image/png"), b"not an image"), "image/png");
+        assert_eq!(
+            sniff_str(
+                Some("video/ogg"),
+                b"\x00\x00\x00\x18ftypmp42\x00\x00\x00\x00mp42isom"
+            ),
+            "video/mp4"
+        );
+        assert_eq!(
+            sniff_str(
+                Some("video/mp4"),
+                b"\x1A\x45\xDF\xA3\x9F\x42\x86\x81\x01\x42\x82\x84webm\x42\x87"
+            ),
+            "video/webm"
+        );
+
+        // Two MPEG-1 Layer III frames at 128 kbit/s and 44.1 kHz.
+        let mut mp3 = vec![0xFF, 0xFB, 0x90, 0x00];
+        mp3.resize(417, 0);
+        mp3.extend_from_slice(&[0xFF, 0xFB, 0x90, 0x00]);
+        assert_eq!(sniff_str(Some("audio/ogg"), &mp3), "audio/mpeg");
+    }
+}
@@ -0,0 +1,240 @@
+use super::{ForwardingHeaders, TrustedProxies};
+use crate::headers::HeaderName;
+use crate::{Request, Url};
+
+const X_FORWARDED_PROTO: HeaderName = HeaderName::from_lowercase_str("x-forwarded-proto");
+const X_FORWARDED_HOST: HeaderName = HeaderName::from_lowercase_str("x-forwarded-host");
+const X_FORWARDED_PORT: HeaderName = HeaderName::from_lowercase_str("x-forwarded-port");
+const X_FORWARDED_PREFIX: HeaderName = HeaderName::from_lowercase_str("x-forwarded-prefix");
+
+/// Rebuild the URL a client requested, from the headers set by trusted
+/// proxies.
+pub(crate) fn external_url(req: &Request, proxies: &TrustedProxies) -> Url {
+    let mut url = req.url().clone();
+    let (hops, index) = match proxies.client_hop(req) {
+        Some(hop) => hop,
+        None => return url,
+    };
+
+    let (proto, host, port, prefix) = match proxies.headers() {
+        ForwardingHeaders::Forwarded => match &hops[index] {
+            Some(hop) => (hop.proto(), hop.host(), None, None),
+            None => return url,
+        },
+        ForwardingHeaders::XForwarded => (
+            hop_value(req, X_FORWARDED_PROTO, index, hops.len()),
+            hop_value(req, X_FORWARDED_HOST, index, hops.len()),
+            hop_value(req, X_FORWARDED_PORT, index, hops.len()),
+            hop_value(req, X_FORWARDED_PREFIX, index, hops.len()),
+        ),
+    };
+
+    if let Some(proto) = proto {
+        let proto = proto.to_ascii_lowercase();
+        if matches!(proto.as_str(), "http" | "https" | "ws" | "wss") {
+            let _ = url.set_scheme(&proto);
+        }
+    }
+
+    let mut host_port = None;
+    if let Some(host) = host.and_then(|host| parse_host(url.scheme(), host)) {
+        if url.set_host(host.host_str()).is_ok() {
+            host_port = Some(host.port());
+        }
+    }
+    let port = port.and_then(|port| port.parse::<u16>().ok());
+    match (host_port, port) {
+        (Some(Some(port)), _) | (_, Some(port)) => {
+            let _ = url.set_port(Some(port));
+        }
+        (Some(None), None) => {
+            let _ = url.set_port(None);
+        }
+        (None, None) => {}
+    }
+
+    if let Some(prefix) = prefix {
+        let valid = prefix.starts_with('/') && !prefix.contains(&['?', '#', '\\'][..]);
+        if valid {
+            let path = format!("{}{}", prefix.trim_end_matches('/'), url.path());
+            url.set_path(&path);
+        }
+    }
+
+    url
+}
+
+/// Parse a `host[:port]` value into a URL, so the host is validated and
+/// normalized the same way as other URLs.
+fn parse_host(scheme: &str, host: &str) -> Option<Url> {
+    if host.is_empty() || host.contains(&['/', '?', '#', '@', '\\'][..]) {
+        return None;
+    }
+    let url = Url::parse(&format!("{}://{}", scheme, host)).ok()?;
+    url.host()?;
+    Some(url)
+}
+
+/// Pick the value a proxy set in a header that every proxy appends to.
+///
+/// Values are aligned with the hops from the right, since the rightmost
+/// values were appended by the trusted proxies and anything further left
+/// may have come from the client. If the list is too short to hold a value
+/// for the hop, there is none.
+fn hop_value(req: &Request, name: HeaderName, index: usize, hops: usize) -> Option<&str> {
+    let values: Vec<&str> = req
+        .header(name)?
+        .iter()
+        .flat_map(|value| value.as_str().split(','))
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .collect();
+    let position = values.len().checked_sub(hops.checked_sub(index)?)?;
+    values.get(position).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn request(headers: &[(&str, &str)]) -> Request {
+        let mut req = Request::get("http://10.0.0.5:8080/users?page=2");
+        req.set_peer_addr(Some("10.0.0.1:50000"));
+        for (name, value) in headers {
+            req.append_header(*name, *value).unwrap();
+        }
+        req
+    }
+
+    fn trusted() -> TrustedProxies {
+        TrustedProxies::cidrs(["10.0.0.0/8"]).unwrap()
+    }
+
+    fn trusted_forwarded() -> TrustedProxies {
+        let mut proxies = trusted();
+        proxies.set_headers(ForwardingHeaders::Forwarded);
+        proxies
+    }
+
+    #[test]
+    fn forwarded() {
+        let req = request(&[("Forwarded", "for=203.0.113.9;proto=https;host=example.com")]);
+        let url = external_url(&req, &trusted_forwarded());
+        assert_eq!(url.as_str(), "https://example.com/users?page=2");
+
+        let req = request(&[(
+            "Forwarded",
+            r#"for=203.0.113.9;proto=https;host="example.com:8443", for=10.0.0.2;proto=http;host=internal"#,
+        )]);
+        let url = external_url(&req, &trusted_forwarded());
+        assert_eq!(url.as_str(), "https://example.com:8443/users?page=2");
+    }
+
+    #[test]
+    fn headers_not_written_by_the_proxies_are_ignored() {
+        let req = request(&[
+            ("Forwarded", "for=10.6.6.6;host=evil.example;proto=https"),
+            ("X-Forwarded-For", "203.0.113.9"),
+            ("X-Forwarded-Host", "example.com"),
+        ]);
+        let url = external_url(&req, &trusted());
+        assert_eq!(url.as_str(), "http://example.com/users?page=2");
+
+        let req = request(&[
+            ("Forwarded", "for=203.0.113.9"),
+            ("X-Forwarded-Host", "evil.example"),
+            ("X-Forwarded-Prefix", "/evil"),
+        ]);
+        let url = external_url(&req, &trusted_forwarded());
+        assert_eq!(url.as_str(), "http://10.0.0.5:8080/users?page=2");
+
+        let req = request(&[("Forwarded", "for=bogus;host=evil.example, for=10.0.0.2")]);
+        let url = external_url(&req, &trusted_forwarded());
+        assert_eq!(url.as_str(), "http://10.0.0.5:8080/users?page=2");
+    }
+
+    #[test]
+    fn x_forwarded() {
+        let req = request(&[
+            ("X-Forwarded-For", "203.0.113.9"),
+            ("X-Forwarded-Proto", "HTTPS"),
+            ("X-Forwarded-Host", "example.com"),
+            ("X-Forwarded-Port", "443"),
+            ("X-Forwarded-Prefix", "/api/"),
+        ]);
+        let url = external_url(&req, &trusted());
+        assert_eq!(url.as_str(), "https://example.com/api/users?page=2");
+
+        let req = request(&[
+            ("X-Forwarded-For", "203.0.113.9"),
+            ("X-Forwarded-Host", "[2001:db8::1]"),
+            ("X-Forwarded-Port", "8443"),
+        ]);
+        let url = external_url(&req, &trusted());
+        assert_eq!(url.as_str(), "http://[2001:db8::1]:8443/users?page=2");
+    }
+
+    #[test]
+    fn appended_values_align_with_hops() {
+        let req = request(&[
+            ("X-Forwarded-For", "203.0.113.9, 10.0.0.2"),
+            ("X-Forwarded-Proto", "https, http"),
+            ("X-Forwarded-Host", "example.com, internal"),
+        ]);
+        let url = external_url(&req, &trusted());
+        assert_eq!(url.as_str(), "https://example.com/users?page=2");
+
+        // Only one proxy set the protocol, so it can't belong to the edge.
+        let req = request(&[
+            ("X-Forwarded-For", "203.0.113.9, 10.0.0.2"),
+            ("X-Forwarded-Proto", "https"),
+        ]);
+        let url = external_url(&req, &trusted());
+        assert_eq!(url.as_str(), "http://10.0.0.5:8080/users?page=2");
+    }
+
+    #[test]
+    fn client_values_left_of_the_proxies_are_ignored() {
+        let req = request(&[
+            ("X-Forwarded-For", "203.0.113.9"),
+            ("X-Forwarded-Host", "evil.example, example.com"),
+            ("X-Forwarded-Proto", "http, https"),
+        ]);
+        let url = external_url(&req, &trusted());
+        assert_eq!(url.as_str(), "https://example.com/users?page=2");
+    }
+
+    #[test]
+    fn untrusted_proxies_are_ignored() {
+        let headers = [
+            ("X-Forwarded-For", "203.0.113.9"),
+            ("X-Forwarded-Host", "evil.example"),
+        ];
+        let req = request(&headers);
+        let url = external_url(&req, &TrustedProxies::none());
+        assert_eq!(url.as_str(), "http://10.0.0.5:8080/users?page=2");
+
+        let mut req = request(&headers);
+        req.set_peer_addr(Some("198.51.100.1:50000"));
+        let url = external_url(&req, &trusted());
+        assert_eq!(url.as_str(), "http://10.0.0.5:8080/users?page=2");
+
+        let mut req = request(&headers);
+        req.set_peer_addr(None::<String>);
+        let url = external_url(&req, &trusted());
+        assert_eq!(url.as_str(), "http://10.0.0.5:8080/users?page=2");
+    }
+
+    #[test]
+    fn invalid_values_are_ignored() {
+        let req = request(&[
+            ("X-Forwarded-For", "203.0.113.9"),
+            ("X-Forwarded-Proto", "gopher"),
+            ("X-Forwarded-Host", "example.com/evil"),
+            ("X-Forwarded-Port", "http"),
+            ("X-Forwarded-Prefix", "api"),
+        ]);
+        let url = external_url(&req, &trusted());
+        assert_eq!(url.as_str(), "http://10.0.0.5:8080/users?page=2");
+    }
+}
@@ -0,0 +1,562 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::str::FromStr;
+
+use super::forwarded::ParseError;
+use crate::headers::{HeaderName, Headers, FORWARDED};
+use crate::parse_utils::{parse_quoted_string, parse_token};
+
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_lowercase_str("x-forwarded-for");
+
+/// A single hop in a [`Forwarded`](super::Forwarded) header.
+///
+/// Each proxy a request passes through appends one element, so the leftmost
+/// element describes the hop closest to the client.
+///
+/// # Specifications
+///
+/// - [RFC 7239, section 4: Forwarded HTTP Header Field](https://www.rfc-editor.org/rfc/rfc7239#section-4)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::proxies::{ForwardedElement, NodeName};
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header(
+///     "Forwarded",
+///     r#"for=192.0.2.43;proto=https, for="[2001:db8:cafe::17]:4711";by=_proxy"#,
+/// );
+///
+/// let hops = ForwardedElement::from_headers(&req)?.unwrap();
+/// assert_eq!(hops.len(), 2);
+/// assert_eq!(hops[0].proto(), Some("https"));
+///
+/// let node = hops[1].forwarded_for().unwrap();
+/// assert_eq!(node.ip(), Some("2001:db8:cafe::17".parse()?));
+/// assert_eq!(node.port(), Some(4711));
+/// assert_eq!(hops[1].by().unwrap().name(), &NodeName::Obfuscated("_proxy".into()));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForwardedElement {
+    by: Option<Node>,
+    forwarded_for: Option<Node>,
+    host: Option<String>,
+    proto: Option<String>,
+}
+
+impl ForwardedElement {
+    /// Create a new, empty instance of `ForwardedElement`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the hops from headers (or a request or response).
+    ///
+    /// All `Forwarded` headers are read in order. If there are none, each
+    /// address in `X-Forwarded-For` is read as a hop with only a `for` node.
+    ///
+    /// Either header may have been set by the client. Use
+    /// [`TrustedProxies`](super::TrustedProxies) to find the client address.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Vec<Self>>, ParseError> {
+        let headers = headers.as_ref();
+
+        if let Some(values) = headers.get(FORWARDED) {
+            let mut elements = vec![];
+            for value in values {
+                elements.extend(Self::parse_list(value.as_str())?);
+            }
+            return Ok(Some(elements));
+        }
+
+        match headers.get(X_FORWARDED_FOR) {
+            Some(values) => {
+                let mut elements = vec![];
+                for value in values {
+                    for node in value.as_str().split(',') {
+                        let node = node.trim();
+                        if node.is_empty() {
+                            continue;
+                        }
+                        elements.push(Self {
+                            forwarded_for: Some(Node::from_x_forwarded_for(node)?),
+                            ..Self::default()
+                        });
+                    }
+                }
+                Ok(Some(elements))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Parse a comma-separated list of forwarded elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::proxies::ForwardedElement;
+    ///
+    /// let hops = ForwardedElement::parse_list(r#"for=unknown;host=example.com, for="_hidden:_port""#)?;
+    /// assert_eq!(hops[0].host(), Some("example.com"));
+    /// assert_eq!(hops[1].forwarded_for().unwrap().to_string(), "_hidden:_port");
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn parse_list(input: &str) -> Result<Vec<Self>, ParseError> {
+        let mut elements = vec![];
+        let mut element = Self::new();
+        let mut rest = input.trim_start();
+
+        loop {
+            if !rest.is_empty() && !rest.starts_with(',') && !rest.starts_with(';') {
+                rest = element.parse_pair(rest)?;
+            }
+
+            let trimmed = rest.trim_start();
+            let mut chars = trimmed.chars();
+            match chars.next() {
+                Some(';') => rest = chars.as_str().trim_start(),
+                Some(',') => {
+                    elements.push(std::mem::take(&mut element));
+                    rest = chars.as_str().trim_start();
+                }
+                None => break,
+                Some(_) => {
+                    return Err(ParseError::new("unexpected character in forwarded-element"))
+                }
+            }
+        }
+        elements.push(element);
+
+        // Empty list elements are allowed, and carry no information.
+        elements.retain(|element| element != &Self::default());
+        Ok(elements)
+    }
+
+    /// Parse a comma-separated list of forwarded elements, keeping an element
+    /// that can't be parsed as `None` in its position.
+    pub(crate) fn parse_list_lossy(input: &str) -> Vec<Option<Self>> {
+        split_list(input)
+            .filter_map(|element| match Self::parse_list(element) {
+                Ok(mut elements) => elements.pop().map(Some),
+                Err(_) => Some(None),
+            })
+            .collect()
+    }
+
+    fn parse_pair<'a>(&mut self, input: &'a str) -> Result<&'a str, ParseError> {
+        let (key, value, rest) = match parse_token(input) {
+            (Some(key), rest) if rest.starts_with('=') => match parse_value(&rest[1..]) {
+                (Some(value), rest) => (key, value, rest),
+                (None, _) => return Err(ParseError::new("parse error in forwarded-pair")),
+            },
+            _ => return Err(ParseError::new("parse error in forwarded-pair")),
+        };
+
+        let duplicate = match key.to_ascii_lowercase().as_str() {
+            "by" => self.by.replace(value.parse()?).is_some(),
+            "for" => self.forwarded_for.replace(value.parse()?).is_some(),
+            "host" => self.host.replace(value.into_owned()).is_some(),
+            "proto" => self.proto.replace(value.into_owned()).is_some(),
+            _ => false, // extensions are allowed in the spec
+        };
+        if duplicate {
+            return Err(ParseError::new(
+                "parse error, duplicate key in forwarded-element",
+            ));
+        }
+        Ok(rest)
+    }
+
+    /// Returns the `for` node of this hop.
+    pub fn forwarded_for(&self) -> Option<&Node> {
+        self.forwarded_for.as_ref()
+    }
+
+    /// Sets the `for` node of this hop.
+    pub fn set_forwarded_for(&mut self, node: Node) {
+        self.forwarded_for = Some(node);
+    }
+
+    /// Returns the `by` node of this hop.
+    pub fn by(&self) -> Option<&Node> {
+        self.by.as_ref()
+    }
+
+    /// Sets the `by` node of this hop.
+    pub fn set_by(&mut self, node: Node) {
+        self.by = Some(node);
+    }
+
+    /// Returns the `host` field of this hop.
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    /// Sets the `host` field of this hop.
+    pub fn set_host(&mut self, host: impl Into<String>) {
+        self.host = Some(host.into());
+    }
+
+    /// Returns the `proto` field of this hop.
+    pub fn proto(&self) -> Option<&str> {
+        self.proto.as_deref()
+    }
+
+    /// Sets the `proto` field of this hop.
+    pub fn set_proto(&mut self, proto: impl Into<String>) {
+        self.proto = Some(proto.into());
+    }
+}
+
+impl fmt::Display for ForwardedElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs = [
+            ("by", self.by.as_ref().map(|n| n.to_string())),
+            ("for", self.forwarded_for.as_ref().map(|n| n.to_string())),
+            ("host", self.host.clone()),
+            ("proto", self.proto.clone()),
+        ];
+        let mut first = true;
+        for (key, value) in pairs.iter() {
+            if let Some(value) = value {
+                if !first {
+                    f.write_str(";")?;
+                }
+                first = false;
+                write!(f, "{}={}", key, format_value(value))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A node identifier in a `for` or `by` parameter.
+///
+/// # Specifications
+///
+/// - [RFC 7239, section 6: Node Identifiers](https://www.rfc-editor.org/rfc/rfc7239#section-6)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    name: NodeName,
+    port: Option<NodePort>,
+}
+
+/// The name part of a [`Node`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeName {
+    /// An IPv4 or IPv6 address.
+    Ip(IpAddr),
+    /// An obfuscated identifier, starting with `_`.
+    Obfuscated(String),
+    /// The node is `unknown`.
+    Unknown,
+}
+
+/// The port part of a [`Node`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodePort {
+    /// A port number.
+    Port(u16),
+    /// An obfuscated port, starting with `_`.
+    Obfuscated(String),
+}
+
+impl Node {
+    /// Create a new instance of `Node`.
+    pub fn new(name: NodeName, port: Option<NodePort>) -> Self {
+        Self { name, port }
+    }
+
+    /// Returns the name of the node.
+    pub fn name(&self) -> &NodeName {
+        &self.name
+    }
+
+    /// Returns the port of the node, if one was given.
+    pub fn node_port(&self) -> Option<&NodePort> {
+        self.port.as_ref()
+    }
+
+    /// Returns the IP address of the node, if it isn't obfuscated or unknown.
+    pub fn ip(&self) -> Option<IpAddr> {
+        match self.name {
+            NodeName::Ip(ip) => Some(ip),
+            _ => None,
+        }
+    }
+
+    /// Returns the port number of the node, if it isn't obfuscated.
+    pub fn port(&self) -> Option<u16> {
+        match self.port {
+            Some(NodePort::Port(port)) => Some(port),
+            _ => None,
+        }
+    }
+
+    /// Parse an `X-Forwarded-For` entry, which may be a bare IPv6 address.
+    pub(crate) fn from_x_forwarded_for(s: &str) -> Result<Self, ParseError> {
+        if let Ok(ip) = s.parse::<IpAddr>() {
+            return Ok(Self::from(ip));
+        }
+        s.parse()
+    }
+}
+
+impl From<IpAddr> for Node {
+    fn from(ip: IpAddr) -> Self {
+        Self::new(NodeName::Ip(ip), None)
+    }
+}
+
+impl From<SocketAddr> for Node {
+    fn from(addr: SocketAddr) -> Self {
+        Self::new(NodeName::Ip(addr.ip()), Some(NodePort::Port(addr.port())))
+    }
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    /// ```txt
+    /// node     = nodename [ ":" node-port ]
+    /// nodename = IPv4address / "[" IPv6address "]" / "unknown" / obfnode
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, port) = if let Some(rest) = s.strip_prefix('[') {
+            let end = rest
+                .find(']')
+                .ok_or_else(|| ParseError::new("unterminated IPv6 node"))?;
+            let ip = rest[..end]
+                .parse::<Ipv6Addr>()
+                .map_err(|_| ParseError::new("invalid IPv6 node"))?;
+            let port = match &rest[end + 1..] {
+                "" => None,
+                port => Some(
+                    port.strip_prefix(':')
+                        .ok_or_else(|| ParseError::new("unexpected character after IPv6 node"))?,
+                ),
+            };
+            (NodeName::Ip(IpAddr::V6(ip)), port)
+        } else {
+            let (name, port) = match s.split_once(':') {
+                Some((name, port)) => (name, Some(port)),
+                None => (s, None),
+            };
+            let name = if name.eq_ignore_ascii_case("unknown") {
+                NodeName::Unknown
+            } else if is_obfuscated(name) {
+                NodeName::Obfuscated(name.to_owned())
+            } else {
+                let ip = name
+                    .parse::<Ipv4Addr>()
+                    .map_err(|_| ParseError::new("invalid node name"))?;
+                NodeName::Ip(IpAddr::V4(ip))
+            };
+            (name, port)
+        };
+
+        let port = match port {
+            None => None,
+            Some(port) if is_obfuscated(port) => Some(NodePort::Obfuscated(port.to_owned())),
+            Some(port) if !port.is_empty() && port.len() <= 5 => {
+                let port = port
+                    .parse::<u16>()
+                    .map_err(|_| ParseError::new("invalid node port"))?;
+                Some(NodePort::Port(port))
+            }
+            Some(_) => return Err(ParseError::new("invalid node port")),
+        };
+
+        Ok(Self { name, port })
+    }
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            NodeName::Ip(IpAddr::V4(ip)) => write!(f, "{}", ip)?,
+            NodeName::Ip(IpAddr::V6(ip)) => write!(f, "[{}]", ip)?,
+            NodeName::Obfuscated(name) => f.write_str(name)?,
+            NodeName::Unknown => f.write_str("unknown")?,
+        }
+        match &self.port {
+            Some(NodePort::Port(port)) => write!(f, ":{}", port),
+            Some(NodePort::Obfuscated(port)) => write!(f, ":{}", port),
+            None => Ok(()),
+        }
+    }
+}
+
+/// ```txt
+/// obfnode = "_" 1*( ALPHA / DIGIT / "." / "_" / "-")
+/// ```
+fn is_obfuscated(s: &str) -> bool {
+    s.len() > 1
+        && s.starts_with('_')
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'))
+}
+
+/// Split a list on the commas that aren't inside a quoted string.
+fn split_list(input: &str) -> impl Iterator<Item = &str> {
+    let mut quoted = false;
+    let mut escaped = false;
+    input.split(move |c| {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ',' => return !quoted,
+            _ => {}
+        }
+        false
+    })
+}
+
+fn parse_value(input: &str) -> (Option<Cow<'_, str>>, &str) {
+    match parse_token(input) {
+        (Some(token), rest) => (Some(Cow::Borrowed(token)), rest),
+        (None, rest) => parse_quoted_string(rest),
+    }
+}
+
+fn format_value(input: &str) -> Cow<'_, str> {
+    match parse_token(input) {
+        (_, "") => input.into(),
+        _ => format!("\"{}\"", input.replace('\\', "\\\\").replace('"', "\\\"")).into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Request;
+
+    #[test]
+    fn keeps_element_boundaries() -> crate::Result<()> {
+        let hops = ForwardedElement::parse_list(
+            "for=192.0.2.43;proto=http;by=203.0.113.43, for=198.51.100.17;proto=https",
+        )?;
+        assert_eq!(hops.len(), 2);
+        assert_eq!(hops[0].proto(), Some("http"));
+        assert_eq!(hops[0].by().unwrap().ip(), Some("203.0.113.43".parse()?));
+        assert_eq!(hops[1].proto(), Some("https"));
+        assert_eq!(hops[1].by(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn nodes() -> crate::Result<()> {
+        let node: Node = "192.0.2.43:47011".parse()?;
+        assert_eq!(node.ip(), Some("192.0.2.43".parse()?));
+        assert_eq!(node.port(), Some(47011));
+
+        let node: Node = "[2001:db8:cafe::17]".parse()?;
+        assert_eq!(node.ip(), Some("2001:db8:cafe::17".parse()?));
+        assert_eq!(node.to_string(), "[2001:db8:cafe::17]");
+
+        let node: Node = "UNKNOWN:_SEVKISEK".parse()?;
+        assert_eq!(node.name(), &NodeName::Unknown);
+        assert_eq!(
+            node.node_port(),
+            Some(&NodePort::Obfuscated("_SEVKISEK".into()))
+        );
+
+        let node: Node = "_gazonk".parse()?;
+        assert_eq!(node.name(), &NodeName::Obfuscated("_gazonk".into()));
+
+        for invalid in &[
+            "2001:db8::1",
+            "[2001:db8::1",
+            "[2001:db8::1]x",
+            "example.com",
+            "_",
+            "192.0.2.43:",
+            "192.0.2.43:65536",
+            "192.0.2.43:_",
+        ] {
+            assert!(invalid.parse::<Node>().is_err(), "{}", invalid);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_headers_and_empty_elements() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        req.append_header("Forwarded", "for=192.0.2.1, ,")?;
+        req.append_header("Forwarded", "For=192.0.2.2;;proto=https")?;
+        let hops = ForwardedElement::from_headers(&req)?.unwrap();
+        let nodes: Vec<_> = hops
+            .iter()
+            .map(|hop| hop.forwarded_for().unwrap().to_string())
+            .collect();
+        assert_eq!(nodes, ["192.0.2.1", "192.0.2.2"]);
+        assert_eq!(hops[1].to_string(), "for=192.0.2.2;proto=https");
+        Ok(())
+    }
+
+    #[test]
+    fn x_forwarded_for() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        req.insert_header(
+            "X-Forwarded-For",
+            "192.0.2.1, 2001:db8::1, [2001:db8::2]:80",
+        )?;
+        let hops = ForwardedElement::from_headers(&req)?.unwrap();
+        let nodes: Vec<_> = hops
+            .iter()
+            .map(|hop| hop.forwarded_for().unwrap().to_string())
+            .collect();
+        assert_eq!(nodes, ["192.0.2.1", "[2001:db8::1]", "[2001:db8::2]:80"]);
+        Ok(())
+    }
+
+    #[test]
+    fn lossy_list() {
+        let hops = ForwardedElement::parse_list_lossy(
+            r#"for=bogus, for="[2001:db8::1]:80";host="a,b", , for=192.0.2.1"#,
+        );
+        let nodes: Vec<_> = hops
+            .iter()
+            .map(|hop| {
+                hop.as_ref()
+                    .map(|hop| hop.forwarded_for().unwrap().to_string())
+            })
+            .collect();
+        assert_eq!(
+            nodes,
+            [
+                None,
+                Some("[2001:db8::1]:80".into()),
+                Some("192.0.2.1".into())
+            ]
+        );
+        assert_eq!(hops[1].as_ref().unwrap().host(), Some("a,b"));
+    }
+
+    #[test]
+    fn bad_parse() {
+        for invalid in &[
+            "for=192.0.2.1;for=192.0.2.2",
+            "for=192.0.2.1 proto=http",
+            "for=example.com",
+            "proto",
+        ] {
+            assert!(
+                ForwardedElement::parse_list(invalid).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::headers::{
+    HeaderName, Headers, CONNECTION, KEEP_ALIVE, PROXY_STATUS, TE, TRAILER, TRANSFER_ENCODING,
+    UPGRADE,
+};
+
+/// Headers that only apply to a single connection.
+const HOP_BY_HOP: [HeaderName; 6] = [
+    CONNECTION,
+    KEEP_ALIVE,
+    TE,
+    TRAILER,
+    TRANSFER_ENCODING,
+    UPGRADE,
+];
+
+/// Remove hop-by-hop headers before a message is forwarded.
+///
+/// This removes `Connection`, `Keep-Alive`, `TE`, `Trailer`,
+/// `Transfer-Encoding`, `Upgrade`, every `Proxy-*` header, and every header
+/// listed in `Connection`. `Proxy-Status` is kept, since it's an end-to-end
+/// header intended for the client.
+///
+/// # Specifications
+///
+/// - [RFC 9110, section 7.6.1: Connection](https://www.rfc-editor.org/rfc/rfc9110#section-7.6.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::proxies::strip_hop_by_hop;
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header("Connection", "keep-alive, X-Session");
+/// req.insert_header("Keep-Alive", "timeout=5");
+/// req.insert_header("X-Session", "abc");
+/// req.insert_header("Proxy-Authorization", "Basic Zm9vOmJhcg==");
+/// req.insert_header("Accept", "*/*");
+///
+/// strip_hop_by_hop(&mut req);
+/// assert_eq!(req.iter().count(), 1);
+/// assert_eq!(req["Accept"], "*/*");
+/// #
+/// # Ok(()) }
+/// ```
+pub fn strip_hop_by_hop(mut headers: impl AsMut<Headers>) {
+    let headers = headers.as_mut();
+
+    let listed: Vec<HeaderName> = match headers.get(CONNECTION) {
+        Some(values) => values
+            .iter()
+            .flat_map(|value| value.as_str().split(','))
+            .filter_map(|name| name.trim().parse().ok())
+            .collect(),
+        None => vec![],
+    };
+    let proxy: Vec<HeaderName> = headers
+        .names()
+        .filter(|name| name.as_str().starts_with("proxy-") && **name != PROXY_STATUS)
+        .cloned()
+        .collect();
+
+    for name in HOP_BY_HOP.iter().chain(&listed).chain(&proxy) {
+        headers.remove(name);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Response;
+
+    #[test]
+    fn strips_hop_by_hop() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.append_header("Connection", "close, x-a")?;
+        res.append_header("Connection", "X-B,")?;
+        res.insert_header("X-A", "1")?;
+        res.insert_header("X-B", "2")?;
+        res.insert_header("X-C", "3")?;
+        res.insert_header("Transfer-Encoding", "chunked")?;
+        res.insert_header("Trailer", "Server-Timing")?;
+        res.insert_header("Upgrade", "websocket")?;
+        res.insert_header("TE", "trailers")?;
+        res.insert_header("Proxy-Authenticate", "Basic")?;
+        res.insert_header("Proxy-Connection", "keep-alive")?;
+        res.insert_header("Proxy-Status", "edge")?;
+
+        strip_hop_by_hop(&mut res);
+        let mut names: Vec<_> = res.header_names().map(|name| name.as_str()).collect();
+        names.sort_unstable();
+        assert_eq!(names, ["proxy-status", "x-c"]);
+        Ok(())
+    }
+}
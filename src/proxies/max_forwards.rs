@@ -0,0 +1,167 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, MAX_FORWARDS};
+use crate::{Method, Request};
+
+use std::fmt;
+
+/// Limit the number of times a `TRACE` or `OPTIONS` request can be
+/// forwarded.
+///
+/// # Specifications
+///
+/// - [RFC 9110, section 7.6.2: Max-Forwards](https://www.rfc-editor.org/rfc/rfc9110#section-7.6.2)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::proxies::MaxForwards;
+/// use http_types::{Method, Request};
+///
+/// let mut req = Request::new(Method::Options, "https://example.com");
+/// req.insert_header("Max-Forwards", "1");
+///
+/// assert!(MaxForwards::decrement(&mut req)?);
+/// assert_eq!(req["Max-Forwards"], "0");
+///
+/// // The next proxy must respond to the request itself.
+/// assert!(!MaxForwards::decrement(&mut req)?);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxForwards {
+    remaining: u32,
+}
+
+impl MaxForwards {
+    /// Create a new instance of `MaxForwards`.
+    pub fn new(remaining: u32) -> Self {
+        Self { remaining }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if the value is not a
+    /// non-negative integer.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let value = match headers.as_ref().get(MAX_FORWARDS) {
+            Some(values) => values.last().as_str().trim(),
+            None => return Ok(None),
+        };
+        if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+            bail!(400, "Invalid Max-Forwards value `{}`", value);
+        }
+        // Values too large to represent allow any number of hops.
+        let remaining = value.parse().unwrap_or(u32::MAX);
+        Ok(Some(Self { remaining }))
+    }
+
+    /// Get the number of times the request may still be forwarded.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Set the number of times the request may still be forwarded.
+    pub fn set_remaining(&mut self, remaining: u32) {
+        self.remaining = remaining;
+    }
+
+    /// Apply `Max-Forwards` to a request a proxy is about to forward.
+    ///
+    /// Returns `false` if the request is a `TRACE` or `OPTIONS` request which
+    /// may not be forwarded any further, in which case the proxy should
+    /// respond to it itself. Otherwise the header is decremented, and `true`
+    /// is returned. Requests with other methods are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if the header is invalid.
+    pub fn decrement(req: &mut Request) -> crate::Result<bool> {
+        if !matches!(req.method(), Method::Trace | Method::Options) {
+            return Ok(true);
+        }
+        let mut max_forwards = match Self::from_headers(&*req)? {
+            Some(max_forwards) => max_forwards,
+            None => return Ok(true),
+        };
+        match max_forwards.remaining.checked_sub(1) {
+            Some(remaining) => {
+                max_forwards.remaining = remaining;
+                max_forwards.apply_header(req);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+impl Header for MaxForwards {
+    fn header_name(&self) -> HeaderName {
+        MAX_FORWARDS
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let output = self.remaining.to_string();
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl fmt::Display for MaxForwards {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.remaining)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_headers() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        assert_eq!(MaxForwards::from_headers(&headers)?, None);
+
+        headers.insert(MAX_FORWARDS, "10")?;
+        assert_eq!(
+            MaxForwards::from_headers(&headers)?.unwrap().remaining(),
+            10
+        );
+
+        headers.insert(MAX_FORWARDS, "99999999999")?;
+        let max_forwards = MaxForwards::from_headers(&headers)?.unwrap();
+        assert_eq!(max_forwards.remaining(), u32::MAX);
+
+        for invalid in &["", "-1", "+1", "1.0", "ten"] {
+            headers.insert(MAX_FORWARDS, *invalid)?;
+            let err = MaxForwards::from_headers(&headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", invalid);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn decrement() -> crate::Result<()> {
+        let mut req = Request::new(Method::Trace, "https://example.com");
+        assert!(MaxForwards::decrement(&mut req)?);
+        assert!(req.header(MAX_FORWARDS).is_none());
+
+        req.insert_header(MAX_FORWARDS, "2")?;
+        assert!(MaxForwards::decrement(&mut req)?);
+        assert!(MaxForwards::decrement(&mut req)?);
+        assert_eq!(req[MAX_FORWARDS], "0");
+        assert!(!MaxForwards::decrement(&mut req)?);
+        assert_eq!(req[MAX_FORWARDS], "0");
+
+        let mut req = Request::get("https://example.com");
+        req.insert_header(MAX_FORWARDS, "0")?;
+        assert!(MaxForwards::decrement(&mut req)?);
+        assert_eq!(req[MAX_FORWARDS], "0");
+        Ok(())
+    }
+}
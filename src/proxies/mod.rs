@@ -1,3 +1,20 @@
 //! Headers that are set by proxies
+mod external_url;
 mod forwarded;
+mod forwarded_element;
+mod hop_by_hop;
+mod max_forwards;
+pub mod proxy_protocol;
+mod proxy_status;
+mod trusted_proxies;
+mod via;
+
 pub use forwarded::Forwarded;
+pub use forwarded_element::{ForwardedElement, Node, NodeName, NodePort};
+pub use hop_by_hop::strip_hop_by_hop;
+pub use max_forwards::MaxForwards;
+pub use proxy_status::{ProxyError, ProxyStatus, ProxyStatusEntry};
+pub use trusted_proxies::{ForwardingHeaders, TrustedProxies};
+pub use via::{Via, ViaEntry};
+
+pub(crate) use external_url::external_url;
@@ -0,0 +1,166 @@
+use std::net::SocketAddr;
+use std::slice;
+
+use super::{SslInfo, Tlv};
+use crate::Request;
+
+/// TLV types defined by the PROXY protocol.
+pub(super) const PP2_TYPE_ALPN: u8 = 0x01;
+pub(super) const PP2_TYPE_AUTHORITY: u8 = 0x02;
+pub(super) const PP2_TYPE_CRC32C: u8 = 0x03;
+pub(super) const PP2_TYPE_UNIQUE_ID: u8 = 0x05;
+pub(super) const PP2_TYPE_SSL: u8 = 0x20;
+pub(super) const PP2_TYPE_NETNS: u8 = 0x30;
+
+/// A decoded PROXY protocol header.
+///
+/// The header describes the connection between the client and the proxy in
+/// front of the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyHeader {
+    pub(super) version: u8,
+    pub(super) command: ProxyCommand,
+    pub(super) transport: Option<Transport>,
+    pub(super) addresses: ProxyAddresses,
+    pub(super) tlvs: Vec<Tlv>,
+    pub(super) ssl: Option<SslInfo>,
+}
+
+/// Whether a connection was proxied on behalf of a client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyCommand {
+    /// The connection was opened by the proxy itself, for example for a
+    /// health check. The addresses of the connection should be used as-is.
+    Local,
+    /// The connection was relayed on behalf of a client.
+    Proxy,
+}
+
+/// The transport protocol of the proxied connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    /// A stream protocol, such as TCP.
+    Stream,
+    /// A datagram protocol, such as UDP.
+    Datagram,
+}
+
+/// The addresses of the proxied connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProxyAddresses {
+    /// The addresses are unknown, or should be ignored.
+    Unspecified,
+    /// IPv4 or IPv6 addresses.
+    Inet {
+        /// The address of the client.
+        source: SocketAddr,
+        /// The address the client connected to.
+        destination: SocketAddr,
+    },
+    /// Unix socket paths.
+    Unix {
+        /// The path of the client socket.
+        source: Vec<u8>,
+        /// The path of the socket the client connected to.
+        destination: Vec<u8>,
+    },
+}
+
+impl ProxyHeader {
+    /// The version of the PROXY protocol the header was encoded with, either
+    /// `1` or `2`.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Whether the connection was proxied on behalf of a client.
+    pub fn command(&self) -> ProxyCommand {
+        self.command
+    }
+
+    /// The transport protocol of the proxied connection, if known.
+    pub fn transport(&self) -> Option<Transport> {
+        self.transport
+    }
+
+    /// The addresses of the proxied connection.
+    pub fn addresses(&self) -> &ProxyAddresses {
+        &self.addresses
+    }
+
+    /// The address of the client, if it's an IP address.
+    pub fn source(&self) -> Option<SocketAddr> {
+        match self.addresses {
+            ProxyAddresses::Inet { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+
+    /// The address the client connected to, if it's an IP address.
+    pub fn destination(&self) -> Option<SocketAddr> {
+        match self.addresses {
+            ProxyAddresses::Inet { destination, .. } => Some(destination),
+            _ => None,
+        }
+    }
+
+    /// An iterator visiting all TLVs in the order they were sent.
+    pub fn tlvs(&self) -> slice::Iter<'_, Tlv> {
+        self.tlvs.iter()
+    }
+
+    /// Get the value of the first TLV of a type.
+    pub fn tlv(&self, kind: u8) -> Option<&[u8]> {
+        self.tlvs
+            .iter()
+            .find(|tlv| tlv.kind() == kind)
+            .map(|tlv| tlv.value())
+    }
+
+    /// The application protocol negotiated with the client, such as `h2`.
+    pub fn alpn(&self) -> Option<&[u8]> {
+        self.tlv(PP2_TYPE_ALPN)
+    }
+
+    /// The host name the client requested through SNI.
+    pub fn authority(&self) -> Option<&str> {
+        self.tlv(PP2_TYPE_AUTHORITY)
+            .and_then(|value| std::str::from_utf8(value).ok())
+    }
+
+    /// An opaque identifier for the connection, assigned by the proxy.
+    pub fn unique_id(&self) -> Option<&[u8]> {
+        self.tlv(PP2_TYPE_UNIQUE_ID)
+    }
+
+    /// Details of the TLS connection between the client and the proxy.
+    pub fn ssl(&self) -> Option<&SslInfo> {
+        self.ssl.as_ref()
+    }
+
+    /// The network namespace the connection was accepted in.
+    pub fn net_namespace(&self) -> Option<&str> {
+        self.tlv(PP2_TYPE_NETNS)
+            .and_then(|value| std::str::from_utf8(value).ok())
+    }
+
+    /// Apply the header to a request.
+    ///
+    /// When the connection was proxied on behalf of a client over IP, the
+    /// request's peer and local addresses are set to the client's and the
+    /// destination's. The header is also stored in the request's extensions.
+    pub fn apply(&self, req: &mut Request) {
+        if let (
+            ProxyCommand::Proxy,
+            ProxyAddresses::Inet {
+                source,
+                destination,
+            },
+        ) = (self.command, &self.addresses)
+        {
+            req.set_peer_addr(Some(source));
+            req.set_local_addr(Some(destination));
+        }
+        req.ext_mut().insert(self.clone());
+    }
+}
@@ -0,0 +1,507 @@
+//! Decoding of the HAProxy PROXY protocol.
+//!
+//! Load balancers that forward TCP connections can't add HTTP headers, so
+//! they describe the original connection in a header sent before any other
+//! data instead. Both the text based version 1 and the binary version 2 of
+//! the protocol are supported.
+//!
+//! # Specifications
+//!
+//! - [The PROXY protocol](https://www.haproxy.org/download/2.9/doc/proxy-protocol.txt)
+//!
+//! # Examples
+//!
+//! ```
+//! # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+//! #
+//! use http_types::proxies::proxy_protocol;
+//! use http_types::Request;
+//!
+//! let mut stream = &b"PROXY TCP4 192.0.2.7 198.51.100.1 56324 443\r\nGET / HTTP/1.1\r\n"[..];
+//! let header = proxy_protocol::decode(&mut stream).await?;
+//! assert_eq!(stream, b"GET / HTTP/1.1\r\n");
+//!
+//! let mut req = Request::get("https://example.com");
+//! header.apply(&mut req);
+//! assert_eq!(req.peer_addr(), Some("192.0.2.7:56324"));
+//! assert_eq!(req.local_addr(), Some("198.51.100.1:443"));
+//! assert!(req.ext().get::<proxy_protocol::ProxyHeader>().is_some());
+//! #
+//! # Ok(()) }) }
+//! ```
+
+mod header;
+mod tlv;
+
+pub use header::{ProxyAddresses, ProxyCommand, ProxyHeader, Transport};
+pub use tlv::{SslInfo, Tlv};
+
+use header::{PP2_TYPE_CRC32C, PP2_TYPE_SSL, PP2_TYPE_UNIQUE_ID};
+use tlv::crc32c;
+
+use crate::bail_status as bail;
+
+use futures_lite::io::{self, AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// The signature every version 2 header starts with.
+const SIGNATURE: [u8; 12] = *b"\r\n\r\n\0\r\nQUIT\n";
+
+/// The maximum length of a version 1 header, including the trailing CRLF.
+const MAX_V1_LEN: usize = 107;
+
+/// The maximum length of the `PP2_TYPE_UNIQUE_ID` TLV.
+const MAX_UNIQUE_ID_LEN: usize = 128;
+
+/// Decode a PROXY protocol header from the start of a connection.
+///
+/// Only the header is consumed, so the reader can be used to read the rest
+/// of the connection afterwards.
+///
+/// # Errors
+///
+/// This function errors with `400: Bad Request` if the connection doesn't
+/// start with a valid PROXY protocol header, and with `500: Internal Server
+/// Error` if reading from the connection fails.
+pub async fn decode<R>(reader: &mut R) -> crate::Result<ProxyHeader>
+where
+    R: AsyncBufRead + Unpin,
+{
+    // The shortest valid header, `PROXY UNKNOWN\r\n`, is longer than the
+    // signature, so this never reads past the end of a header.
+    let mut prefix = [0; 12];
+    read_exact(reader, &mut prefix).await?;
+
+    if prefix == SIGNATURE {
+        let mut fixed = [0; 4];
+        read_exact(reader, &mut fixed).await?;
+        let len = usize::from(u16::from_be_bytes([fixed[2], fixed[3]]));
+        let mut header = Vec::with_capacity(16 + len);
+        header.extend_from_slice(&prefix);
+        header.extend_from_slice(&fixed);
+        header.resize(16 + len, 0);
+        read_exact(reader, &mut header[16..]).await?;
+        parse_v2(&header)
+    } else if prefix.starts_with(b"PROXY ") {
+        let mut line = prefix.to_vec();
+        let mut rest = reader.take((MAX_V1_LEN - prefix.len()) as u64);
+        rest.read_until(b'\n', &mut line).await?;
+        parse_v1(&line)
+    } else {
+        bail!(400, "Connection doesn't start with a PROXY protocol header");
+    }
+}
+
+async fn read_exact<R>(reader: &mut R, buf: &mut [u8]) -> crate::Result<()>
+where
+    R: AsyncBufRead + Unpin,
+{
+    match reader.read_exact(buf).await {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => {
+            bail!(400, "Truncated PROXY protocol header")
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Parse a version 1 header, such as
+/// `PROXY TCP4 192.0.2.7 198.51.100.1 56324 443\r\n`.
+fn parse_v1(line: &[u8]) -> crate::Result<ProxyHeader> {
+    let line = match line.strip_suffix(b"\r\n") {
+        Some(line) => line,
+        None => bail!(400, "PROXY protocol header is too long or not terminated"),
+    };
+    let line = match std::str::from_utf8(line) {
+        Ok(line) => line,
+        Err(_) => bail!(400, "PROXY protocol header is not valid ASCII"),
+    };
+
+    let mut fields = line.split(' ').skip(1);
+    let (transport, addresses) = match fields.next() {
+        // Anything after `UNKNOWN` is ignored.
+        Some("UNKNOWN") => (None, ProxyAddresses::Unspecified),
+        Some(family @ "TCP4") | Some(family @ "TCP6") => {
+            let fields: Vec<_> = fields.collect();
+            let (src, dst, sport, dport) = match fields[..] {
+                [src, dst, sport, dport] => (src, dst, sport, dport),
+                _ => bail!(400, "Invalid PROXY protocol header `{}`", line),
+            };
+            let (src, dst) = match family {
+                "TCP4" => parse_v1_addrs::<Ipv4Addr>(src, dst)?,
+                _ => parse_v1_addrs::<Ipv6Addr>(src, dst)?,
+            };
+            let addresses = ProxyAddresses::Inet {
+                source: SocketAddr::new(src, parse_v1_port(sport)?),
+                destination: SocketAddr::new(dst, parse_v1_port(dport)?),
+            };
+            (Some(Transport::Stream), addresses)
+        }
+        _ => bail!(400, "Invalid PROXY protocol header `{}`", line),
+    };
+
+    Ok(ProxyHeader {
+        version: 1,
+        command: ProxyCommand::Proxy,
+        transport,
+        addresses,
+        tlvs: vec![],
+        ssl: None,
+    })
+}
+
+fn parse_v1_addrs<T>(src: &str, dst: &str) -> crate::Result<(IpAddr, IpAddr)>
+where
+    T: std::str::FromStr + Into<IpAddr>,
+{
+    match (src.parse::<T>(), dst.parse::<T>()) {
+        (Ok(src), Ok(dst)) => Ok((src.into(), dst.into())),
+        _ => bail!(400, "Invalid PROXY protocol addresses `{} {}`", src, dst),
+    }
+}
+
+fn parse_v1_port(port: &str) -> crate::Result<u16> {
+    let digits = !port.is_empty() && port.bytes().all(|b| b.is_ascii_digit());
+    let canonical = port == "0" || !port.starts_with('0');
+    match port.parse() {
+        Ok(n) if digits && canonical => Ok(n),
+        _ => bail!(400, "Invalid PROXY protocol port `{}`", port),
+    }
+}
+
+/// Parse a version 2 header, including the 16 byte fixed part.
+fn parse_v2(header: &[u8]) -> crate::Result<ProxyHeader> {
+    let (version, command) = (header[12] >> 4, header[12] & 0x0f);
+    if version != 2 {
+        bail!(400, "Unsupported PROXY protocol version {}", version);
+    }
+    let command = match command {
+        0x0 => ProxyCommand::Local,
+        0x1 => ProxyCommand::Proxy,
+        _ => bail!(400, "Invalid PROXY protocol command {:#x}", command),
+    };
+
+    let (family, transport) = (header[13] >> 4, header[13] & 0x0f);
+    let transport = match transport {
+        0x0 => None,
+        0x1 => Some(Transport::Stream),
+        0x2 => Some(Transport::Datagram),
+        _ => bail!(400, "Invalid PROXY protocol transport {:#x}", transport),
+    };
+    let addr_len = match family {
+        0x0 => 0,
+        0x1 => 12,
+        0x2 => 36,
+        0x3 => 216,
+        _ => bail!(400, "Invalid PROXY protocol address family {:#x}", family),
+    };
+
+    let body = &header[16..];
+    if body.len() < addr_len {
+        bail!(400, "PROXY protocol addresses are truncated");
+    }
+    let (addrs, tlv_bytes) = body.split_at(addr_len);
+
+    // Addresses sent with the `LOCAL` command must be ignored.
+    let addresses = match (command, family) {
+        (ProxyCommand::Local, _) | (_, 0x0) => ProxyAddresses::Unspecified,
+        (_, 0x1) => {
+            let ip =
+                |at: usize| IpAddr::from([addrs[at], addrs[at + 1], addrs[at + 2], addrs[at + 3]]);
+            ProxyAddresses::Inet {
+                source: SocketAddr::new(ip(0), port(addrs, 8)),
+                destination: SocketAddr::new(ip(4), port(addrs, 10)),
+            }
+        }
+        (_, 0x2) => {
+            let ip = |at: usize| {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(&addrs[at..at + 16]);
+                IpAddr::from(octets)
+            };
+            ProxyAddresses::Inet {
+                source: SocketAddr::new(ip(0), port(addrs, 32)),
+                destination: SocketAddr::new(ip(16), port(addrs, 34)),
+            }
+        }
+        _ => {
+            let path = |bytes: &[u8]| {
+                let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+                bytes[..end].to_vec()
+            };
+            ProxyAddresses::Unix {
+                source: path(&addrs[..108]),
+                destination: path(&addrs[108..]),
+            }
+        }
+    };
+
+    let tlvs = Tlv::parse_list(tlv_bytes)?;
+    let mut ssl = None;
+    let mut offset = 16 + addr_len;
+    for tlv in &tlvs {
+        match tlv.kind() {
+            PP2_TYPE_CRC32C => verify_crc32c(header, offset + 3, tlv.value())?,
+            PP2_TYPE_UNIQUE_ID if tlv.value().len() > MAX_UNIQUE_ID_LEN => {
+                bail!(400, "PROXY protocol unique ID is too long")
+            }
+            PP2_TYPE_SSL if ssl.is_none() => ssl = Some(SslInfo::parse(tlv.value())?),
+            _ => {}
+        }
+        offset += 3 + tlv.value().len();
+    }
+
+    Ok(ProxyHeader {
+        version: 2,
+        command,
+        transport,
+        addresses,
+        tlvs,
+        ssl,
+    })
+}
+
+fn port(bytes: &[u8], at: usize) -> u16 {
+    u16::from_be_bytes([bytes[at], bytes[at + 1]])
+}
+
+/// Check a `PP2_TYPE_CRC32C` TLV, which covers the whole header with the
+/// checksum itself set to zero.
+fn verify_crc32c(header: &[u8], at: usize, value: &[u8]) -> crate::Result<()> {
+    if value.len() != 4 {
+        bail!(400, "Invalid PROXY protocol checksum length");
+    }
+    let mut zeroed = header.to_vec();
+    zeroed[at..at + 4].copy_from_slice(&[0; 4]);
+    let expected = u32::from_be_bytes([value[0], value[1], value[2], value[3]]);
+    if crc32c(&zeroed) != expected {
+        bail!(400, "PROXY protocol checksum mismatch");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Request;
+
+    async fn decode_bytes(bytes: &[u8]) -> crate::Result<ProxyHeader> {
+        let mut reader = bytes;
+        decode(&mut reader).await
+    }
+
+    /// Build a version 2 header from its parts, appending a checksum if
+    /// requested.
+    fn v2(ver_cmd: u8, fam: u8, addrs: &[u8], tlvs: &[(u8, &[u8])], checksum: bool) -> Vec<u8> {
+        let mut body = addrs.to_vec();
+        for (kind, value) in tlvs {
+            body.push(*kind);
+            body.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            body.extend_from_slice(value);
+        }
+        if checksum {
+            body.extend_from_slice(&[PP2_TYPE_CRC32C, 0, 4, 0, 0, 0, 0]);
+        }
+        let mut header = SIGNATURE.to_vec();
+        header.extend_from_slice(&[ver_cmd, fam]);
+        header.extend_from_slice(&(body.len() as u16).to_be_bytes());
+        header.extend_from_slice(&body);
+        if checksum {
+            let crc = crc32c(&header).to_be_bytes();
+            let len = header.len();
+            header[len - 4..].copy_from_slice(&crc);
+        }
+        header
+    }
+
+    const INET4: [u8; 12] = [192, 0, 2, 7, 198, 51, 100, 1, 0xdc, 0x04, 0x01, 0xbb];
+
+    #[async_std::test]
+    async fn v1() -> crate::Result<()> {
+        let header = decode_bytes(b"PROXY TCP4 192.0.2.7 198.51.100.1 56324 443\r\n").await?;
+        assert_eq!(header.version(), 1);
+        assert_eq!(header.command(), ProxyCommand::Proxy);
+        assert_eq!(header.transport(), Some(Transport::Stream));
+        assert_eq!(header.source(), Some("192.0.2.7:56324".parse()?));
+        assert_eq!(header.destination(), Some("198.51.100.1:443".parse()?));
+
+        let header = decode_bytes(b"PROXY TCP6 2001:db8::1 ::1 65535 0\r\n").await?;
+        assert_eq!(header.source(), Some("[2001:db8::1]:65535".parse()?));
+        assert_eq!(header.destination(), Some("[::1]:0".parse()?));
+
+        let header = decode_bytes(b"PROXY UNKNOWN ffff::1 ffff::2 1 2\r\n").await?;
+        assert_eq!(header.addresses(), &ProxyAddresses::Unspecified);
+        assert_eq!(header.transport(), None);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn v1_invalid() {
+        let longest = format!(
+            "PROXY UNKNOWN {}\r\n",
+            "f".repeat(MAX_V1_LEN - "PROXY UNKNOWN \r\n".len())
+        );
+        assert!(decode_bytes(longest.as_bytes()).await.is_ok());
+
+        let too_long = format!("PROXY UNKNOWN {}\r\n", "f".repeat(MAX_V1_LEN));
+        let invalid = [
+            too_long.as_bytes(),
+            b"PROXY TCP4 192.0.2.7 198.51.100.1 56324 443\n",
+            b"PROXY TCP4 192.0.2.7 198.51.100.1 56324\r\n",
+            b"PROXY TCP4 2001:db8::1 198.51.100.1 56324 443\r\n",
+            b"PROXY TCP6 192.0.2.7 ::1 56324 443\r\n",
+            b"PROXY TCP4 192.0.2.7 198.51.100.1 056324 443\r\n",
+            b"PROXY TCP4 192.0.2.7 198.51.100.1 65536 443\r\n",
+            b"PROXY TCP4  192.0.2.7 198.51.100.1 56324 443\r\n",
+            b"PROXY UDP4 192.0.2.7 198.51.100.1 56324 443\r\n",
+            b"GET / HTTP/1.1\r\nHost: example.com\r\n",
+            b"PROXY TCP4",
+        ];
+        for bytes in &invalid {
+            let err = decode_bytes(bytes).await.unwrap_err();
+            assert_eq!(err.status(), 400, "{:?}", String::from_utf8_lossy(bytes));
+        }
+    }
+
+    #[async_std::test]
+    async fn v2_inet() -> crate::Result<()> {
+        let bytes = v2(0x21, 0x11, &INET4, &[], false);
+        let header = decode_bytes(&bytes).await?;
+        assert_eq!(header.version(), 2);
+        assert_eq!(header.transport(), Some(Transport::Stream));
+        assert_eq!(header.source(), Some("192.0.2.7:56324".parse()?));
+        assert_eq!(header.destination(), Some("198.51.100.1:443".parse()?));
+
+        let mut addrs = vec![];
+        addrs.extend_from_slice(&"2001:db8::7".parse::<Ipv6Addr>()?.octets());
+        addrs.extend_from_slice(&Ipv6Addr::LOCALHOST.octets());
+        addrs.extend_from_slice(&[0x00, 0x35, 0x00, 0x35]);
+        let bytes = v2(0x21, 0x22, &addrs, &[], false);
+        let header = decode_bytes(&bytes).await?;
+        assert_eq!(header.transport(), Some(Transport::Datagram));
+        assert_eq!(header.source(), Some("[2001:db8::7]:53".parse()?));
+        assert_eq!(header.destination(), Some("[::1]:53".parse()?));
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn v2_unix_and_local() -> crate::Result<()> {
+        let mut addrs = vec![0; 216];
+        addrs[..9].copy_from_slice(b"/tmp/a.sk");
+        addrs[108..117].copy_from_slice(b"/tmp/b.sk");
+        let bytes = v2(0x21, 0x31, &addrs, &[], false);
+        let header = decode_bytes(&bytes).await?;
+        assert_eq!(
+            header.addresses(),
+            &ProxyAddresses::Unix {
+                source: b"/tmp/a.sk".to_vec(),
+                destination: b"/tmp/b.sk".to_vec(),
+            }
+        );
+        assert_eq!(header.source(), None);
+
+        let bytes = v2(0x20, 0x11, &INET4, &[], false);
+        let header = decode_bytes(&bytes).await?;
+        assert_eq!(header.command(), ProxyCommand::Local);
+        assert_eq!(header.addresses(), &ProxyAddresses::Unspecified);
+
+        let bytes = v2(0x20, 0x00, &[], &[], false);
+        let header = decode_bytes(&bytes).await?;
+        assert_eq!(header.transport(), None);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn v2_tlvs() -> crate::Result<()> {
+        let mut ssl = vec![0x07, 0, 0, 0, 0];
+        for (kind, value) in &[
+            (0x21, &b"TLSv1.3"[..]),
+            (0x22, b"client"),
+            (0x23, b"TLS_AES_128_GCM_SHA256"),
+        ] {
+            ssl.push(*kind);
+            ssl.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            ssl.extend_from_slice(value);
+        }
+        let tlvs: [(u8, &[u8]); 6] = [
+            (0x01, b"h2"),
+            (0x02, b"example.com"),
+            (0x04, b"\0\0\0"),
+            (0x05, b"conn-1234"),
+            (0x20, &ssl),
+            (0xe0, b"custom"),
+        ];
+        let bytes = v2(0x21, 0x11, &INET4, &tlvs, true);
+        let header = decode_bytes(&bytes).await?;
+        assert_eq!(header.alpn(), Some(&b"h2"[..]));
+        assert_eq!(header.authority(), Some("example.com"));
+        assert_eq!(header.unique_id(), Some(&b"conn-1234"[..]));
+        assert_eq!(header.tlv(0xe0), Some(&b"custom"[..]));
+        assert_eq!(header.tlvs().count(), 7);
+
+        let ssl = header.ssl().unwrap();
+        assert!(ssl.is_tls());
+        assert!(ssl.client_cert_conn());
+        assert!(ssl.client_cert_sess());
+        assert!(ssl.is_verified());
+        assert_eq!(ssl.version(), Some("TLSv1.3"));
+        assert_eq!(ssl.common_name(), Some("client"));
+        assert_eq!(ssl.cipher(), Some("TLS_AES_128_GCM_SHA256"));
+        assert_eq!(ssl.key_algorithm(), None);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn v2_invalid() {
+        let mut bad_checksum = v2(0x21, 0x11, &INET4, &[(0x01, b"h2")], true);
+        let len = bad_checksum.len();
+        bad_checksum[len - 1] ^= 1;
+
+        let mut truncated = v2(0x21, 0x11, &INET4, &[], false);
+        truncated.pop();
+
+        let long_id = [b'a'; MAX_UNIQUE_ID_LEN + 1];
+        let invalid = [
+            bad_checksum,
+            truncated,
+            v2(0x11, 0x11, &INET4, &[], false),
+            v2(0x22, 0x11, &INET4, &[], false),
+            v2(0x21, 0x41, &INET4, &[], false),
+            v2(0x21, 0x13, &INET4, &[], false),
+            v2(0x21, 0x21, &INET4, &[], false),
+            v2(0x21, 0x11, &INET4, &[(0x05, &long_id)], false),
+            v2(0x21, 0x11, &INET4, &[(0x20, &[0x01])], false),
+        ];
+        for bytes in &invalid {
+            let err = decode_bytes(bytes).await.unwrap_err();
+            assert_eq!(err.status(), 400, "{:?}", bytes);
+        }
+    }
+
+    #[async_std::test]
+    async fn leaves_rest_of_stream() -> crate::Result<()> {
+        let mut bytes = v2(0x21, 0x11, &INET4, &[], false);
+        bytes.extend_from_slice(b"GET / HTTP/1.1\r\n");
+        let mut reader = &bytes[..];
+        decode(&mut reader).await?;
+        assert_eq!(reader, b"GET / HTTP/1.1\r\n");
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn apply() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        req.set_peer_addr(Some("10.0.0.1:4000"));
+        let local = decode_bytes(&v2(0x20, 0x11, &INET4, &[], false)).await?;
+        local.apply(&mut req);
+        assert_eq!(req.peer_addr(), Some("10.0.0.1:4000"));
+        assert_eq!(req.ext().get::<ProxyHeader>(), Some(&local));
+
+        let proxied = decode_bytes(&v2(0x21, 0x11, &INET4, &[], false)).await?;
+        proxied.apply(&mut req);
+        assert_eq!(req.peer_addr(), Some("192.0.2.7:56324"));
+        assert_eq!(req.local_addr(), Some("198.51.100.1:443"));
+        assert_eq!(req.ext().get::<ProxyHeader>(), Some(&proxied));
+        Ok(())
+    }
+}
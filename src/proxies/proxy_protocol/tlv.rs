@@ -0,0 +1,168 @@
+use std::slice;
+
+use crate::bail_status as bail;
+
+/// SSL sub-TLV types defined by the PROXY protocol.
+const PP2_SUBTYPE_SSL_VERSION: u8 = 0x21;
+const PP2_SUBTYPE_SSL_CN: u8 = 0x22;
+const PP2_SUBTYPE_SSL_CIPHER: u8 = 0x23;
+const PP2_SUBTYPE_SSL_SIG_ALG: u8 = 0x24;
+const PP2_SUBTYPE_SSL_KEY_ALG: u8 = 0x25;
+
+/// SSL client flags.
+const PP2_CLIENT_SSL: u8 = 0x01;
+const PP2_CLIENT_CERT_CONN: u8 = 0x02;
+const PP2_CLIENT_CERT_SESS: u8 = 0x04;
+
+/// A type-length-value field in a version 2 PROXY protocol header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tlv {
+    kind: u8,
+    value: Vec<u8>,
+}
+
+impl Tlv {
+    /// The type of the field.
+    pub fn kind(&self) -> u8 {
+        self.kind
+    }
+
+    /// The value of the field.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Decode a list of TLVs.
+    pub(super) fn parse_list(mut bytes: &[u8]) -> crate::Result<Vec<Self>> {
+        let mut tlvs = vec![];
+        while !bytes.is_empty() {
+            if bytes.len() < 3 {
+                bail!(400, "Truncated PROXY protocol TLV");
+            }
+            let len = usize::from(u16::from_be_bytes([bytes[1], bytes[2]]));
+            if bytes.len() < 3 + len {
+                bail!(400, "Truncated PROXY protocol TLV");
+            }
+            tlvs.push(Self {
+                kind: bytes[0],
+                value: bytes[3..3 + len].to_vec(),
+            });
+            bytes = &bytes[3 + len..];
+        }
+        Ok(tlvs)
+    }
+}
+
+/// Details of the TLS connection between the client and a proxy, sent in
+/// the `PP2_TYPE_SSL` TLV.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SslInfo {
+    client: u8,
+    verify: u32,
+    tlvs: Vec<Tlv>,
+}
+
+impl SslInfo {
+    pub(super) fn parse(bytes: &[u8]) -> crate::Result<Self> {
+        if bytes.len() < 5 {
+            bail!(400, "Truncated PROXY protocol SSL TLV");
+        }
+        Ok(Self {
+            client: bytes[0],
+            verify: u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]),
+            tlvs: Tlv::parse_list(&bytes[5..])?,
+        })
+    }
+
+    /// Returns `true` if the client connected over TLS.
+    pub fn is_tls(&self) -> bool {
+        self.client & PP2_CLIENT_SSL != 0
+    }
+
+    /// Returns `true` if the client presented a certificate on this
+    /// connection.
+    pub fn client_cert_conn(&self) -> bool {
+        self.client & PP2_CLIENT_CERT_CONN != 0
+    }
+
+    /// Returns `true` if the client presented a certificate at least once
+    /// during the TLS session.
+    pub fn client_cert_sess(&self) -> bool {
+        self.client & PP2_CLIENT_CERT_SESS != 0
+    }
+
+    /// Returns `true` if the client certificate was verified.
+    pub fn is_verified(&self) -> bool {
+        self.verify == 0
+    }
+
+    /// The TLS version, such as `TLSv1.3`.
+    pub fn version(&self) -> Option<&str> {
+        self.text(PP2_SUBTYPE_SSL_VERSION)
+    }
+
+    /// The common name of the client certificate's subject.
+    pub fn common_name(&self) -> Option<&str> {
+        self.text(PP2_SUBTYPE_SSL_CN)
+    }
+
+    /// The negotiated cipher.
+    pub fn cipher(&self) -> Option<&str> {
+        self.text(PP2_SUBTYPE_SSL_CIPHER)
+    }
+
+    /// The algorithm used to sign the certificate presented by the proxy.
+    pub fn signature_algorithm(&self) -> Option<&str> {
+        self.text(PP2_SUBTYPE_SSL_SIG_ALG)
+    }
+
+    /// The algorithm of the key used by the proxy's certificate.
+    pub fn key_algorithm(&self) -> Option<&str> {
+        self.text(PP2_SUBTYPE_SSL_KEY_ALG)
+    }
+
+    /// An iterator visiting all sub-TLVs.
+    pub fn tlvs(&self) -> slice::Iter<'_, Tlv> {
+        self.tlvs.iter()
+    }
+
+    fn text(&self, kind: u8) -> Option<&str> {
+        let tlv = self.tlvs.iter().find(|tlv| tlv.kind == kind)?;
+        std::str::from_utf8(&tlv.value).ok()
+    }
+}
+
+/// Compute the CRC-32C (Castagnoli) checksum of some bytes.
+pub(super) fn crc32c(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0x82f6_3b78 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn crc32c_check_value() {
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+        assert_eq!(crc32c(b""), 0);
+    }
+
+    #[test]
+    fn truncated_tlvs() {
+        assert!(Tlv::parse_list(&[0x01, 0x00]).is_err());
+        assert!(Tlv::parse_list(&[0x01, 0x00, 0x02, b'h']).is_err());
+        assert!(SslInfo::parse(&[0x01, 0, 0, 0]).is_err());
+
+        let tlvs = Tlv::parse_list(&[0x04, 0x00, 0x00, 0x01, 0x00, 0x01, b'x']).unwrap();
+        assert_eq!(tlvs.len(), 2);
+        assert_eq!(tlvs[1].value(), b"x");
+    }
+}
@@ -0,0 +1,563 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, PROXY_STATUS};
+use crate::utils::structured_field::{self, BareItem, Item};
+use crate::StatusCode;
+
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::slice;
+
+/// Describe how intermediaries have handled a response.
+///
+/// # Specifications
+///
+/// - [RFC 9209: The Proxy-Status HTTP Response Header Field](https://www.rfc-editor.org/rfc/rfc9209)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::{Response, StatusCode};
+/// use http_types::proxies::{ProxyError, ProxyStatus, ProxyStatusEntry};
+///
+/// let mut entry = ProxyStatusEntry::new("ExampleCDN");
+/// entry.set_error(Some(ProxyError::ConnectionTimeout));
+/// entry.set_next_hop(Some("backend.example.org:8001".into()));
+///
+/// let mut proxy_status = ProxyStatus::new();
+/// proxy_status.push(entry);
+///
+/// let mut res = Response::new(504);
+/// res.insert_header(&proxy_status, &proxy_status);
+/// assert_eq!(
+///     res["Proxy-Status"],
+///     "ExampleCDN;error=connection_timeout;next-hop=backend.example.org:8001"
+/// );
+///
+/// let proxy_status = ProxyStatus::from_headers(res)?.unwrap();
+/// let error = proxy_status.last().unwrap().error().unwrap();
+/// assert_eq!(error, &ProxyError::ConnectionTimeout);
+/// assert_eq!(error.recommended_status(), Some(StatusCode::GatewayTimeout));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProxyStatus {
+    entries: Vec<ProxyStatusEntry>,
+}
+
+impl ProxyStatus {
+    /// Create a new instance of `ProxyStatus`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Entries from multiple `Proxy-Status` headers are combined in order.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let items = match structured_field::parse_header(headers.as_ref(), PROXY_STATUS)? {
+            Some(items) => items,
+            None => return Ok(None),
+        };
+        let entries = items
+            .into_iter()
+            .map(ProxyStatusEntry::from_item)
+            .collect::<crate::Result<_>>()?;
+        Ok(Some(Self { entries }))
+    }
+
+    /// Append an entry for the intermediary that handled the response.
+    pub fn push(&mut self, entry: ProxyStatusEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Get the entry of the intermediary closest to the user.
+    pub fn last(&self) -> Option<&ProxyStatusEntry> {
+        self.entries.last()
+    }
+
+    /// An iterator visiting all entries, starting with the intermediary
+    /// closest to the origin server.
+    pub fn iter(&self) -> slice::Iter<'_, ProxyStatusEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Header for ProxyStatus {
+    fn header_name(&self) -> HeaderName {
+        PROXY_STATUS
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        structured_field::header_value(self.entries.iter().map(|entry| entry.to_item()))
+    }
+}
+
+impl IntoIterator for ProxyStatus {
+    type Item = ProxyStatusEntry;
+    type IntoIter = std::vec::IntoIter<ProxyStatusEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ProxyStatus {
+    type Item = &'a ProxyStatusEntry;
+    type IntoIter = slice::Iter<'a, ProxyStatusEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// How a single intermediary handled a response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProxyStatusEntry {
+    proxy: String,
+    error: Option<ProxyError>,
+    next_hop: Option<String>,
+    next_protocol: Option<String>,
+    received_status: Option<u16>,
+    details: Option<String>,
+    extensions: Vec<(String, BareItem)>,
+}
+
+impl ProxyStatusEntry {
+    /// Create a new entry for the intermediary with the given identifier.
+    pub fn new(proxy: impl Into<String>) -> Self {
+        Self {
+            proxy: proxy.into(),
+            error: None,
+            next_hop: None,
+            next_protocol: None,
+            received_status: None,
+            details: None,
+            extensions: vec![],
+        }
+    }
+
+    /// Get the identifier of the intermediary.
+    pub fn proxy(&self) -> &str {
+        &self.proxy
+    }
+
+    /// Get the error the intermediary encountered.
+    pub fn error(&self) -> Option<&ProxyError> {
+        self.error.as_ref()
+    }
+
+    /// Set the error the intermediary encountered.
+    pub fn set_error(&mut self, error: Option<ProxyError>) {
+        self.error = error;
+    }
+
+    /// Get the hostname, IP address or alias of the next hop.
+    pub fn next_hop(&self) -> Option<&str> {
+        self.next_hop.as_deref()
+    }
+
+    /// Set the hostname, IP address or alias of the next hop.
+    pub fn set_next_hop(&mut self, next_hop: Option<String>) {
+        self.next_hop = next_hop;
+    }
+
+    /// Get the ALPN protocol identifier used to connect to the next hop.
+    pub fn next_protocol(&self) -> Option<&str> {
+        self.next_protocol.as_deref()
+    }
+
+    /// Set the ALPN protocol identifier used to connect to the next hop.
+    pub fn set_next_protocol(&mut self, protocol: Option<String>) {
+        self.next_protocol = protocol;
+    }
+
+    /// Get the status code the intermediary received from the next hop.
+    pub fn received_status(&self) -> Option<u16> {
+        self.received_status
+    }
+
+    /// Set the status code the intermediary received from the next hop.
+    pub fn set_received_status(&mut self, status: Option<u16>) {
+        self.received_status = status;
+    }
+
+    /// Get additional implementation-specific details.
+    pub fn details(&self) -> Option<&str> {
+        self.details.as_deref()
+    }
+
+    /// Set additional implementation-specific details.
+    pub fn set_details(&mut self, details: Option<String>) {
+        self.details = details;
+    }
+
+    /// Get the serialized value of an extension parameter, such as `?1` or
+    /// `"text"`.
+    ///
+    /// This includes the parameters specific to some error types, such as
+    /// `rcode` and `info-code` for `dns_error`, or `alert-id` and
+    /// `alert-message` for `tls_alert_received`.
+    pub fn extension(&self, name: &str) -> Option<String> {
+        structured_field::get_param(&self.extensions, name)
+    }
+
+    /// Set an extension parameter from its serialized value.
+    ///
+    /// # Errors
+    ///
+    /// This method errors if the name is not a valid parameter key, or the
+    /// value is not a valid structured field item.
+    pub fn set_extension(&mut self, name: &str, value: &str) -> crate::Result<()> {
+        structured_field::set_param(&mut self.extensions, name, value)
+    }
+
+    fn from_item(item: Item) -> crate::Result<Self> {
+        let proxy = match item.bare_item.as_str() {
+            Some(proxy) => proxy,
+            None => crate::bail_status!(400, "Proxy-Status entries must start with a proxy"),
+        };
+        let mut entry = Self::new(proxy);
+        for (key, value) in item.params {
+            match key.as_str() {
+                "error" => {
+                    let error = param(&key, value.as_str())?;
+                    entry.error = Some(ProxyError::from_token(error));
+                }
+                "next-hop" => entry.next_hop = Some(param(&key, value.as_str())?.to_owned()),
+                "next-protocol" => {
+                    let protocol = match value {
+                        BareItem::ByteSequence(bytes) => String::from_utf8(bytes).ok(),
+                        value => value.as_str().map(|s| s.to_owned()),
+                    };
+                    entry.next_protocol = Some(param(&key, protocol)?);
+                }
+                "received-status" => {
+                    let status = param(&key, value.as_integer())?;
+                    entry.received_status = Some(param(&key, u16::try_from(status).ok())?);
+                }
+                "details" => entry.details = Some(param(&key, value.as_str())?.to_owned()),
+                _ => entry.extensions.push((key, value)),
+            }
+        }
+        Ok(entry)
+    }
+
+    fn to_item(&self) -> Item {
+        let mut item = Item::new(BareItem::token_or_string(&self.proxy));
+        if let Some(error) = &self.error {
+            item.push_param("error", BareItem::token_or_string(error.as_str()));
+        }
+        if let Some(next_hop) = &self.next_hop {
+            item.push_param("next-hop", BareItem::token_or_string(next_hop));
+        }
+        if let Some(protocol) = &self.next_protocol {
+            // Protocol identifiers which aren't tokens are sent as raw bytes.
+            let protocol = if structured_field::is_token(protocol) {
+                BareItem::Token(protocol.clone())
+            } else {
+                BareItem::ByteSequence(protocol.as_bytes().to_vec())
+            };
+            item.push_param("next-protocol", protocol);
+        }
+        if let Some(status) = self.received_status {
+            item.push_param("received-status", BareItem::Integer(status.into()));
+        }
+        if let Some(details) = &self.details {
+            item.push_param("details", BareItem::String(details.clone()));
+        }
+        for (key, value) in &self.extensions {
+            item.push_param(key, value.clone());
+        }
+        item
+    }
+}
+
+/// Check the type of a parameter value.
+fn param<T>(key: &str, value: Option<T>) -> crate::Result<T> {
+    structured_field::expect_param("Proxy-Status", key, value)
+}
+
+/// An error an intermediary encountered while handling a request.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProxyError {
+    /// The intermediary encountered a timeout when performing DNS resolution.
+    DnsTimeout,
+    /// The intermediary encountered a DNS error when trying to find an IP
+    /// address for the next hop.
+    DnsError,
+    /// The intermediary cannot determine the appropriate next hop.
+    DestinationNotFound,
+    /// The intermediary considers the next hop unavailable.
+    DestinationUnavailable,
+    /// The intermediary is configured to prohibit connections to the next
+    /// hop IP address.
+    DestinationIpProhibited,
+    /// The intermediary cannot find a route to the next hop IP address.
+    DestinationIpUnroutable,
+    /// The intermediary's connection to the next hop was refused.
+    ConnectionRefused,
+    /// The intermediary's connection to the next hop was closed before any
+    /// part of the response was received.
+    ConnectionTerminated,
+    /// The intermediary's attempt to open a connection to the next hop timed
+    /// out.
+    ConnectionTimeout,
+    /// The intermediary was expecting data on a connection but did not
+    /// receive any new data in a configured time limit.
+    ConnectionReadTimeout,
+    /// The intermediary was attempting to write data to a connection but was
+    /// not able to.
+    ConnectionWriteTimeout,
+    /// The intermediary is configured to limit the number of connections it
+    /// has to the next hop, and that limit has been exceeded.
+    ConnectionLimitReached,
+    /// The intermediary encountered a TLS error when communicating with the
+    /// next hop.
+    TlsProtocolError,
+    /// The intermediary encountered an error when verifying the certificate
+    /// presented by the next hop.
+    TlsCertificateError,
+    /// The intermediary received a TLS alert from the next hop.
+    TlsAlertReceived,
+    /// The intermediary is generating a client (4xx) response on the
+    /// origin's behalf.
+    HttpRequestError,
+    /// The intermediary rejected the request because it is configured to
+    /// deny it.
+    HttpRequestDenied,
+    /// The intermediary received an incomplete response from the next hop.
+    HttpResponseIncomplete,
+    /// The intermediary received a response with a header section that was
+    /// too large.
+    HttpResponseHeaderSectionSize,
+    /// The intermediary received a response containing an individual header
+    /// field line that was too large.
+    HttpResponseHeaderSize,
+    /// The intermediary received a response with a body that was too large.
+    HttpResponseBodySize,
+    /// The intermediary received a response with a trailer section that was
+    /// too large.
+    HttpResponseTrailerSectionSize,
+    /// The intermediary received a response containing an individual trailer
+    /// field line that was too large.
+    HttpResponseTrailerSize,
+    /// The intermediary encountered an error decoding the transfer coding of
+    /// the response.
+    HttpResponseTransferCoding,
+    /// The intermediary encountered an error decoding the content coding of
+    /// the response.
+    HttpResponseContentCoding,
+    /// The intermediary timed out waiting for the response from the next
+    /// hop.
+    HttpResponseTimeout,
+    /// The HTTP Upgrade between the intermediary and the next hop failed.
+    HttpUpgradeFailed,
+    /// The intermediary encountered an HTTP protocol error when communicating
+    /// with the next hop.
+    HttpProtocolError,
+    /// The intermediary generated the response locally, without attempting
+    /// to connect to the next hop.
+    ProxyInternalResponse,
+    /// The intermediary encountered an internal error unrelated to the
+    /// origin.
+    ProxyInternalError,
+    /// The intermediary encountered an error regarding its configuration.
+    ProxyConfigurationError,
+    /// The intermediary tried to forward the request to itself, or a loop
+    /// has been detected using different means.
+    ProxyLoopDetected,
+    /// An unregistered error type.
+    Extension(String),
+}
+
+impl ProxyError {
+    /// Get the serialized token.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::DnsTimeout => "dns_timeout",
+            Self::DnsError => "dns_error",
+            Self::DestinationNotFound => "destination_not_found",
+            Self::DestinationUnavailable => "destination_unavailable",
+            Self::DestinationIpProhibited => "destination_ip_prohibited",
+            Self::DestinationIpUnroutable => "destination_ip_unroutable",
+            Self::ConnectionRefused => "connection_refused",
+            Self::ConnectionTerminated => "connection_terminated",
+            Self::ConnectionTimeout => "connection_timeout",
+            Self::ConnectionReadTimeout => "connection_read_timeout",
+            Self::ConnectionWriteTimeout => "connection_write_timeout",
+            Self::ConnectionLimitReached => "connection_limit_reached",
+            Self::TlsProtocolError => "tls_protocol_error",
+            Self::TlsCertificateError => "tls_certificate_error",
+            Self::TlsAlertReceived => "tls_alert_received",
+            Self::HttpRequestError => "http_request_error",
+            Self::HttpRequestDenied => "http_request_denied",
+            Self::HttpResponseIncomplete => "http_response_incomplete",
+            Self::HttpResponseHeaderSectionSize => "http_response_header_section_size",
+            Self::HttpResponseHeaderSize => "http_response_header_size",
+            Self::HttpResponseBodySize => "http_response_body_size",
+            Self::HttpResponseTrailerSectionSize => "http_response_trailer_section_size",
+            Self::HttpResponseTrailerSize => "http_response_trailer_size",
+            Self::HttpResponseTransferCoding => "http_response_transfer_coding",
+            Self::HttpResponseContentCoding => "http_response_content_coding",
+            Self::HttpResponseTimeout => "http_response_timeout",
+            Self::HttpUpgradeFailed => "http_upgrade_failed",
+            Self::HttpProtocolError => "http_protocol_error",
+            Self::ProxyInternalResponse => "proxy_internal_response",
+            Self::ProxyInternalError => "proxy_internal_error",
+            Self::ProxyConfigurationError => "proxy_configuration_error",
+            Self::ProxyLoopDetected => "proxy_loop_detected",
+            Self::Extension(s) => s,
+        }
+    }
+
+    /// Get the status code the specification recommends sending for this
+    /// error, if any.
+    pub fn recommended_status(&self) -> Option<StatusCode> {
+        use StatusCode::*;
+        let status = match self {
+            Self::DestinationNotFound
+            | Self::ProxyInternalError
+            | Self::ProxyConfigurationError => InternalServerError,
+            Self::DestinationUnavailable | Self::ConnectionLimitReached => ServiceUnavailable,
+            Self::DnsTimeout
+            | Self::ConnectionTimeout
+            | Self::ConnectionReadTimeout
+            | Self::ConnectionWriteTimeout
+            | Self::HttpResponseTimeout => GatewayTimeout,
+            Self::HttpRequestError => BadRequest,
+            Self::HttpRequestDenied => Forbidden,
+            Self::ProxyInternalResponse | Self::Extension(_) => return None,
+            _ => BadGateway,
+        };
+        Some(status)
+    }
+
+    fn from_token(s: &str) -> Self {
+        match s {
+            "dns_timeout" => Self::DnsTimeout,
+            "dns_error" => Self::DnsError,
+            "destination_not_found" => Self::DestinationNotFound,
+            "destination_unavailable" => Self::DestinationUnavailable,
+            "destination_ip_prohibited" => Self::DestinationIpProhibited,
+            "destination_ip_unroutable" => Self::DestinationIpUnroutable,
+            "connection_refused" => Self::ConnectionRefused,
+            "connection_terminated" => Self::ConnectionTerminated,
+            "connection_timeout" => Self::ConnectionTimeout,
+            "connection_read_timeout" => Self::ConnectionReadTimeout,
+            "connection_write_timeout" => Self::ConnectionWriteTimeout,
+            "connection_limit_reached" => Self::ConnectionLimitReached,
+            "tls_protocol_error" => Self::TlsProtocolError,
+            "tls_certificate_error" => Self::TlsCertificateError,
+            "tls_alert_received" => Self::TlsAlertReceived,
+            "http_request_error" => Self::HttpRequestError,
+            "http_request_denied" => Self::HttpRequestDenied,
+            "http_response_incomplete" => Self::HttpResponseIncomplete,
+            "http_response_header_section_size" => Self::HttpResponseHeaderSectionSize,
+            "http_response_header_size" => Self::HttpResponseHeaderSize,
+            "http_response_body_size" => Self::HttpResponseBodySize,
+            "http_response_trailer_section_size" => Self::HttpResponseTrailerSectionSize,
+            "http_response_trailer_size" => Self::HttpResponseTrailerSize,
+            "http_response_transfer_coding" => Self::HttpResponseTransferCoding,
+            "http_response_content_coding" => Self::HttpResponseContentCoding,
+            "http_response_timeout" => Self::HttpResponseTimeout,
+            "http_upgrade_failed" => Self::HttpUpgradeFailed,
+            "http_protocol_error" => Self::HttpProtocolError,
+            "proxy_internal_response" => Self::ProxyInternalResponse,
+            "proxy_internal_error" => Self::ProxyInternalError,
+            "proxy_configuration_error" => Self::ProxyConfigurationError,
+            "proxy_loop_detected" => Self::ProxyLoopDetected,
+            s => Self::Extension(s.to_owned()),
+        }
+    }
+}
+
+impl Display for ProxyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Response;
+
+    #[test]
+    fn multi_hop() -> crate::Result<()> {
+        let mut res = Response::new(502);
+        res.insert_header(
+            "Proxy-Status",
+            r#"SomeCDN; error=dns_error; rcode="NXDOMAIN"; info-code=22, "Reverse Proxy"; next-protocol=:aDI=:; received-status=503"#,
+        )?;
+        res.append_header(
+            "Proxy-Status",
+            r#"ExampleGateway; error=x_custom_error; details="upstream reset""#,
+        )?;
+
+        let proxy_status = ProxyStatus::from_headers(&res)?.unwrap();
+        assert_eq!(proxy_status.len(), 3);
+        let entries: Vec<_> = proxy_status.iter().collect();
+
+        assert_eq!(entries[0].proxy(), "SomeCDN");
+        assert_eq!(entries[0].error(), Some(&ProxyError::DnsError));
+        assert_eq!(entries[0].extension("rcode"), Some(r#""NXDOMAIN""#.into()));
+        assert_eq!(entries[0].extension("info-code"), Some("22".into()));
+
+        assert_eq!(entries[1].proxy(), "Reverse Proxy");
+        assert_eq!(entries[1].next_protocol(), Some("h2"));
+        assert_eq!(entries[1].received_status(), Some(503));
+
+        let last = proxy_status.last().unwrap();
+        assert_eq!(
+            last.error(),
+            Some(&ProxyError::Extension("x_custom_error".into()))
+        );
+        assert_eq!(last.details(), Some("upstream reset"));
+
+        let mut res = Response::new(502);
+        res.insert_header(&proxy_status, &proxy_status)?;
+        assert_eq!(
+            res["Proxy-Status"],
+            r#"SomeCDN;error=dns_error;rcode="NXDOMAIN";info-code=22, "Reverse Proxy";next-protocol=h2;received-status=503, ExampleGateway;error=x_custom_error;details="upstream reset""#
+        );
+        assert_eq!(ProxyStatus::from_headers(res)?.unwrap(), proxy_status);
+        Ok(())
+    }
+
+    #[test]
+    fn recommended_status() {
+        assert_eq!(
+            ProxyError::DestinationIpProhibited.recommended_status(),
+            Some(StatusCode::BadGateway)
+        );
+        assert_eq!(
+            ProxyError::HttpRequestDenied.recommended_status(),
+            Some(StatusCode::Forbidden)
+        );
+        assert_eq!(ProxyError::ProxyInternalResponse.recommended_status(), None);
+    }
+
+    #[test]
+    fn invalid() -> crate::Result<()> {
+        for value in &["1", "Proxy;error=?1", "Proxy;received-status=70000"] {
+            let mut res = Response::new(502);
+            res.insert_header("Proxy-Status", *value)?;
+            let err = ProxyStatus::from_headers(res).unwrap_err();
+            assert_eq!(err.status(), 400);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,395 @@
+use std::net::{IpAddr, SocketAddr};
+
+use super::{ForwardedElement, Node};
+use crate::headers::{HeaderName, FORWARDED};
+use crate::Request;
+
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_lowercase_str("x-forwarded-for");
+
+/// A policy describing which proxies in front of a server are trusted to
+/// report the client address.
+///
+/// `Forwarded` and `X-Forwarded-For` headers can be set by anyone, so only
+/// the hops added by trusted proxies are meaningful. The chain of hops is
+/// walked from the right, starting at the transport's peer address, and the
+/// first address not covered by the policy is the client.
+///
+/// Only the headers the trusted proxies write are read; see
+/// [`ForwardingHeaders`]. By default that's `X-Forwarded-For`.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::proxies::TrustedProxies;
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com");
+/// req.set_peer_addr(Some("10.0.0.2:5000"));
+/// req.insert_header("X-Forwarded-For", "203.0.113.7, 198.51.100.1, 10.0.0.1");
+///
+/// let proxies = TrustedProxies::cidrs(&["10.0.0.0/8"])?;
+/// let client = proxies.client_addr(&req).unwrap();
+/// assert_eq!(client.ip(), Some("198.51.100.1".parse()?));
+///
+/// let proxies = TrustedProxies::hop_count(3);
+/// let client = proxies.client_addr(&req).unwrap();
+/// assert_eq!(client.ip(), Some("203.0.113.7".parse()?));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedProxies {
+    policy: Policy,
+    headers: ForwardingHeaders,
+}
+
+/// The headers trusted proxies use to report the hops of a request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ForwardingHeaders {
+    /// The `Forwarded` header.
+    Forwarded,
+    /// The `X-Forwarded-For` header, along with `X-Forwarded-Proto` and
+    /// `X-Forwarded-Host`.
+    #[default]
+    XForwarded,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Policy {
+    Cidrs(Vec<Cidr>),
+    HopCount(usize),
+}
+
+impl TrustedProxies {
+    /// Trust no proxies; the peer address is always the client.
+    pub fn none() -> Self {
+        Self::hop_count(0)
+    }
+
+    /// Trust a fixed number of proxies in front of the server.
+    ///
+    /// The peer address counts as the first hop.
+    pub fn hop_count(hops: usize) -> Self {
+        Self {
+            policy: Policy::HopCount(hops),
+            headers: ForwardingHeaders::default(),
+        }
+    }
+
+    /// Trust proxies whose addresses fall within the given CIDR ranges, such
+    /// as `10.0.0.0/8` or `fd00::/8`. A bare address trusts just that address.
+    ///
+    /// # Errors
+    ///
+    /// This function errors if a range can't be parsed.
+    pub fn cidrs<I, S>(cidrs: I) -> crate::Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let cidrs = cidrs
+            .into_iter()
+            .map(|cidr| Cidr::parse(cidr.as_ref()))
+            .collect::<crate::Result<_>>()?;
+        Ok(Self {
+            policy: Policy::Cidrs(cidrs),
+            headers: ForwardingHeaders::default(),
+        })
+    }
+
+    /// Set the headers the trusted proxies write.
+    ///
+    /// The other headers are ignored, since the client may have sent them.
+    pub fn set_headers(&mut self, headers: ForwardingHeaders) {
+        self.headers = headers;
+    }
+
+    /// Get the headers the trusted proxies write.
+    pub fn headers(&self) -> ForwardingHeaders {
+        self.headers
+    }
+
+    /// Returns `true` if the address belongs to a trusted proxy.
+    ///
+    /// Under a hop count policy no address is trusted by itself.
+    pub fn is_trusted(&self, ip: IpAddr) -> bool {
+        match &self.policy {
+            Policy::Cidrs(cidrs) => cidrs.iter().any(|cidr| cidr.contains(ip)),
+            Policy::HopCount(_) => false,
+        }
+    }
+
+    /// Resolve the client address of a request.
+    ///
+    /// The chain is made of the `for` node of every `Forwarded` hop (or every
+    /// `X-Forwarded-For` address), followed by the peer address. A hop that
+    /// can't be parsed is kept in the chain as an untrusted node.
+    /// When every hop is trusted, the leftmost hop is returned.
+    pub fn client_addr(&self, req: &Request) -> Option<Node> {
+        let mut chain: Vec<Option<Node>> = self
+            .hops(req)
+            .into_iter()
+            .map(|hop| hop?.forwarded_for().cloned())
+            .collect();
+        if let Some(peer) = req.peer_addr() {
+            chain.push(parse_peer_addr(peer));
+        }
+
+        let index = self.client_index(&chain)?;
+        chain.swap_remove(index)
+    }
+
+    /// Find the hop added by the trusted proxy that received the request
+    /// from the client.
+    ///
+    /// Returns the request's hops, and the index of that hop. Returns `None`
+    /// if the request didn't come through a trusted proxy.
+    pub(crate) fn client_hop(
+        &self,
+        req: &Request,
+    ) -> Option<(Vec<Option<ForwardedElement>>, usize)> {
+        let peer = parse_peer_addr(req.peer_addr()?);
+        let hops = self.hops(req);
+        let mut chain: Vec<Option<Node>> = hops
+            .iter()
+            .map(|hop| hop.as_ref()?.forwarded_for().cloned())
+            .collect();
+        chain.push(peer);
+
+        let index = self.client_index(&chain)?;
+        if index < hops.len() {
+            Some((hops, index))
+        } else {
+            None
+        }
+    }
+
+    /// Read the hops of a request from the headers the trusted proxies write.
+    fn hops(&self, req: &Request) -> Vec<Option<ForwardedElement>> {
+        let name = match self.headers {
+            ForwardingHeaders::Forwarded => FORWARDED,
+            ForwardingHeaders::XForwarded => X_FORWARDED_FOR,
+        };
+        let values = req.header(name).into_iter().flatten();
+        match self.headers {
+            ForwardingHeaders::Forwarded => values
+                .flat_map(|value| ForwardedElement::parse_list_lossy(value.as_str()))
+                .collect(),
+            ForwardingHeaders::XForwarded => values
+                .flat_map(|value| value.as_str().split(','))
+                .map(str::trim)
+                .filter(|node| !node.is_empty())
+                .map(|node| {
+                    let node = Node::from_x_forwarded_for(node).ok()?;
+                    let mut hop = ForwardedElement::new();
+                    hop.set_forwarded_for(node);
+                    Some(hop)
+                })
+                .collect(),
+        }
+    }
+
+    /// Find the index of the client in a chain of hops.
+    pub(crate) fn client_index(&self, chain: &[Option<Node>]) -> Option<usize> {
+        let last = chain.len().checked_sub(1)?;
+        match &self.policy {
+            Policy::HopCount(hops) => Some(last.saturating_sub(*hops)),
+            Policy::Cidrs(_) => {
+                let untrusted =
+                    chain
+                        .iter()
+                        .rposition(|node| match node.as_ref().and_then(|node| node.ip()) {
+                            Some(ip) => !self.is_trusted(ip),
+                            None => true,
+                        });
+                Some(untrusted.unwrap_or(0))
+            }
+        }
+    }
+}
+
+impl Default for TrustedProxies {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+fn parse_peer_addr(peer: &str) -> Option<Node> {
+    if let Ok(addr) = peer.parse::<SocketAddr>() {
+        return Some(addr.into());
+    }
+    peer.parse::<IpAddr>().ok().map(Node::from)
+}
+
+/// An IP address range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl Cidr {
+    fn parse(s: &str) -> crate::Result<Self> {
+        let (addr, prefix) = match s.split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (s, None),
+        };
+        let addr: IpAddr = match addr.trim().parse() {
+            Ok(addr) => addr,
+            Err(_) => crate::bail!("Invalid CIDR address `{}`", s),
+        };
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix.map(|p| p.trim().parse::<u8>()) {
+            None => max,
+            Some(Ok(prefix)) if prefix <= max => prefix,
+            Some(_) => crate::bail!("Invalid CIDR prefix length `{}`", s),
+        };
+        Ok(Self { addr, prefix })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, canonical(ip)) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                mask_eq(u32::from(net).into(), u32::from(ip).into(), self.prefix, 32)
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                mask_eq(u128::from(net), u128::from(ip), self.prefix, 128)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Compare the first `prefix` bits of two addresses of `bits` bits each.
+fn mask_eq(a: u128, b: u128, prefix: u8, bits: u8) -> bool {
+    let shift = u32::from(bits - prefix);
+    a.checked_shr(shift).unwrap_or(0) == b.checked_shr(shift).unwrap_or(0)
+}
+
+/// Treat IPv4-mapped IPv6 addresses as IPv4.
+fn canonical(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => match v6.segments() {
+            [0, 0, 0, 0, 0, 0xffff, hi, lo] => {
+                IpAddr::V4((u32::from(hi) << 16 | u32::from(lo)).into())
+            }
+            _ => ip,
+        },
+        ip => ip,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn request(forwarded: Option<&str>, xff: Option<&str>, peer: Option<&str>) -> Request {
+        let mut req = Request::get("https://example.com");
+        if let Some(forwarded) = forwarded {
+            req.insert_header("Forwarded", forwarded).unwrap();
+        }
+        if let Some(xff) = xff {
+            req.insert_header("X-Forwarded-For", xff).unwrap();
+        }
+        req.set_peer_addr(peer);
+        req
+    }
+
+    fn client(proxies: &TrustedProxies, req: &Request) -> Option<String> {
+        proxies.client_addr(req).map(|node| node.to_string())
+    }
+
+    #[test]
+    fn cidrs() -> crate::Result<()> {
+        let mut proxies = TrustedProxies::cidrs(["10.0.0.0/8", "fd00::/8", "192.0.2.1"])?;
+        assert!(proxies.is_trusted("10.1.2.3".parse()?));
+        assert!(proxies.is_trusted("::ffff:10.1.2.3".parse()?));
+        assert!(proxies.is_trusted("fd12::1".parse()?));
+        assert!(proxies.is_trusted("192.0.2.1".parse()?));
+        assert!(!proxies.is_trusted("192.0.2.2".parse()?));
+        assert!(!proxies.is_trusted("11.0.0.1".parse()?));
+
+        let req = request(None, Some("10.0.0.3, 10.0.0.2"), Some("10.0.0.1:443"));
+        assert_eq!(client(&proxies, &req).unwrap(), "10.0.0.3");
+
+        let req = request(None, Some("203.0.113.9"), Some("198.51.100.1:443"));
+        assert_eq!(client(&proxies, &req).unwrap(), "198.51.100.1:443");
+
+        proxies.set_headers(ForwardingHeaders::Forwarded);
+        let req = request(
+            Some(r#"for=198.51.100.66, for=203.0.113.9, for="[fd00::1]""#),
+            None,
+            Some("10.0.0.1:443"),
+        );
+        assert_eq!(client(&proxies, &req).unwrap(), "203.0.113.9");
+
+        let req = request(
+            Some("for=unknown, for=10.0.0.2"),
+            None,
+            Some("10.0.0.1:443"),
+        );
+        assert_eq!(client(&proxies, &req).unwrap(), "unknown");
+        Ok(())
+    }
+
+    #[test]
+    fn hop_count() {
+        let req = request(
+            None,
+            Some("203.0.113.9, 198.51.100.2"),
+            Some("10.0.0.1:443"),
+        );
+        let hops = |n| client(&TrustedProxies::hop_count(n), &req).unwrap();
+        assert_eq!(hops(0), "10.0.0.1:443");
+        assert_eq!(hops(1), "198.51.100.2");
+        assert_eq!(hops(2), "203.0.113.9");
+        assert_eq!(hops(5), "203.0.113.9");
+
+        let req = request(None, None, None);
+        assert_eq!(client(&TrustedProxies::hop_count(1), &req), None);
+    }
+
+    #[test]
+    fn malformed_hops_are_untrusted() -> crate::Result<()> {
+        let req = request(None, Some("bogus, 203.0.113.9"), Some("10.0.0.1:443"));
+        let proxies = TrustedProxies::cidrs(["10.0.0.0/8"])?;
+        assert_eq!(client(&proxies, &req).unwrap(), "203.0.113.9");
+        let proxies = TrustedProxies::hop_count(1);
+        assert_eq!(client(&proxies, &req).unwrap(), "203.0.113.9");
+
+        let req = request(None, Some("203.0.113.9, bogus"), Some("10.0.0.1:443"));
+        let proxies = TrustedProxies::cidrs(["10.0.0.0/8"])?;
+        assert_eq!(client(&proxies, &req), None);
+
+        let mut proxies = TrustedProxies::hop_count(1);
+        proxies.set_headers(ForwardingHeaders::Forwarded);
+        let req = request(Some("for=;;;, for=203.0.113.9"), None, Some("10.0.0.1:443"));
+        assert_eq!(client(&proxies, &req).unwrap(), "203.0.113.9");
+        Ok(())
+    }
+
+    #[test]
+    fn other_headers_are_ignored() -> crate::Result<()> {
+        let req = request(
+            Some("for=10.6.6.6"),
+            Some("203.0.113.9"),
+            Some("10.0.0.1:443"),
+        );
+        let mut proxies = TrustedProxies::cidrs(["10.0.0.0/8"])?;
+        assert_eq!(client(&proxies, &req).unwrap(), "203.0.113.9");
+
+        let req = request(None, Some("10.6.6.6"), Some("10.0.0.1:443"));
+        proxies.set_headers(ForwardingHeaders::Forwarded);
+        assert_eq!(client(&proxies, &req).unwrap(), "10.0.0.1:443");
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_cidrs() {
+        for invalid in &["10.0.0.0/33", "fd00::/129", "example.com/8", "10.0.0.0/x"] {
+            assert!(TrustedProxies::cidrs([invalid]).is_err(), "{}", invalid);
+        }
+    }
+}
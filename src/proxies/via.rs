@@ -0,0 +1,377 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, VIA};
+use crate::Version;
+
+use std::fmt::{self, Write};
+use std::slice;
+
+/// Track the intermediaries a message was forwarded through.
+///
+/// Every intermediary appends an entry, so the first entry belongs to the
+/// intermediary closest to the sender.
+///
+/// # Specifications
+///
+/// - [RFC 9110, section 7.6.3: Via](https://www.rfc-editor.org/rfc/rfc9110#section-7.6.3)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::proxies::Via;
+/// use http_types::{Request, Version};
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header("Via", "1.0 fred, 1.1 p.example.net");
+///
+/// let mut via = Via::from_headers(&req)?.unwrap();
+/// via.append_hop(Version::Http2_0, "edge")?;
+/// req.insert_header(&via, &via);
+///
+/// assert_eq!(req["Via"], "1.0 fred, 1.1 p.example.net, 2 edge");
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Via {
+    entries: Vec<ViaEntry>,
+}
+
+impl Via {
+    /// Create a new instance of `Via`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Entries from multiple `Via` headers are combined in order.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if an entry is malformed.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let values = match headers.as_ref().get(VIA) {
+            Some(values) => values,
+            None => return Ok(None),
+        };
+
+        let mut entries = vec![];
+        for value in values {
+            for entry in split_list(value.as_str())? {
+                let entry = entry.trim();
+                if !entry.is_empty() {
+                    entries.push(ViaEntry::from_str(entry)?);
+                }
+            }
+        }
+        Ok(Some(Self { entries }))
+    }
+
+    /// Append an entry for the intermediary forwarding the message.
+    pub fn push(&mut self, entry: ViaEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Append an entry for this intermediary, which received the message
+    /// using `version` of HTTP.
+    ///
+    /// # Errors
+    ///
+    /// This method errors if `received_by` is not a valid host or pseudonym.
+    pub fn append_hop(&mut self, version: Version, received_by: &str) -> crate::Result<()> {
+        let version = match version {
+            Version::Http0_9 => "0.9",
+            Version::Http1_0 => "1.0",
+            Version::Http1_1 => "1.1",
+            Version::Http2_0 => "2",
+            Version::Http3_0 => "3",
+        };
+        self.push(ViaEntry::new(None, version, received_by)?);
+        Ok(())
+    }
+
+    /// An iterator visiting all entries, starting with the intermediary
+    /// closest to the sender.
+    pub fn iter(&self) -> slice::Iter<'_, ViaEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Header for Via {
+    fn header_name(&self) -> HeaderName {
+        VIA
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, entry) in self.entries.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", entry).unwrap(),
+                _ => write!(output, ", {}", entry).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal strings are validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl IntoIterator for Via {
+    type Item = ViaEntry;
+    type IntoIter = std::vec::IntoIter<ViaEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Via {
+    type Item = &'a ViaEntry;
+    type IntoIter = slice::Iter<'a, ViaEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A single intermediary in a `Via` header.
+///
+/// ```txt
+/// Via = #( received-protocol RWS received-by [ RWS comment ] )
+/// received-protocol = [ protocol-name "/" ] protocol-version
+/// received-by = pseudonym [ ":" port ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViaEntry {
+    protocol: Option<String>,
+    version: String,
+    received_by: String,
+    comment: Option<String>,
+}
+
+impl ViaEntry {
+    /// Create a new instance of `ViaEntry`.
+    ///
+    /// The protocol name defaults to `HTTP` when it's `None`.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the protocol or version
+    /// are not tokens, or if `received_by` is not a host or pseudonym with an
+    /// optional port.
+    pub fn new(protocol: Option<&str>, version: &str, received_by: &str) -> crate::Result<Self> {
+        if let Some(protocol) = protocol {
+            if !is_token(protocol) {
+                bail!(400, "Invalid Via protocol name `{}`", protocol);
+            }
+        }
+        if !is_token(version) {
+            bail!(400, "Invalid Via protocol version `{}`", version);
+        }
+        let valid = !received_by.is_empty()
+            && received_by
+                .bytes()
+                .all(|b| b.is_ascii_graphic() && !matches!(b, b',' | b'(' | b')' | b'"' | b'\\'));
+        if !valid {
+            bail!(400, "Invalid Via received-by `{}`", received_by);
+        }
+
+        Ok(Self {
+            protocol: protocol.map(String::from),
+            version: version.to_owned(),
+            received_by: received_by.to_owned(),
+            comment: None,
+        })
+    }
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let (head, comment) = match s.find('(') {
+            Some(start) if s.ends_with(')') => (&s[..start], Some(&s[start + 1..s.len() - 1])),
+            Some(_) => bail!(400, "Invalid Via comment in `{}`", s),
+            None => (s, None),
+        };
+
+        let mut parts = head.split_whitespace();
+        let (protocol, received_by) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(protocol), Some(received_by), None) => (protocol, received_by),
+            _ => bail!(400, "Invalid Via entry `{}`", s),
+        };
+        let (name, version) = match protocol.split_once('/') {
+            Some((name, version)) => (Some(name), version),
+            None => (None, protocol),
+        };
+
+        let mut entry = Self::new(name, version, received_by)?;
+        if let Some(comment) = comment {
+            entry.set_comment(Some(comment))?;
+        }
+        Ok(entry)
+    }
+
+    /// The name of the protocol the message was received with, if one was
+    /// given. `HTTP` is implied when it's omitted.
+    pub fn protocol(&self) -> Option<&str> {
+        self.protocol.as_deref()
+    }
+
+    /// The version of the protocol the message was received with.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// The host or pseudonym of the intermediary.
+    pub fn received_by(&self) -> &str {
+        &self.received_by
+    }
+
+    /// The comment describing the intermediary, without the surrounding
+    /// parentheses.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Set the comment describing the intermediary.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the comment contains
+    /// non-printable characters or unbalanced parentheses.
+    pub fn set_comment(&mut self, comment: Option<&str>) -> crate::Result<()> {
+        if let Some(comment) = comment {
+            let printable = comment.bytes().all(|b| b == b' ' || b.is_ascii_graphic());
+            if !printable || !is_balanced(comment) {
+                bail!(400, "Invalid Via comment `{}`", comment);
+            }
+        }
+        self.comment = comment.map(String::from);
+        Ok(())
+    }
+}
+
+impl fmt::Display for ViaEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(protocol) = &self.protocol {
+            write!(f, "{}/", protocol)?;
+        }
+        write!(f, "{} {}", self.version, self.received_by)?;
+        if let Some(comment) = &self.comment {
+            write!(f, " ({})", comment)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_token(s: &str) -> bool {
+    matches!(crate::parse_utils::parse_token(s), (Some(_), ""))
+}
+
+/// Check that parentheses in a comment are balanced, skipping quoted pairs.
+fn is_balanced(comment: &str) -> bool {
+    let mut depth = 0usize;
+    let mut bytes = comment.bytes();
+    while let Some(b) = bytes.next() {
+        match b {
+            b'\\' if bytes.next().is_none() => return false,
+            b'\\' => {}
+            b'(' => depth += 1,
+            b')' => match depth.checked_sub(1) {
+                Some(d) => depth = d,
+                None => return false,
+            },
+            _ => {}
+        }
+    }
+    depth == 0
+}
+
+/// Split a list on commas that aren't inside a comment.
+fn split_list(s: &str) -> crate::Result<Vec<&str>> {
+    let mut entries = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, b) in s.bytes().enumerate() {
+        match b {
+            _ if escaped => escaped = false,
+            b'\\' if depth > 0 => escaped = true,
+            b'(' => depth += 1,
+            b')' => match depth.checked_sub(1) {
+                Some(d) => depth = d,
+                None => bail!(400, "Unbalanced Via comment in `{}`", s),
+            },
+            b',' if depth == 0 => {
+                entries.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        bail!(400, "Unbalanced Via comment in `{}`", s);
+    }
+    entries.push(&s[start..]);
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Response;
+
+    #[test]
+    fn parse_entries() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.append_header("Via", "1.0 fred, 1.1 p.example.net")?;
+        res.append_header("Via", "HTTP/2 edge:8443 (cache (v1), region=eu)")?;
+
+        let via = Via::from_headers(&res)?.unwrap();
+        let entries: Vec<_> = via.iter().collect();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].version(), "1.0");
+        assert_eq!(entries[0].received_by(), "fred");
+        assert_eq!(entries[2].protocol(), Some("HTTP"));
+        assert_eq!(entries[2].received_by(), "edge:8443");
+        assert_eq!(entries[2].comment(), Some("cache (v1), region=eu"));
+        assert_eq!(
+            via.header_value(),
+            "1.0 fred, 1.1 p.example.net, HTTP/2 edge:8443 (cache (v1), region=eu)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn append_hop() -> crate::Result<()> {
+        let mut via = Via::new();
+        via.append_hop(Version::Http1_1, "proxy.example.com:8080")?;
+        via.append_hop(Version::Http3_0, "_edge")?;
+        assert_eq!(via.header_value(), "1.1 proxy.example.com:8080, 3 _edge");
+        assert!(via.append_hop(Version::Http1_1, "two words").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for invalid in &["1.1", "1.1 a b", "1.1 a (open", "1.1 a close)", "/1.1 a"] {
+            let mut res = Response::new(200);
+            res.insert_header("Via", *invalid).unwrap();
+            let err = Via::from_headers(&res).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", invalid);
+        }
+
+        let mut entry = ViaEntry::new(None, "1.1", "a").unwrap();
+        assert!(entry.set_comment(Some("unbalanced (")).is_err());
+        assert!(entry.set_comment(Some("escaped \\(")).is_ok());
+    }
+}
@@ -6,13 +6,19 @@ use std::ops::Index;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+#[cfg(feature = "serde")]
+use crate::content::UnsupportedMediaType;
 #[cfg(feature = "serde")]
 use crate::convert::{DeserializeOwned, Serialize};
 use crate::headers::{
     self, HeaderName, HeaderValue, HeaderValues, Headers, Names, ToHeaderValues, Values,
     CONTENT_TYPE,
 };
+#[cfg(feature = "serde")]
+use crate::mime;
 use crate::mime::Mime;
+use crate::proxies::TrustedProxies;
+use crate::trace::RequestId;
 use crate::trailers::{self, Trailers};
 use crate::{Body, Extensions, Method, Url, Version};
 
@@ -100,6 +106,10 @@ impl Request {
     /// 1. `Forwarded` header `for` key
     /// 2. The first `X-Forwarded-For` header
     /// 3. Peer address of the transport
+    ///
+    /// Forwarding headers can be set by the client, so this address should
+    /// not be relied on. Use [`TrustedProxies`][crate::proxies::TrustedProxies]
+    /// to resolve the client address through known proxies.
     pub fn remote(&self) -> Option<&str> {
         self.forwarded_for().or_else(|| self.peer_addr())
     }
@@ -121,6 +131,44 @@ impl Request {
             .or_else(|| self.url().host_str())
     }
 
+    /// Get the URL the client requested, as seen before any proxies.
+    ///
+    /// The scheme, host, port and path prefix are taken from the hop added
+    /// by the trusted proxy that received the request from the client. They
+    /// are read from the headers the proxies write: either the `Forwarded`
+    /// header's `proto` and `host` keys, or `X-Forwarded-Proto`,
+    /// `X-Forwarded-Host`, `X-Forwarded-Port` and `X-Forwarded-Prefix`. If
+    /// the request didn't come through a trusted proxy, or a value is
+    /// invalid, the request's own URL is used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::proxies::TrustedProxies;
+    /// use http_types::Request;
+    ///
+    /// let mut req = Request::get("http://10.0.0.5:8080/users");
+    /// req.set_peer_addr(Some("10.0.0.1:50000"));
+    /// req.insert_header("X-Forwarded-For", "203.0.113.9");
+    /// req.insert_header("X-Forwarded-Proto", "https");
+    /// req.insert_header("X-Forwarded-Host", "example.com");
+    /// req.insert_header("X-Forwarded-Prefix", "/api");
+    ///
+    /// let proxies = TrustedProxies::cidrs(["10.0.0.0/8"])?;
+    /// let url = req.external_url(&proxies);
+    /// assert_eq!(url.as_str(), "https://example.com/api/users");
+    ///
+    /// let url = req.external_url(&TrustedProxies::none());
+    /// assert_eq!(url.as_str(), "http://10.0.0.5:8080/users");
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn external_url(&self, proxies: &TrustedProxies) -> Url {
+        crate::proxies::external_url(self, proxies)
+    }
+
     fn forwarded_header_part(&self, part: &str) -> Option<&str> {
         self.header("Forwarded").and_then(|header| {
             header.as_str().split(';').find_map(|key_equals_value| {
@@ -288,6 +336,9 @@ impl Request {
     /// then calling `Body::into_string` or using the Request's AsyncRead
     /// implementation to read the body.
     ///
+    /// With the `charset` feature enabled, the body is decoded using the
+    /// `charset` parameter of the `Content-Type` header.
+    ///
     /// # Examples
     ///
     /// ```
@@ -305,7 +356,12 @@ impl Request {
     /// # Ok(()) }) }
     /// ```
     pub async fn body_string(&mut self) -> crate::Result<String> {
-        let body = self.take_body();
+        #[cfg_attr(not(feature = "charset"), allow(unused_mut))]
+        let mut body = self.take_body();
+        #[cfg(feature = "charset")]
+        if let Some(mime) = self.content_type() {
+            body.set_mime(Some(mime));
+        }
         body.into_string().await
     }
 
@@ -371,6 +427,46 @@ impl Request {
         body.into_json().await
     }
 
+    /// Read the body as JSON, after checking the request declared a JSON
+    /// `Content-Type`.
+    ///
+    /// Both `application/json` and types with the `+json` suffix, such as
+    /// `application/vnd.api+json`, are accepted.
+    ///
+    /// # Errors
+    ///
+    /// If the `Content-Type` is missing or not JSON, an error with the status
+    /// of `415` is returned. It wraps an
+    /// [`UnsupportedMediaType`](crate::content::UnsupportedMediaType), which
+    /// can be used to set the `Accept-Post` or `Accept-Patch` header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::convert::{json, Deserialize};
+    /// use http_types::{Body, Request};
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// # #[serde(crate = "serde_crate")]
+    /// struct Cat {
+    ///     name: String,
+    /// }
+    ///
+    /// let mut req = Request::post("https://example.com");
+    /// req.set_body(Body::from_json(&json!({ "name": "chashu" }))?);
+    /// req.insert_header("Content-Type", "application/vnd.cat+json");
+    ///
+    /// let cat: Cat = req.body_json_strict().await?;
+    /// assert_eq!(&cat.name, "chashu");
+    /// # Ok(()) }) }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub async fn body_json_strict<T: DeserializeOwned>(&mut self) -> crate::Result<T> {
+        UnsupportedMediaType::check(self.content_type(), &[mime::JSON], Mime::is_json)?;
+        self.body_json().await
+    }
+
     /// Read the body as `x-www-form-urlencoded`.
     ///
     /// This consumes the request. If you want to read the body without
@@ -407,6 +503,23 @@ impl Request {
         body.into_form().await
     }
 
+    /// Read the body as `x-www-form-urlencoded`, after checking the request
+    /// declared a form `Content-Type`.
+    ///
+    /// # Errors
+    ///
+    /// If the `Content-Type` is missing or not `application/x-www-form-urlencoded`,
+    /// an error with the status of `415` is returned. It wraps an
+    /// [`UnsupportedMediaType`](crate::content::UnsupportedMediaType), which
+    /// can be used to set the `Accept-Post` or `Accept-Patch` header.
+    #[cfg(feature = "serde")]
+    pub async fn body_form_strict<T: DeserializeOwned>(&mut self) -> crate::Result<T> {
+        UnsupportedMediaType::check(self.content_type(), &[mime::FORM], |mime| {
+            mime.essence() == mime::FORM.essence()
+        })?;
+        self.body_form().await
+    }
+
     /// Get an HTTP header.
     pub fn header(&self, name: impl Into<HeaderName>) -> Option<&HeaderValues> {
         self.headers.get(name)
@@ -617,6 +730,13 @@ impl Request {
         &mut self.ext
     }
 
+    /// Returns the request id stored in the local state, if any.
+    ///
+    /// Ids are stored by [`RequestId::get_or_insert`][crate::trace::RequestId::get_or_insert].
+    pub fn request_id(&self) -> Option<&RequestId> {
+        self.ext.get()
+    }
+
     /// Get the URL querystring.
     ///
     /// # Examples
@@ -1188,6 +1308,55 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "serde")]
+    mod strict_body {
+        use super::*;
+        use crate::content::UnsupportedMediaType;
+
+        #[async_std::test]
+        async fn json() -> crate::Result<()> {
+            for json in &["application/json", "application/problem+json"] {
+                let mut req = build_test_request();
+                req.set_body("[1]");
+                req.insert_header(CONTENT_TYPE, *json)?;
+                let body: Vec<u8> = req.body_json_strict().await?;
+                assert_eq!(body, [1]);
+            }
+
+            let mut req = build_test_request();
+            req.set_body("[1]");
+            req.insert_header(CONTENT_TYPE, "application/xml")?;
+            let err = req.body_json_strict::<Vec<u8>>().await.unwrap_err();
+            assert_eq!(err.status(), 415);
+            Ok(())
+        }
+
+        #[async_std::test]
+        async fn form() -> crate::Result<()> {
+            let mut req = build_test_request();
+            req.set_body("name=chashu");
+            req.insert_header(CONTENT_TYPE, "application/x-www-form-urlencoded")?;
+            let form: std::collections::HashMap<String, String> = req.body_form_strict().await?;
+            assert_eq!(form["name"], "chashu");
+            Ok(())
+        }
+
+        #[async_std::test]
+        async fn mismatch() -> crate::Result<()> {
+            let mut req = build_test_request();
+            req.set_body("{}");
+            let err = req.body_form_strict::<()>().await.unwrap_err();
+            assert_eq!(err.status(), 415);
+            let err = err.downcast_ref::<UnsupportedMediaType>().unwrap();
+            assert_eq!(err.found(), Some(&mime::PLAIN));
+            assert_eq!(err.supported(), [mime::FORM]);
+
+            // The body is left untouched.
+            assert_eq!(req.body_string().await?, "{}");
+            Ok(())
+        }
+    }
+
     fn build_test_request() -> Request {
         let url = Url::parse("http://async.rs/").unwrap();
         Request::new(Method::Get, url)
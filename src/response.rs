@@ -7,11 +7,12 @@ use std::ops::Index;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use crate::conditional::LastModified;
 #[cfg(feature = "serde")]
 use crate::convert::DeserializeOwned;
 use crate::headers::{
     self, HeaderName, HeaderValue, HeaderValues, Headers, Names, ToHeaderValues, Values,
-    CONTENT_TYPE,
+    CONTENT_TYPE, ETAG, LAST_MODIFIED,
 };
 use crate::mime::Mime;
 use crate::trailers::{self, Trailers};
@@ -250,6 +251,54 @@ impl Response {
         self.replace_body(Body::empty())
     }
 
+    /// Set the `ETag` header from the response body.
+    ///
+    /// Bodies created from a file get a weak ETag derived from the file size
+    /// and modification time, and a `Last-Modified` header, without reading
+    /// the file. Other bodies are buffered and get a strong ETag hashed from
+    /// their contents while they are read. Existing `ETag` and
+    /// `Last-Modified` headers are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::conditional::ETag;
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let mut res = Response::new(StatusCode::Ok);
+    /// res.set_body("Hello, Nori!");
+    /// res.with_etag().await?;
+    ///
+    /// let etag = ETag::from_headers(&res)?.unwrap();
+    /// assert!(etag.is_strong());
+    /// assert_eq!(res.body_string().await?, "Hello, Nori!");
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn with_etag(&mut self) -> crate::Result<()> {
+        let has_etag = self.header(ETAG).is_some();
+        if let Some(modified) = self.body.modified() {
+            if !has_etag {
+                if let Some(etag) = self.body.weak_etag() {
+                    self.insert_header(&etag, &etag)?;
+                }
+            }
+            if self.header(LAST_MODIFIED).is_none() {
+                let last_modified = LastModified::new(modified);
+                self.insert_header(&last_modified, &last_modified)?;
+            }
+        } else if !has_etag {
+            let body = self.take_body();
+            let mime = body.mime().cloned();
+            let (bytes, etag) = body.into_bytes_with_etag().await?;
+            let mut body = Body::from_bytes(bytes);
+            body.set_mime(mime);
+            self.set_body(body);
+            self.insert_header(&etag, &etag)?;
+        }
+        Ok(())
+    }
+
     /// Read the body as a string.
     ///
     /// This consumes the response. If you want to read the body without
@@ -257,6 +306,9 @@ impl Response {
     /// then calling `Body::into_string` or using the Response's AsyncRead
     /// implementation to read the body.
     ///
+    /// With the `charset` feature enabled, the body is decoded using the
+    /// `charset` parameter of the `Content-Type` header.
+    ///
     /// # Examples
     ///
     /// ```
@@ -273,7 +325,12 @@ impl Response {
     /// # Ok(()) }) }
     /// ```
     pub async fn body_string(&mut self) -> crate::Result<String> {
-        let body = self.take_body();
+        #[cfg_attr(not(feature = "charset"), allow(unused_mut))]
+        let mut body = self.take_body();
+        #[cfg(feature = "charset")]
+        if let Some(mime) = self.content_type() {
+            body.set_mime(Some(mime));
+        }
         body.into_string().await
     }
 
@@ -735,4 +792,14 @@ mod test {
     fn construct_shorthand_with_invalid_status_code() {
         let _res = Response::new(600);
     }
+
+    #[cfg(feature = "charset")]
+    #[async_std::test]
+    async fn body_string_uses_content_type_charset() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.set_body(b"na\xefve".to_vec());
+        res.insert_header("Content-Type", "text/plain; charset=latin1")?;
+        assert_eq!(res.body_string().await?, "naïve");
+        Ok(())
+    }
 }
@@ -176,6 +176,30 @@ pub fn nosniff(mut headers: impl AsMut<Headers>) {
         .unwrap();
 }
 
+/// Returns `true` if the `X-Content-Type-Options` header disables mime type
+/// sniffing.
+///
+/// This follows the Fetch standard, which only considers the first value of
+/// the header.
+///
+/// # Specifications
+///
+/// - [Fetch Standard, section 3.5: X-Content-Type-Options](https://fetch.spec.whatwg.org/#x-content-type-options-header)
+pub fn is_nosniff(headers: impl AsRef<Headers>) -> bool {
+    let values = match headers.as_ref().get("X-Content-Type-Options") {
+        Some(values) => values,
+        None => return false,
+    };
+    let first = values
+        .iter()
+        .flat_map(|value| value.as_str().split(','))
+        .next();
+    match first {
+        Some(first) => first.trim().eq_ignore_ascii_case("nosniff"),
+        None => false,
+    }
+}
+
 /// Sets the `X-XSS-Protection` header to prevent reflected XSS attacks.
 ///
 /// [read more](https://helmetjs.github.io/docs/xss-filter/)
@@ -0,0 +1,435 @@
+use std::fmt::{self, Write};
+use std::slice;
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, BAGGAGE};
+use crate::parse_utils::parse_token;
+
+/// The maximum number of list-members in a `baggage` header.
+const MAX_MEMBERS: usize = 180;
+
+/// The maximum length in bytes of a `baggage` header.
+const MAX_BYTES: usize = 8192;
+
+/// Code points outside of `baggage-octet`, plus the percent sign itself.
+const ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b',')
+    .add(b';')
+    .add(b'\\')
+    .add(b'%');
+
+/// User-defined properties propagated alongside a trace, carried in the
+/// [`baggage`](https://www.w3.org/TR/baggage/) header.
+///
+/// Values are percent-decoded when parsed and percent-encoded when
+/// serialized. Inserting never lets the list grow past 180 members or
+/// 8192 bytes.
+///
+/// # Specifications
+///
+/// - [W3C Propagation format for distributed context: Baggage](https://www.w3.org/TR/baggage/)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::trace::{Baggage, BaggageMember};
+/// use http_types::Response;
+///
+/// let mut baggage = Baggage::new();
+/// baggage.insert(BaggageMember::new("tenant", "acme corp")?)?;
+///
+/// let mut experiment = BaggageMember::new("experiment", "checkout-v2")?;
+/// experiment.push_property("sampled", None)?;
+/// baggage.insert(experiment)?;
+///
+/// let mut res = Response::new(200);
+/// baggage.apply(&mut res);
+/// assert_eq!(res["baggage"], "tenant=acme%20corp,experiment=checkout-v2;sampled");
+///
+/// let baggage = Baggage::from_headers(res)?.unwrap();
+/// assert_eq!(baggage.get("tenant"), Some("acme corp"));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baggage {
+    members: Vec<BaggageMember>,
+}
+
+impl Baggage {
+    /// Create a new, empty instance of `Baggage`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Multiple `baggage` headers are combined into a single list.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if a list-member is
+    /// malformed, or if the headers exceed 180 members or 8192 bytes.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(BAGGAGE) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let len: usize = headers.iter().map(|value| value.as_str().len()).sum();
+        if len > MAX_BYTES {
+            bail!(400, "Baggage is longer than {} bytes", MAX_BYTES);
+        }
+
+        let mut members = vec![];
+        for value in headers {
+            for member in value.as_str().split(',') {
+                let member = trim_ows(member);
+                if member.is_empty() {
+                    continue;
+                }
+                if members.len() == MAX_MEMBERS {
+                    bail!(400, "Baggage holds more than {} members", MAX_MEMBERS);
+                }
+                members.push(BaggageMember::from_str(member)?);
+            }
+        }
+
+        Ok(Some(Self { members }))
+    }
+
+    /// Sets the `Baggage` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        headers
+            .as_mut()
+            .insert(BAGGAGE, self.header_value())
+            .unwrap();
+    }
+
+    /// Get the decoded value of a member.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.member(key).map(|member| member.value())
+    }
+
+    /// Get a member, including its properties.
+    pub fn member(&self, key: &str) -> Option<&BaggageMember> {
+        self.members.iter().find(|member| member.key == key)
+    }
+
+    /// Insert a member, replacing any existing member with the same key.
+    ///
+    /// # Errors
+    ///
+    /// This method errors if the resulting list would hold more than 180
+    /// members or serialize to more than 8192 bytes. The list is left
+    /// unchanged in that case.
+    pub fn insert(&mut self, member: BaggageMember) -> crate::Result<()> {
+        let mut members = self.members.clone();
+        match members.iter().position(|m| m.key == member.key) {
+            Some(index) => {
+                members.retain(|m| m.key != member.key);
+                members.insert(index, member);
+            }
+            None => members.push(member),
+        }
+
+        crate::ensure!(
+            members.len() <= MAX_MEMBERS,
+            "Baggage can hold at most {} members",
+            MAX_MEMBERS
+        );
+        let baggage = Self { members };
+        crate::ensure!(
+            baggage.to_string().len() <= MAX_BYTES,
+            "Baggage can be at most {} bytes long",
+            MAX_BYTES
+        );
+
+        *self = baggage;
+        Ok(())
+    }
+
+    /// Remove a member, returning it if it was present.
+    pub fn remove(&mut self, key: &str) -> Option<BaggageMember> {
+        let index = self.members.iter().position(|member| member.key == key)?;
+        let member = self.members.remove(index);
+        self.members.retain(|m| m.key != key);
+        Some(member)
+    }
+
+    /// An iterator visiting all members.
+    pub fn iter(&self) -> slice::Iter<'_, BaggageMember> {
+        self.members.iter()
+    }
+
+    /// Returns the number of members.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns `true` if there are no members.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+}
+
+impl Header for Baggage {
+    fn header_name(&self) -> HeaderName {
+        BAGGAGE
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let output = format!("{}", self);
+
+        // SAFETY: keys are validated to be tokens, and values are percent-encoded.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl fmt::Display for Baggage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (n, member) in self.members.iter().enumerate() {
+            match n {
+                0 => write!(f, "{}", member)?,
+                _ => write!(f, ",{}", member)?,
+            };
+        }
+        Ok(())
+    }
+}
+
+impl IntoIterator for Baggage {
+    type Item = BaggageMember;
+    type IntoIter = std::vec::IntoIter<BaggageMember>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Baggage {
+    type Item = &'a BaggageMember;
+    type IntoIter = slice::Iter<'a, BaggageMember>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A single key/value member of [`Baggage`], with optional properties.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaggageMember {
+    key: String,
+    value: String,
+    properties: Vec<(String, Option<String>)>,
+}
+
+impl BaggageMember {
+    /// Create a new instance of `BaggageMember`.
+    ///
+    /// The value is stored as-is, and percent-encoded when serialized.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the key is not a valid
+    /// token.
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> crate::Result<Self> {
+        let key = key.into();
+        validate_key(&key)?;
+        Ok(Self {
+            key,
+            value: value.into(),
+            properties: vec![],
+        })
+    }
+
+    /// Get the key of the member.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the decoded value of the member.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// An iterator visiting all properties as `(key, value)` pairs.
+    pub fn properties(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.properties
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_deref()))
+    }
+
+    /// Append a property to the member.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the key is not a valid
+    /// token.
+    pub fn push_property(
+        &mut self,
+        key: impl Into<String>,
+        value: Option<&str>,
+    ) -> crate::Result<()> {
+        let key = key.into();
+        validate_key(&key)?;
+        self.properties.push((key, value.map(String::from)));
+        Ok(())
+    }
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let mut parts = s.split(';');
+        let (key, value) = match parts.next().unwrap().split_once('=') {
+            Some((key, value)) => (trim_ows(key), decode(trim_ows(value))?),
+            None => bail!(400, "Invalid baggage member `{}`", s),
+        };
+        let mut member = Self::new(key, value)?;
+
+        for property in parts {
+            let property = trim_ows(property);
+            match property.split_once('=') {
+                Some((key, value)) => {
+                    let value = decode(trim_ows(value))?;
+                    member.push_property(trim_ows(key), Some(&value))?;
+                }
+                None => member.push_property(property, None)?,
+            }
+        }
+        Ok(member)
+    }
+}
+
+impl fmt::Display for BaggageMember {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}={}",
+            self.key,
+            utf8_percent_encode(&self.value, ENCODE_SET)
+        )?;
+        for (key, value) in &self.properties {
+            f.write_char(';')?;
+            f.write_str(key)?;
+            if let Some(value) = value {
+                write!(f, "={}", utf8_percent_encode(value, ENCODE_SET))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn trim_ows(s: &str) -> &str {
+    s.trim_matches(|c| c == ' ' || c == '\t')
+}
+
+fn validate_key(key: &str) -> crate::Result<()> {
+    if !matches!(parse_token(key), (Some(_), "")) {
+        bail!(400, "Invalid baggage key `{}`", key);
+    }
+    Ok(())
+}
+
+/// Decode a value made of `baggage-octet`s.
+///
+/// ```txt
+/// baggage-octet = %x21 / %x23-2B / %x2D-3A / %x3C-5B / %x5D-7E
+/// ```
+fn decode(value: &str) -> crate::Result<String> {
+    let valid = value
+        .bytes()
+        .all(|b| matches!(b, 0x21 | 0x23..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E));
+    if !valid {
+        bail!(400, "Invalid baggage value `{}`", value);
+    }
+    Ok(percent_decode_str(value).decode_utf8_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(s: &str) -> crate::Result<Baggage> {
+        let mut headers = Headers::new();
+        headers.insert(BAGGAGE, s).unwrap();
+        Ok(Baggage::from_headers(headers)?.unwrap())
+    }
+
+    #[test]
+    fn properties() -> crate::Result<()> {
+        let baggage = parse("key1 = value1 ; property1;property2 = value%202, key2=%E2%9C%93")?;
+        let member = baggage.member("key1").unwrap();
+        assert_eq!(member.value(), "value1");
+        let properties: Vec<_> = member.properties().collect();
+        assert_eq!(
+            properties,
+            [("property1", None), ("property2", Some("value 2"))]
+        );
+        assert_eq!(baggage.get("key2"), Some("✓"));
+        assert_eq!(
+            baggage.to_string(),
+            "key1=value1;property1;property2=value%202,key2=%E2%9C%93"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_headers() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.append(BAGGAGE, "tenant=acme").unwrap();
+        headers.append(BAGGAGE, "experiment=a,").unwrap();
+        let baggage = Baggage::from_headers(headers)?.unwrap();
+        let keys: Vec<_> = baggage.iter().map(|m| m.key()).collect();
+        assert_eq!(keys, ["tenant", "experiment"]);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(parse("key").unwrap_err().status(), 400);
+        assert_eq!(parse("k y=v").unwrap_err().status(), 400);
+        assert_eq!(parse("key=a\\b").unwrap_err().status(), 400);
+        assert_eq!(parse("key=v;p y").unwrap_err().status(), 400);
+    }
+
+    #[test]
+    fn limits() -> crate::Result<()> {
+        let members: Vec<_> = (0..181).map(|n| format!("k{}=v", n)).collect();
+        assert_eq!(parse(&members.join(",")).unwrap_err().status(), 400);
+        let long = format!("key={}", "v".repeat(MAX_BYTES));
+        assert_eq!(parse(&long).unwrap_err().status(), 400);
+
+        // Limits are checked before any member is parsed.
+        let long = format!("{},bad member", long);
+        assert!(parse(&long).unwrap_err().to_string().contains("bytes"));
+        let many = format!("{},bad member", members.join(","));
+        assert!(parse(&many).unwrap_err().to_string().contains("members"));
+
+        let mut baggage = parse(&members[..180].join(","))?;
+        assert!(baggage.insert(BaggageMember::new("extra", "v")?).is_err());
+        baggage.insert(BaggageMember::new("k0", "replaced")?)?;
+        assert_eq!(baggage.len(), 180);
+        assert_eq!(baggage.iter().next().unwrap().value(), "replaced");
+
+        let mut baggage = Baggage::new();
+        let value = "v".repeat(MAX_BYTES - "key=".len());
+        baggage.insert(BaggageMember::new("key", value)?)?;
+        assert!(baggage.insert(BaggageMember::new("k", "v")?).is_err());
+        assert_eq!(baggage.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn remove() -> crate::Result<()> {
+        let mut baggage = parse("a=1,b=2")?;
+        assert_eq!(baggage.remove("a").unwrap().value(), "1");
+        assert_eq!(baggage.remove("a"), None);
+        assert_eq!(baggage.to_string(), "b=2");
+        Ok(())
+    }
+}
@@ -1,17 +1,29 @@
 //! HTTP timings and traces.
 //!
-//! This module implements parsers and serializers for timing-related headers.
+//! This module implements parsers and serializers for timing-related headers,
+//! and for headers that correlate requests across services.
 //! These headers enable tracing and timing requests, and help answer the
 //! question of: _"Where is my program spending its time?"_
 //!
 //! # Specifications
 //!
 //! - [W3C Trace-Context header](https://w3c.github.io/trace-context/)
+//! - [W3C Baggage header](https://www.w3.org/TR/baggage/)
+//! - [Zipkin B3 Propagation](https://github.com/openzipkin/b3-propagation)
+//! - [Jaeger Propagation Format](https://www.jaegertracing.io/docs/1.21/client-libraries/#propagation-format)
 //! - [W3C Server-Timing header](https://w3c.github.io/server-timing/#the-server-timing-header-field)
 
+mod baggage;
+mod propagation;
+mod request_id;
 pub mod server_timing;
 mod trace_context;
+mod trace_state;
 
+pub use baggage::{Baggage, BaggageMember};
+pub use propagation::{extract, inject, Propagator};
+pub use request_id::{RequestId, RequestIdFormat};
 #[doc(inline)]
-pub use server_timing::{Metric, ServerTiming};
+pub use server_timing::{Metric, ServerTiming, Timer};
 pub use trace_context::TraceContext;
+pub use trace_state::TraceState;
@@ -0,0 +1,456 @@
+use std::fmt;
+
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, Headers};
+use crate::trace::TraceContext;
+
+const B3: HeaderName = HeaderName::from_lowercase_str("b3");
+const X_B3_TRACE_ID: HeaderName = HeaderName::from_lowercase_str("x-b3-traceid");
+const X_B3_SPAN_ID: HeaderName = HeaderName::from_lowercase_str("x-b3-spanid");
+const X_B3_PARENT_SPAN_ID: HeaderName = HeaderName::from_lowercase_str("x-b3-parentspanid");
+const X_B3_SAMPLED: HeaderName = HeaderName::from_lowercase_str("x-b3-sampled");
+const X_B3_FLAGS: HeaderName = HeaderName::from_lowercase_str("x-b3-flags");
+const UBER_TRACE_ID: HeaderName = HeaderName::from_lowercase_str("uber-trace-id");
+
+/// A format used to propagate a [`TraceContext`] across services.
+///
+/// # Specifications
+///
+/// - [W3C Trace-Context](https://www.w3.org/TR/trace-context/)
+/// - [Zipkin B3 Propagation](https://github.com/openzipkin/b3-propagation)
+/// - [Jaeger Propagation Format](https://www.jaegertracing.io/docs/1.21/client-libraries/#propagation-format)
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Propagator {
+    /// The W3C `traceparent` and `tracestate` headers.
+    TraceContext,
+    /// The Zipkin B3 multi-header format, using the `X-B3-*` headers.
+    B3Multi,
+    /// The Zipkin B3 single-header format, using the `b3` header.
+    B3Single,
+    /// The Jaeger format, using the `uber-trace-id` header.
+    Jaeger,
+}
+
+impl Propagator {
+    /// All supported propagators, in the order they're tried by default.
+    pub const ALL: &'static [Propagator] = &[
+        Propagator::TraceContext,
+        Propagator::B3Single,
+        Propagator::B3Multi,
+        Propagator::Jaeger,
+    ];
+
+    /// Read a `TraceContext` from headers in this format.
+    ///
+    /// Contexts received without a sampling decision are sampled, like a
+    /// newly created `TraceContext`.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if the headers for this
+    /// format are present but malformed.
+    pub fn extract(&self, headers: impl AsRef<Headers>) -> crate::Result<Option<TraceContext>> {
+        let headers = headers.as_ref();
+        match self {
+            Propagator::TraceContext => TraceContext::from_headers(headers),
+            Propagator::B3Multi => extract_b3_multi(headers),
+            Propagator::B3Single => extract_b3_single(headers),
+            Propagator::Jaeger => extract_jaeger(headers),
+        }
+    }
+
+    /// Write a `TraceContext` to headers in this format.
+    ///
+    /// The context's own `id` is sent as the span id, so the receiving
+    /// service sees it as its parent.
+    pub fn inject(&self, context: &TraceContext, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        let trace_id = format!("{:032x}", context.trace_id());
+        let span_id = format!("{:016x}", context.id());
+        let sampled = if context.sampled() { "1" } else { "0" };
+
+        match self {
+            Propagator::TraceContext => {
+                context.apply_header(&mut *headers);
+                if !context.state().is_empty() {
+                    context.state().apply_header(&mut *headers);
+                }
+            }
+            Propagator::B3Multi => {
+                headers.insert(X_B3_TRACE_ID, trace_id).unwrap();
+                headers.insert(X_B3_SPAN_ID, span_id).unwrap();
+                match context.parent_id() {
+                    Some(parent_id) => {
+                        let parent_id = format!("{:016x}", parent_id);
+                        headers.insert(X_B3_PARENT_SPAN_ID, parent_id).unwrap();
+                    }
+                    None => {
+                        headers.remove(X_B3_PARENT_SPAN_ID);
+                    }
+                }
+                headers.insert(X_B3_SAMPLED, sampled).unwrap();
+                headers.remove(X_B3_FLAGS);
+            }
+            Propagator::B3Single => {
+                let mut value = format!("{}-{}-{}", trace_id, span_id, sampled);
+                if let Some(parent_id) = context.parent_id() {
+                    value.push_str(&format!("-{:016x}", parent_id));
+                }
+                headers.insert(B3, value).unwrap();
+            }
+            Propagator::Jaeger => {
+                let parent_id = context.parent_id().unwrap_or(0);
+                let value = format!("{}:{}:{:x}:{}", trace_id, span_id, parent_id, sampled);
+                headers.insert(UBER_TRACE_ID, value).unwrap();
+            }
+        }
+    }
+}
+
+impl fmt::Display for Propagator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Propagator::TraceContext => "tracecontext",
+            Propagator::B3Multi => "b3multi",
+            Propagator::B3Single => "b3",
+            Propagator::Jaeger => "jaeger",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Read a `TraceContext` using the first propagator whose headers are present.
+///
+/// Propagators are tried in order. If a propagator's headers are malformed
+/// the next one is tried, and the first error is returned only when no
+/// propagator produced a context.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::trace::{self, Propagator};
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header("b3", "80f198ee56343ba864fe8b2a57d3eff7-e457b5a2e4d86bd1-1");
+///
+/// let context = trace::extract(&req, Propagator::ALL)?.unwrap();
+/// assert_eq!(context.trace_id(), 0x80f198ee56343ba864fe8b2a57d3eff7);
+/// assert_eq!(context.parent_id(), Some(0xe457b5a2e4d86bd1));
+/// assert!(context.sampled());
+/// #
+/// # Ok(()) }
+/// ```
+pub fn extract(
+    headers: impl AsRef<Headers>,
+    propagators: &[Propagator],
+) -> crate::Result<Option<TraceContext>> {
+    let headers = headers.as_ref();
+    let mut error = None;
+    for propagator in propagators {
+        match propagator.extract(headers) {
+            Ok(Some(context)) => return Ok(Some(context)),
+            Ok(None) => {}
+            Err(err) => {
+                error.get_or_insert(err);
+            }
+        }
+    }
+    match error {
+        Some(err) => Err(err),
+        None => Ok(None),
+    }
+}
+
+/// Write a `TraceContext` to headers using each of the given propagators.
+///
+/// # Examples
+///
+/// ```
+/// use http_types::trace::{self, Propagator, TraceContext};
+/// use http_types::Request;
+///
+/// let context = TraceContext::new();
+///
+/// let mut req = Request::get("https://example.com");
+/// trace::inject(&context, &mut req, &[Propagator::TraceContext, Propagator::B3Multi]);
+/// assert!(req.header("traceparent").is_some());
+/// assert_eq!(req["X-B3-Sampled"], "1");
+/// ```
+pub fn inject(
+    context: &TraceContext,
+    mut headers: impl AsMut<Headers>,
+    propagators: &[Propagator],
+) {
+    for propagator in propagators {
+        propagator.inject(context, headers.as_mut());
+    }
+}
+
+/// ```txt
+/// X-B3-TraceId: {TraceId}
+/// X-B3-SpanId: {SpanId}
+/// X-B3-ParentSpanId: {ParentSpanId}
+/// X-B3-Sampled: {SamplingState}
+/// X-B3-Flags: 1
+/// ```
+fn extract_b3_multi(headers: &Headers) -> crate::Result<Option<TraceContext>> {
+    let (trace_id, span_id) = match (headers.get(X_B3_TRACE_ID), headers.get(X_B3_SPAN_ID)) {
+        (Some(trace_id), Some(span_id)) => (trace_id.as_str(), span_id.as_str()),
+        (None, None) => return Ok(None),
+        _ => bail!(400, "B3 headers must include both a trace id and a span id"),
+    };
+
+    let debug = headers.get(X_B3_FLAGS).map(|flags| flags.as_str()) == Some("1");
+    let sampled = match headers.get(X_B3_SAMPLED).map(|s| s.as_str()) {
+        _ if debug => true,
+        Some("1") | Some("true") | None => true,
+        Some("0") | Some("false") => false,
+        Some(other) => bail!(400, "Invalid X-B3-Sampled value `{}`", other),
+    };
+
+    let trace_id = parse_id(trace_id, &[16, 32], "trace id")?;
+    let span_id = parse_id(span_id, &[16], "span id")? as u64;
+    Ok(Some(TraceContext::from_remote(trace_id, span_id, sampled)))
+}
+
+/// ```txt
+/// b3: {TraceId}-{SpanId}-{SamplingState}-{ParentSpanId}
+/// ```
+///
+/// A header holding only a sampling state carries no ids, and yields no
+/// context.
+fn extract_b3_single(headers: &Headers) -> crate::Result<Option<TraceContext>> {
+    let value = match headers.get(B3) {
+        Some(value) => value.as_str(),
+        None => return Ok(None),
+    };
+
+    let parts: Vec<&str> = value.split('-').collect();
+    let sampled = match parts.as_slice() {
+        [state] => {
+            parse_b3_sampling_state(state)?;
+            return Ok(None);
+        }
+        [_, _] => true,
+        [_, _, state] | [_, _, state, _] => parse_b3_sampling_state(state)?,
+        _ => bail!(400, "Invalid b3 header `{}`", value),
+    };
+    if let Some(parent_id) = parts.get(3) {
+        parse_id(parent_id, &[16], "parent span id")?;
+    }
+
+    let trace_id = parse_id(parts[0], &[16, 32], "trace id")?;
+    let span_id = parse_id(parts[1], &[16], "span id")? as u64;
+    Ok(Some(TraceContext::from_remote(trace_id, span_id, sampled)))
+}
+
+fn parse_b3_sampling_state(state: &str) -> crate::Result<bool> {
+    match state {
+        "1" | "d" => Ok(true),
+        "0" => Ok(false),
+        _ => bail!(400, "Invalid b3 sampling state `{}`", state),
+    }
+}
+
+/// ```txt
+/// uber-trace-id: {trace-id}:{span-id}:{parent-span-id}:{flags}
+/// ```
+fn extract_jaeger(headers: &Headers) -> crate::Result<Option<TraceContext>> {
+    let value = match headers.get(UBER_TRACE_ID) {
+        Some(value) => value.as_str(),
+        None => return Ok(None),
+    };
+
+    // Some clients send the header URL-encoded.
+    let value = value.replace("%3A", ":").replace("%3a", ":");
+    let parts: Vec<&str> = value.split(':').collect();
+    let (trace_id, span_id, flags) = match parts.as_slice() {
+        [trace_id, span_id, _, flags] => (*trace_id, *span_id, *flags),
+        _ => bail!(400, "Invalid uber-trace-id header `{}`", value),
+    };
+
+    let trace_id = parse_var_id(trace_id, 32, "trace id")?;
+    let span_id = parse_var_id(span_id, 16, "span id")? as u64;
+    let flags = match u8::from_str_radix(flags, 16) {
+        Ok(flags) => flags,
+        Err(_) => bail!(400, "Invalid uber-trace-id flags `{}`", flags),
+    };
+
+    // Flag `0x02` marks a debug trace, which is always sampled.
+    let sampled = flags & 0b11 != 0;
+    Ok(Some(TraceContext::from_remote(trace_id, span_id, sampled)))
+}
+
+/// Parse a non-zero id of lowercase hex digits with one of the given lengths.
+fn parse_id(id: &str, lengths: &[usize], name: &str) -> crate::Result<u128> {
+    if !lengths.contains(&id.len()) {
+        bail!(400, "Invalid {} `{}`", name, id);
+    }
+    parse_var_id(id, 32, name)
+}
+
+/// Parse a non-zero id of at most `max` hex digits.
+fn parse_var_id(id: &str, max: usize, name: &str) -> crate::Result<u128> {
+    let valid = !id.is_empty()
+        && id.len() <= max
+        && id.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'));
+    match u128::from_str_radix(id, 16) {
+        Ok(id) if valid && id != 0 => Ok(id),
+        _ => bail!(400, "Invalid {} `{}`", name, id),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TRACE_ID: u128 = 0x80f198ee56343ba864fe8b2a57d3eff7;
+    const SPAN_ID: u64 = 0xe457b5a2e4d86bd1;
+
+    fn headers(pairs: &[(&str, &str)]) -> Headers {
+        let mut headers = Headers::new();
+        for (name, value) in pairs {
+            headers.insert(*name, *value).unwrap();
+        }
+        headers
+    }
+
+    #[test]
+    fn b3_multi() -> crate::Result<()> {
+        let headers = headers(&[
+            ("X-B3-TraceId", "80f198ee56343ba864fe8b2a57d3eff7"),
+            ("X-B3-SpanId", "e457b5a2e4d86bd1"),
+            ("X-B3-ParentSpanId", "05e3ac9a4f6e3b90"),
+            ("X-B3-Sampled", "0"),
+        ]);
+        let context = Propagator::B3Multi.extract(&headers)?.unwrap();
+        assert_eq!(context.trace_id(), TRACE_ID);
+        assert_eq!(context.parent_id(), Some(SPAN_ID));
+        assert!(!context.sampled());
+
+        let mut out = Headers::new();
+        Propagator::B3Multi.inject(&context, &mut out);
+        assert_eq!(out["X-B3-TraceId"], "80f198ee56343ba864fe8b2a57d3eff7");
+        assert_eq!(
+            out["X-B3-SpanId"],
+            format!("{:016x}", context.id()).as_str()
+        );
+        assert_eq!(out["X-B3-ParentSpanId"], "e457b5a2e4d86bd1");
+        assert_eq!(out["X-B3-Sampled"], "0");
+        Ok(())
+    }
+
+    #[test]
+    fn b3_multi_debug_and_short_ids() -> crate::Result<()> {
+        let headers = headers(&[
+            ("X-B3-TraceId", "64fe8b2a57d3eff7"),
+            ("X-B3-SpanId", "e457b5a2e4d86bd1"),
+            ("X-B3-Sampled", "0"),
+            ("X-B3-Flags", "1"),
+        ]);
+        let context = Propagator::B3Multi.extract(&headers)?.unwrap();
+        assert_eq!(context.trace_id(), 0x64fe8b2a57d3eff7);
+        assert!(context.sampled());
+
+        let headers = self::headers(&[("X-B3-TraceId", "64fe8b2a57d3eff7")]);
+        let err = Propagator::B3Multi.extract(&headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+        Ok(())
+    }
+
+    #[test]
+    fn b3_single() -> crate::Result<()> {
+        let headers = headers(&[(
+            "b3",
+            "80f198ee56343ba864fe8b2a57d3eff7-e457b5a2e4d86bd1-d-05e3ac9a4f6e3b90",
+        )]);
+        let context = Propagator::B3Single.extract(&headers)?.unwrap();
+        assert_eq!(context.trace_id(), TRACE_ID);
+        assert_eq!(context.parent_id(), Some(SPAN_ID));
+        assert!(context.sampled());
+
+        let mut out = Headers::new();
+        Propagator::B3Single.inject(&context, &mut out);
+        let expected = format!(
+            "80f198ee56343ba864fe8b2a57d3eff7-{:016x}-1-e457b5a2e4d86bd1",
+            context.id()
+        );
+        assert_eq!(out["b3"], expected.as_str());
+
+        let headers = self::headers(&[("b3", "0")]);
+        assert!(Propagator::B3Single.extract(&headers)?.is_none());
+        for invalid in &[
+            "x",
+            "80f198ee56343ba8-e457b5a2e4d86bd1-2",
+            "0-e457b5a2e4d86bd1",
+        ] {
+            let headers = self::headers(&[("b3", invalid)]);
+            let err = Propagator::B3Single.extract(&headers).unwrap_err();
+            assert_eq!(err.status(), 400);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn jaeger() -> crate::Result<()> {
+        let headers = headers(&[(
+            "uber-trace-id",
+            "80f198ee56343ba864fe8b2a57d3eff7%3Ae457b5a2e4d86bd1%3A0%3A1",
+        )]);
+        let context = Propagator::Jaeger.extract(&headers)?.unwrap();
+        assert_eq!(context.trace_id(), TRACE_ID);
+        assert_eq!(context.parent_id(), Some(SPAN_ID));
+        assert!(context.sampled());
+
+        let headers = self::headers(&[("uber-trace-id", "abc:def:0:0")]);
+        let context = Propagator::Jaeger.extract(&headers)?.unwrap();
+        assert_eq!(context.trace_id(), 0xabc);
+        assert!(!context.sampled());
+
+        let mut out = Headers::new();
+        Propagator::Jaeger.inject(&context, &mut out);
+        let expected = format!(
+            "00000000000000000000000000000abc:{:016x}:def:0",
+            context.id()
+        );
+        assert_eq!(out["uber-trace-id"], expected.as_str());
+        Ok(())
+    }
+
+    #[test]
+    fn extract_in_order() -> crate::Result<()> {
+        let headers = headers(&[
+            ("traceparent", "00-invalid"),
+            ("uber-trace-id", "abc:def:0:1"),
+            ("b3", "80f198ee56343ba864fe8b2a57d3eff7-e457b5a2e4d86bd1"),
+        ]);
+        let context = extract(&headers, &[Propagator::Jaeger, Propagator::B3Single])?.unwrap();
+        assert_eq!(context.trace_id(), 0xabc);
+        let context = extract(&headers, Propagator::ALL)?.unwrap();
+        assert_eq!(context.trace_id(), TRACE_ID);
+
+        let err = extract(&headers, &[Propagator::TraceContext, Propagator::B3Multi]).unwrap_err();
+        assert_eq!(err.status(), 400);
+        assert!(extract(&headers, &[Propagator::B3Multi])?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> crate::Result<()> {
+        let mut context = TraceContext::new();
+        context.set_sampled(false);
+        let mut headers = Headers::new();
+        inject(&context, &mut headers, Propagator::ALL);
+        for propagator in Propagator::ALL {
+            let received = propagator.extract(&headers)?.unwrap();
+            assert_eq!(received.trace_id(), context.trace_id(), "{}", propagator);
+            assert_eq!(received.parent_id(), Some(context.id()), "{}", propagator);
+            assert!(!received.sampled(), "{}", propagator);
+        }
+        Ok(())
+    }
+}
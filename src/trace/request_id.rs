@@ -0,0 +1,394 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers};
+use crate::Request;
+
+const X_REQUEST_ID: HeaderName = HeaderName::from_lowercase_str("x-request-id");
+const X_CORRELATION_ID: HeaderName = HeaderName::from_lowercase_str("x-correlation-id");
+
+/// The maximum length of a request id.
+const MAX_LEN: usize = 200;
+
+/// The alphabet used by ULIDs.
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// An identifier for a request, carried in the `X-Request-Id` header.
+///
+/// Request ids are between 1 and 200 characters long, and may contain ASCII
+/// letters, digits, and `-`, `_`, `.`, `:`, `+`, `/`, `=` or `@`. The header
+/// an id was read from is remembered, so echoing it writes the same header.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::trace::{RequestId, RequestIdFormat};
+/// use http_types::{Request, Response};
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header("X-Correlation-Id", "f058ebd6-02f7-4d3f-942e-904344e8cde5");
+///
+/// let id = RequestId::get_or_insert(&mut req, RequestIdFormat::UuidV4);
+/// assert_eq!(req.request_id(), Some(&id));
+///
+/// let mut res = Response::new(200);
+/// res.insert_header(&id, &id);
+/// assert_eq!(res["X-Correlation-Id"], "f058ebd6-02f7-4d3f-942e-904344e8cde5");
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId {
+    id: String,
+    name: HeaderName,
+}
+
+/// The format of a generated [`RequestId`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestIdFormat {
+    /// A random [UUID version 4](https://www.rfc-editor.org/rfc/rfc9562#section-5.4).
+    UuidV4,
+    /// A time-ordered [UUID version 7](https://www.rfc-editor.org/rfc/rfc9562#section-5.7).
+    UuidV7,
+    /// A time-ordered [ULID](https://github.com/ulid/spec).
+    Ulid,
+}
+
+impl RequestId {
+    /// Create a new instance of `RequestId`, sent as `X-Request-Id`.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the id is empty, longer
+    /// than 200 characters, or contains disallowed characters.
+    pub fn new(id: impl Into<String>) -> crate::Result<Self> {
+        let id = id.into();
+        if id.is_empty() || id.len() > MAX_LEN {
+            bail!(
+                400,
+                "Request ids must be between 1 and {} characters",
+                MAX_LEN
+            );
+        }
+        let valid = id.bytes().all(|b| {
+            b.is_ascii_alphanumeric()
+                || matches!(b, b'-' | b'_' | b'.' | b':' | b'+' | b'/' | b'=' | b'@')
+        });
+        if !valid {
+            bail!(400, "Invalid request id `{}`", id);
+        }
+        Ok(Self {
+            id,
+            name: X_REQUEST_ID,
+        })
+    }
+
+    /// Generate a new request id, sent as `X-Request-Id`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::trace::{RequestId, RequestIdFormat};
+    ///
+    /// let id = RequestId::generate(RequestIdFormat::UuidV7);
+    /// assert_eq!(id.as_str().len(), 36);
+    /// assert_eq!(&id.as_str()[14..15], "7");
+    ///
+    /// let id = RequestId::generate(RequestIdFormat::Ulid);
+    /// assert_eq!(id.as_str().len(), 26);
+    /// ```
+    pub fn generate(format: RequestIdFormat) -> Self {
+        let id = match format {
+            RequestIdFormat::UuidV4 => format_uuid(fastrand::u128(..), 4),
+            RequestIdFormat::UuidV7 => format_uuid(timestamped(), 7),
+            RequestIdFormat::Ulid => format_ulid(timestamped()),
+        };
+        Self {
+            id,
+            name: X_REQUEST_ID,
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// `X-Request-Id` is tried first, followed by `X-Correlation-Id`. A valid
+    /// `X-Correlation-Id` is used if `X-Request-Id` is invalid.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if neither header holds a
+    /// valid id.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = headers.as_ref();
+        let request_id = Self::from_headers_with(headers, X_REQUEST_ID);
+        if let Ok(Some(_)) = request_id {
+            return request_id;
+        }
+        match Self::from_headers_with(headers, X_CORRELATION_ID) {
+            Ok(Some(id)) => Ok(Some(id)),
+            correlation_id => request_id.and(correlation_id),
+        }
+    }
+
+    /// Create a new instance from a custom header.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if the id is invalid.
+    pub fn from_headers_with(
+        headers: impl AsRef<Headers>,
+        name: impl Into<HeaderName>,
+    ) -> crate::Result<Option<Self>> {
+        let name = name.into();
+        let value = match headers.as_ref().get(name.clone()) {
+            Some(value) => value.last().as_str(),
+            None => return Ok(None),
+        };
+        let mut id = Self::new(value)?;
+        id.name = name;
+        Ok(Some(id))
+    }
+
+    /// Get the request id stored on a request, or read it from the request's
+    /// headers, or generate a new one.
+    ///
+    /// The id is stored in the request's extensions, where it's available
+    /// through [`Request::request_id`]. Invalid ids sent by the client are
+    /// replaced by a generated one.
+    pub fn get_or_insert(req: &mut Request, format: RequestIdFormat) -> Self {
+        if let Some(id) = req.request_id() {
+            return id.clone();
+        }
+        let id = match Self::from_headers(&*req) {
+            Ok(Some(id)) => id,
+            _ => Self::generate(format),
+        };
+        req.ext_mut().insert(id.clone());
+        id
+    }
+
+    /// Get the request id stored on a request, or read it from a custom
+    /// header, or generate a new one which is sent as that header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::trace::{RequestId, RequestIdFormat};
+    /// use http_types::{Request, Response};
+    ///
+    /// let mut req = Request::get("https://example.com");
+    /// let id = RequestId::get_or_insert_with(&mut req, "Fly-Request-Id", RequestIdFormat::Ulid);
+    ///
+    /// let mut res = Response::new(200);
+    /// res.insert_header(&id, &id);
+    /// assert_eq!(res["Fly-Request-Id"], id.as_str());
+    /// ```
+    pub fn get_or_insert_with(
+        req: &mut Request,
+        name: impl Into<HeaderName>,
+        format: RequestIdFormat,
+    ) -> Self {
+        if let Some(id) = req.request_id() {
+            return id.clone();
+        }
+        let name = name.into();
+        let id = match Self::from_headers_with(&*req, name.clone()) {
+            Ok(Some(id)) => id,
+            _ => {
+                let mut id = Self::generate(format);
+                id.name = name;
+                id
+            }
+        };
+        req.ext_mut().insert(id.clone());
+        id
+    }
+
+    /// Set the header this id is sent as.
+    pub fn set_header_name(&mut self, name: impl Into<HeaderName>) {
+        self.name = name.into();
+    }
+
+    /// Get the id as a string.
+    pub fn as_str(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Header for RequestId {
+    fn header_name(&self) -> HeaderName {
+        self.name.clone()
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        // SAFETY: the id is validated to be a subset of ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(self.id.clone().into()) }
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.id)
+    }
+}
+
+impl PartialEq<str> for RequestId {
+    fn eq(&self, other: &str) -> bool {
+        self.id == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for RequestId {
+    fn eq(&self, other: &&'a str) -> bool {
+        self.id == *other
+    }
+}
+
+/// 48 bits of milliseconds since the Unix epoch, followed by 80 random bits.
+fn timestamped() -> u128 {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis());
+    let millis = millis & 0xffff_ffff_ffff;
+    (millis << 80) | (fastrand::u128(..) >> 48)
+}
+
+/// Set the version and variant bits of a UUID, and format it.
+fn format_uuid(bits: u128, version: u128) -> String {
+    let bits = (bits & !(0xf << 76)) | (version << 76);
+    let bits = (bits & !(0b11 << 62)) | (0b10 << 62);
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        bits >> 96,
+        (bits >> 80) & 0xffff,
+        (bits >> 64) & 0xffff,
+        (bits >> 48) & 0xffff,
+        bits & 0xffff_ffff_ffff
+    )
+}
+
+/// Encode 128 bits as 26 characters of Crockford's base32.
+fn format_ulid(bits: u128) -> String {
+    (0..26)
+        .rev()
+        .map(|n| CROCKFORD_BASE32[((bits >> (n * 5)) & 0x1f) as usize] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        assert!(RequestId::new("req_01HV8X:abc+def/ghi=@host").is_ok());
+        assert!(RequestId::new("a".repeat(200)).is_ok());
+        for invalid in &["", "with space", "quote\"", "ünicode"] {
+            let err = RequestId::new(*invalid).unwrap_err();
+            assert_eq!(err.status(), 400);
+        }
+        assert_eq!(RequestId::new("a".repeat(201)).unwrap_err().status(), 400);
+    }
+
+    #[test]
+    fn from_headers() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert("X-Correlation-Id", "abc").unwrap();
+        let id = RequestId::from_headers(&headers)?.unwrap();
+        assert_eq!(id, "abc");
+        assert_eq!(id.header_name(), "x-correlation-id");
+
+        headers.insert("X-Request-Id", "def").unwrap();
+        assert_eq!(RequestId::from_headers(&headers)?.unwrap(), "def");
+
+        headers.insert("Fly-Request-Id", "ghi").unwrap();
+        let mut id = RequestId::from_headers_with(&headers, "Fly-Request-Id")?.unwrap();
+        assert_eq!(id.header_name(), "fly-request-id");
+        id.set_header_name("X-Request-Id");
+        assert_eq!(id.header_name(), "x-request-id");
+
+        headers.insert("X-Request-Id", "not valid").unwrap();
+        let id = RequestId::from_headers(&headers)?.unwrap();
+        assert_eq!(id, "abc");
+        assert_eq!(id.header_name(), "x-correlation-id");
+
+        headers.remove("X-Correlation-Id");
+        assert_eq!(RequestId::from_headers(&headers).unwrap_err().status(), 400);
+        headers
+            .insert("X-Correlation-Id", "not valid either")
+            .unwrap();
+        assert_eq!(RequestId::from_headers(&headers).unwrap_err().status(), 400);
+        Ok(())
+    }
+
+    #[test]
+    fn uuid_format() {
+        let id = format_uuid(u128::MAX, 4);
+        assert_eq!(id, "ffffffff-ffff-4fff-bfff-ffffffffffff");
+        let id = format_uuid(0, 7);
+        assert_eq!(id, "00000000-0000-7000-8000-000000000000");
+
+        let id = RequestId::generate(RequestIdFormat::UuidV4);
+        assert_eq!(&id.as_str()[14..15], "4");
+        assert!(RequestId::new(id.as_str()).is_ok());
+    }
+
+    #[test]
+    fn ulid_format() {
+        assert_eq!(format_ulid(0), "00000000000000000000000000");
+        assert_eq!(format_ulid(u128::MAX), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+        assert_eq!(format_ulid(0x1f), "0000000000000000000000000Z");
+    }
+
+    #[test]
+    fn time_ordered() {
+        let a = RequestId::generate(RequestIdFormat::Ulid);
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let b = RequestId::generate(RequestIdFormat::Ulid);
+        assert!(a.as_str()[..10] < b.as_str()[..10]);
+    }
+
+    #[test]
+    fn stored_on_request() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        assert!(req.request_id().is_none());
+        let id = RequestId::get_or_insert(&mut req, RequestIdFormat::Ulid);
+        assert_eq!(id.as_str().len(), 26);
+        let again = RequestId::get_or_insert(&mut req, RequestIdFormat::UuidV4);
+        assert_eq!(id, again);
+        assert_eq!(req.request_id(), Some(&id));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_ids_are_replaced() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        req.insert_header("X-Request-Id", "not valid")?;
+        let id = RequestId::get_or_insert(&mut req, RequestIdFormat::Ulid);
+        assert_eq!(id.as_str().len(), 26);
+        assert_eq!(id.header_name(), "x-request-id");
+        Ok(())
+    }
+
+    #[test]
+    fn custom_header() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com");
+        req.insert_header("X-Request-Id", "abc")?;
+        req.insert_header("Fly-Request-Id", "def")?;
+        let id = RequestId::get_or_insert_with(&mut req, "Fly-Request-Id", RequestIdFormat::Ulid);
+        assert_eq!(id, "def");
+        assert_eq!(id.header_name(), "fly-request-id");
+
+        let mut req = Request::get("https://example.com");
+        req.insert_header("Fly-Request-Id", "not valid")?;
+        let id = RequestId::get_or_insert_with(&mut req, "Fly-Request-Id", RequestIdFormat::Ulid);
+        assert_eq!(id.as_str().len(), 26);
+        assert_eq!(id.header_name(), "fly-request-id");
+        assert_eq!(req.request_id(), Some(&id));
+        Ok(())
+    }
+}
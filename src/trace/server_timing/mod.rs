@@ -20,19 +20,52 @@
 //! #
 //! # Ok(()) }
 //! ```
+//!
+//! Durations can also be measured with timers, which record an entry when
+//! they're dropped. For streaming responses whose timings are only known once
+//! the body has been written, the header can be sent as a trailer instead:
+//!
+//! ```
+//! # fn main() -> http_types::Result<()> {
+//! # async_std::task::block_on(async {
+//! #
+//! use http_types::Response;
+//! use http_types::trace::ServerTiming;
+//!
+//! let mut res = Response::new(200);
+//! let sender = res.send_trailers();
+//!
+//! let mut timings = ServerTiming::new();
+//! timings.declare_trailer(&mut res);
+//! {
+//!     let mut db = timings.start("db")?;
+//!     let _query = db.start("query")?;
+//! }
+//! timings.send_trailers(sender).await;
+//!
+//! let trailers = res.recv_trailers().await.unwrap();
+//! let timings = ServerTiming::from_headers(trailers)?.unwrap();
+//! let names: Vec<_> = timings.iter().map(|m| m.name().as_str()).collect();
+//! assert_eq!(names, ["db", "db.query"]);
+//! #
+//! # Ok(()) })}
+//! ```
 
 mod metric;
 mod parse;
+mod timer;
 
 pub use metric::Metric;
 use parse::parse_header;
+pub use timer::Timer;
 
 use std::fmt::Write;
 use std::iter::Iterator;
 
 use std::slice;
 
-use crate::headers::{Header, HeaderName, HeaderValue, Headers, SERVER_TIMING};
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, SERVER_TIMING, TRAILER};
+use crate::trailers::{self, Trailers};
 
 /// Metrics and descriptions for the given request-response cycle.
 ///
@@ -90,6 +123,61 @@ impl ServerTiming {
         self.timings.push(entry);
     }
 
+    /// Start a timer for a new entry.
+    ///
+    /// The entry is added immediately, and its duration is recorded when the
+    /// returned [`Timer`] is stopped or dropped.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the name is not valid ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::trace::ServerTiming;
+    ///
+    /// let mut timings = ServerTiming::new();
+    /// let mut timer = timings.start("db")?;
+    /// timer.set_description("Database")?;
+    /// let elapsed = timer.stop();
+    ///
+    /// let entry = timings.iter().next().unwrap();
+    /// assert_eq!(entry.duration(), Some(elapsed));
+    /// assert_eq!(entry.description(), Some("Database"));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn start(&mut self, name: impl Into<String>) -> crate::Result<Timer<'_>> {
+        Timer::new(&mut self.timings, name.into())
+    }
+
+    /// Announce in the `Trailer` header that `Server-Timing` will be sent as
+    /// a trailer.
+    pub fn declare_trailer(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        let declared = headers.get(TRAILER).into_iter().flatten().any(|value| {
+            value
+                .as_str()
+                .split(',')
+                .any(|name| name.trim().eq_ignore_ascii_case(SERVER_TIMING.as_str()))
+        });
+        if !declared {
+            headers.append(TRAILER, SERVER_TIMING.as_str()).unwrap();
+        }
+    }
+
+    /// Send the entries as a `Server-Timing` trailer.
+    ///
+    /// The sender is obtained from [`Response::send_trailers`][crate::Response::send_trailers].
+    pub async fn send_trailers(&self, sender: trailers::Sender) {
+        let mut trailers = Trailers::new();
+        self.apply_header(&mut trailers);
+        sender.send(trailers).await;
+    }
+
     /// An iterator visiting all server timings.
     pub fn iter(&self) -> Iter<'_> {
         Iter {
@@ -247,6 +335,46 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn nested_timers() -> crate::Result<()> {
+        let mut timings = ServerTiming::new();
+        {
+            let mut request = timings.start("request")?;
+            let mut db = request.start("db")?;
+            let query = db.start("query")?;
+            assert_eq!(query.name(), "request.db.query");
+            let query = query.stop();
+            db.set_description("Database")?;
+            drop(db);
+            assert!(query <= request.elapsed());
+            let _render = request.start("render")?;
+        }
+
+        let names: Vec<_> = timings.iter().map(|m| m.name().as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "request",
+                "request.db",
+                "request.db.query",
+                "request.render"
+            ]
+        );
+        assert!(timings.iter().all(|m| m.duration().is_some()));
+        Ok(())
+    }
+
+    #[test]
+    fn declare_trailer() {
+        let timings = ServerTiming::new();
+        let mut headers = Headers::new();
+        headers.insert(TRAILER, "Content-MD5").unwrap();
+        timings.declare_trailer(&mut headers);
+        timings.declare_trailer(&mut headers);
+        let trailer: Vec<_> = headers[TRAILER].iter().map(|v| v.as_str()).collect();
+        assert_eq!(trailer, ["Content-MD5", "server-timing"]);
+    }
+
     #[test]
     fn bad_request_on_parse_error() {
         let mut headers = Headers::new();
@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+use super::Metric;
+
+/// A running timer for an entry in `ServerTiming`.
+///
+/// The timer's duration is recorded when it's stopped or dropped. Entries
+/// appear in the order their timers were started, so a span started inside
+/// another span is listed after it.
+///
+/// This type is created by [`ServerTiming::start`][super::ServerTiming::start].
+#[derive(Debug)]
+pub struct Timer<'a> {
+    timings: &'a mut Vec<Metric>,
+    index: usize,
+    start: Instant,
+}
+
+impl<'a> Timer<'a> {
+    pub(crate) fn new(timings: &'a mut Vec<Metric>, name: String) -> crate::Result<Self> {
+        let metric = Metric::new(name, None, None)?;
+        timings.push(metric);
+        Ok(Self {
+            index: timings.len() - 1,
+            timings,
+            start: Instant::now(),
+        })
+    }
+
+    /// The name of the entry being timed.
+    pub fn name(&self) -> &str {
+        &self.timings[self.index].name
+    }
+
+    /// The time passed since the timer was started.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Set the description of the entry.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the description is not valid ASCII.
+    pub fn set_description(&mut self, desc: impl Into<String>) -> crate::Result<()> {
+        let desc = desc.into();
+        crate::ensure!(desc.is_ascii(), "Description should be valid ASCII");
+        self.timings[self.index].desc = Some(desc);
+        Ok(())
+    }
+
+    /// Start a nested timer.
+    ///
+    /// The nested entry is named `{parent}.{name}`.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the name is not valid ASCII.
+    pub fn start(&mut self, name: &str) -> crate::Result<Timer<'_>> {
+        let name = format!("{}.{}", self.name(), name);
+        Timer::new(self.timings, name)
+    }
+
+    /// Stop the timer, and return the recorded duration.
+    pub fn stop(mut self) -> Duration {
+        self.record()
+    }
+
+    fn record(&mut self) -> Duration {
+        let start = self.start;
+        let metric = &mut self.timings[self.index];
+        *metric.dur.get_or_insert_with(|| start.elapsed())
+    }
+}
+
+impl Drop for Timer<'_> {
+    fn drop(&mut self) {
+        self.record();
+    }
+}
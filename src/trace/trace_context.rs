@@ -1,7 +1,14 @@
 use std::fmt;
 
+use crate::bail_status as bail;
 use crate::headers::{Header, HeaderName, HeaderValue, Headers, TRACEPARENT};
-use crate::Status;
+use crate::trace::TraceState;
+
+/// The highest `traceparent` version this implementation understands.
+const SUPPORTED_VERSION: u8 = 0;
+
+/// The length of a version `00` `traceparent` header.
+const TRACEPARENT_LEN: usize = 55;
 
 /// Extract and apply [Trace-Context](https://w3c.github.io/trace-context/) headers.
 ///
@@ -41,6 +48,7 @@ pub struct TraceContext {
     trace_id: u128,
     parent_id: Option<u64>,
     flags: u8,
+    state: TraceState,
 }
 
 impl TraceContext {
@@ -65,15 +73,22 @@ impl TraceContext {
             trace_id: fastrand::u128(..),
             parent_id: None,
             flags: 1,
+            state: TraceState::new(),
         }
     }
 
     /// Create and return TraceContext object based on `traceparent` HTTP header.
     ///
+    /// Headers with a version higher than `00` are parsed as far as the spec
+    /// allows: the known fields are read, and flags other than `sampled` are
+    /// discarded. A malformed `tracestate` header is discarded rather than
+    /// propagated.
+    ///
     /// # Errors
     ///
-    /// This function may error if the header is malformed. An error with a
-    /// status code of `400: Bad Request` will be generated.
+    /// This function may error if the header is malformed, uses the invalid
+    /// version `ff`, or carries an all-zero trace id or parent id. An error
+    /// with a status code of `400: Bad Request` will be generated.
     ///
     /// # Examples
     ///
@@ -106,21 +121,72 @@ impl TraceContext {
             Some(header) => header,
             None => return Ok(None),
         };
-        let parts: Vec<&str> = traceparent.as_str().split('-').collect();
+        let traceparent = traceparent.as_str();
+
+        let version = parse_hex(traceparent.get(0..2), "version")? as u8;
+        if version == 0xff {
+            bail!(400, "Invalid traceparent version `ff`");
+        }
+
+        // Version `00` has an exact length. Later versions may only append
+        // fields, which must be separated from the known ones by a dash.
+        let len = traceparent.len();
+        let valid_len = match version {
+            SUPPORTED_VERSION => len == TRACEPARENT_LEN,
+            _ => {
+                len == TRACEPARENT_LEN
+                    || (len > TRACEPARENT_LEN && traceparent.as_bytes()[TRACEPARENT_LEN] == b'-')
+            }
+        };
+        let dashes = [2, 35, 52]
+            .iter()
+            .all(|&n| traceparent.as_bytes().get(n) == Some(&b'-'));
+        if !valid_len || !dashes {
+            bail!(400, "Malformed traceparent header `{}`", traceparent);
+        }
+
+        let trace_id = parse_hex(traceparent.get(3..35), "trace id")?;
+        let parent_id = parse_hex(traceparent.get(36..52), "parent id")? as u64;
+        let mut flags = parse_hex(traceparent.get(53..55), "trace flags")? as u8;
+        if trace_id == 0 || parent_id == 0 {
+            bail!(400, "Traceparent ids must not be all zeroes");
+        }
+        if version > SUPPORTED_VERSION {
+            flags &= 0b00000001;
+        }
+
+        let state = TraceState::from_headers(headers)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
 
         Ok(Some(Self {
             id: fastrand::u64(..),
-            version: u8::from_str_radix(parts[0], 16)?,
-            trace_id: u128::from_str_radix(parts[1], 16).status(400)?,
-            parent_id: Some(u64::from_str_radix(parts[2], 16).status(400)?),
-            flags: u8::from_str_radix(parts[3], 16).status(400)?,
+            version,
+            trace_id,
+            parent_id: Some(parent_id),
+            flags,
+            state,
         }))
     }
 
+    /// Create a TraceContext received from a remote parent in another
+    /// propagation format.
+    pub(crate) fn from_remote(trace_id: u128, parent_id: u64, sampled: bool) -> Self {
+        Self {
+            id: fastrand::u64(..),
+            version: SUPPORTED_VERSION,
+            trace_id,
+            parent_id: Some(parent_id),
+            flags: sampled as u8,
+            state: TraceState::new(),
+        }
+    }
+
     /// Generate a child of the current TraceContext and return it.
     ///
     /// The child will have a new randomly genrated `id` and its `parent_id` will be set to the
-    /// `id` of this TraceContext.
+    /// `id` of this TraceContext. The trace flags and `tracestate` are carried over.
     pub fn child(&self) -> Self {
         Self {
             id: fastrand::u64(..),
@@ -128,6 +194,7 @@ impl TraceContext {
             trace_id: self.trace_id,
             parent_id: Some(self.id),
             flags: self.flags,
+            state: self.state.clone(),
         }
     }
 
@@ -138,6 +205,9 @@ impl TraceContext {
 
     /// Return the version of the TraceContext spec used.
     ///
+    /// This is the version that was received. Contexts are always propagated
+    /// using version `00`.
+    ///
     /// You probably don't need this.
     pub fn version(&self) -> u8 {
         self.version
@@ -156,6 +226,50 @@ impl TraceContext {
         self.parent_id
     }
 
+    /// Return all trace flags.
+    ///
+    /// Unknown flags are preserved and propagated as they were received.
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Set all trace flags at once.
+    pub fn set_flags(&mut self, flags: u8) {
+        self.flags = flags;
+    }
+
+    /// Access the vendor-specific `tracestate` entries.
+    pub fn state(&self) -> &TraceState {
+        &self.state
+    }
+
+    /// Mutably access the vendor-specific `tracestate` entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::trace::TraceContext;
+    /// use http_types::Response;
+    ///
+    /// let mut context = TraceContext::new();
+    /// context.state_mut().insert("rojo", "00f067aa0ba902b7")?;
+    ///
+    /// let child = context.child();
+    /// assert_eq!(child.state().get("rojo"), Some("00f067aa0ba902b7"));
+    ///
+    /// let mut res = Response::new(200);
+    /// res.insert_header(&child, &child);
+    /// res.insert_header(child.state(), child.state());
+    /// assert_eq!(res["tracestate"], "rojo=00f067aa0ba902b7");
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn state_mut(&mut self) -> &mut TraceState {
+        &mut self.state
+    }
+
     /// Returns true if the trace is sampled
     ///
     /// # Examples
@@ -211,11 +325,26 @@ impl fmt::Display for TraceContext {
         write!(
             f,
             "{:02x}-{:032x}-{:016x}-{:02x}",
-            self.version, self.trace_id, self.id, self.flags
+            SUPPORTED_VERSION, self.trace_id, self.id, self.flags
         )
     }
 }
 
+/// Parse a fixed-width field of lowercase hex digits.
+fn parse_hex(field: Option<&str>, name: &str) -> crate::Result<u128> {
+    let field = match field {
+        Some(field) => field,
+        None => bail!(400, "Missing traceparent {}", name),
+    };
+    if !field
+        .bytes()
+        .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+    {
+        bail!(400, "Invalid traceparent {} `{}`", name, field);
+    }
+    Ok(u128::from_str_radix(field, 16)?)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -223,7 +352,12 @@ mod test {
     #[test]
     fn default() -> crate::Result<()> {
         let mut headers = crate::headers::Headers::new();
-        headers.insert(TRACEPARENT, "00-01-deadbeef-00").unwrap();
+        headers
+            .insert(
+                TRACEPARENT,
+                "00-00000000000000000000000000000001-00000000deadbeef-00",
+            )
+            .unwrap();
         let context = TraceContext::from_headers(&mut headers)?.unwrap();
         assert_eq!(context.version(), 0);
         assert_eq!(context.trace_id(), 1);
@@ -245,7 +379,12 @@ mod test {
     #[test]
     fn not_sampled() -> crate::Result<()> {
         let mut headers = crate::headers::Headers::new();
-        headers.insert(TRACEPARENT, "00-01-02-00").unwrap();
+        headers
+            .insert(
+                TRACEPARENT,
+                "00-00000000000000000000000000000001-0000000000000002-00",
+            )
+            .unwrap();
         let context = TraceContext::from_headers(&mut headers)?.unwrap();
         assert!(!context.sampled());
         Ok(())
@@ -254,9 +393,86 @@ mod test {
     #[test]
     fn sampled() -> crate::Result<()> {
         let mut headers = crate::headers::Headers::new();
-        headers.insert(TRACEPARENT, "00-01-02-01").unwrap();
+        headers
+            .insert(
+                TRACEPARENT,
+                "00-00000000000000000000000000000001-0000000000000002-01",
+            )
+            .unwrap();
         let context = TraceContext::from_headers(&mut headers)?.unwrap();
         assert!(context.sampled());
         Ok(())
     }
+
+    fn parse(traceparent: &str) -> crate::Result<TraceContext> {
+        let mut headers = crate::headers::Headers::new();
+        headers.insert(TRACEPARENT, traceparent).unwrap();
+        Ok(TraceContext::from_headers(headers)?.unwrap())
+    }
+
+    #[test]
+    fn all_flags() -> crate::Result<()> {
+        let mut context = parse("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-03")?;
+        assert_eq!(context.flags(), 0b11);
+        assert!(context.sampled());
+        context.set_sampled(false);
+        assert_eq!(context.flags(), 0b10);
+        assert_eq!(context.child().flags(), 0b10);
+        assert!(context.to_string().ends_with("-02"));
+        Ok(())
+    }
+
+    #[test]
+    fn future_version() -> crate::Result<()> {
+        let context =
+            parse("cc-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-03-what-the-future")?;
+        assert_eq!(context.version(), 0xcc);
+        assert_eq!(context.trace_id(), 0x0af7651916cd43dd8448eb211c80319c);
+        assert_eq!(context.parent_id(), Some(0xb7ad6b7169203331));
+        assert_eq!(context.flags(), 0b01);
+        assert!(context.to_string().starts_with("00-"));
+
+        parse("cc-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01")?;
+        let err =
+            parse("cc-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01.what").unwrap_err();
+        assert_eq!(err.status(), 400);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for traceparent in &[
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01-",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-1",
+            "00-0AF7651916CD43DD8448EB211C80319C-b7ad6b7169203331-01",
+            "00-00000000000000000000000000000000-b7ad6b7169203331-01",
+            "00-0af7651916cd43dd8448eb211c80319c-0000000000000000-01",
+            "ff-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            "0-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            "00_0af7651916cd43dd8448eb211c80319c_b7ad6b7169203331_01",
+        ] {
+            let err = parse(traceparent).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", traceparent);
+        }
+    }
+
+    #[test]
+    fn tracestate() -> crate::Result<()> {
+        let mut headers = crate::headers::Headers::new();
+        headers
+            .insert(
+                TRACEPARENT,
+                "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            )
+            .unwrap();
+        headers.insert("tracestate", "congo=t61rcWkgMzE").unwrap();
+        let context = TraceContext::from_headers(&headers)?.unwrap();
+        assert_eq!(context.state().get("congo"), Some("t61rcWkgMzE"));
+        assert_eq!(context.child().state(), context.state());
+
+        headers.insert("tracestate", "Congo=t61rcWkgMzE").unwrap();
+        let context = TraceContext::from_headers(&headers)?.unwrap();
+        assert!(context.state().is_empty());
+        Ok(())
+    }
 }
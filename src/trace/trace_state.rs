@@ -0,0 +1,316 @@
+use std::fmt;
+
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, TRACESTATE};
+
+/// The maximum number of list-members in a `tracestate` header.
+const MAX_ENTRIES: usize = 32;
+
+/// Vendor-specific trace identification data, carried in the
+/// [`tracestate`](https://www.w3.org/TR/trace-context/#tracestate-header) header.
+///
+/// Entries are ordered from most to least recently updated. Updating an
+/// entry moves it to the front of the list, and when more than 32 entries
+/// are present the rightmost entries are dropped.
+///
+/// # Specifications
+///
+/// - [Trace Context, section 3.3: Tracestate Header](https://www.w3.org/TR/trace-context/#tracestate-header)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::trace::TraceState;
+/// use http_types::Response;
+///
+/// let mut res = Response::new(200);
+/// res.insert_header("tracestate", "rojo=00f067aa0ba902b7, congo=t61rcWkgMzE");
+///
+/// let mut state = TraceState::from_headers(&res)?.unwrap();
+/// assert_eq!(state.get("congo"), Some("t61rcWkgMzE"));
+///
+/// state.insert("congo", "ucfJifl5GOE")?;
+/// let entries: Vec<_> = state.iter().collect();
+/// assert_eq!(entries, [("congo", "ucfJifl5GOE"), ("rojo", "00f067aa0ba902b7")]);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceState {
+    entries: Vec<(String, String)>,
+}
+
+impl TraceState {
+    /// Create a new, empty instance of `TraceState`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Multiple `tracestate` headers are combined into a single list.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if a list-member is
+    /// malformed, if a key occurs more than once, or if the list holds more
+    /// than 32 entries.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(TRACESTATE) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut entries: Vec<(String, String)> = vec![];
+        for value in headers {
+            for member in value.as_str().split(',') {
+                // Empty list-members are allowed and carry no data.
+                let member = member.trim_matches(|c| c == ' ' || c == '\t');
+                if member.is_empty() {
+                    continue;
+                }
+
+                let (key, value) = match member.split_once('=') {
+                    Some(pair) => pair,
+                    None => bail!(400, "Invalid tracestate list-member `{}`", member),
+                };
+                validate(key, value)?;
+                if entries.iter().any(|(k, _)| k == key) {
+                    bail!(400, "Duplicate tracestate key `{}`", key);
+                }
+                if entries.len() == MAX_ENTRIES {
+                    bail!(400, "Tracestate holds more than {} entries", MAX_ENTRIES);
+                }
+                entries.push((key.to_owned(), value.to_owned()));
+            }
+        }
+
+        Ok(Some(Self { entries }))
+    }
+
+    /// Get the value stored for a key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Insert or update an entry, moving it to the front of the list.
+    ///
+    /// If the list would grow beyond 32 entries, the rightmost entry is
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the key or value does
+    /// not match the format defined by the spec.
+    pub fn insert(
+        &mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> crate::Result<()> {
+        let key = key.into();
+        let value = value.into();
+        validate(&key, &value)?;
+
+        self.remove(&key);
+        self.entries.insert(0, (key, value));
+        self.entries.truncate(MAX_ENTRIES);
+        Ok(())
+    }
+
+    /// Remove an entry, returning its value if it was present.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let index = self.entries.iter().position(|(k, _)| k == key)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    /// An iterator visiting all entries as `(key, value)` pairs, most recently
+    /// updated first.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Header for TraceState {
+    fn header_name(&self) -> HeaderName {
+        TRACESTATE
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let output = format!("{}", self);
+
+        // SAFETY: keys and values are validated to be visible ASCII or spaces.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl fmt::Display for TraceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (n, (key, value)) in self.entries.iter().enumerate() {
+            match n {
+                0 => write!(f, "{}={}", key, value)?,
+                _ => write!(f, ",{}={}", key, value)?,
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Validate a list-member against the `key` and `value` grammar.
+fn validate(key: &str, value: &str) -> crate::Result<()> {
+    if !is_valid_key(key) {
+        bail!(400, "Invalid tracestate key `{}`", key);
+    }
+    if !is_valid_value(value) {
+        bail!(400, "Invalid tracestate value `{}`", value);
+    }
+    Ok(())
+}
+
+/// ```txt
+/// key = simple-key / multi-tenant-key
+/// simple-key = lcalpha 0*255( lcalpha / DIGIT / "_" / "-"/ "*" / "/" )
+/// multi-tenant-key = tenant-id "@" system-id
+/// tenant-id = ( lcalpha / DIGIT ) 0*240( lcalpha / DIGIT / "_" / "-"/ "*" / "/" )
+/// system-id = lcalpha 0*13( lcalpha / DIGIT / "_" / "-"/ "*" / "/" )
+/// ```
+fn is_valid_key(key: &str) -> bool {
+    fn is_key_char(b: u8) -> bool {
+        matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-' | b'*' | b'/')
+    }
+
+    match key.split_once('@') {
+        None => {
+            let bytes = key.as_bytes();
+            matches!(bytes.first(), Some(b'a'..=b'z'))
+                && bytes.len() <= 256
+                && bytes.iter().all(|&b| is_key_char(b))
+        }
+        Some((tenant, system)) => {
+            let tenant = tenant.as_bytes();
+            let system = system.as_bytes();
+            matches!(tenant.first(), Some(b'a'..=b'z' | b'0'..=b'9'))
+                && tenant.len() <= 241
+                && tenant.iter().all(|&b| is_key_char(b))
+                && matches!(system.first(), Some(b'a'..=b'z'))
+                && system.len() <= 14
+                && system.iter().all(|&b| is_key_char(b))
+        }
+    }
+}
+
+/// ```txt
+/// value = 0*255(chr) nblk-chr
+/// nblk-chr = %x21-2B / %x2D-3C / %x3E-7E
+/// chr = %x20 / nblk-chr
+/// ```
+fn is_valid_value(value: &str) -> bool {
+    fn is_nblk_char(b: u8) -> bool {
+        matches!(b, 0x21..=0x2B | 0x2D..=0x3C | 0x3E..=0x7E)
+    }
+
+    let bytes = value.as_bytes();
+    match bytes.last() {
+        Some(&last) => {
+            bytes.len() <= 256
+                && is_nblk_char(last)
+                && bytes.iter().all(|&b| b == b' ' || is_nblk_char(b))
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(s: &str) -> crate::Result<TraceState> {
+        let mut headers = Headers::new();
+        headers.insert(TRACESTATE, s).unwrap();
+        Ok(TraceState::from_headers(headers)?.unwrap())
+    }
+
+    #[test]
+    fn multiple_headers() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers
+            .append(TRACESTATE, "rojo=00f067aa0ba902b7,,")
+            .unwrap();
+        headers.append(TRACESTATE, " congo=t61rcWkgMzE").unwrap();
+        let state = TraceState::from_headers(headers)?.unwrap();
+        assert_eq!(state.len(), 2);
+        assert_eq!(
+            state.header_value(),
+            "rojo=00f067aa0ba902b7,congo=t61rcWkgMzE"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn keys() {
+        assert!(is_valid_key("rojo"));
+        assert!(is_valid_key("fw529a3039@dt"));
+        assert!(is_valid_key("0tenant@vendor"));
+        assert!(is_valid_key(&"a".repeat(256)));
+        assert!(!is_valid_key(&"a".repeat(257)));
+        assert!(!is_valid_key("0rojo"));
+        assert!(!is_valid_key("Rojo"));
+        assert!(!is_valid_key("tenant@0vendor"));
+        assert!(!is_valid_key("tenant@vendorvendorvend"));
+        assert!(!is_valid_key("a@b@c"));
+        assert!(!is_valid_key(""));
+    }
+
+    #[test]
+    fn values() {
+        assert!(is_valid_value("00f067aa0ba902b7"));
+        assert!(is_valid_value("with space"));
+        assert!(!is_valid_value("trailing "));
+        assert!(!is_valid_value("a=b"));
+        assert!(!is_valid_value(""));
+        assert!(!is_valid_value(&"a".repeat(257)));
+    }
+
+    #[test]
+    fn invalid_lists() {
+        assert_eq!(parse("rojo").unwrap_err().status(), 400);
+        assert_eq!(parse("rojo=1,rojo=2").unwrap_err().status(), 400);
+        let members: Vec<_> = (0..33).map(|n| format!("k{}=v", n)).collect();
+        assert_eq!(parse(&members.join(",")).unwrap_err().status(), 400);
+    }
+
+    #[test]
+    fn insert_moves_to_front() -> crate::Result<()> {
+        let mut state = parse("a=1,b=2,c=3")?;
+        state.insert("c", "4")?;
+        state.insert("d", "5")?;
+        assert_eq!(state.to_string(), "d=5,c=4,a=1,b=2");
+        assert_eq!(state.insert("D", "6").unwrap_err().status(), 400);
+        Ok(())
+    }
+
+    #[test]
+    fn insert_truncates() -> crate::Result<()> {
+        let members: Vec<_> = (0..32).map(|n| format!("k{}=v", n)).collect();
+        let mut state = parse(&members.join(","))?;
+        state.insert("new", "v")?;
+        assert_eq!(state.len(), 32);
+        assert_eq!(state.get("new"), Some("v"));
+        assert_eq!(state.get("k31"), None);
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@
 mod connection;
 mod receiver;
 mod sender;
+pub mod websocket;
 
 pub use connection::Connection;
 pub use receiver::Receiver;
@@ -0,0 +1,295 @@
+//! The WebSocket opening handshake.
+//!
+//! A client asks to open a WebSocket connection by sending a `GET` request
+//! with an [`upgrade_request`]. The server answers with [`accept`], after
+//! which the connection is handed over through
+//! [`Response::send_upgrade`][crate::Response::send_upgrade]. The client
+//! checks the server's answer with [`validate_response`].
+//!
+//! Only the `HTTP/1.1` handshake is supported.
+//!
+//! # Specifications
+//!
+//! - [RFC 6455, section 4: Opening Handshake](https://www.rfc-editor.org/rfc/rfc6455#section-4)
+//!
+//! # Examples
+//!
+//! ```
+//! # fn main() -> http_types::Result<()> {
+//! #
+//! use http_types::upgrade::websocket::{self, SecWebSocketProtocol};
+//! use http_types::{Request, StatusCode};
+//!
+//! // The client offers two subprotocols.
+//! let mut req = Request::get("https://example.com/chat");
+//! websocket::upgrade_request(&mut req);
+//! req.insert_header("Sec-WebSocket-Protocol", "chat.v2, chat");
+//!
+//! // The server picks one of them.
+//! let mut res = websocket::accept(&req)?;
+//! let offered = SecWebSocketProtocol::from_headers(&req)?.unwrap();
+//! if offered.contains("chat") {
+//!     res.insert_header("Sec-WebSocket-Protocol", "chat");
+//! }
+//! assert_eq!(res.status(), StatusCode::SwitchingProtocols);
+//!
+//! websocket::validate_response(&req, &res)?;
+//! #
+//! # Ok(()) }
+//! ```
+
+mod sec_websocket_accept;
+mod sec_websocket_extensions;
+mod sec_websocket_key;
+mod sec_websocket_protocol;
+mod sec_websocket_version;
+
+pub use sec_websocket_accept::SecWebSocketAccept;
+pub use sec_websocket_extensions::{SecWebSocketExtensions, WebSocketExtension};
+pub use sec_websocket_key::SecWebSocketKey;
+pub use sec_websocket_protocol::SecWebSocketProtocol;
+pub use sec_websocket_version::SecWebSocketVersion;
+
+use sec_websocket_version::WEBSOCKET_VERSION;
+
+use crate::headers::{Header, HeaderName, Headers, CONNECTION, UPGRADE};
+use crate::{ensure_status, Method, Request, Response, StatusCode, Version};
+
+/// Turn a request into a WebSocket handshake request.
+///
+/// The `Upgrade`, `Connection`, `Sec-WebSocket-Version` and
+/// `Sec-WebSocket-Key` headers are set. The generated key is returned.
+pub fn upgrade_request(req: &mut Request) -> SecWebSocketKey {
+    req.set_method(Method::Get);
+    req.insert_header(UPGRADE, "websocket").unwrap();
+    req.insert_header(CONNECTION, "Upgrade").unwrap();
+    SecWebSocketVersion::new().apply_header(&mut *req);
+    let key = SecWebSocketKey::new();
+    key.apply_header(&mut *req);
+    key
+}
+
+/// Validate a WebSocket handshake request, and create the
+/// `101: Switching Protocols` response accepting it.
+///
+/// Subprotocols and extensions aren't negotiated; insert a
+/// [`SecWebSocketProtocol`] or [`SecWebSocketExtensions`] header into the
+/// response to accept any of the ones offered.
+///
+/// # Errors
+///
+/// This function errors with `405: Method Not Allowed` if the request isn't a
+/// `GET` request, and with `400: Bad Request` if it isn't a valid handshake.
+/// If the client asked for a version of the protocol other than 13, it
+/// errors with `426: Upgrade Required`; the response to it should contain
+/// `Sec-WebSocket-Version: 13`.
+pub fn accept(req: &Request) -> crate::Result<Response> {
+    ensure_status!(
+        req.method() == Method::Get,
+        405,
+        "WebSocket handshakes must use GET"
+    );
+    ensure_status!(
+        !matches!(req.version(), Some(Version::Http0_9 | Version::Http1_0)),
+        400,
+        "WebSocket handshakes require HTTP/1.1"
+    );
+    ensure_status!(
+        has_token(req.as_ref(), UPGRADE, "websocket"),
+        400,
+        "Upgrade header must contain `websocket`"
+    );
+    ensure_status!(
+        has_token(req.as_ref(), CONNECTION, "upgrade"),
+        400,
+        "Connection header must contain `upgrade`"
+    );
+
+    let version = SecWebSocketVersion::from_headers(req)?;
+    ensure_status!(version.is_some(), 400, "Missing Sec-WebSocket-Version");
+    ensure_status!(
+        version.and_then(|v| v.version()) == Some(WEBSOCKET_VERSION),
+        426,
+        "Unsupported WebSocket version"
+    );
+    let key = match SecWebSocketKey::from_headers(req)? {
+        Some(key) => key,
+        None => crate::bail_status!(400, "Missing Sec-WebSocket-Key"),
+    };
+
+    let mut res = Response::new(StatusCode::SwitchingProtocols);
+    res.insert_header(UPGRADE, "websocket")?;
+    res.insert_header(CONNECTION, "Upgrade")?;
+    SecWebSocketAccept::new(&key).apply_header(&mut res);
+    Ok(res)
+}
+
+/// Check the server's response to a WebSocket handshake request.
+///
+/// The response must be a `101: Switching Protocols` response that answers
+/// the request's key, and may only select a subprotocol and extensions the
+/// request offered.
+///
+/// # Errors
+///
+/// This function errors if the server didn't accept the handshake, or
+/// answered with an invalid response.
+pub fn validate_response(req: &Request, res: &Response) -> crate::Result<()> {
+    crate::ensure!(
+        res.status() == StatusCode::SwitchingProtocols,
+        "The server didn't switch protocols, and responded with {}",
+        res.status()
+    );
+    crate::ensure!(
+        has_token(res.as_ref(), UPGRADE, "websocket"),
+        "Upgrade header must contain `websocket`"
+    );
+    crate::ensure!(
+        has_token(res.as_ref(), CONNECTION, "upgrade"),
+        "Connection header must contain `upgrade`"
+    );
+
+    let key = match SecWebSocketKey::from_headers(req)? {
+        Some(key) => key,
+        None => crate::bail!("The request has no Sec-WebSocket-Key"),
+    };
+    let accept = SecWebSocketAccept::from_headers(res)?;
+    crate::ensure!(
+        accept.into_iter().any(|accept| accept.matches(&key)),
+        "Sec-WebSocket-Accept doesn't match the request's key"
+    );
+
+    if let Some(selected) = SecWebSocketProtocol::from_headers(res)? {
+        let offered = SecWebSocketProtocol::from_headers(req)?.unwrap_or_default();
+        crate::ensure!(
+            selected.len() == 1 && selected.iter().all(|p| offered.contains(p)),
+            "The server selected a subprotocol that wasn't offered"
+        );
+    }
+    if let Some(accepted) = SecWebSocketExtensions::from_headers(res)? {
+        let offered = SecWebSocketExtensions::from_headers(req)?.unwrap_or_default();
+        crate::ensure!(
+            accepted.iter().all(|ext| offered.contains(ext.name())),
+            "The server accepted an extension that wasn't offered"
+        );
+    }
+    Ok(())
+}
+
+/// Check whether a comma-separated header contains a token, ignoring case.
+fn has_token(headers: &Headers, name: HeaderName, token: &str) -> bool {
+    headers
+        .get(name)
+        .into_iter()
+        .flatten()
+        .flat_map(|value| value.as_str().split(','))
+        .any(|value| value.trim().eq_ignore_ascii_case(token))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn handshake() -> Request {
+        let mut req = Request::get("https://example.com/chat");
+        req.insert_header("Upgrade", "WebSocket").unwrap();
+        req.insert_header("Connection", "keep-alive, Upgrade")
+            .unwrap();
+        req.insert_header("Sec-WebSocket-Version", "13").unwrap();
+        req.insert_header("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ==")
+            .unwrap();
+        req
+    }
+
+    #[test]
+    fn accept_handshake() -> crate::Result<()> {
+        let res = accept(&handshake())?;
+        assert_eq!(res.status(), StatusCode::SwitchingProtocols);
+        assert_eq!(res["Upgrade"], "websocket");
+        assert_eq!(res["Connection"], "Upgrade");
+        assert_eq!(res["Sec-WebSocket-Accept"], "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+        Ok(())
+    }
+
+    #[test]
+    fn reject_handshake() {
+        let status = |req: &Request| accept(req).unwrap_err().status();
+
+        let mut req = handshake();
+        req.set_method(Method::Post);
+        assert_eq!(status(&req), 405);
+
+        let mut req = handshake();
+        req.set_version(Some(Version::Http1_0));
+        assert_eq!(status(&req), 400);
+
+        for name in &[
+            "Upgrade",
+            "Connection",
+            "Sec-WebSocket-Version",
+            "Sec-WebSocket-Key",
+        ] {
+            let mut req = handshake();
+            req.remove_header(*name);
+            assert_eq!(status(&req), 400, "{}", name);
+        }
+
+        let mut req = handshake();
+        req.insert_header("Upgrade", "h2c").unwrap();
+        assert_eq!(status(&req), 400);
+
+        let mut req = handshake();
+        req.insert_header("Sec-WebSocket-Key", "c2hvcnQ=").unwrap();
+        assert_eq!(status(&req), 400);
+
+        let mut req = handshake();
+        req.insert_header("Sec-WebSocket-Version", "8").unwrap();
+        assert_eq!(status(&req), 426);
+    }
+
+    #[test]
+    fn client_validation() -> crate::Result<()> {
+        let mut req = Request::get("https://example.com/chat");
+        let key = upgrade_request(&mut req);
+        assert_eq!(req["Sec-WebSocket-Key"], key.as_str());
+        req.insert_header("Sec-WebSocket-Protocol", "chat").unwrap();
+        req.insert_header("Sec-WebSocket-Extensions", "permessage-deflate")
+            .unwrap();
+
+        let mut res = accept(&req)?;
+        validate_response(&req, &res)?;
+
+        res.insert_header("Sec-WebSocket-Protocol", "chat").unwrap();
+        res.insert_header("Sec-WebSocket-Extensions", "permessage-deflate")
+            .unwrap();
+        validate_response(&req, &res)?;
+
+        let mut bad = res.clone();
+        bad.insert_header("Sec-WebSocket-Protocol", "mqtt").unwrap();
+        assert!(validate_response(&req, &bad).is_err());
+
+        let mut bad = res.clone();
+        bad.insert_header("Sec-WebSocket-Protocol", "chat, chat")
+            .unwrap();
+        assert!(validate_response(&req, &bad).is_err());
+
+        let mut bad = res.clone();
+        bad.insert_header("Sec-WebSocket-Extensions", "x-unknown")
+            .unwrap();
+        assert!(validate_response(&req, &bad).is_err());
+
+        let mut bad = res.clone();
+        bad.insert_header("Sec-WebSocket-Accept", "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=")
+            .unwrap();
+        assert!(validate_response(&req, &bad).is_err());
+
+        let mut bad = res.clone();
+        bad.remove_header("Upgrade");
+        assert!(validate_response(&req, &bad).is_err());
+
+        let mut bad = Response::new(426);
+        bad.insert_header("Sec-WebSocket-Version", "8").unwrap();
+        assert!(validate_response(&req, &bad).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use super::SecWebSocketKey;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, SEC_WEBSOCKET_ACCEPT};
+
+use std::fmt;
+
+/// The GUID appended to the key before hashing it.
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// The server's answer to a [`SecWebSocketKey`].
+///
+/// The value is the base64 encoded SHA-1 hash of the key followed by a fixed
+/// GUID.
+///
+/// # Specifications
+///
+/// - [RFC 6455, section 11.3.3: Sec-WebSocket-Accept](https://www.rfc-editor.org/rfc/rfc6455#section-11.3.3)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::upgrade::websocket::{SecWebSocketAccept, SecWebSocketKey};
+/// use http_types::{Request, Response};
+///
+/// let mut req = Request::get("https://example.com/chat");
+/// req.insert_header("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ==");
+///
+/// let key = SecWebSocketKey::from_headers(&req)?.unwrap();
+/// let accept = SecWebSocketAccept::new(&key);
+/// assert_eq!(accept.as_str(), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+///
+/// let mut res = Response::new(101);
+/// res.insert_header(&accept, &accept);
+/// let accept = SecWebSocketAccept::from_headers(&res)?.unwrap();
+/// assert!(accept.matches(&key));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecWebSocketAccept {
+    accept: String,
+}
+
+impl SecWebSocketAccept {
+    /// Compute the answer to a key.
+    pub fn new(key: &SecWebSocketKey) -> Self {
+        let mut hasher = sha1_smol::Sha1::new();
+        hasher.update(key.as_str().as_bytes());
+        hasher.update(WEBSOCKET_GUID.as_bytes());
+        Self {
+            accept: base64::encode(hasher.digest().bytes()),
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// The value isn't validated; use [`matches`][Self::matches] to check
+    /// it against the key that was sent.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let accept = match headers.as_ref().get(SEC_WEBSOCKET_ACCEPT) {
+            Some(values) => values.last().as_str().trim(),
+            None => return Ok(None),
+        };
+        Ok(Some(Self {
+            accept: accept.to_owned(),
+        }))
+    }
+
+    /// Returns `true` if this is the answer to `key`.
+    pub fn matches(&self, key: &SecWebSocketKey) -> bool {
+        *self == Self::new(key)
+    }
+
+    /// Get the value as a string.
+    pub fn as_str(&self) -> &str {
+        &self.accept
+    }
+}
+
+impl Header for SecWebSocketAccept {
+    fn header_name(&self) -> HeaderName {
+        SEC_WEBSOCKET_ACCEPT
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        // SAFETY: the value is either base64, or was read from a header.
+        unsafe { HeaderValue::from_bytes_unchecked(self.accept.clone().into()) }
+    }
+}
+
+impl fmt::Display for SecWebSocketAccept {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.accept)
+    }
+}
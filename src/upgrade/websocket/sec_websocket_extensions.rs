@@ -0,0 +1,337 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, SEC_WEBSOCKET_EXTENSIONS};
+use crate::parse_utils::{parse_quoted_string, parse_token};
+
+use std::fmt::{self, Write};
+use std::slice;
+
+/// The WebSocket extensions a client offers, or a server accepted.
+///
+/// # Specifications
+///
+/// - [RFC 6455, section 9.1: Negotiating Extensions](https://www.rfc-editor.org/rfc/rfc6455#section-9.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::upgrade::websocket::{SecWebSocketExtensions, WebSocketExtension};
+/// use http_types::headers::Header;
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com/chat");
+/// req.insert_header(
+///     "Sec-WebSocket-Extensions",
+///     "permessage-deflate; client_max_window_bits, x-custom; level=\"high\"",
+/// );
+///
+/// let offered = SecWebSocketExtensions::from_headers(&req)?.unwrap();
+/// let deflate = offered.get("permessage-deflate").unwrap();
+/// assert_eq!(deflate.param("client_max_window_bits"), Some(None));
+/// assert_eq!(offered.get("x-custom").unwrap().param("level"), Some(Some("high")));
+///
+/// let mut accepted = SecWebSocketExtensions::new();
+/// let mut extension = WebSocketExtension::new("permessage-deflate")?;
+/// extension.push_param("server_no_context_takeover", None)?;
+/// accepted.push(extension);
+/// assert_eq!(
+///     accepted.header_value(),
+///     "permessage-deflate; server_no_context_takeover"
+/// );
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecWebSocketExtensions {
+    extensions: Vec<WebSocketExtension>,
+}
+
+impl SecWebSocketExtensions {
+    /// Create a new, empty instance of `SecWebSocketExtensions`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Extensions from multiple headers are combined in order.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if an extension is
+    /// malformed.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let values = match headers.as_ref().get(SEC_WEBSOCKET_EXTENSIONS) {
+            Some(values) => values,
+            None => return Ok(None),
+        };
+
+        let mut extensions = vec![];
+        for value in values {
+            let mut input = value.as_str();
+            loop {
+                let (extension, rest) = WebSocketExtension::parse(input)?;
+                extensions.push(extension);
+                match rest.trim_start().strip_prefix(',') {
+                    Some(rest) => input = rest,
+                    None if rest.trim().is_empty() => break,
+                    None => bail!(400, "Invalid Sec-WebSocket-Extensions `{}`", value),
+                }
+            }
+        }
+        Ok(Some(Self { extensions }))
+    }
+
+    /// Append an extension.
+    pub fn push(&mut self, extension: WebSocketExtension) {
+        self.extensions.push(extension);
+    }
+
+    /// Get the first extension with a name.
+    pub fn get(&self, name: &str) -> Option<&WebSocketExtension> {
+        self.extensions.iter().find(|ext| ext.name == name)
+    }
+
+    /// Returns `true` if the list contains an extension.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// An iterator visiting all extensions, in order of preference.
+    pub fn iter(&self) -> slice::Iter<'_, WebSocketExtension> {
+        self.extensions.iter()
+    }
+
+    /// Returns the number of extensions.
+    pub fn len(&self) -> usize {
+        self.extensions.len()
+    }
+
+    /// Returns `true` if there are no extensions.
+    pub fn is_empty(&self) -> bool {
+        self.extensions.is_empty()
+    }
+}
+
+impl Header for SecWebSocketExtensions {
+    fn header_name(&self) -> HeaderName {
+        SEC_WEBSOCKET_EXTENSIONS
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, extension) in self.extensions.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", extension).unwrap(),
+                _ => write!(output, ", {}", extension).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal strings are validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl IntoIterator for SecWebSocketExtensions {
+    type Item = WebSocketExtension;
+    type IntoIter = std::vec::IntoIter<WebSocketExtension>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.extensions.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SecWebSocketExtensions {
+    type Item = &'a WebSocketExtension;
+    type IntoIter = slice::Iter<'a, WebSocketExtension>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A single extension in a `Sec-WebSocket-Extensions` header, with its
+/// parameters.
+///
+/// ```txt
+/// extension = extension-token *( ";" extension-param )
+/// extension-param = token [ "=" ( token | quoted-string ) ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebSocketExtension {
+    name: String,
+    params: Vec<(String, Option<String>)>,
+}
+
+impl WebSocketExtension {
+    /// Create a new instance of `WebSocketExtension`.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the name isn't a token.
+    pub fn new(name: &str) -> crate::Result<Self> {
+        if !is_token(name) {
+            bail!(400, "Invalid WebSocket extension `{}`", name);
+        }
+        Ok(Self {
+            name: name.to_owned(),
+            params: vec![],
+        })
+    }
+
+    /// Parse an extension, returning the unparsed remainder of the input.
+    fn parse(input: &str) -> crate::Result<(Self, &str)> {
+        let (name, mut rest) = match parse_token(input.trim_start()) {
+            (Some(name), rest) => (name, rest),
+            (None, _) => bail!(400, "Invalid WebSocket extension in `{}`", input),
+        };
+        let mut extension = Self::new(name)?;
+
+        while let Some(param) = rest.trim_start().strip_prefix(';') {
+            let (key, after_key) = match parse_token(param.trim_start()) {
+                (Some(key), after_key) => (key, after_key),
+                (None, _) => bail!(400, "Invalid WebSocket extension parameter in `{}`", input),
+            };
+            rest = after_key;
+
+            let mut value = None;
+            if let Some(after_eq) = rest.trim_start().strip_prefix('=') {
+                let after_eq = after_eq.trim_start();
+                let (parsed, after_value) = match parse_token(after_eq) {
+                    (Some(token), after_value) => (token.into(), after_value),
+                    (None, _) => match parse_quoted_string(after_eq) {
+                        (Some(quoted), after_value) => (quoted, after_value),
+                        (None, _) => {
+                            bail!(400, "Invalid WebSocket extension parameter in `{}`", input)
+                        }
+                    },
+                };
+                value = Some(parsed);
+                rest = after_value;
+            }
+            extension.push_param(key, value.as_deref())?;
+        }
+        Ok((extension, rest))
+    }
+
+    /// The name of the extension.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get a parameter.
+    ///
+    /// Returns `Some(None)` if the parameter has no value.
+    pub fn param(&self, name: &str) -> Option<Option<&str>> {
+        self.params
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_deref())
+    }
+
+    /// An iterator visiting all parameters.
+    pub fn params(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.params
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_deref()))
+    }
+
+    /// Append a parameter.
+    ///
+    /// Values that aren't tokens are sent as quoted strings.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the name isn't a token,
+    /// or if the value contains non-printable characters.
+    pub fn push_param(&mut self, name: &str, value: Option<&str>) -> crate::Result<()> {
+        if !is_token(name) {
+            bail!(400, "Invalid WebSocket extension parameter `{}`", name);
+        }
+        if let Some(value) = value {
+            if !value.bytes().all(|b| b == b' ' || b.is_ascii_graphic()) {
+                bail!(
+                    400,
+                    "Invalid WebSocket extension parameter value `{}`",
+                    value
+                );
+            }
+        }
+        self.params.push((name.to_owned(), value.map(String::from)));
+        Ok(())
+    }
+}
+
+impl fmt::Display for WebSocketExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        for (key, value) in &self.params {
+            write!(f, "; {}", key)?;
+            match value {
+                Some(value) if is_token(value) => write!(f, "={}", value)?,
+                Some(value) => {
+                    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+                    write!(f, "=\"{}\"", escaped)?
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_token(s: &str) -> bool {
+    matches!(parse_token(s), (Some(_), ""))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(
+            SEC_WEBSOCKET_EXTENSIONS,
+            "permessage-deflate ; client_max_window_bits=10;server_no_context_takeover",
+        )?;
+        headers.append(SEC_WEBSOCKET_EXTENSIONS, r#"x-a; q="a \"b\"", x-b"#)?;
+
+        let extensions = SecWebSocketExtensions::from_headers(&headers)?.unwrap();
+        let names: Vec<_> = extensions.iter().map(|ext| ext.name()).collect();
+        assert_eq!(names, ["permessage-deflate", "x-a", "x-b"]);
+
+        let deflate = extensions.get("permessage-deflate").unwrap();
+        let params: Vec<_> = deflate.params().collect();
+        assert_eq!(
+            params,
+            [
+                ("client_max_window_bits", Some("10")),
+                ("server_no_context_takeover", None)
+            ]
+        );
+        assert_eq!(
+            extensions.get("x-a").unwrap().param("q"),
+            Some(Some("a \"b\""))
+        );
+        assert_eq!(extensions.get("x-a").unwrap().param("missing"), None);
+
+        assert_eq!(
+            extensions.header_value(),
+            r#"permessage-deflate; client_max_window_bits=10; server_no_context_takeover, x-a; q="a \"b\"", x-b"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        for invalid in &["", "a,", "a;", "a; =1", "a; b=", "a b", "a; b=\"open"] {
+            headers.insert(SEC_WEBSOCKET_EXTENSIONS, *invalid)?;
+            let err = SecWebSocketExtensions::from_headers(&headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", invalid);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, SEC_WEBSOCKET_KEY};
+
+use std::fmt;
+
+/// A nonce sent by a client opening a WebSocket connection.
+///
+/// The key is 16 random bytes, encoded as base64. The server proves it
+/// understood the handshake by answering with the matching
+/// [`SecWebSocketAccept`][super::SecWebSocketAccept].
+///
+/// # Specifications
+///
+/// - [RFC 6455, section 11.3.1: Sec-WebSocket-Key](https://www.rfc-editor.org/rfc/rfc6455#section-11.3.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::upgrade::websocket::SecWebSocketKey;
+/// use http_types::Request;
+///
+/// let key = SecWebSocketKey::new();
+/// assert_eq!(key.as_str().len(), 24);
+///
+/// let mut req = Request::get("https://example.com/chat");
+/// req.insert_header(&key, &key);
+/// assert_eq!(SecWebSocketKey::from_headers(&req)?, Some(key));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecWebSocketKey {
+    key: String,
+}
+
+impl SecWebSocketKey {
+    /// Generate a new random key.
+    pub fn new() -> Self {
+        let nonce = fastrand::u128(..).to_be_bytes();
+        Self {
+            key: base64::encode(nonce),
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if the header is sent
+    /// more than once, or isn't the base64 encoding of 16 bytes.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let values = match headers.as_ref().get(SEC_WEBSOCKET_KEY) {
+            Some(values) => values,
+            None => return Ok(None),
+        };
+        if values.iter().count() > 1 {
+            bail!(400, "Sec-WebSocket-Key must only be sent once");
+        }
+        let key = values.last().as_str().trim();
+        match base64::decode(key) {
+            Ok(nonce) if nonce.len() == 16 => Ok(Some(Self {
+                key: key.to_owned(),
+            })),
+            _ => bail!(400, "Invalid Sec-WebSocket-Key `{}`", key),
+        }
+    }
+
+    /// Get the key as a string.
+    pub fn as_str(&self) -> &str {
+        &self.key
+    }
+}
+
+impl Default for SecWebSocketKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Header for SecWebSocketKey {
+    fn header_name(&self) -> HeaderName {
+        SEC_WEBSOCKET_KEY
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        // SAFETY: base64 is a subset of ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(self.key.clone().into()) }
+    }
+}
+
+impl fmt::Display for SecWebSocketKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.key)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_headers() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        assert_eq!(SecWebSocketKey::from_headers(&headers)?, None);
+
+        headers.insert(SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")?;
+        let key = SecWebSocketKey::from_headers(&headers)?.unwrap();
+        assert_eq!(key.as_str(), "dGhlIHNhbXBsZSBub25jZQ==");
+
+        headers.append(SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")?;
+        let err = SecWebSocketKey::from_headers(&headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+
+        for invalid in &[
+            "",
+            "not base64!",
+            "c2hvcnQ=",
+            "dGhpcyBub25jZSBpcyB0b28gbG9uZw==",
+        ] {
+            headers.insert(SEC_WEBSOCKET_KEY, *invalid)?;
+            let err = SecWebSocketKey::from_headers(&headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", invalid);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn generated_keys_are_unique() {
+        assert_ne!(SecWebSocketKey::new(), SecWebSocketKey::new());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, SEC_WEBSOCKET_PROTOCOL};
+
+use std::fmt::Write;
+use std::slice;
+
+/// The subprotocols a WebSocket client offers, or the one a server selected.
+///
+/// # Specifications
+///
+/// - [RFC 6455, section 11.3.4: Sec-WebSocket-Protocol](https://www.rfc-editor.org/rfc/rfc6455#section-11.3.4)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::upgrade::websocket::SecWebSocketProtocol;
+/// use http_types::headers::Header;
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com/chat");
+/// req.insert_header("Sec-WebSocket-Protocol", "chat.v2, chat");
+///
+/// let offered = SecWebSocketProtocol::from_headers(&req)?.unwrap();
+/// assert!(offered.contains("chat"));
+///
+/// let mut selected = SecWebSocketProtocol::new();
+/// selected.push("chat")?;
+/// assert_eq!(selected.header_value(), "chat");
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SecWebSocketProtocol {
+    protocols: Vec<String>,
+}
+
+impl SecWebSocketProtocol {
+    /// Create a new, empty instance of `SecWebSocketProtocol`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Protocols from multiple headers are combined in order.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if a protocol isn't a
+    /// token.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let values = match headers.as_ref().get(SEC_WEBSOCKET_PROTOCOL) {
+            Some(values) => values,
+            None => return Ok(None),
+        };
+
+        let mut protocols = Self::new();
+        for value in values {
+            for protocol in value.as_str().split(',') {
+                protocols.push(protocol.trim())?;
+            }
+        }
+        Ok(Some(protocols))
+    }
+
+    /// Append a protocol.
+    ///
+    /// # Errors
+    ///
+    /// This method errors with `400: Bad Request` if the protocol isn't a
+    /// token.
+    pub fn push(&mut self, protocol: &str) -> crate::Result<()> {
+        if !matches!(crate::parse_utils::parse_token(protocol), (Some(_), "")) {
+            bail!(400, "Invalid WebSocket subprotocol `{}`", protocol);
+        }
+        self.protocols.push(protocol.to_owned());
+        Ok(())
+    }
+
+    /// Returns `true` if the list contains a protocol.
+    ///
+    /// Subprotocol names are case-sensitive.
+    pub fn contains(&self, protocol: &str) -> bool {
+        self.protocols.iter().any(|p| p == protocol)
+    }
+
+    /// An iterator visiting all protocols, in order of preference.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.protocols.iter().map(String::as_str)
+    }
+
+    /// Returns the number of protocols.
+    pub fn len(&self) -> usize {
+        self.protocols.len()
+    }
+
+    /// Returns `true` if there are no protocols.
+    pub fn is_empty(&self) -> bool {
+        self.protocols.is_empty()
+    }
+}
+
+impl Header for SecWebSocketProtocol {
+    fn header_name(&self) -> HeaderName {
+        SEC_WEBSOCKET_PROTOCOL
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, protocol) in self.protocols.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", protocol).unwrap(),
+                _ => write!(output, ", {}", protocol).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal strings are validated to be tokens.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl IntoIterator for SecWebSocketProtocol {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.protocols.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SecWebSocketProtocol {
+    type Item = &'a String;
+    type IntoIter = slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.protocols.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_headers() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(SEC_WEBSOCKET_PROTOCOL, "chat.v2, chat")?;
+        headers.append(SEC_WEBSOCKET_PROTOCOL, "mqtt")?;
+        let protocols = SecWebSocketProtocol::from_headers(&headers)?.unwrap();
+        assert_eq!(
+            protocols.iter().collect::<Vec<_>>(),
+            ["chat.v2", "chat", "mqtt"]
+        );
+        assert!(!protocols.contains("MQTT"));
+        assert_eq!(protocols.header_value(), "chat.v2, chat, mqtt");
+
+        for invalid in &["", "chat,", "chat v2", "\"chat\""] {
+            headers.insert(SEC_WEBSOCKET_PROTOCOL, *invalid)?;
+            let err = SecWebSocketProtocol::from_headers(&headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", invalid);
+        }
+        Ok(())
+    }
+}
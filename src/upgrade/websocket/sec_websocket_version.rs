@@ -0,0 +1,147 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, SEC_WEBSOCKET_VERSION};
+
+use std::fmt::Write;
+
+/// The WebSocket protocol version defined by RFC 6455.
+pub(super) const WEBSOCKET_VERSION: u8 = 13;
+
+/// The versions of the WebSocket protocol a client wants to use, or a server
+/// supports.
+///
+/// Clients send a single version. Servers that don't support it answer with
+/// `426: Upgrade Required`, listing the versions they do support.
+///
+/// # Specifications
+///
+/// - [RFC 6455, section 11.3.5: Sec-WebSocket-Version](https://www.rfc-editor.org/rfc/rfc6455#section-11.3.5)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::upgrade::websocket::SecWebSocketVersion;
+/// use http_types::Response;
+///
+/// let version = SecWebSocketVersion::new();
+/// assert_eq!(version.version(), Some(13));
+///
+/// let mut res = Response::new(426);
+/// res.insert_header("Sec-WebSocket-Version", "13, 8, 7");
+/// let versions = SecWebSocketVersion::from_headers(&res)?.unwrap();
+/// assert!(versions.contains(13));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecWebSocketVersion {
+    versions: Vec<u8>,
+}
+
+impl SecWebSocketVersion {
+    /// Create a new instance containing version 13, the version defined by
+    /// RFC 6455.
+    pub fn new() -> Self {
+        Self::with_versions(&[WEBSOCKET_VERSION])
+    }
+
+    /// Create a new instance containing a list of versions.
+    pub fn with_versions(versions: &[u8]) -> Self {
+        Self {
+            versions: versions.to_vec(),
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// This function errors with `400: Bad Request` if a version isn't an
+    /// integer between 0 and 255.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let values = match headers.as_ref().get(SEC_WEBSOCKET_VERSION) {
+            Some(values) => values,
+            None => return Ok(None),
+        };
+
+        let mut versions = vec![];
+        for value in values {
+            for version in value.as_str().split(',') {
+                let version = version.trim();
+                let digits = !version.is_empty() && version.bytes().all(|b| b.is_ascii_digit());
+                let canonical = version == "0" || !version.starts_with('0');
+                match version.parse() {
+                    Ok(version) if digits && canonical => versions.push(version),
+                    _ => bail!(400, "Invalid Sec-WebSocket-Version `{}`", version),
+                }
+            }
+        }
+        Ok(Some(Self { versions }))
+    }
+
+    /// The first version in the list.
+    pub fn version(&self) -> Option<u8> {
+        self.versions.first().copied()
+    }
+
+    /// Returns `true` if the list contains a version.
+    pub fn contains(&self, version: u8) -> bool {
+        self.versions.contains(&version)
+    }
+
+    /// An iterator visiting all versions.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.versions.iter().copied()
+    }
+}
+
+impl Default for SecWebSocketVersion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Header for SecWebSocketVersion {
+    fn header_name(&self) -> HeaderName {
+        SEC_WEBSOCKET_VERSION
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, version) in self.versions.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", version).unwrap(),
+                _ => write!(output, ", {}", version).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_headers() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(SEC_WEBSOCKET_VERSION, "13")?;
+        let version = SecWebSocketVersion::from_headers(&headers)?.unwrap();
+        assert_eq!(version, SecWebSocketVersion::new());
+
+        headers.append(SEC_WEBSOCKET_VERSION, "8, 0")?;
+        let versions = SecWebSocketVersion::from_headers(&headers)?.unwrap();
+        assert_eq!(versions.iter().collect::<Vec<_>>(), [13, 8, 0]);
+        assert_eq!(versions.header_value(), "13, 8, 0");
+
+        for invalid in &["", "256", "013", "-1", "13,", "v13"] {
+            headers.insert(SEC_WEBSOCKET_VERSION, *invalid)?;
+            let err = SecWebSocketVersion::from_headers(&headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", invalid);
+        }
+        Ok(())
+    }
+}
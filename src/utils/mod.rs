@@ -1,4 +1,5 @@
 mod date;
+pub(crate) mod structured_field;
 
 pub(crate) use date::fmt_http_date;
 pub(crate) use date::parse_http_date;
@@ -0,0 +1,473 @@
+//! A minimal parser and serializer for Structured Field Values.
+//!
+//! Only lists of items are supported; inner lists and dictionaries are not
+//! used by any of the headers in this crate.
+//!
+//! - [RFC 8941: Structured Field Values for HTTP](https://www.rfc-editor.org/rfc/rfc8941)
+
+use crate::bail_status as bail;
+use crate::headers::{HeaderName, HeaderValue, Headers};
+
+use std::fmt::{self, Display, Write};
+
+/// A bare item.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum BareItem {
+    Integer(i64),
+    Decimal(f64),
+    String(String),
+    Token(String),
+    ByteSequence(Vec<u8>),
+    Boolean(bool),
+}
+
+impl BareItem {
+    /// Create a token if `s` is a valid token, or a string otherwise.
+    pub(crate) fn token_or_string(s: &str) -> Self {
+        if is_token(s) {
+            Self::Token(s.to_owned())
+        } else {
+            Self::String(s.to_owned())
+        }
+    }
+
+    /// Get the value of a token or string.
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) | Self::Token(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Get the value of an integer.
+    pub(crate) fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a boolean.
+    pub(crate) fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+impl Display for BareItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{}", n),
+            Self::Decimal(n) => {
+                let s = format!("{:.3}", n);
+                let s = s.trim_end_matches('0');
+                match s.strip_suffix('.') {
+                    Some(s) => write!(f, "{}.0", s),
+                    None => f.write_str(s),
+                }
+            }
+            Self::String(s) => {
+                // Strings can only hold printable ASCII, so anything else is
+                // replaced rather than producing an invalid header.
+                f.write_char('"')?;
+                for c in s.chars() {
+                    match c {
+                        '"' | '\\' => write!(f, "\\{}", c)?,
+                        ' '..='~' => f.write_char(c)?,
+                        _ => f.write_char('?')?,
+                    }
+                }
+                f.write_char('"')
+            }
+            Self::Token(s) => f.write_str(s),
+            Self::ByteSequence(bytes) => write!(f, ":{}:", base64::encode(bytes)),
+            Self::Boolean(true) => f.write_str("?1"),
+            Self::Boolean(false) => f.write_str("?0"),
+        }
+    }
+}
+
+/// An item with its parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Item {
+    pub(crate) bare_item: BareItem,
+    pub(crate) params: Vec<(String, BareItem)>,
+}
+
+impl Item {
+    /// Create a new item without parameters.
+    pub(crate) fn new(bare_item: BareItem) -> Self {
+        Self {
+            bare_item,
+            params: vec![],
+        }
+    }
+
+    /// Append a parameter.
+    pub(crate) fn push_param(&mut self, key: &str, value: BareItem) {
+        self.params.push((key.to_owned(), value));
+    }
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.bare_item)?;
+        for (key, value) in &self.params {
+            match value {
+                BareItem::Boolean(true) => write!(f, ";{}", key)?,
+                value => write!(f, ";{}={}", key, value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse the items of all values of a list header, in order.
+pub(crate) fn parse_header(
+    headers: &Headers,
+    name: HeaderName,
+) -> crate::Result<Option<Vec<Item>>> {
+    let values = match headers.get(name) {
+        Some(values) => values,
+        None => return Ok(None),
+    };
+    let mut items = vec![];
+    for value in values {
+        items.extend(parse_list(value.as_str())?);
+    }
+    Ok(Some(items))
+}
+
+/// Serialize a list of items as a header value.
+pub(crate) fn header_value(items: impl IntoIterator<Item = Item>) -> HeaderValue {
+    let items: Vec<Item> = items.into_iter().collect();
+    let output = fmt_list(&items);
+
+    // SAFETY: structured fields are always serialized as ASCII.
+    unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+}
+
+/// Serialize a list of items.
+pub(crate) fn fmt_list<'a>(items: impl IntoIterator<Item = &'a Item>) -> String {
+    let mut output = String::new();
+    for (n, item) in items.into_iter().enumerate() {
+        if n > 0 {
+            output.push_str(", ");
+        }
+        write!(output, "{}", item).unwrap();
+    }
+    output
+}
+
+/// Parse a list of items.
+pub(crate) fn parse_list(input: &str) -> crate::Result<Vec<Item>> {
+    let mut parser = Parser {
+        input: input.trim_matches(' ').as_bytes(),
+        pos: 0,
+    };
+    let mut items = vec![];
+    while !parser.is_empty() {
+        items.push(parser.parse_item()?);
+        parser.skip_ows();
+        if parser.is_empty() {
+            break;
+        }
+        if parser.next() != Some(b',') {
+            bail!(400, "Expected a `,` between list members");
+        }
+        parser.skip_ows();
+        if parser.is_empty() {
+            bail!(400, "Unexpected trailing `,` in list");
+        }
+    }
+    Ok(items)
+}
+
+/// Parse a single bare item, such as the value of a parameter.
+pub(crate) fn parse_bare_item(input: &str) -> crate::Result<BareItem> {
+    let mut parser = Parser {
+        input: input.as_bytes(),
+        pos: 0,
+    };
+    let item = parser.parse_bare_item()?;
+    if !parser.is_empty() {
+        bail!(400, "Unexpected characters after structured field item");
+    }
+    Ok(item)
+}
+
+/// Validate the key of a parameter, and parse its serialized value.
+pub(crate) fn parse_param(key: &str, value: &str) -> crate::Result<BareItem> {
+    if !is_key(key) {
+        bail!(400, "Invalid parameter key `{}`", key);
+    }
+    parse_bare_item(value)
+}
+
+/// Get the serialized value of a parameter.
+pub(crate) fn get_param(params: &[(String, BareItem)], key: &str) -> Option<String> {
+    params
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, value)| value.to_string())
+}
+
+/// Set a parameter from its serialized value, replacing any existing value.
+pub(crate) fn set_param(
+    params: &mut Vec<(String, BareItem)>,
+    key: &str,
+    value: &str,
+) -> crate::Result<()> {
+    let value = parse_param(key, value)?;
+    match params.iter_mut().find(|(k, _)| k == key) {
+        Some((_, v)) => *v = value,
+        None => params.push((key.to_owned(), value)),
+    }
+    Ok(())
+}
+
+/// Check the type of a parameter value of the named header.
+pub(crate) fn expect_param<T>(header: &str, key: &str, value: Option<T>) -> crate::Result<T> {
+    match value {
+        Some(value) => Ok(value),
+        None => bail!(400, "Invalid value for {} parameter `{}`", header, key),
+    }
+}
+
+/// Returns `true` if `s` is a valid token.
+pub(crate) fn is_token(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    match bytes.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == b'*' => {}
+        _ => return false,
+    }
+    bytes.all(|c| is_tchar(c) || c == b':' || c == b'/')
+}
+
+/// Returns `true` if `s` is a valid key.
+pub(crate) fn is_key(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    match bytes.next() {
+        Some(c) if c.is_ascii_lowercase() || c == b'*' => {}
+        _ => return false,
+    }
+    bytes.all(is_key_char)
+}
+
+fn is_key_char(c: u8) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, b'_' | b'-' | b'.' | b'*')
+}
+
+fn is_tchar(c: u8) -> bool {
+    c.is_ascii_alphanumeric()
+        || matches!(
+            c,
+            b'!' | b'#'
+                | b'$'
+                | b'%'
+                | b'&'
+                | b'\''
+                | b'*'
+                | b'+'
+                | b'-'
+                | b'.'
+                | b'^'
+                | b'_'
+                | b'`'
+                | b'|'
+                | b'~'
+        )
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn is_empty(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn skip_ows(&mut self) {
+        while matches!(self.peek(), Some(b' ') | Some(b'\t')) {
+            self.pos += 1;
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if f(c)) {
+            self.pos += 1;
+        }
+        // Only ASCII bytes are accepted by the callers, so this is valid UTF-8.
+        std::str::from_utf8(&self.input[start..self.pos]).unwrap()
+    }
+
+    fn parse_item(&mut self) -> crate::Result<Item> {
+        if self.peek() == Some(b'(') {
+            bail!(400, "Inner lists are not supported");
+        }
+        let bare_item = self.parse_bare_item()?;
+        let mut params = vec![];
+        while self.peek() == Some(b';') {
+            self.pos += 1;
+            while self.peek() == Some(b' ') {
+                self.pos += 1;
+            }
+            let key = self.parse_key()?;
+            let value = if self.peek() == Some(b'=') {
+                self.pos += 1;
+                self.parse_bare_item()?
+            } else {
+                BareItem::Boolean(true)
+            };
+            match params.iter_mut().find(|(k, _)| k == &key) {
+                Some((_, v)) => *v = value,
+                None => params.push((key, value)),
+            }
+        }
+        Ok(Item { bare_item, params })
+    }
+
+    fn parse_key(&mut self) -> crate::Result<String> {
+        match self.peek() {
+            Some(c) if c.is_ascii_lowercase() || c == b'*' => {}
+            _ => bail!(400, "Expected a parameter key"),
+        }
+        Ok(self.take_while(is_key_char).to_owned())
+    }
+
+    fn parse_bare_item(&mut self) -> crate::Result<BareItem> {
+        match self.peek() {
+            Some(c) if c == b'-' || c.is_ascii_digit() => self.parse_number(),
+            Some(b'"') => self.parse_string(),
+            Some(c) if c.is_ascii_alphabetic() || c == b'*' => {
+                let token = self.take_while(|c| is_tchar(c) || c == b':' || c == b'/');
+                Ok(BareItem::Token(token.to_owned()))
+            }
+            Some(b':') => {
+                self.pos += 1;
+                let encoded = self.take_while(|c| {
+                    c.is_ascii_alphanumeric() || c == b'+' || c == b'/' || c == b'='
+                });
+                if self.next() != Some(b':') {
+                    bail!(400, "Expected a closing `:` for byte sequence");
+                }
+                match base64::decode(encoded) {
+                    Ok(bytes) => Ok(BareItem::ByteSequence(bytes)),
+                    Err(_) => bail!(400, "Invalid byte sequence"),
+                }
+            }
+            Some(b'?') => {
+                self.pos += 1;
+                match self.next() {
+                    Some(b'1') => Ok(BareItem::Boolean(true)),
+                    Some(b'0') => Ok(BareItem::Boolean(false)),
+                    _ => bail!(400, "Invalid boolean"),
+                }
+            }
+            _ => bail!(400, "Expected a structured field item"),
+        }
+    }
+
+    fn parse_number(&mut self) -> crate::Result<BareItem> {
+        let negative = self.peek() == Some(b'-');
+        if negative {
+            self.pos += 1;
+        }
+        let number = self.take_while(|c| c.is_ascii_digit() || c == b'.');
+        let sign = if negative { -1 } else { 1 };
+        match number.split_once('.') {
+            None if !number.is_empty() && number.len() <= 15 => match number.parse::<i64>() {
+                Ok(n) => Ok(BareItem::Integer(sign * n)),
+                Err(_) => bail!(400, "Invalid integer"),
+            },
+            Some((int, frac))
+                if !int.is_empty() && int.len() <= 12 && (1..=3).contains(&frac.len()) =>
+            {
+                match number.parse::<f64>() {
+                    Ok(n) => Ok(BareItem::Decimal(sign as f64 * n)),
+                    Err(_) => bail!(400, "Invalid decimal"),
+                }
+            }
+            _ => bail!(400, "Invalid number"),
+        }
+    }
+
+    fn parse_string(&mut self) -> crate::Result<BareItem> {
+        self.pos += 1;
+        let mut output = String::new();
+        loop {
+            match self.next() {
+                Some(b'\\') => match self.next() {
+                    Some(c @ b'"') | Some(c @ b'\\') => output.push(c as char),
+                    _ => bail!(400, "Invalid escape in string"),
+                },
+                Some(b'"') => return Ok(BareItem::String(output)),
+                Some(c) if (0x20..0x7f).contains(&c) => output.push(c as char),
+                _ => bail!(400, "Invalid string"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() -> crate::Result<()> {
+        let items =
+            parse_list(r#"ExampleCache; hit; ttl=-30; key="a \"b\"", Other;n=1.25;b=?0;s=:aGk=:"#)?;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].bare_item, BareItem::Token("ExampleCache".into()));
+        assert_eq!(
+            items[0].params,
+            vec![
+                ("hit".into(), BareItem::Boolean(true)),
+                ("ttl".into(), BareItem::Integer(-30)),
+                ("key".into(), BareItem::String(r#"a "b""#.into())),
+            ]
+        );
+        assert_eq!(
+            items[1].params,
+            vec![
+                ("n".into(), BareItem::Decimal(1.25)),
+                ("b".into(), BareItem::Boolean(false)),
+                ("s".into(), BareItem::ByteSequence(b"hi".to_vec())),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn serialize() -> crate::Result<()> {
+        let input = r#"ExampleCache;hit;ttl=-30;key="a \"b\"", Other;n=1.25;b=?0;s=:aGk=:"#;
+        assert_eq!(fmt_list(&parse_list(input)?), input);
+        assert_eq!(BareItem::Decimal(2.0).to_string(), "2.0");
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse_list("a,").is_err());
+        assert!(parse_list("a b").is_err());
+        assert!(parse_list(r#""unterminated"#).is_err());
+        assert!(parse_list("a;Key=1").is_err());
+        assert!(parse_list("(a b)").is_err());
+        assert!(parse_list("1.2345").is_err());
+    }
+}